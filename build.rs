@@ -0,0 +1,40 @@
+//! Detects the language features of the compiler that functions of the crate
+//! can use when available, while still building on the MSRV; see the MSRV
+//! section of the README.
+
+use std::{env, process::Command};
+
+/// Configuration flags, each with the minor Rust version that enables it.
+const FLAGS: [(&str, u32); 2] = [
+    // Floating point arithmetic in `const fn`.
+    ("material_colors_const_float_arithmetic", 82),
+    // `const` float methods such as `f64::abs`.
+    ("material_colors_const_float_methods", 85),
+];
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+
+    let minor = rustc_minor_version().unwrap_or(0);
+
+    for (flag, since) in FLAGS {
+        println!("cargo:rustc-check-cfg=cfg({flag})");
+
+        if minor >= since {
+            println!("cargo:rustc-cfg={flag}");
+        }
+    }
+}
+
+fn rustc_minor_version() -> Option<u32> {
+    let rustc = env::var_os("RUSTC")?;
+    let output = Command::new(rustc).arg("--version").output().ok()?;
+    let version = String::from_utf8(output.stdout).ok()?;
+    let mut pieces = version.split('.');
+
+    if pieces.next()? != "rustc 1" {
+        return None;
+    }
+
+    pieces.next()?.parse().ok()
+}
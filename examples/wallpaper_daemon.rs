@@ -0,0 +1,248 @@
+//! Watches a wallpaper and keeps a directory of theme files in sync with it.
+//!
+//! ```text
+//! cargo run --example wallpaper-daemon --features cli -- \
+//!     <wallpaper> <output-dir> [--format <name>]... [--template <path>]...
+//!     [--variant <name>] [--interval <seconds>] [--once]
+//! ```
+//!
+//! Every `--format` names an exporter of [`ExporterRegistry::with_builtin`],
+//! written to `<name>-light`, `<name>-dark` and, if the format supports it,
+//! `<name>-theme` files. Every `--template` is copied to the output directory
+//! with `{{source}}`, `{{light.<role>}}` and `{{dark.<role>}}` placeholders
+//! replaced by hex colors, e.g. `{{dark.on_primary}}`.
+//!
+//! The wallpaper is polled for modifications, and the theme is only
+//! regenerated when [`DominantColorWatch`] reports a meaningful change.
+
+use material_colors::{
+    bundle::{BundleOptions, ThemeBundle},
+    dynamic_color::Variant,
+    export::ExporterRegistry,
+    image::{FilterType, ImageReader},
+    quantize::{Quantizer, QuantizerCelebi},
+    score::Score,
+    theme::Theme,
+    watch::DominantColorWatch,
+};
+use std::{
+    env, fs, panic,
+    path::{Path, PathBuf},
+    process,
+    thread::sleep,
+    time::{Duration, SystemTime},
+};
+
+struct Args {
+    wallpaper: PathBuf,
+    output: PathBuf,
+    formats: Vec<String>,
+    templates: Vec<PathBuf>,
+    variant: Variant,
+    interval: Duration,
+    once: bool,
+}
+
+fn parse_variant(name: &str) -> Option<Variant> {
+    Some(match name {
+        "monochrome" => Variant::Monochrome,
+        "neutral" => Variant::Neutral,
+        "tonal-spot" => Variant::TonalSpot,
+        "vibrant" => Variant::Vibrant,
+        "expressive" => Variant::Expressive,
+        "fidelity" => Variant::Fidelity,
+        "content" => Variant::Content,
+        "rainbow" => Variant::Rainbow,
+        "fruit-salad" => Variant::FruitSalad,
+        _ => return None,
+    })
+}
+
+fn parse_args() -> Result<Args, String> {
+    let mut args = env::args().skip(1);
+    let mut positional = Vec::new();
+    let mut parsed = Args {
+        wallpaper: PathBuf::new(),
+        output: PathBuf::new(),
+        formats: Vec::new(),
+        templates: Vec::new(),
+        variant: Variant::TonalSpot,
+        interval: Duration::from_secs(2),
+        once: false,
+    };
+
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or(format!("missing value for {arg}"));
+
+        match arg.as_str() {
+            "--format" => parsed.formats.push(value()?),
+            "--template" => parsed.templates.push(value()?.into()),
+            "--variant" => {
+                let name = value()?;
+
+                parsed.variant = parse_variant(&name).ok_or(format!("unknown variant {name}"))?;
+            }
+            "--interval" => {
+                let seconds = value()?;
+
+                parsed.interval = Duration::from_secs_f64(
+                    seconds
+                        .parse()
+                        .map_err(|_| format!("invalid interval {seconds}"))?,
+                );
+            }
+            "--once" => parsed.once = true,
+            _ if arg.starts_with("--") => return Err(format!("unknown option {arg}")),
+            _ => positional.push(PathBuf::from(arg)),
+        }
+    }
+
+    match <[PathBuf; 2]>::try_from(positional) {
+        Ok([wallpaper, output]) => {
+            parsed.wallpaper = wallpaper;
+            parsed.output = output;
+
+            Ok(parsed)
+        }
+        Err(_) => Err("expected <wallpaper> and <output-dir>".into()),
+    }
+}
+
+fn render_template(template: &str, theme: &Theme) -> String {
+    let mut output = template.replace("{{source}}", &theme.source.to_hex_with_pound());
+
+    for (mode, scheme) in [
+        ("light", &theme.schemes.light),
+        ("dark", &theme.schemes.dark),
+    ] {
+        for (role, color) in scheme.clone() {
+            output = output.replace(
+                &format!("{{{{{mode}.{role}}}}}"),
+                &color.to_hex_with_pound(),
+            );
+        }
+    }
+
+    output
+}
+
+fn write(path: &Path, contents: &str) {
+    if let Err(error) = fs::write(path, contents) {
+        eprintln!("failed to write {}: {error}", path.display());
+    }
+}
+
+fn regenerate(args: &Args, options: &BundleOptions, watch: &mut DominantColorWatch) {
+    // The wallpaper may be caught half-written, which fails to decode.
+    let mut image = match panic::catch_unwind(|| ImageReader::open(&args.wallpaper)) {
+        Ok(Ok(image)) => image,
+        Ok(Err(error)) => {
+            eprintln!("failed to read wallpaper: {error}");
+
+            return;
+        }
+        Err(_) => {
+            eprintln!("failed to decode wallpaper");
+
+            return;
+        }
+    };
+
+    image.resize(128, 128, FilterType::Lanczos3);
+
+    let result = QuantizerCelebi::quantize_iter(image.view().pixels(), 128);
+    let seed = Score::score(&result.color_to_count, None, None, None)[0];
+
+    if !watch.update(&result, seed) {
+        println!("wallpaper changed, theme did not");
+
+        return;
+    }
+
+    let bundle = ThemeBundle::generate(seed, options);
+
+    for artifact in &bundle.exports {
+        let name = &artifact.name;
+
+        write(&args.output.join(format!("{name}-light")), &artifact.light);
+        write(&args.output.join(format!("{name}-dark")), &artifact.dark);
+
+        if let Some(theme) = &artifact.theme {
+            write(&args.output.join(format!("{name}-theme")), theme);
+        }
+    }
+
+    for template in &args.templates {
+        match (fs::read_to_string(template), template.file_name()) {
+            (Ok(contents), Some(file_name)) => write(
+                &args.output.join(file_name),
+                &render_template(&contents, &bundle.theme),
+            ),
+            _ => eprintln!("failed to read template {}", template.display()),
+        }
+    }
+
+    for (report, mode) in [
+        (&bundle.light_contrast, "light"),
+        (&bundle.dark_contrast, "dark"),
+    ] {
+        for check in report.failing(3.0) {
+            println!(
+                "{mode}: {:?} on {:?} has a contrast ratio of {:.2}",
+                check.foreground, check.background, check.ratio
+            );
+        }
+    }
+
+    println!("theme regenerated from {}", seed.to_hex_with_pound());
+}
+
+fn main() {
+    let args = parse_args().unwrap_or_else(|error| {
+        eprintln!("{error}");
+        process::exit(2);
+    });
+
+    let mut registry = ExporterRegistry::with_builtin();
+    let mut options = BundleOptions {
+        variant: args.variant.clone(),
+        ..BundleOptions::default()
+    };
+
+    for format in &args.formats {
+        match registry.remove(format) {
+            Some(exporter) => options.exports.push(exporter),
+            None => {
+                let names = registry.names().collect::<Vec<_>>().join(", ");
+
+                eprintln!("unknown format {format}, available: {names}");
+                process::exit(2);
+            }
+        }
+    }
+
+    if let Err(error) = fs::create_dir_all(&args.output) {
+        eprintln!("failed to create {}: {error}", args.output.display());
+        process::exit(1);
+    }
+
+    let mut watch = DominantColorWatch::default();
+    let mut last_modified = None::<SystemTime>;
+
+    loop {
+        let modified = fs::metadata(&args.wallpaper)
+            .and_then(|metadata| metadata.modified())
+            .ok();
+
+        if modified.is_some() && modified != last_modified {
+            last_modified = modified;
+            regenerate(&args, &options, &mut watch);
+        }
+
+        if args.once {
+            break;
+        }
+
+        sleep(args.interval);
+    }
+}
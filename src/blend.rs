@@ -7,6 +7,8 @@ use crate::{
     utils::math::{difference_degrees, rotate_direction, sanitize_degrees_double},
 };
 
+/// Rotates the hue of `design_color` towards that of `source_color` by at
+/// most 15 degrees, keeping its chroma, tone and alpha.
 pub fn harmonize(design_color: Argb, source_color: Argb) -> Argb {
     let from_hct: Hct = design_color.into();
     let to_hct: Hct = source_color.into();
@@ -19,9 +21,16 @@ pub fn harmonize(design_color: Argb, source_color: Argb) -> Argb {
         from_hct.get_hue(),
     ));
 
-    Hct::from(output_hue, from_hct.get_chroma(), from_hct.get_tone()).into()
+    Argb::from(Hct::from(
+        output_hue,
+        from_hct.get_chroma(),
+        from_hct.get_tone(),
+    ))
+    .with_alpha(design_color.alpha)
 }
 
+/// Blends the hue of `from` towards that of `to` in CAM16-UCS by `amount`,
+/// keeping the chroma, tone and alpha of `from`.
 pub fn hct_hue(from: Argb, to: Argb, amount: f64) -> Argb {
     let ucs = cam16_ucs(from, to, amount);
 
@@ -30,9 +39,11 @@ pub fn hct_hue(from: Argb, to: Argb, amount: f64) -> Argb {
 
     let blended = Hct::from(ucs_cam.hue, from_cam.chroma, from.as_lstar());
 
-    blended.into()
+    Argb::from(blended).with_alpha(from.alpha)
 }
 
+/// Blends `from` towards `to` in CAM16-UCS by `amount`, from 0.0 (`from`) to
+/// 1.0 (`to`). Alpha is interpolated linearly.
 pub fn cam16_ucs(from: Argb, to: Argb, amount: f64) -> Argb {
     let from_cam = Cam16::from(from);
     let to_cam = Cam16::from(to);
@@ -51,13 +62,150 @@ pub fn cam16_ucs(from: Argb, to: Argb, amount: f64) -> Argb {
         (to_b - from_b).mul_add(amount, from_b),
     );
 
-    Cam16::from_ucs(jstar, astar, bstar).into()
+    let alpha = (f64::from(to.alpha) - f64::from(from.alpha))
+        .mul_add(amount, f64::from(from.alpha))
+        .round()
+        .clamp(0.0, 255.0) as u8;
+
+    Argb::from(Cam16::from_ucs(jstar, astar, bstar)).with_alpha(alpha)
+}
+
+/// Combines a user-chosen accent with a seed extracted from the wallpaper into
+/// the seed of the final theme.
+///
+/// The colors are blended in CAM16-UCS, then the chroma of the result is
+/// raised to `min_chroma` if needed, keeping its hue and tone, so that mixing
+/// with a muted wallpaper can't wash out the theme.
+///
+/// - `accent`: Color chosen by the user
+/// - `wallpaper`: Seed extracted from the wallpaper
+/// - `accent_weight`: Weight of `accent`, from 0.0 (wallpaper only) to 1.0
+///   (accent only); values outside are clamped
+/// - `min_chroma`: Chroma floor of the result
+///
+/// Alpha is interpolated as by [`cam16_ucs`].
+pub fn mix_seed(accent: Argb, wallpaper: Argb, accent_weight: f64, min_chroma: f64) -> Argb {
+    let mixed = Hct::new(cam16_ucs(wallpaper, accent, accent_weight.clamp(0.0, 1.0)));
+
+    if mixed.get_chroma() >= min_chroma {
+        return mixed.into();
+    }
+
+    Argb::from(Hct::from(mixed.get_hue(), min_chroma, mixed.get_tone()))
+        .with_alpha(mixed.get_alpha())
+}
+
+/// Multiplies the color channels of `color` by its alpha, as stored by
+/// premultiplied pixel formats and compositors.
+pub fn premultiply(color: Argb) -> Argb {
+    let multiply = |channel: u8| ((u32::from(channel) * u32::from(color.alpha) + 127) / 255) as u8;
+
+    Argb::new(
+        color.alpha,
+        multiply(color.red),
+        multiply(color.green),
+        multiply(color.blue),
+    )
+}
+
+/// Inverse of [`premultiply`], up to rounding. Transparent colors become
+/// transparent black, since their color channels are lost.
+pub fn unpremultiply(color: Argb) -> Argb {
+    if color.alpha == 0 {
+        return Argb::new(0, 0, 0, 0);
+    }
+
+    let divide = |channel: u8| {
+        let channel =
+            (u32::from(channel) * 255 + u32::from(color.alpha) / 2) / u32::from(color.alpha);
+
+        if channel > 255 {
+            255
+        } else {
+            channel as u8
+        }
+    };
+
+    Argb::new(
+        color.alpha,
+        divide(color.red),
+        divide(color.green),
+        divide(color.blue),
+    )
+}
+
+/// Composites `foreground` over `background` with the Porter-Duff source
+/// over operator, in gamma-encoded sRgb as browsers and most compositors do.
+///
+/// Both colors and the result have straight, not premultiplied, alpha. The
+/// result is opaque if `background` is, and transparent black if both colors
+/// are transparent.
+///
+/// ```
+/// use material_colors::{blend::composite_over, color::Argb};
+///
+/// let scrim = Argb::from_u32(0x80000000);
+///
+/// assert_eq!(
+///     composite_over(scrim, Argb::from_u32(0xffffffff)),
+///     Argb::from_u32(0xff7f7f7f)
+/// );
+/// ```
+pub fn composite_over(foreground: Argb, background: Argb) -> Argb {
+    let foreground_alpha = u32::from(foreground.alpha);
+    let background_alpha = u32::from(background.alpha) * (255 - foreground_alpha);
+    // Alpha of the result, scaled by 255.
+    let alpha = foreground_alpha * 255 + background_alpha;
+
+    if alpha == 0 {
+        return Argb::new(0, 0, 0, 0);
+    }
+
+    let channel = |front: u8, back: u8| {
+        let premultiplied =
+            u32::from(front) * foreground_alpha * 255 + u32::from(back) * background_alpha;
+
+        ((premultiplied + alpha / 2) / alpha) as u8
+    };
+
+    Argb::new(
+        ((alpha + 127) / 255) as u8,
+        channel(foreground.red, background.red),
+        channel(foreground.green, background.green),
+        channel(foreground.blue, background.blue),
+    )
+}
+
+/// Composites `foreground` over `background` like [`composite_over`], for
+/// colors with premultiplied alpha, e.g. pixels of a premultiplied buffer.
+pub fn composite_over_premultiplied(foreground: Argb, background: Argb) -> Argb {
+    let channel = |front: u8, back: u8| {
+        let channel =
+            u32::from(front) + (u32::from(back) * (255 - u32::from(foreground.alpha)) + 127) / 255;
+
+        if channel > 255 {
+            255
+        } else {
+            channel as u8
+        }
+    };
+
+    Argb::new(
+        channel(foreground.alpha, background.alpha),
+        channel(foreground.red, background.red),
+        channel(foreground.green, background.green),
+        channel(foreground.blue, background.blue),
+    )
 }
 
 #[cfg(test)]
 mod tests {
-    use super::hct_hue;
+    use super::{
+        cam16_ucs, composite_over, composite_over_premultiplied, harmonize, hct_hue, mix_seed,
+        premultiply, unpremultiply,
+    };
     use crate::color::Argb;
+    use crate::hct::Hct;
     use core::str::FromStr;
 
     #[test]
@@ -70,4 +218,92 @@ mod tests {
 
         assert_eq!(blended.to_hex(), "905eff");
     }
+
+    #[test]
+    fn test_mix_seed() {
+        let accent = Argb::from_u32(0xff0061a4);
+        let wallpaper = Argb::from_u32(0xff386a20);
+
+        assert_eq!(mix_seed(accent, wallpaper, 1.0, 0.0), accent);
+        assert_eq!(mix_seed(accent, wallpaper, 0.0, 0.0), wallpaper);
+        assert_eq!(mix_seed(accent, wallpaper, -1.0, 0.0), wallpaper);
+
+        let mixed = Hct::new(mix_seed(accent, wallpaper, 0.5, 0.0));
+        let (accent_hue, wallpaper_hue) =
+            (Hct::new(accent).get_hue(), Hct::new(wallpaper).get_hue());
+
+        assert!(mixed.get_hue() > accent_hue.min(wallpaper_hue));
+        assert!(mixed.get_hue() < accent_hue.max(wallpaper_hue));
+
+        let gray = Argb::from_u32(0xff808080);
+        let floored = Hct::new(mix_seed(gray, gray, 0.5, 16.0));
+
+        assert!((floored.get_chroma() - 16.0).abs() < 1.0);
+        assert!((floored.get_tone() - gray.as_lstar()).abs() < 1.0);
+    }
+
+    #[test]
+    fn test_alpha() {
+        let from = Argb::from_u32(0x80ff0000);
+        let to = Argb::from_u32(0x400000ff);
+
+        assert_eq!(harmonize(from, to).alpha, 0x80);
+        assert_eq!(hct_hue(from, to, 0.8).alpha, 0x80);
+        assert_eq!(cam16_ucs(from, to, 0.0).alpha, 0x80);
+        assert_eq!(cam16_ucs(from, to, 0.5).alpha, 0x60);
+        assert_eq!(cam16_ucs(from, to, 1.0).alpha, 0x40);
+        assert_eq!(mix_seed(from, to, 1.0, 0.0), from);
+    }
+
+    #[test]
+    fn test_premultiply() {
+        let color = Argb::from_u32(0x80ff8040);
+
+        assert_eq!(premultiply(color), Argb::from_u32(0x80804020));
+        assert_eq!(unpremultiply(premultiply(color)), color);
+        assert_eq!(
+            premultiply(Argb::from_u32(0xff4285f4)),
+            Argb::from_u32(0xff4285f4)
+        );
+        assert_eq!(
+            unpremultiply(Argb::from_u32(0x00123456)),
+            Argb::from_u32(0x00000000)
+        );
+    }
+
+    #[test]
+    fn test_composite_over() {
+        let white = Argb::from_u32(0xffffffff);
+        let blue = Argb::from_u32(0xff4285f4);
+
+        assert_eq!(composite_over(blue, white), blue);
+        assert_eq!(composite_over(Argb::from_u32(0x00ff0000), white), white);
+        assert_eq!(
+            composite_over(Argb::from_u32(0x80000000), white),
+            Argb::from_u32(0xff7f7f7f)
+        );
+        assert_eq!(
+            composite_over(Argb::from_u32(0x80ff0000), Argb::from_u32(0x800000ff)),
+            Argb::from_u32(0xc0aa0055)
+        );
+        assert_eq!(
+            composite_over(Argb::default(), Argb::default()),
+            Argb::default()
+        );
+    }
+
+    #[test]
+    fn test_composite_over_premultiplied() {
+        let foreground = Argb::from_u32(0x80ff0000);
+        let background = Argb::from_u32(0xff4285f4);
+
+        assert_eq!(
+            composite_over_premultiplied(premultiply(foreground), premultiply(background)),
+            composite_over(foreground, background)
+        );
+        assert_eq!(
+            composite_over_premultiplied(Argb::from_u32(0x80800000), Argb::from_u32(0x800000ff)),
+            Argb::from_u32(0xc080007f)
+        );
+    }
 }
@@ -0,0 +1,203 @@
+//! One-call generation of everything most applications need from a seed:
+//! the theme, the palettes its schemes were built from, contrast reports and
+//! rendered exports.
+
+use crate::{
+    color::Argb,
+    contrast::ContrastReport,
+    dynamic_color::{DynamicScheme, Variant},
+    export::{ExportOptions, Exporter},
+    hct::Hct,
+    quantize::{Quantizer, QuantizerCelebi},
+    score::Score,
+    theme::{CustomColor, Palettes, Theme, ThemeBuilder},
+};
+#[cfg(not(feature = "std"))]
+use alloc::{boxed::Box, string::String, vec::Vec};
+#[cfg(feature = "std")]
+use std::{boxed::Box, string::String, vec::Vec};
+
+/// Input a [`ThemeBundle`] is generated from.
+#[derive(Debug, Clone)]
+pub enum SeedInput {
+    /// A source color used as-is.
+    Color(Hct),
+    /// Pixels of an image, whose source color is extracted the same way as
+    /// by [`ImageReader::extract_color`].
+    ///
+    /// [`ImageReader::extract_color`]: crate::image::ImageReader::extract_color
+    Pixels(Vec<Argb>),
+}
+
+impl From<Argb> for SeedInput {
+    fn from(color: Argb) -> Self {
+        Self::Color(Hct::new(color))
+    }
+}
+
+impl From<Hct> for SeedInput {
+    fn from(color: Hct) -> Self {
+        Self::Color(color)
+    }
+}
+
+impl From<Vec<Argb>> for SeedInput {
+    fn from(pixels: Vec<Argb>) -> Self {
+        Self::Pixels(pixels)
+    }
+}
+
+/// An export rendered for both schemes of a theme.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExportArtifact {
+    /// [`Exporter::name`] of the exporter.
+    pub name: String,
+    pub light: String,
+    pub dark: String,
+    /// Both schemes in one document, see [`Exporter::export_theme`].
+    pub theme: Option<String>,
+}
+
+/// Options of [`ThemeBundle::generate`].
+#[derive(Debug, Default)]
+pub struct BundleOptions {
+    pub variant: Variant,
+    pub custom_colors: Vec<CustomColor>,
+    /// Exports rendered into [`ThemeBundle::exports`], in this order.
+    pub exports: Vec<Box<dyn Exporter>>,
+    pub export_options: ExportOptions,
+}
+
+/// A theme together with everything derived from it.
+#[derive(Debug)]
+pub struct ThemeBundle {
+    pub theme: Theme,
+    /// Palettes the light and dark schemes were built from.
+    ///
+    /// Unlike [`Theme::palettes`], these follow the variant of the theme.
+    pub palettes: Palettes,
+    pub light_contrast: ContrastReport,
+    pub dark_contrast: ContrastReport,
+    pub exports: Vec<ExportArtifact>,
+}
+
+impl ThemeBundle {
+    /// Generates a theme from `input`, then its palettes, contrast reports
+    /// and the exports requested in `options`.
+    ///
+    /// `options` is borrowed, so that it can be reused when the theme is
+    /// regenerated, e.g. whenever the wallpaper changes.
+    pub fn generate<T: Into<SeedInput>>(input: T, options: &BundleOptions) -> Self {
+        let source = match input.into() {
+            SeedInput::Color(color) => color,
+            SeedInput::Pixels(pixels) => {
+                let result = QuantizerCelebi::quantize(&pixels, 128);
+
+                Hct::new(Score::score(&result.color_to_count, None, None, None)[0])
+            }
+        };
+
+        let scheme = DynamicScheme::by_variant(source, &options.variant, false, None);
+        let palettes = Palettes {
+            primary: scheme.primary_palette,
+            secondary: scheme.secondary_palette,
+            tertiary: scheme.tertiary_palette,
+            neutral: scheme.neutral_palette,
+            neutral_variant: scheme.neutral_variant_palette,
+            error: scheme.error_palette,
+        };
+
+        let theme = ThemeBuilder::with_source(source)
+            .variant(options.variant.clone())
+            .custom_colors(options.custom_colors.clone())
+            .build();
+
+        let exports = options
+            .exports
+            .iter()
+            .map(|exporter| ExportArtifact {
+                name: exporter.name().into(),
+                light: exporter.export(&theme.schemes.light, &options.export_options),
+                dark: exporter.export(&theme.schemes.dark, &options.export_options),
+                theme: exporter.export_theme(&theme, &options.export_options),
+            })
+            .collect();
+
+        Self {
+            light_contrast: ContrastReport::new(&theme.schemes.light),
+            dark_contrast: ContrastReport::new(&theme.schemes.dark),
+            palettes,
+            exports,
+            theme,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BundleOptions, SeedInput, ThemeBundle};
+    use crate::{color::Argb, dynamic_color::Variant, theme::ThemeBuilder};
+    #[cfg(not(feature = "std"))]
+    use alloc::vec;
+    #[cfg(feature = "std")]
+    use std::vec;
+
+    #[test]
+    fn test_generate_from_color() {
+        let source = Argb::from_u32(0xff6750a4);
+        let bundle = ThemeBundle::generate(
+            source,
+            &BundleOptions {
+                variant: Variant::Vibrant,
+                ..BundleOptions::default()
+            },
+        );
+        let theme = ThemeBuilder::with_source(source)
+            .variant(Variant::Vibrant)
+            .build();
+
+        assert_eq!(bundle.theme.source, source);
+        assert_eq!(bundle.theme.schemes.light, theme.schemes.light);
+        assert_eq!(
+            bundle.palettes.primary.tone(40),
+            bundle.theme.schemes.light.primary
+        );
+        assert!(bundle.light_contrast.passes(3.0));
+        assert!(bundle.dark_contrast.passes(3.0));
+        assert!(bundle.exports.is_empty());
+    }
+
+    #[test]
+    fn test_generate_from_pixels() {
+        let pixels = vec![Argb::from_u32(0xff0000ff); 64];
+        let bundle = ThemeBundle::generate(SeedInput::Pixels(pixels), &BundleOptions::default());
+
+        assert_eq!(bundle.theme.source, Argb::from_u32(0xff0000ff));
+    }
+
+    #[cfg(feature = "slint")]
+    #[test]
+    fn test_generate_exports() {
+        use crate::export::slint::{to_global, SlintExporter};
+        #[cfg(not(feature = "std"))]
+        use alloc::boxed::Box;
+        #[cfg(feature = "std")]
+        use std::boxed::Box;
+
+        let bundle = ThemeBundle::generate(
+            Argb::from_u32(0xff6750a4),
+            &BundleOptions {
+                exports: vec![Box::new(SlintExporter::default())],
+                ..BundleOptions::default()
+            },
+        );
+
+        assert_eq!(bundle.exports.len(), 1);
+        assert_eq!(bundle.exports[0].name, "slint");
+        assert_eq!(
+            bundle.exports[0].light,
+            to_global(&bundle.theme.schemes.light, "Palette")
+        );
+        assert_eq!(bundle.exports[0].theme, None);
+    }
+}
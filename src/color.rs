@@ -1,20 +1,20 @@
 #[cfg(all(not(feature = "std"), feature = "libm"))]
 #[allow(unused_imports)]
 use crate::utils::no_std::FloatExt;
-use crate::{utils::math::matrix_multiply, Error};
-#[cfg(not(feature = "std"))]
-use alloc::{
-    format,
-    string::{String, ToString},
+#[cfg(feature = "serde")]
+use crate::utils::precision;
+use crate::{
+    hct::{Cam16, Hct, ViewingConditions},
+    utils::math::{matrix_multiply, sanitize_degrees_double},
+    Error,
 };
-use core::{fmt, str::FromStr};
+#[cfg(not(feature = "std"))]
+use alloc::{format, string::String, vec::Vec};
+use core::{cmp::Ordering, fmt, mem, str::FromStr};
 #[cfg(feature = "serde")]
 use serde::Serialize;
 #[cfg(feature = "std")]
-use std::{
-    format,
-    string::{String, ToString},
-};
+use std::{format, string::String, vec::Vec};
 
 pub const SRGB_TO_XYZ: [[f64; 3]; 3] = [
     [0.41233895, 0.35762064, 0.18051042],
@@ -34,16 +34,319 @@ pub const XYZ_TO_SRGB: [[f64; 3]; 3] = [
         1.0571799111220335,
     ],
 ];
+const REC_2020_ALPHA: f64 = 1.09929682680944;
+const REC_2020_BETA: f64 = 0.018053968510807;
 pub const WHITE_POINT_D65: [f64; 3] = [95.047, 100.0, 108.883];
+pub const WHITE_POINT_D50: [f64; 3] = [96.422, 100.0, 82.521];
+pub const XYZ_TO_BRADFORD: [[f64; 3]; 3] = [
+    [0.8951, 0.2664, -0.1614],
+    [-0.7502, 1.7135, 0.0367],
+    [0.0389, -0.0685, 1.0296],
+];
+pub const BRADFORD_TO_XYZ: [[f64; 3]; 3] = [
+    [0.9869929054667121, -0.1470542564209901, 0.15996265166373125],
+    [0.4323052697233945, 0.5183602715367776, 0.049291228212855615],
+    [-0.008528664575177331, 0.04004282165408486, 0.96848669578755],
+];
+pub const XYZ_TO_CAT16: [[f64; 3]; 3] = [
+    [0.401288, 0.650173, -0.051461],
+    [-0.250268, 1.204414, 0.045854],
+    [-0.002079, 0.048952, 0.953127],
+];
+pub const CAT16_TO_XYZ: [[f64; 3]; 3] = [
+    [1.8620678550872327, -1.0112546305316843, 0.14918677544445172],
+    [
+        0.38752654323613717,
+        0.6214474419314754,
+        -0.008973985167612518,
+    ],
+    [
+        -0.015841498849333856,
+        -0.03412293802851556,
+        1.0499644368778493,
+    ],
+];
+pub const DISPLAY_P3_TO_XYZ: [[f64; 3]; 3] = [
+    [0.48663265000000006, 0.2656631625, 0.19817418749999996],
+    [0.22900360000000003, 0.6917267249999999, 0.07926967499999998],
+    [0.0, 0.04511261250000004, 1.0437173874999999],
+];
+pub const XYZ_TO_DISPLAY_P3: [[f64; 3]; 3] = [
+    [
+        2.4931807553289667,
+        -0.9312655254971399,
+        -0.40265972375888176,
+    ],
+    [-0.829503115821079, 1.762694121119793, 0.02362508874173959],
+    [
+        0.03585362578007171,
+        -0.07618895478265221,
+        0.9570926215180217,
+    ],
+];
+pub const REC_2020_TO_XYZ: [[f64; 3]; 3] = [
+    [0.6370101914111008, 0.14461502739696927, 0.16884478119192986],
+    [0.26272171736164046, 0.6779892755022618, 0.0592890071360975],
+    [0.0, 0.028072328847646908, 1.060757671152353],
+];
+pub const XYZ_TO_REC_2020: [[f64; 3]; 3] = [
+    [
+        1.7165106697619736,
+        -0.3556416699867159,
+        -0.25334554182190727,
+    ],
+    [
+        -0.6666930011826243,
+        1.6165022083469107,
+        0.015768750389995017,
+    ],
+    [
+        0.01764363876745901,
+        -0.04277978166904462,
+        0.9423050727200186,
+    ],
+];
+
+/// Chromatic adaptation transform used by [`adapt`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChromaticAdaptation {
+    /// The Bradford transform, used by ICC profiles.
+    Bradford,
+    /// The CAT16 transform, used by CAM16 and therefore HCT.
+    Cat16,
+}
+
+impl ChromaticAdaptation {
+    const fn matrices(self) -> ([[f64; 3]; 3], [[f64; 3]; 3]) {
+        match self {
+            Self::Bradford => (XYZ_TO_BRADFORD, BRADFORD_TO_XYZ),
+            Self::Cat16 => (XYZ_TO_CAT16, CAT16_TO_XYZ),
+        }
+    }
+}
+
+/// Transfer function relating encoded channel values to linear light, both
+/// normalized to 0.0..=1.0.
+///
+/// Values below zero, as found in extended-range wide-gamut colors, are
+/// mirrored around zero.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TransferFunction {
+    /// The piecewise sRGB function, also used by Display P3, see
+    /// [`srgb_decode`].
+    Srgb,
+    /// A pure power function with the given exponent, e.g. 2.2 for displays
+    /// approximating sRGB, see [`gamma_decode`].
+    Gamma(f64),
+    /// Values already in linear light.
+    Linear,
+    /// The piecewise function of Rec. 2020, see [`rec2020_decode`].
+    Rec2020,
+}
+
+impl TransferFunction {
+    /// Converts an encoded channel value to linear light.
+    pub fn decode(self, encoded: f64) -> f64 {
+        match self {
+            Self::Srgb => srgb_decode(encoded),
+            Self::Gamma(exponent) => gamma_decode(encoded, exponent),
+            Self::Linear => encoded,
+            Self::Rec2020 => rec2020_decode(encoded),
+        }
+    }
+
+    /// Converts a channel value in linear light to its encoding.
+    pub fn encode(self, linear: f64) -> f64 {
+        match self {
+            Self::Srgb => srgb_encode(linear),
+            Self::Gamma(exponent) => gamma_encode(linear, exponent),
+            Self::Linear => linear,
+            Self::Rec2020 => rec2020_encode(linear),
+        }
+    }
+}
+
+/// RGB color space, i.e. primaries and transfer function, that a
+/// [`GamutArgb`] is encoded in and that [`HctSolver`](crate::hct::HctSolver)
+/// maps colors into. All of them share the D65 white point.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum Gamut {
+    /// sRGB, the gamut of [`Argb`] and most displays.
+    Srgb,
+    /// Display P3, the gamut of most phones and laptops of the last years,
+    /// with the sRGB transfer function.
+    DisplayP3,
+    /// Rec. 2020, the gamut of UHD television, which few displays cover
+    /// fully.
+    Rec2020,
+}
+
+impl Gamut {
+    /// Matrix converting linear RGB in this gamut to [`Xyz`].
+    pub const fn to_xyz(self) -> [[f64; 3]; 3] {
+        match self {
+            Self::Srgb => SRGB_TO_XYZ,
+            Self::DisplayP3 => DISPLAY_P3_TO_XYZ,
+            Self::Rec2020 => REC_2020_TO_XYZ,
+        }
+    }
+
+    /// Matrix converting [`Xyz`] to linear RGB in this gamut.
+    pub const fn from_xyz(self) -> [[f64; 3]; 3] {
+        match self {
+            Self::Srgb => XYZ_TO_SRGB,
+            Self::DisplayP3 => XYZ_TO_DISPLAY_P3,
+            Self::Rec2020 => XYZ_TO_REC_2020,
+        }
+    }
+
+    /// Transfer function the channels of a [`GamutArgb`] are encoded with.
+    pub const fn transfer_function(self) -> TransferFunction {
+        match self {
+            Self::Srgb | Self::DisplayP3 => TransferFunction::Srgb,
+            Self::Rec2020 => TransferFunction::Rec2020,
+        }
+    }
+}
+
+/// An [`Argb`] whose channels are encoded in `gamut` rather than sRGB.
+///
+/// Colors solved by
+/// [`HctSolver::solve_to_gamut`](crate::hct::HctSolver::solve_to_gamut) are
+/// meant for apps rendering on wide-gamut displays.
+///
+/// ```
+/// use material_colors::{
+///     color::{Argb, Gamut, GamutArgb},
+///     hct::Hct,
+/// };
+///
+/// // Chroma 120 is out of reach of sRGB, but not of Display P3.
+/// let srgb = Hct::from(140.0, 120.0, 80.0);
+/// let p3 = Hct::from_in_gamut(140.0, 120.0, 80.0, Gamut::DisplayP3);
+///
+/// assert_eq!(p3.gamut, Gamut::DisplayP3);
+/// assert!(p3.chroma() > srgb.get_chroma() + 10.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct GamutArgb {
+    pub gamut: Gamut,
+    pub argb: Argb,
+}
+
+impl GamutArgb {
+    pub const fn new(gamut: Gamut, argb: Argb) -> Self {
+        Self { gamut, argb }
+    }
+
+    /// Converts an sRGB color into `gamut`, keeping its alpha. Channels
+    /// outside of `gamut`, which only happen for narrower gamuts than sRGB,
+    /// are clipped.
+    pub fn from_argb(argb: Argb, gamut: Gamut) -> Self {
+        if gamut == Gamut::Srgb {
+            return Self::new(gamut, argb);
+        }
+
+        Self::from_xyz(Xyz::from(argb), gamut).with_alpha(argb.alpha)
+    }
+
+    /// Converts an [`Xyz`] color into `gamut`, clipping channels outside of
+    /// it. The result is opaque.
+    pub fn from_xyz(Xyz { x, y, z }: Xyz, gamut: Gamut) -> Self {
+        let [red, green, blue] = matrix_multiply([x, y, z], gamut.from_xyz());
+
+        Self::from_linear_rgb(LinearRgb { red, green, blue }, gamut)
+    }
+
+    /// Encodes a color in linear RGB of `gamut`, with channels from 0.0 to
+    /// 100.0 like [`linearized`], clipping channels outside of that range.
+    /// The result is opaque.
+    pub fn from_linear_rgb(linear: LinearRgb, gamut: Gamut) -> Self {
+        let transfer_function = gamut.transfer_function();
+        let encode = |channel: f64| {
+            (transfer_function.encode(channel / 100.0) * 255.0)
+                .round()
+                .clamp(0.0, 255.0) as u8
+        };
+
+        Self::new(
+            gamut,
+            Rgb::new(
+                encode(linear.red),
+                encode(linear.green),
+                encode(linear.blue),
+            )
+            .into(),
+        )
+    }
+
+    /// Returns the color with its alpha replaced by `alpha`.
+    #[must_use]
+    pub const fn with_alpha(self, alpha: u8) -> Self {
+        Self::new(self.gamut, self.argb.with_alpha(alpha))
+    }
+
+    /// Decodes the color to linear RGB of its gamut, with channels from 0.0
+    /// to 100.0 like [`linearized`].
+    pub fn to_linear_rgb(&self) -> LinearRgb {
+        let transfer_function = self.gamut.transfer_function();
+        let decode = |channel: u8| transfer_function.decode(f64::from(channel) / 255.0) * 100.0;
+
+        LinearRgb {
+            red: decode(self.argb.red),
+            green: decode(self.argb.green),
+            blue: decode(self.argb.blue),
+        }
+    }
+
+    /// Converts the color to [`Xyz`], dropping alpha.
+    pub fn to_xyz(&self) -> Xyz {
+        let LinearRgb { red, green, blue } = self.to_linear_rgb();
+        let [x, y, z] = matrix_multiply([red, green, blue], self.gamut.to_xyz());
+
+        Xyz::new(x, y, z)
+    }
+
+    /// Converts the color to sRGB, keeping its alpha. Colors outside of
+    /// sRGB are clipped, which changes their hue; map them with
+    /// [`HctSolver`](crate::hct::HctSolver) instead to keep it.
+    pub fn to_argb(&self) -> Argb {
+        if self.gamut == Gamut::Srgb {
+            return self.argb;
+        }
+
+        Argb::from(self.to_xyz()).with_alpha(self.argb.alpha)
+    }
+
+    /// Chroma of the color in CAM16, which, unlike that of [`Hct`], is not
+    /// limited to sRGB.
+    pub fn chroma(&self) -> f64 {
+        let Xyz { x, y, z } = self.to_xyz();
 
+        Cam16::from_xyz_in_viewing_conditions(x, y, z, &ViewingConditions::current()).chroma
+    }
+}
+
+impl From<GamutArgb> for Xyz {
+    fn from(value: GamutArgb) -> Self {
+        value.to_xyz()
+    }
+}
+
+/// Has the layout of `[u8; 3]`, in the order red, green, blue.
 #[derive(Debug, Default, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
+#[repr(C)]
 pub struct Rgb {
     pub red: u8,
     pub green: u8,
     pub blue: u8,
 }
 
+const _: () = assert!(mem::size_of::<Rgb>() == 3 && mem::align_of::<Rgb>() == 1);
+
 /// ARGB representation of color. Can be created using [`Argb::new`], [`Argb::from_u32`] or
 /// [`Argb::from_str`].
 ///
@@ -54,14 +357,31 @@ pub struct Rgb {
 ///
 /// // from_str can accept any valid HEX color
 /// let color = Argb::from_str("abc").unwrap();
+/// let color = Argb::from_str("fabc").unwrap();
 /// let color = Argb::from_str("aabbcc").unwrap();
 /// let color = Argb::from_str("aabbccdd").unwrap();
 /// let color = Argb::from_str("#abc").unwrap();
+/// let color = Argb::from_str("#fabc").unwrap();
 /// let color = Argb::from_str("#aabbcc").unwrap();
 /// let color = Argb::from_str("#aabbccdd").unwrap();
 /// ```
+///
+/// ## Ordering
+///
+/// The derived [`Ord`] implementation compares the components
+/// lexicographically in the order alpha, red, green, blue, which is the same
+/// as comparing the values returned by packing the color into a `u32` as
+/// `0xAARRGGBB`. It is a total order suitable for sorted collections and
+/// deduplication, but it is not perceptually meaningful. To order colors for
+/// display, use [`Argb::cmp_by_luminance`] or [`Argb::cmp_by_hue`].
+///
+/// ## Layout
+///
+/// Has the layout of `[u8; 4]`, in the order alpha, red, green, blue, with
+/// no padding. This is part of the public API and checked at compile time.
 #[derive(Debug, Default, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
+#[repr(C)]
 pub struct Argb {
     pub alpha: u8,
     pub red: u8,
@@ -69,30 +389,75 @@ pub struct Argb {
     pub blue: u8,
 }
 
+const _: () = assert!(mem::size_of::<Argb>() == 4 && mem::align_of::<Argb>() == 1);
+
 #[derive(Debug, Default, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct LinearRgb {
+    #[cfg_attr(feature = "serde", serde(serialize_with = "precision::serialize"))]
     pub red: f64,
+    #[cfg_attr(feature = "serde", serde(serialize_with = "precision::serialize"))]
     pub green: f64,
+    #[cfg_attr(feature = "serde", serde(serialize_with = "precision::serialize"))]
     pub blue: f64,
 }
 
 #[derive(Debug, Default, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct Xyz {
+    #[cfg_attr(feature = "serde", serde(serialize_with = "precision::serialize"))]
     pub x: f64,
+    #[cfg_attr(feature = "serde", serde(serialize_with = "precision::serialize"))]
     pub y: f64,
+    #[cfg_attr(feature = "serde", serde(serialize_with = "precision::serialize"))]
     pub z: f64,
 }
 
 #[derive(Debug, Default, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct Lab {
+    #[cfg_attr(feature = "serde", serde(serialize_with = "precision::serialize"))]
     pub l: f64,
+    #[cfg_attr(feature = "serde", serde(serialize_with = "precision::serialize"))]
     pub a: f64,
+    #[cfg_attr(feature = "serde", serde(serialize_with = "precision::serialize"))]
     pub b: f64,
 }
 
+/// A color in HSL, the cylindrical form of sRgb used by color pickers and
+/// CSS `hsl()`.
+///
+/// `hue` is in degrees, from 0 to 360, while `saturation` and `lightness`
+/// range from 0.0 to 1.0. Unlike the tone of [`Hct`], `lightness` is not
+/// perceptual: yellow and blue of the same lightness differ widely in
+/// contrast.
+#[derive(Debug, Default, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct Hsl {
+    #[cfg_attr(feature = "serde", serde(serialize_with = "precision::serialize"))]
+    pub hue: f64,
+    #[cfg_attr(feature = "serde", serde(serialize_with = "precision::serialize"))]
+    pub saturation: f64,
+    #[cfg_attr(feature = "serde", serde(serialize_with = "precision::serialize"))]
+    pub lightness: f64,
+}
+
+/// A color in HSV, also known as HSB, the cylindrical form of sRgb used by
+/// most color wheels.
+///
+/// `hue` is in degrees, from 0 to 360, while `saturation` and `value` range
+/// from 0.0 to 1.0.
+#[derive(Debug, Default, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct Hsv {
+    #[cfg_attr(feature = "serde", serde(serialize_with = "precision::serialize"))]
+    pub hue: f64,
+    #[cfg_attr(feature = "serde", serde(serialize_with = "precision::serialize"))]
+    pub saturation: f64,
+    #[cfg_attr(feature = "serde", serde(serialize_with = "precision::serialize"))]
+    pub value: f64,
+}
+
 /** Converts a color from Rgb components to Argb format. */
 impl From<Rgb> for Argb {
     fn from(Rgb { red, green, blue }: Rgb) -> Self {
@@ -105,7 +470,7 @@ impl From<Rgb> for Argb {
     }
 }
 
-/** Converts a color from linear Rgb components to Argb format. */
+/** Converts a color from linear Rgb components to Argb format. The result is opaque. */
 impl From<LinearRgb> for Argb {
     fn from(linear: LinearRgb) -> Self {
         let r = delinearized(linear.red);
@@ -116,7 +481,7 @@ impl From<LinearRgb> for Argb {
     }
 }
 
-/** Converts a color from Argb to Xyz. */
+/** Converts a color from Xyz to Argb. The result is opaque. */
 impl From<Xyz> for Argb {
     fn from(Xyz { x, y, z }: Xyz) -> Self {
         let matrix = XYZ_TO_SRGB;
@@ -135,7 +500,7 @@ impl From<Xyz> for Argb {
     }
 }
 
-/** Converts a color from Xyz to Argb. */
+/** Converts a color from Argb to Xyz, dropping alpha. */
 impl From<Argb> for Xyz {
     fn from(
         Argb {
@@ -155,8 +520,15 @@ impl From<Argb> for Xyz {
     }
 }
 
-/** Converts a color represented in Lab color space into an Argb integer. */
+/** Converts a color represented in Lab color space into an Argb integer. The result is opaque. */
 impl From<Lab> for Argb {
+    fn from(lab: Lab) -> Self {
+        Xyz::from(lab).into()
+    }
+}
+
+/** Converts a color represented in Lab color space into Xyz. */
+impl From<Lab> for Xyz {
     fn from(Lab { l, a, b }: Lab) -> Self {
         let white_point = WHITE_POINT_D65;
 
@@ -172,10 +544,28 @@ impl From<Lab> for Argb {
         let y = y_normalized * white_point[1];
         let z = z_normalized * white_point[2];
 
-        Xyz::new(x, y, z).into()
+        Self::new(x, y, z)
+    }
+}
+
+/** Converts a color represented in Xyz into Lab. */
+impl From<Xyz> for Lab {
+    fn from(Xyz { x, y, z }: Xyz) -> Self {
+        let white_point = WHITE_POINT_D65;
+
+        let fx = lab_f(x / white_point[0]);
+        let fy = lab_f(y / white_point[1]);
+        let fz = lab_f(z / white_point[2]);
+
+        let l = 116.0f64.mul_add(fy, -16.0);
+        let a = 500.0 * (fx - fy);
+        let b = 200.0 * (fy - fz);
+
+        Self { l, a, b }
     }
 }
 
+/** Converts a color from Argb to Lab, dropping alpha. */
 impl From<Argb> for Lab {
     fn from(
         Argb {
@@ -226,35 +616,248 @@ impl From<Argb> for Lab {
 
 const HASH: char = '#';
 
+/// Thresholds of a 4x4 ordered dithering matrix, in sixteenths.
+const BAYER_4X4: [[u8; 4]; 4] = [[0, 8, 2, 10], [12, 4, 14, 6], [3, 11, 1, 9], [15, 7, 13, 5]];
+
+/// Returns the hue, in degrees, and the chroma, from 0.0 to 1.0, shared by
+/// HSL and HSV, along with the largest and smallest channels.
+fn hue_and_chroma(
+    Argb {
+        red, green, blue, ..
+    }: Argb,
+) -> (f64, f64, f64, f64) {
+    let max = red.max(green).max(blue);
+    let min = red.min(green).min(blue);
+    let [r, g, b, max_f, min_f] = [red, green, blue, max, min].map(|c| f64::from(c) / 255.0);
+    let chroma = max_f - min_f;
+
+    let hue = if max == min {
+        0.0
+    } else if max == red {
+        60.0 * ((g - b) / chroma)
+    } else if max == green {
+        60.0 * ((b - r) / chroma + 2.0)
+    } else {
+        60.0 * ((r - g) / chroma + 4.0)
+    };
+
+    (sanitize_degrees_double(hue), chroma, max_f, min_f)
+}
+
+/// Converts `channel`, from 0.0 to 1.0, to 8 bits.
+fn to_channel(channel: f64) -> u8 {
+    (channel * 255.0).round().clamp(0.0, 255.0) as u8
+}
+
+/// Converts a color from Argb to HSL, dropping alpha.
+impl From<Argb> for Hsl {
+    fn from(argb: Argb) -> Self {
+        let (hue, chroma, max, min) = hue_and_chroma(argb);
+        let lightness = (max + min) / 2.0;
+        let saturation = if chroma == 0.0 {
+            0.0
+        } else {
+            chroma / (1.0 - 2.0f64.mul_add(lightness, -1.0).abs())
+        };
+
+        Self::new(hue, saturation, lightness)
+    }
+}
+
+/// Converts a color from HSL to Argb. The result is opaque; hue wraps around
+/// and saturation and lightness are clamped to their ranges.
+impl From<Hsl> for Argb {
+    fn from(hsl: Hsl) -> Self {
+        let hue = sanitize_degrees_double(hsl.hue);
+        let saturation = hsl.saturation.clamp(0.0, 1.0);
+        let lightness = hsl.lightness.clamp(0.0, 1.0);
+        let a = saturation * lightness.min(1.0 - lightness);
+        let channel = |n: f64| {
+            let k = (n + hue / 30.0) % 12.0;
+
+            to_channel(lightness - a * (k - 3.0).min(9.0 - k).clamp(-1.0, 1.0))
+        };
+
+        Rgb::new(channel(0.0), channel(8.0), channel(4.0)).into()
+    }
+}
+
+/// Converts a color from Argb to HSV, dropping alpha.
+impl From<Argb> for Hsv {
+    fn from(argb: Argb) -> Self {
+        let (hue, chroma, max, _) = hue_and_chroma(argb);
+        let saturation = if max == 0.0 { 0.0 } else { chroma / max };
+
+        Self::new(hue, saturation, max)
+    }
+}
+
+/// Converts a color from HSV to Argb. The result is opaque; hue wraps around
+/// and saturation and value are clamped to their ranges.
+impl From<Hsv> for Argb {
+    fn from(hsv: Hsv) -> Self {
+        let hue = sanitize_degrees_double(hsv.hue);
+        let saturation = hsv.saturation.clamp(0.0, 1.0);
+        let value = hsv.value.clamp(0.0, 1.0);
+        let channel = |n: f64| {
+            let k = (n + hue / 60.0) % 6.0;
+
+            to_channel(value - value * saturation * k.min(4.0 - k).clamp(0.0, 1.0))
+        };
+
+        Rgb::new(channel(5.0), channel(3.0), channel(1.0)).into()
+    }
+}
+
+impl From<Hsl> for Hsv {
+    fn from(hsl: Hsl) -> Self {
+        let value = hsl
+            .saturation
+            .mul_add(hsl.lightness.min(1.0 - hsl.lightness), hsl.lightness);
+        let saturation = if value == 0.0 {
+            0.0
+        } else {
+            2.0 * (1.0 - hsl.lightness / value)
+        };
+
+        Self::new(hsl.hue, saturation, value)
+    }
+}
+
+impl From<Hsv> for Hsl {
+    fn from(hsv: Hsv) -> Self {
+        let lightness = hsv.value * (1.0 - hsv.saturation / 2.0);
+        // Distance of the lightness to black or white, whichever is nearest.
+        let extent = lightness.min(1.0 - lightness);
+        let saturation = if extent <= 0.0 {
+            0.0
+        } else {
+            (hsv.value - lightness) / extent
+        };
+
+        Self::new(hsv.hue, saturation, lightness)
+    }
+}
+
+/// Converts `value` to the bits of the nearest IEEE 754 half-precision float,
+/// rounding ties away from zero. Values beyond the half range become
+/// infinite.
+fn to_f16_bits(value: f64) -> u16 {
+    let bits = (value as f32).to_bits();
+    let sign = ((bits >> 16) & 0x8000) as u16;
+    let exponent = ((bits >> 23) & 0xff) as i32 - 127 + 15;
+    let mantissa = bits & 0x7f_ffff;
+
+    if value.is_nan() {
+        return sign | 0x7e00;
+    }
+
+    if exponent >= 31 {
+        return sign | 0x7c00;
+    }
+
+    if exponent <= 0 {
+        let shift = 14 - exponent;
+
+        if shift > 24 {
+            return sign;
+        }
+
+        let mantissa = mantissa | 0x80_0000;
+        let rounding = (mantissa >> (shift - 1)) & 1;
+
+        return sign | ((mantissa >> shift) + rounding) as u16;
+    }
+
+    // A carry out of the mantissa correctly bumps the exponent.
+    let half = (exponent as u32) << 10 | mantissa >> 13;
+
+    sign | (half + ((mantissa >> 12) & 1)) as u16
+}
+
+/// Converts the bits of an IEEE 754 half-precision float to its value.
+fn from_f16_bits(bits: u16) -> f64 {
+    let sign = u64::from(bits >> 15) << 63;
+    let exponent = u64::from((bits >> 10) & 0x1f);
+    let mantissa = u64::from(bits & 0x3ff);
+
+    match exponent {
+        0 => {
+            let magnitude = mantissa as f64 / 16_777_216.0;
+
+            if sign == 0 {
+                magnitude
+            } else {
+                -magnitude
+            }
+        }
+        31 if mantissa == 0 => f64::from_bits(sign | 0x7ff0_0000_0000_0000),
+        31 => f64::NAN,
+        _ => f64::from_bits(sign | (exponent + 1023 - 15) << 52 | mantissa << 42),
+    }
+}
+
 impl FromStr for Argb {
     type Err = Error;
 
+    /// Parses a hexadecimal color.
+    ///
+    /// Accepted forms, with an optional leading `#`:
+    ///
+    /// - `RGB`, expanded to `RRGGBB` with opaque alpha
+    /// - `ARGB`, expanded to `AARRGGBB`
+    /// - `RRGGBB`, with opaque alpha
+    /// - `AARRGGBB`
+    ///
+    /// Note that alpha comes first, matching [`Argb::from_u32`], unlike the
+    /// `#RGBA` and `#RRGGBBAA` forms of CSS. Digits are case-insensitive and
+    /// surrounding whitespace is ignored. Anything else, including signs and
+    /// whitespace between the `#` and the digits, is rejected.
     fn from_str(hex: &str) -> Result<Self, Self::Err> {
+        let hex = hex.trim();
         let hex = hex.strip_prefix(HASH).unwrap_or(hex);
 
-        if ![3, 6, 8].contains(&hex.len()) {
+        if !hex.bytes().all(|byte| byte.is_ascii_hexdigit()) {
             return Err(Error::ParseRGB);
         }
 
-        let hex_str = if hex.len() == 3 {
-            format!(
-                "FF{a}{a}{b}{b}{c}{c}",
-                a = hex.get(..1).unwrap(),
-                b = hex.get(1..2).unwrap(),
-                c = hex.get(2..3).unwrap()
-            )
-        } else if hex.len() == 6 {
-            format!("FF{hex}")
-        } else {
-            hex.to_string()
-        };
+        let digits = u32::from_str_radix(hex, 16).map_err(|_| Error::ParseRGB)?;
+        let expand = |shift: u32| ((digits >> shift) & 0xf) * 0x11;
 
-        let hex_digit = u32::from_str_radix(&hex_str, 16).map_err(|_| Error::ParseRGB)?;
+        let value = match hex.len() {
+            3 => 0xff00_0000 | expand(8) << 16 | expand(4) << 8 | expand(0),
+            4 => expand(12) << 24 | expand(8) << 16 | expand(4) << 8 | expand(0),
+            6 => 0xff00_0000 | digits,
+            8 => digits,
+            _ => return Err(Error::ParseRGB),
+        };
 
-        Ok(Self::from_u32(hex_digit))
+        Ok(Self::from_u32(value))
     }
 }
 
+/// Creates an [`Argb`] from a hexadecimal string literal at compile time,
+/// with [`Argb::from_hex`]. Invalid colors fail the build.
+///
+/// ```
+/// use material_colors::{argb, color::Argb};
+///
+/// assert_eq!(argb!("#4285f4"), Argb::from_u32(0xff4285f4));
+/// assert_eq!(argb!("8f00"), Argb::from_u32(0x88ff0000));
+/// ```
+///
+/// ```compile_fail
+/// let color = material_colors::argb!("#4285g4");
+/// ```
+#[macro_export]
+macro_rules! argb {
+    ($hex:literal) => {{
+        const ARGB: $crate::color::Argb = $crate::color::Argb::from_hex($hex);
+
+        ARGB
+    }};
+}
+
 impl Xyz {
     pub const fn new(x: f64, y: f64, z: f64) -> Self {
         Self { x, y, z }
@@ -267,6 +870,26 @@ impl Lab {
     }
 }
 
+impl Hsl {
+    pub const fn new(hue: f64, saturation: f64, lightness: f64) -> Self {
+        Self {
+            hue,
+            saturation,
+            lightness,
+        }
+    }
+}
+
+impl Hsv {
+    pub const fn new(hue: f64, saturation: f64, value: f64) -> Self {
+        Self {
+            hue,
+            saturation,
+            value,
+        }
+    }
+}
+
 impl Rgb {
     pub const fn new(red: u8, green: u8, blue: u8) -> Self {
         Self { red, green, blue }
@@ -292,6 +915,218 @@ impl Argb {
         }
     }
 
+    /// Parses a hexadecimal color in a constant context, in the forms
+    /// accepted by [`Argb::from_str`], except that surrounding whitespace is
+    /// rejected. See also the [`argb!`](crate::argb) macro.
+    ///
+    /// ```
+    /// use material_colors::color::Argb;
+    ///
+    /// const SEED: Argb = Argb::from_hex("#4285f4");
+    ///
+    /// assert_eq!(SEED, Argb::from_u32(0xff4285f4));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `hex` is not a valid color, which fails the build when
+    /// evaluated in a constant.
+    pub const fn from_hex(hex: &str) -> Self {
+        const fn digit(byte: u8) -> u32 {
+            (match byte {
+                b'0'..=b'9' => byte - b'0',
+                b'a'..=b'f' => byte - b'a' + 10,
+                b'A'..=b'F' => byte - b'A' + 10,
+                _ => panic!("invalid hexadecimal digit in color"),
+            }) as u32
+        }
+
+        const fn expand(digits: u32, shift: u32) -> u32 {
+            ((digits >> shift) & 0xf) * 0x11
+        }
+
+        let bytes = hex.as_bytes();
+        let start = if !bytes.is_empty() && bytes[0] == HASH as u8 {
+            1
+        } else {
+            0
+        };
+        let mut digits = 0;
+        let mut index = start;
+
+        while index < bytes.len() {
+            digits = digits << 4 | digit(bytes[index]);
+            index += 1;
+        }
+
+        Self::from_u32(match bytes.len() - start {
+            3 => 0xff00_0000 | expand(digits, 8) << 16 | expand(digits, 4) << 8 | expand(digits, 0),
+            4 => {
+                expand(digits, 12) << 24
+                    | expand(digits, 8) << 16
+                    | expand(digits, 4) << 8
+                    | expand(digits, 0)
+            }
+            6 => 0xff00_0000 | digits,
+            8 => digits,
+            _ => panic!("invalid length of hexadecimal color"),
+        })
+    }
+
+    /// Creates an opaque color from a packed RGB565 value, replicating the
+    /// high bits of each channel into its low bits.
+    pub const fn from_rgb565(value: u16) -> Self {
+        let red = ((value >> 11) & 0x1f) as u8;
+        let green = ((value >> 5) & 0x3f) as u8;
+        let blue = (value & 0x1f) as u8;
+
+        Self::new(
+            255,
+            (red << 3) | (red >> 2),
+            (green << 2) | (green >> 4),
+            (blue << 3) | (blue >> 2),
+        )
+    }
+
+    /// Packs the color into RGB565, rounding each channel to the nearest
+    /// level. Alpha is discarded.
+    pub fn to_rgb565(&self) -> u16 {
+        self.quantize_rgb565(0.5)
+    }
+
+    /// Packs the color into RGB565 with 4x4 ordered (Bayer) dithering, where
+    /// `x` and `y` are the coordinates of the pixel on the display.
+    ///
+    /// Averaged over neighbouring pixels, the dithered values reproduce the
+    /// original color, which avoids the banding that rounding causes in
+    /// smooth tonal ramps. Alpha is discarded.
+    pub fn to_rgb565_dithered(&self, x: usize, y: usize) -> u16 {
+        let threshold = (f64::from(BAYER_4X4[y % 4][x % 4]) + 0.5) / 16.0;
+
+        self.quantize_rgb565(threshold)
+    }
+
+    fn quantize_rgb565(self, threshold: f64) -> u16 {
+        let quantize = |component: u8, max: f64| {
+            (f64::from(component) / 255.0)
+                .mul_add(max, threshold)
+                .floor()
+                .min(max) as u16
+        };
+
+        quantize(self.red, 31.0) << 11 | quantize(self.green, 63.0) << 5 | quantize(self.blue, 31.0)
+    }
+
+    /// Creates a color from the bytes of an RGBA8 pixel, in memory order.
+    pub const fn from_rgba8(bytes: [u8; 4]) -> Self {
+        let [red, green, blue, alpha] = bytes;
+
+        Self::new(alpha, red, green, blue)
+    }
+
+    /// Returns the bytes of the color as an RGBA8 pixel, in memory order, as
+    /// expected by `RGBA8Unorm` textures.
+    pub const fn to_rgba8(&self) -> [u8; 4] {
+        [self.red, self.green, self.blue, self.alpha]
+    }
+
+    /// Creates a color from the bytes of a BGRA8 pixel, in memory order.
+    pub const fn from_bgra8(bytes: [u8; 4]) -> Self {
+        let [blue, green, red, alpha] = bytes;
+
+        Self::new(alpha, red, green, blue)
+    }
+
+    /// Returns the bytes of the color as a BGRA8 pixel, in memory order, as
+    /// expected by `BGRA8Unorm` textures and most swapchains.
+    pub const fn to_bgra8(&self) -> [u8; 4] {
+        [self.blue, self.green, self.red, self.alpha]
+    }
+
+    /// Creates a color from a packed ARGB2101010 value, i.e. 2 bits of alpha
+    /// followed by 10 bits per channel from the most significant bit, rounding
+    /// each channel to the nearest 8-bit value.
+    pub const fn from_argb2101010(value: u32) -> Self {
+        const fn channel(value: u32) -> u8 {
+            (((value & 0x3ff) * 255 + 511) / 1023) as u8
+        }
+
+        Self::new(
+            ((value >> 30) * 85) as u8,
+            channel(value >> 20),
+            channel(value >> 10),
+            channel(value),
+        )
+    }
+
+    /// Packs the color into ARGB2101010, the layout of
+    /// `A2R10G10B10_UNORM_PACK32` in Vulkan. Channels are widened exactly by
+    /// replicating their high bits, while alpha is rounded to 2 bits.
+    pub const fn to_argb2101010(&self) -> u32 {
+        const fn channel(component: u8) -> u32 {
+            (component as u32) << 2 | (component as u32) >> 6
+        }
+
+        ((self.alpha as u32 * 3 + 127) / 255) << 30
+            | channel(self.red) << 20
+            | channel(self.green) << 10
+            | channel(self.blue)
+    }
+
+    /// Creates a color from the bits of a linear RGBA16F pixel, see
+    /// [`Argb::to_rgba16f_linear`]. Channels are clamped to 0..=1.
+    pub fn from_rgba16f_linear(pixel: [u16; 4]) -> Self {
+        let [red, green, blue, alpha] = pixel.map(|bits| {
+            let value = from_f16_bits(bits);
+
+            if value.is_nan() {
+                0.0
+            } else {
+                value.clamp(0.0, 1.0)
+            }
+        });
+
+        Self::new(
+            (alpha * 255.0).round() as u8,
+            delinearized(red * 100.0),
+            delinearized(green * 100.0),
+            delinearized(blue * 100.0),
+        )
+    }
+
+    /// Returns the bits of the color as an RGBA16F pixel with linear
+    /// channels from 0 to 1, as sampled by shaders rendering in linear
+    /// light. Alpha is linear already and only normalized.
+    pub fn to_rgba16f_linear(&self) -> [u16; 4] {
+        [
+            to_f16_bits(linearized(self.red) / 100.0),
+            to_f16_bits(linearized(self.green) / 100.0),
+            to_f16_bits(linearized(self.blue) / 100.0),
+            to_f16_bits(f64::from(self.alpha) / 255.0),
+        ]
+    }
+
+    /// Compares colors by perceived lightness (L*), darkest first.
+    ///
+    /// Colors with equal lightness are ordered by the derived [`Ord`]
+    /// implementation, so the result is a total order.
+    pub fn cmp_by_luminance(&self, other: &Self) -> Ordering {
+        self.as_lstar()
+            .total_cmp(&other.as_lstar())
+            .then_with(|| self.cmp(other))
+    }
+
+    /// Compares colors by HCT hue, from 0 up to 360 degrees.
+    ///
+    /// Colors with equal hue, such as grays, are ordered by
+    /// [`Argb::cmp_by_luminance`], so the result is a total order.
+    pub fn cmp_by_hue(&self, other: &Self) -> Ordering {
+        Hct::new(*self)
+            .get_hue()
+            .total_cmp(&Hct::new(*other).get_hue())
+            .then_with(|| self.cmp_by_luminance(other))
+    }
+
     /// Converts an L* value to an Argb representation.
     ///
     /// - `lstar`: L* in L*a*b*
@@ -323,88 +1158,402 @@ impl Argb {
         }
     }
 
-    pub fn to_hex(&self) -> String {
-        format!(
-            "{}{}{}",
-            Self::hex(self.red),
-            Self::hex(self.green),
-            Self::hex(self.blue)
-        )
+    pub fn to_hex(&self) -> String {
+        format!(
+            "{}{}{}",
+            Self::hex(self.red),
+            Self::hex(self.green),
+            Self::hex(self.blue)
+        )
+    }
+
+    pub fn to_hex_with_pound(&self) -> String {
+        format!(
+            "#{}{}{}",
+            Self::hex(self.red),
+            Self::hex(self.green),
+            Self::hex(self.blue)
+        )
+    }
+
+    /// Formats the color as `aarrggbb`, with alpha first like the forms
+    /// parsed by [`Argb::from_str`], so that the output round-trips.
+    ///
+    /// ```
+    /// use core::str::FromStr;
+    /// use material_colors::color::Argb;
+    ///
+    /// let color = Argb::from_u32(0x804285f4);
+    ///
+    /// assert_eq!(color.to_hex_with_alpha(), "804285f4");
+    /// assert_eq!(Argb::from_str(&color.to_hex_with_alpha()), Ok(color));
+    /// ```
+    pub fn to_hex_with_alpha(&self) -> String {
+        format!("{}{}", Self::hex(self.alpha), self.to_hex())
+    }
+
+    /// Returns the color with its alpha replaced by `alpha`.
+    ///
+    /// Conversions to [`Xyz`], [`Lab`], [`Cam16`] and the
+    /// other alpha-less spaces drop alpha, and those back to [`Argb`] are
+    /// opaque, so use this to carry the alpha of the original color over.
+    #[must_use]
+    pub const fn with_alpha(self, alpha: u8) -> Self {
+        Self { alpha, ..self }
+    }
+
+    /// Whether the alpha of the color is 255.
+    pub const fn is_opaque(&self) -> bool {
+        self.alpha == 255
+    }
+}
+
+impl fmt::Display for Argb {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_hex_with_pound())
+    }
+}
+
+/// Converts an L* value to a Y value.
+///
+/// L* in L*a*b* and Y in Xyz measure the same quantity, luminance.
+///
+/// L* measures perceptual luminance, a linear scale. Y in Xyz measures relative luminance, a
+/// logarithmic scale.
+///
+/// - `lstar`: L* in L*a*b*
+///
+/// Returns Y in Xyz
+pub fn y_from_lstar(lstar: f64) -> f64 {
+    100.0 * lab_invf((lstar + 16.0) / 116.0)
+}
+
+/// Converts a Y value to an L* value.
+///
+/// L* in L*a*b* and Y in Xyz measure the same quantity, luminance.
+///
+/// L* measures perceptual luminance, a linear scale. Y in Xyz measures relative luminance, a
+/// logarithmic scale.
+///
+/// - `y`: Y in Xyz
+///
+/// Returns L* in L*a*b*
+pub fn lstar_from_y(y: f64) -> f64 {
+    lab_f(y / 100.0).mul_add(116.0, -16.0)
+}
+
+/// Linearizes an Rgb component.
+///
+/// - `rgb_component`: 0 <= `rgb_component` <= 255, represents R/G/B channel
+///
+/// Returns 0.0 <= output <= 100.0, color channel converted to linear Rgb space
+pub fn linearized(rgb_component: u8) -> f64 {
+    srgb_decode(f64::from(rgb_component) / 255.0) * 100.0
+}
+
+/// Delinearizes an Rgb component.
+///
+/// - `rgb_component`: 0.0 <= `rgb_component` <= 100.0, represents linear R/G/B channel
+///
+/// Returns 0 <= output <= 255, color channel converted to regular Rgb space
+pub fn delinearized(rgb_component: f64) -> u8 {
+    ((srgb_encode(rgb_component / 100.0) * 255.0).round() as u8).clamp(0, 255)
+}
+
+/// Converts an sRGB-encoded channel value to linear light with the exact
+/// piecewise sRGB function, both normalized to 0.0..=1.0. Values below zero
+/// are mirrored around zero.
+pub fn srgb_decode(encoded: f64) -> f64 {
+    let magnitude = encoded.abs();
+    let linear = if magnitude <= 0.040449936 {
+        magnitude / 12.92
+    } else {
+        ((magnitude + 0.055) / 1.055).powf(2.4)
+    };
+
+    linear.copysign(encoded)
+}
+
+/// Inverse of [`srgb_decode`].
+pub fn srgb_encode(linear: f64) -> f64 {
+    let magnitude = linear.abs();
+    let encoded = if magnitude <= 0.0031308 {
+        magnitude * 12.92
+    } else {
+        1.055f64.mul_add(magnitude.powf(1.0 / 2.4), -0.055)
+    };
+
+    encoded.copysign(linear)
+}
+
+/// Converts a channel value encoded with a pure power function of
+/// `exponent` to linear light, both normalized to 0.0..=1.0. Values below
+/// zero are mirrored around zero.
+///
+/// Unlike [`srgb_decode`], there is no linear segment near black, so a
+/// gamma of 2.2 differs from sRGB noticeably in shadows.
+pub fn gamma_decode(encoded: f64, exponent: f64) -> f64 {
+    encoded.abs().powf(exponent).copysign(encoded)
+}
+
+/// Inverse of [`gamma_decode`].
+pub fn gamma_encode(linear: f64, exponent: f64) -> f64 {
+    linear.abs().powf(exponent.recip()).copysign(linear)
+}
+
+/// Converts a channel value encoded with the transfer function of Rec. 2020
+/// to linear light, both normalized to 0.0..=1.0. Values below zero are
+/// mirrored around zero.
+pub fn rec2020_decode(encoded: f64) -> f64 {
+    let magnitude = encoded.abs();
+    let linear = if magnitude < REC_2020_BETA * 4.5 {
+        magnitude / 4.5
+    } else {
+        ((magnitude + REC_2020_ALPHA - 1.0) / REC_2020_ALPHA).powf(1.0 / 0.45)
+    };
+
+    linear.copysign(encoded)
+}
+
+/// Inverse of [`rec2020_decode`].
+pub fn rec2020_encode(linear: f64) -> f64 {
+    let magnitude = linear.abs();
+    let encoded = if magnitude < REC_2020_BETA {
+        magnitude * 4.5
+    } else {
+        REC_2020_ALPHA.mul_add(magnitude.powf(0.45), -(REC_2020_ALPHA - 1.0))
+    };
+
+    encoded.copysign(linear)
+}
+
+/// Packs `pixels` into RGB565 with ordered dithering.
+///
+/// `pixels` are laid out in rows of `width` pixels, so that the dithering
+/// pattern lines up with the display; use a `width` of `pixels.len()` for a
+/// single row, such as a palette ramp. See [`Argb::to_rgb565_dithered`].
+///
+/// # Panics
+///
+/// Will panic if `width` is 0 and `pixels` is not empty.
+pub fn dither_rgb565(pixels: &[Argb], width: usize) -> Vec<u16> {
+    assert!(width > 0 || pixels.is_empty(), "width must not be 0");
+
+    pixels
+        .iter()
+        .enumerate()
+        .map(|(index, pixel)| pixel.to_rgb565_dithered(index % width, index / width))
+        .collect()
+}
+
+/// Adapts a color between illuminants with a von Kries-style transform.
+///
+/// - `xyz`: Color under the `from_white_point` illuminant
+/// - `from_white_point`: Xyz of the source illuminant, e.g. [`WHITE_POINT_D65`]
+/// - `to_white_point`: Xyz of the destination illuminant, e.g. [`WHITE_POINT_D50`]
+/// - `method`: Transform used to model cone responses
+///
+/// Returns the corresponding color under the `to_white_point` illuminant
+pub fn adapt(
+    xyz: Xyz,
+    from_white_point: [f64; 3],
+    to_white_point: [f64; 3],
+    method: ChromaticAdaptation,
+) -> Xyz {
+    let (to_cone, from_cone) = method.matrices();
+
+    let source = matrix_multiply(from_white_point, to_cone);
+    let destination = matrix_multiply(to_white_point, to_cone);
+    let cone = matrix_multiply([xyz.x, xyz.y, xyz.z], to_cone);
+
+    let [x, y, z] = matrix_multiply(
+        [
+            cone[0] * destination[0] / source[0],
+            cone[1] * destination[1] / source[1],
+            cone[2] * destination[2] / source[2],
+        ],
+        from_cone,
+    );
+
+    Xyz::new(x, y, z)
+}
+
+/// Color space tag used to request a [`ColorValue`] conversion at runtime.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ColorSpace {
+    Argb,
+    Rgb,
+    Xyz,
+    Lab,
+    Hct,
+    Cam16,
+    Hsl,
+    Hsv,
+}
+
+/// A color tagged with the space it is expressed in.
+///
+/// Useful when the source and destination spaces are only known at runtime,
+/// e.g. in scripting layers or color tools: any value can be turned into any
+/// other [`ColorSpace`] with [`ColorValue::convert`]. Alpha is only carried by
+/// [`ColorValue::Argb`]; converting through any other space makes the color
+/// opaque.
+#[derive(Debug, Clone, Copy)]
+pub enum ColorValue {
+    Argb(Argb),
+    Rgb(Rgb),
+    Xyz(Xyz),
+    Lab(Lab),
+    Hct(Hct),
+    Cam16(Cam16),
+    Hsl(Hsl),
+    Hsv(Hsv),
+}
+
+impl ColorValue {
+    /// The color space this value is expressed in.
+    pub const fn space(&self) -> ColorSpace {
+        match self {
+            Self::Argb(_) => ColorSpace::Argb,
+            Self::Rgb(_) => ColorSpace::Rgb,
+            Self::Xyz(_) => ColorSpace::Xyz,
+            Self::Lab(_) => ColorSpace::Lab,
+            Self::Hct(_) => ColorSpace::Hct,
+            Self::Cam16(_) => ColorSpace::Cam16,
+            Self::Hsl(_) => ColorSpace::Hsl,
+            Self::Hsv(_) => ColorSpace::Hsv,
+        }
+    }
+
+    /// Converts the value into Argb, rounding to the nearest sRGB color.
+    pub fn to_argb(self) -> Argb {
+        match self {
+            Self::Argb(argb) => argb,
+            Self::Rgb(rgb) => rgb.into(),
+            Self::Xyz(xyz) => xyz.into(),
+            Self::Lab(lab) => lab.into(),
+            Self::Hct(hct) => hct.into(),
+            Self::Cam16(cam16) => cam16.into(),
+            Self::Hsl(hsl) => hsl.into(),
+            Self::Hsv(hsv) => hsv.into(),
+        }
+    }
+
+    /// Converts the value into Xyz without going through 8-bit Argb when the
+    /// source space is continuous.
+    pub fn to_xyz(self) -> Xyz {
+        match self {
+            Self::Argb(argb) => argb.into(),
+            Self::Rgb(rgb) => Argb::from(rgb).into(),
+            Self::Xyz(xyz) => xyz,
+            Self::Lab(lab) => lab.into(),
+            Self::Hct(hct) => Argb::from(hct).into(),
+            Self::Cam16(cam16) => cam16.xyz_in_viewing_conditions(&ViewingConditions::current()),
+            Self::Hsl(hsl) => Argb::from(hsl).into(),
+            Self::Hsv(hsv) => Argb::from(hsv).into(),
+        }
+    }
+
+    /// Converts the value into the `to` color space.
+    ///
+    /// Conversions between Xyz, Lab and Cam16 go through Xyz and keep full
+    /// precision, as do those between Hsl and Hsv; Argb and Rgb are only used
+    /// as an intermediate step when either side is an 8-bit space, Hct, Hsl
+    /// or Hsv.
+    #[must_use]
+    pub fn convert(self, to: ColorSpace) -> Self {
+        if self.space() == to {
+            return self;
+        }
+
+        match to {
+            ColorSpace::Argb => Self::Argb(self.to_argb()),
+            ColorSpace::Rgb => {
+                let argb = self.to_argb();
+
+                Self::Rgb(Rgb::new(argb.red, argb.green, argb.blue))
+            }
+            ColorSpace::Xyz => Self::Xyz(self.to_xyz()),
+            ColorSpace::Lab => Self::Lab(self.to_xyz().into()),
+            ColorSpace::Hct => Self::Hct(match self {
+                Self::Argb(_) | Self::Rgb(_) | Self::Hsl(_) | Self::Hsv(_) => {
+                    Hct::new(self.to_argb())
+                }
+                _ => Lab::from(self.to_xyz()).into(),
+            }),
+            ColorSpace::Cam16 => {
+                let Xyz { x, y, z } = self.to_xyz();
+
+                Self::Cam16(Cam16::from_xyz_in_viewing_conditions(
+                    x,
+                    y,
+                    z,
+                    &ViewingConditions::current(),
+                ))
+            }
+            ColorSpace::Hsl => Self::Hsl(match self {
+                Self::Hsv(hsv) => hsv.into(),
+                _ => self.to_argb().into(),
+            }),
+            ColorSpace::Hsv => Self::Hsv(match self {
+                Self::Hsl(hsl) => hsl.into(),
+                _ => self.to_argb().into(),
+            }),
+        }
+    }
+}
+
+impl From<Argb> for ColorValue {
+    fn from(value: Argb) -> Self {
+        Self::Argb(value)
     }
+}
 
-    pub fn to_hex_with_pound(&self) -> String {
-        format!(
-            "#{}{}{}",
-            Self::hex(self.red),
-            Self::hex(self.green),
-            Self::hex(self.blue)
-        )
+impl From<Rgb> for ColorValue {
+    fn from(value: Rgb) -> Self {
+        Self::Rgb(value)
     }
 }
 
-impl fmt::Display for Argb {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.to_hex_with_pound())
+impl From<Xyz> for ColorValue {
+    fn from(value: Xyz) -> Self {
+        Self::Xyz(value)
     }
 }
 
-/// Converts an L* value to a Y value.
-///
-/// L* in L*a*b* and Y in Xyz measure the same quantity, luminance.
-///
-/// L* measures perceptual luminance, a linear scale. Y in Xyz measures relative luminance, a
-/// logarithmic scale.
-///
-/// - `lstar`: L* in L*a*b*
-///
-/// Returns Y in Xyz
-pub fn y_from_lstar(lstar: f64) -> f64 {
-    100.0 * lab_invf((lstar + 16.0) / 116.0)
+impl From<Lab> for ColorValue {
+    fn from(value: Lab) -> Self {
+        Self::Lab(value)
+    }
 }
 
-/// Converts a Y value to an L* value.
-///
-/// L* in L*a*b* and Y in Xyz measure the same quantity, luminance.
-///
-/// L* measures perceptual luminance, a linear scale. Y in Xyz measures relative luminance, a
-/// logarithmic scale.
-///
-/// - `y`: Y in Xyz
-///
-/// Returns L* in L*a*b*
-pub fn lstar_from_y(y: f64) -> f64 {
-    lab_f(y / 100.0).mul_add(116.0, -16.0)
+impl From<Hct> for ColorValue {
+    fn from(value: Hct) -> Self {
+        Self::Hct(value)
+    }
 }
 
-/// Linearizes an Rgb component.
-///
-/// - `rgb_component`: 0 <= `rgb_component` <= 255, represents R/G/B channel
-///
-/// Returns 0.0 <= output <= 100.0, color channel converted to linear Rgb space
-pub fn linearized(rgb_component: u8) -> f64 {
-    let normalized = f64::from(rgb_component) / 255.0;
+impl From<Cam16> for ColorValue {
+    fn from(value: Cam16) -> Self {
+        Self::Cam16(value)
+    }
+}
 
-    if normalized <= 0.040449936 {
-        normalized / 12.92 * 100.0
-    } else {
-        ((normalized + 0.055) / 1.055).powf(2.4) * 100.0
+impl From<Hsl> for ColorValue {
+    fn from(value: Hsl) -> Self {
+        Self::Hsl(value)
     }
 }
 
-/// Delinearizes an Rgb component.
-///
-/// - `rgb_component`: 0.0 <= `rgb_component` <= 100.0, represents linear R/G/B channel
-///
-/// Returns 0 <= output <= 255, color channel converted to regular Rgb space
-pub fn delinearized(rgb_component: f64) -> u8 {
-    let normalized = rgb_component / 100.0;
-    let delinearized = if normalized <= 0.0031308 {
-        normalized * 12.92
-    } else {
-        1.055f64.mul_add(normalized.powf(1.0 / 2.4), -0.055)
-    };
+impl From<Hsv> for ColorValue {
+    fn from(value: Hsv) -> Self {
+        Self::Hsv(value)
+    }
+}
 
-    ((delinearized * 255.0).round() as u8).clamp(0, 255)
+/// Converts every value in `values` into the `to` color space.
+pub fn convert_all(values: &[ColorValue], to: ColorSpace) -> Vec<ColorValue> {
+    values.iter().map(|value| value.convert(to)).collect()
 }
 
 fn lab_f(t: f64) -> f64 {
@@ -430,12 +1579,103 @@ fn lab_invf(ft: f64) -> f64 {
     }
 }
 
+// Conversions to and from the types of the `palette` crate, which use
+// channels and coordinates normalized to 0.0..=1.0 where this crate uses
+// 0.0..=100.0. Both use the D65 white point.
+
+#[cfg(feature = "palette")]
+impl From<Argb> for palettes::Srgba<u8> {
+    fn from(argb: Argb) -> Self {
+        Self::new(argb.red, argb.green, argb.blue, argb.alpha)
+    }
+}
+
+#[cfg(feature = "palette")]
+impl From<palettes::Srgba<u8>> for Argb {
+    fn from(color: palettes::Srgba<u8>) -> Self {
+        Self::new(color.alpha, color.red, color.green, color.blue)
+    }
+}
+
+/// Drops the alpha channel.
+#[cfg(feature = "palette")]
+impl From<Argb> for palettes::Srgb<u8> {
+    fn from(argb: Argb) -> Self {
+        Self::new(argb.red, argb.green, argb.blue)
+    }
+}
+
+/// Creates an opaque color.
+#[cfg(feature = "palette")]
+impl From<palettes::Srgb<u8>> for Argb {
+    fn from(color: palettes::Srgb<u8>) -> Self {
+        Rgb::new(color.red, color.green, color.blue).into()
+    }
+}
+
+#[cfg(feature = "palette")]
+impl From<LinearRgb> for palettes::LinSrgb<f64> {
+    fn from(linear: LinearRgb) -> Self {
+        Self::new(
+            linear.red / 100.0,
+            linear.green / 100.0,
+            linear.blue / 100.0,
+        )
+    }
+}
+
+#[cfg(feature = "palette")]
+impl From<palettes::LinSrgb<f64>> for LinearRgb {
+    fn from(color: palettes::LinSrgb<f64>) -> Self {
+        Self {
+            red: color.red * 100.0,
+            green: color.green * 100.0,
+            blue: color.blue * 100.0,
+        }
+    }
+}
+
+#[cfg(feature = "palette")]
+impl From<Xyz> for palettes::Xyz<palettes::white_point::D65, f64> {
+    fn from(Xyz { x, y, z }: Xyz) -> Self {
+        Self::new(x / 100.0, y / 100.0, z / 100.0)
+    }
+}
+
+#[cfg(feature = "palette")]
+impl From<palettes::Xyz<palettes::white_point::D65, f64>> for Xyz {
+    fn from(color: palettes::Xyz<palettes::white_point::D65, f64>) -> Self {
+        Self::new(color.x * 100.0, color.y * 100.0, color.z * 100.0)
+    }
+}
+
+#[cfg(feature = "palette")]
+impl From<Lab> for palettes::Lab<palettes::white_point::D65, f64> {
+    fn from(Lab { l, a, b }: Lab) -> Self {
+        Self::new(l, a, b)
+    }
+}
+
+#[cfg(feature = "palette")]
+impl From<palettes::Lab<palettes::white_point::D65, f64>> for Lab {
+    fn from(color: palettes::Lab<palettes::white_point::D65, f64>) -> Self {
+        Self::new(color.l, color.a, color.b)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::Lab;
-    use crate::color::{delinearized, linearized, lstar_from_y, y_from_lstar, Argb, Rgb, Xyz};
+    use super::{from_f16_bits, to_f16_bits, Lab};
+    use crate::color::{
+        adapt, convert_all, delinearized, dither_rgb565, gamma_decode, gamma_encode, linearized,
+        lstar_from_y, rec2020_decode, rec2020_encode, srgb_decode, srgb_encode, y_from_lstar, Argb,
+        ChromaticAdaptation, ColorSpace, ColorValue, Gamut, GamutArgb, Hsl, Hsv, Rgb,
+        TransferFunction, Xyz, WHITE_POINT_D50, WHITE_POINT_D65,
+    };
+    use crate::{hct::Cam16, utils::math::matrix_multiply, Error};
     #[cfg(not(feature = "std"))]
     use alloc::vec::Vec;
+    use core::str::FromStr;
     use float_cmp::assert_approx_eq;
     #[cfg(feature = "std")]
     use std::vec::Vec;
@@ -459,6 +1699,299 @@ mod tests {
         (0..=255).collect()
     }
 
+    #[test]
+    fn test_hsl() {
+        let cases = [
+            (0xffff0000, 0.0, 1.0, 0.5),
+            (0xff00ff00, 120.0, 1.0, 0.5),
+            (0xff0000ff, 240.0, 1.0, 0.5),
+            (0xffffffff, 0.0, 0.0, 1.0),
+            (0xff000000, 0.0, 0.0, 0.0),
+            (0xff808080, 0.0, 0.0, 128.0 / 255.0),
+            (0xff4285f4, 217.41573033707866, 0.89, 310.0 / 510.0),
+        ];
+
+        for (argb, hue, saturation, lightness) in cases {
+            let argb = Argb::from_u32(argb);
+            let hsl = Hsl::from(argb);
+
+            assert_approx_eq!(f64, hsl.hue, hue, epsilon = 1e-9);
+            assert_approx_eq!(f64, hsl.saturation, saturation, epsilon = 1e-9);
+            assert_approx_eq!(f64, hsl.lightness, lightness, epsilon = 1e-9);
+            assert_eq!(Argb::from(hsl), argb);
+        }
+
+        assert_eq!(
+            Argb::from(Hsl::new(-240.0, 2.0, 0.5)),
+            Argb::from_u32(0xff00ff00)
+        );
+        assert!(Argb::from(Hsl::from(Argb::from_u32(0x80ff0000))).is_opaque());
+    }
+
+    #[test]
+    fn test_hsv() {
+        let cases = [
+            (0xffff0000, 0.0, 1.0, 1.0),
+            (0xff00ff00, 120.0, 1.0, 1.0),
+            (0xff0000ff, 240.0, 1.0, 1.0),
+            (0xffffffff, 0.0, 0.0, 1.0),
+            (0xff000000, 0.0, 0.0, 0.0),
+            (0xff4285f4, 217.41573033707866, 178.0 / 244.0, 244.0 / 255.0),
+        ];
+
+        for (argb, hue, saturation, value) in cases {
+            let argb = Argb::from_u32(argb);
+            let hsv = Hsv::from(argb);
+
+            assert_approx_eq!(f64, hsv.hue, hue, epsilon = 1e-9);
+            assert_approx_eq!(f64, hsv.saturation, saturation, epsilon = 1e-9);
+            assert_approx_eq!(f64, hsv.value, value, epsilon = 1e-9);
+            assert_eq!(Argb::from(hsv), argb);
+        }
+
+        for argb in (0..=0xffffff_u32).step_by(0x10101) {
+            let argb = Argb::from_u32(0xff000000 | argb);
+            let hsl = Hsl::from(argb);
+            let hsv = Hsv::from(argb);
+
+            assert_eq!(Argb::from(hsv), argb);
+            assert_eq!(Argb::from(Hsv::from(hsl)), argb);
+            assert_eq!(Argb::from(Hsl::from(hsv)), argb);
+        }
+    }
+
+    #[test]
+    fn test_color_value_convert() {
+        let argb = Argb::from_u32(0xff_4a_6a_a8);
+        let hct = ColorValue::from(argb).convert(ColorSpace::Hct);
+
+        assert_eq!(hct.space(), ColorSpace::Hct);
+        assert_eq!(hct.to_argb(), argb);
+
+        for space in [
+            ColorSpace::Argb,
+            ColorSpace::Rgb,
+            ColorSpace::Xyz,
+            ColorSpace::Lab,
+            ColorSpace::Hct,
+            ColorSpace::Cam16,
+            ColorSpace::Hsl,
+            ColorSpace::Hsv,
+        ] {
+            let value = ColorValue::from(argb).convert(space);
+
+            assert_eq!(value.space(), space);
+            assert_eq!(value.to_argb(), argb);
+        }
+    }
+
+    #[test]
+    fn test_color_value_keeps_precision() {
+        let lab = Lab::new(52.25, -12.5, 31.75);
+        let round_trip = match ColorValue::from(lab)
+            .convert(ColorSpace::Xyz)
+            .convert(ColorSpace::Cam16)
+            .convert(ColorSpace::Lab)
+        {
+            ColorValue::Lab(lab) => lab,
+            other => panic!("expected a Lab value, got {other:?}"),
+        };
+
+        assert_approx_eq!(f64, round_trip.l, lab.l, epsilon = 1e-6);
+        assert_approx_eq!(f64, round_trip.a, lab.a, epsilon = 1e-6);
+        assert_approx_eq!(f64, round_trip.b, lab.b, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_convert_all() {
+        let values = [
+            ColorValue::from(Argb::from_u32(0xffff0000)),
+            ColorValue::from(Lab::new(50.0, 0.0, 0.0)),
+            ColorValue::from(Xyz::new(95.047, 100.0, 108.883)),
+        ];
+        let converted = convert_all(&values, ColorSpace::Argb);
+
+        assert_eq!(converted.len(), 3);
+        assert!(converted
+            .iter()
+            .all(|value| value.space() == ColorSpace::Argb));
+        assert_eq!(converted[2].to_argb(), Argb::from_u32(0xffffffff));
+    }
+
+    #[test]
+    fn test_gpu_formats() {
+        let color = Argb::from_u32(0x80ff8000);
+
+        assert_eq!(color.to_rgba8(), [0xff, 0x80, 0x00, 0x80]);
+        assert_eq!(color.to_bgra8(), [0x00, 0x80, 0xff, 0x80]);
+        assert_eq!(Argb::from_rgba8(color.to_rgba8()), color);
+        assert_eq!(Argb::from_bgra8(color.to_bgra8()), color);
+
+        assert_eq!(Argb::from_u32(0xffffffff).to_argb2101010(), 0xffffffff);
+        assert_eq!(Argb::from_u32(0x00ff0000).to_argb2101010(), 0x3ff << 20);
+        assert_eq!(color.to_argb2101010() >> 30, 2);
+        assert_eq!(
+            Argb::from_argb2101010(0x3ff << 10),
+            Argb::from_u32(0x0000ff00)
+        );
+        assert_eq!(
+            Argb::from_argb2101010(3 << 30 | 0x200),
+            Argb::from_u32(0xff000080)
+        );
+
+        for argb in [0xff000000, 0xffffffff, 0xff777777, 0x4012b3f0, 0x00010203] {
+            let color = Argb::from_u32(argb);
+
+            assert_eq!(
+                Argb::from_argb2101010(color.to_argb2101010()).red,
+                color.red
+            );
+            assert_eq!(Argb::from_rgba16f_linear(color.to_rgba16f_linear()), color);
+        }
+
+        assert_eq!(Argb::from_u32(0xffffffff).to_rgba16f_linear(), [0x3c00; 4]);
+        assert_eq!(Argb::from_u32(0x00000000).to_rgba16f_linear(), [0; 4]);
+        assert_eq!(
+            Argb::from_rgba16f_linear([0x4000, 0xbc00, 0x7e00, 0x3c00]),
+            Argb::from_u32(0xffff0000)
+        );
+    }
+
+    #[test]
+    fn test_f16_bits() {
+        for (value, bits) in [
+            (0.0, 0x0000),
+            (1.0, 0x3c00),
+            (-2.0, 0xc000),
+            (0.5, 0x3800),
+            (65504.0, 0x7bff),
+            (6.103_515_625e-5, 0x0400),
+            (5.960_464_477_539_063e-8, 0x0001),
+        ] {
+            assert_eq!(to_f16_bits(value), bits, "{value}");
+            assert_approx_eq!(f64, from_f16_bits(bits), value);
+        }
+
+        assert_eq!(to_f16_bits(1e6), 0x7c00);
+        assert!(from_f16_bits(0x7c00).is_infinite());
+
+        assert_eq!(to_f16_bits(1.0 + 1.0 / 2048.0), 0x3c01);
+        assert_eq!(to_f16_bits(1e-9), 0);
+        assert!(from_f16_bits(0x7e00).is_nan());
+    }
+
+    #[test]
+    fn test_rgb565() {
+        let color = Argb::from_u32(0xffff8000);
+
+        assert_eq!(color.to_rgb565(), 0xfc00);
+        assert_eq!(Argb::from_rgb565(0xffff), Argb::from_u32(0xffffffff));
+        assert_eq!(Argb::from_rgb565(0), Argb::from_u32(0xff000000));
+
+        for value in [0x0000, 0x1234, 0x8410, 0xf81f, 0xffff] {
+            assert_eq!(Argb::from_rgb565(value).to_rgb565(), value);
+        }
+    }
+
+    #[test]
+    fn test_dither_rgb565() {
+        // Sits between two RGB565 levels in every channel.
+        let color = Argb::new(255, 132, 130, 132);
+        let dithered = dither_rgb565(&[color; 16], 4);
+        let mean = |shift: u32, mask: u16, max: f64| {
+            dithered
+                .iter()
+                .map(|value| f64::from((value >> shift) & mask))
+                .sum::<f64>()
+                / 16.0
+                / max
+                * 255.0
+        };
+
+        assert!(dithered.iter().any(|value| *value != dithered[0]));
+        assert_approx_eq!(f64, mean(11, 0x1f, 31.0), 132.0, epsilon = 8.0 / 16.0 + 0.5);
+        assert_approx_eq!(f64, mean(5, 0x3f, 63.0), 130.0, epsilon = 4.0 / 16.0 + 0.5);
+        assert_approx_eq!(f64, mean(0, 0x1f, 31.0), 132.0, epsilon = 8.0 / 16.0 + 0.5);
+
+        let black = dither_rgb565(&[Argb::from_u32(0xff000000); 16], 4);
+        let white = dither_rgb565(&[Argb::from_u32(0xffffffff); 16], 4);
+
+        assert!(black.iter().all(|value| *value == 0));
+        assert!(white.iter().all(|value| *value == 0xffff));
+        assert!(dither_rgb565(&[], 0).is_empty());
+    }
+
+    #[test]
+    fn test_from_str() {
+        let cases = [
+            ("abc", 0xffaabbcc),
+            ("ABC", 0xffaabbcc),
+            ("#aBc", 0xffaabbcc),
+            ("8abc", 0x88aabbcc),
+            ("#8AbC", 0x88aabbcc),
+            ("0000", 0x0000_0000),
+            ("aabbcc", 0xffaabbcc),
+            ("#AaBbCc", 0xffaabbcc),
+            ("80aabbcc", 0x80aabbcc),
+            ("#80AABBCC", 0x80aabbcc),
+            ("  #abc\n", 0xffaabbcc),
+            ("\t80aabbcc ", 0x80aabbcc),
+        ];
+
+        for (input, expected) in cases {
+            assert_eq!(
+                Argb::from_str(input),
+                Ok(Argb::from_u32(expected)),
+                "{input:?}"
+            );
+
+            if input.trim() == input {
+                assert_eq!(Argb::from_hex(input), Argb::from_u32(expected), "{input:?}");
+            }
+        }
+
+        assert_eq!(crate::argb!("#80aabbcc"), Argb::from_u32(0x80aabbcc));
+    }
+
+    #[test]
+    fn test_alpha() {
+        let color = Argb::from_u32(0x0a4285f4);
+
+        assert_eq!(color.to_hex_with_alpha(), "0a4285f4");
+        assert_eq!(color.to_hex(), "4285f4");
+        assert_eq!(Argb::from_str(&color.to_hex_with_alpha()), Ok(color));
+        assert_eq!(color.with_alpha(255), Argb::from_u32(0xff4285f4));
+        assert!(!color.is_opaque());
+        assert!(color.with_alpha(255).is_opaque());
+        assert!(Argb::from(Lab::from(color)).is_opaque());
+    }
+
+    #[test]
+    fn test_from_str_invalid() {
+        let cases = [
+            "",
+            "#",
+            "a",
+            "ab",
+            "abcde",
+            "abcdefa",
+            "abcdef012",
+            "##abc",
+            "# abc",
+            "+abc",
+            "+abcdef1",
+            "-abc",
+            "abg",
+            "0xabcdef",
+            "ab cd",
+            "äbc",
+        ];
+
+        for input in cases {
+            assert_eq!(Argb::from_str(input), Err(Error::ParseRGB), "{input:?}");
+        }
+    }
+
     #[test]
     fn test_range_integrity() {
         let range = _range(3.0, 9999.0, 1234);
@@ -677,6 +2210,94 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_cmp_by_luminance() {
+        let mut colors = [
+            Argb::from_u32(0xffffffff),
+            Argb::from_u32(0xff0000ff),
+            Argb::from_u32(0xff000000),
+            Argb::from_u32(0xffffff00),
+        ];
+
+        colors.sort_by(Argb::cmp_by_luminance);
+
+        assert_eq!(
+            colors,
+            [
+                Argb::from_u32(0xff000000),
+                Argb::from_u32(0xff0000ff),
+                Argb::from_u32(0xffffff00),
+                Argb::from_u32(0xffffffff),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_cmp_by_hue() {
+        let mut colors = [
+            Argb::from_u32(0xff0000ff),
+            Argb::from_u32(0xff00ff00),
+            Argb::from_u32(0xffff0000),
+        ];
+
+        colors.sort_by(Argb::cmp_by_hue);
+
+        assert_eq!(
+            colors,
+            [
+                Argb::from_u32(0xffff0000),
+                Argb::from_u32(0xff00ff00),
+                Argb::from_u32(0xff0000ff),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_adapt_white_point() {
+        for method in [ChromaticAdaptation::Bradford, ChromaticAdaptation::Cat16] {
+            let [x, y, z] = WHITE_POINT_D65;
+            let adapted = adapt(Xyz::new(x, y, z), WHITE_POINT_D65, WHITE_POINT_D50, method);
+
+            assert_approx_eq!(f64, adapted.x, WHITE_POINT_D50[0], epsilon = 1e-9);
+            assert_approx_eq!(f64, adapted.y, WHITE_POINT_D50[1], epsilon = 1e-9);
+            assert_approx_eq!(f64, adapted.z, WHITE_POINT_D50[2], epsilon = 1e-9);
+
+            let color = Xyz::new(41.24, 21.26, 1.93);
+            let adapted = adapt(color, WHITE_POINT_D65, WHITE_POINT_D50, method);
+            let restored = adapt(adapted, WHITE_POINT_D50, WHITE_POINT_D65, method);
+
+            assert_approx_eq!(f64, restored.x, color.x, epsilon = 1e-9);
+            assert_approx_eq!(f64, restored.y, color.y, epsilon = 1e-9);
+            assert_approx_eq!(f64, restored.z, color.z, epsilon = 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_adapt_bradford_reference() {
+        // Bradford D65 to D50 matrix published by Bruce Lindbloom.
+        let reference = [
+            [1.0478112, 0.0228866, -0.0501270],
+            [0.0295424, 0.9904844, -0.0170491],
+            [-0.0092345, 0.0150436, 0.7521316],
+        ];
+
+        for (column, unit) in [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]]
+            .into_iter()
+            .enumerate()
+        {
+            let adapted = adapt(
+                Xyz::new(unit[0], unit[1], unit[2]),
+                WHITE_POINT_D65,
+                WHITE_POINT_D50,
+                ChromaticAdaptation::Bradford,
+            );
+
+            assert_approx_eq!(f64, adapted.x, reference[0][column], epsilon = 1e-6);
+            assert_approx_eq!(f64, adapted.y, reference[1][column], epsilon = 1e-6);
+            assert_approx_eq!(f64, adapted.z, reference[2][column], epsilon = 1e-6);
+        }
+    }
+
     #[test]
     fn test_linearize_delinearize() {
         let full_rgb_range = full_rgb_range();
@@ -687,4 +2308,149 @@ mod tests {
             assert_eq!(converted, rgb_component);
         }
     }
+
+    #[test]
+    fn test_srgb_transfer_function() {
+        for component in 0..=255 {
+            let encoded = f64::from(component) / 255.0;
+
+            assert_approx_eq!(
+                f64,
+                srgb_decode(encoded) * 100.0,
+                linearized(component as u8)
+            );
+            assert_approx_eq!(
+                f64,
+                srgb_encode(srgb_decode(encoded)),
+                encoded,
+                epsilon = 1e-12
+            );
+        }
+
+        assert_approx_eq!(f64, srgb_decode(0.5), 0.21404114048223255);
+        assert_approx_eq!(f64, srgb_decode(-0.5), -0.21404114048223255);
+        assert_approx_eq!(f64, srgb_encode(-0.001), -0.01292);
+    }
+
+    #[test]
+    fn test_gamma_transfer_function() {
+        assert_approx_eq!(f64, gamma_decode(0.5, 2.2), 0.21763764082403103);
+        assert_approx_eq!(f64, gamma_encode(0.21763764082403103, 2.2), 0.5);
+        assert_approx_eq!(f64, gamma_decode(-0.5, 2.0), -0.25);
+        // A pure power function is darker than sRGB near black.
+        assert!(gamma_decode(0.02, 2.2) < srgb_decode(0.02));
+    }
+
+    #[test]
+    fn test_gamut_argb() {
+        for gamut in [Gamut::Srgb, Gamut::DisplayP3, Gamut::Rec2020] {
+            let white = matrix_multiply([100.0; 3], gamut.to_xyz());
+
+            for (channel, expected) in white.iter().zip(WHITE_POINT_D65) {
+                assert_approx_eq!(f64, *channel, expected, epsilon = 1e-5);
+            }
+
+            for argb in [0x80ff0000, 0xff00ff00, 0xff4285f4, 0xff808080, 0xffffffff] {
+                let argb = Argb::from_u32(argb);
+                let color = GamutArgb::from_argb(argb, gamut);
+
+                let round_trip = color.to_argb();
+
+                assert_eq!(color.gamut, gamut);
+                assert_eq!(round_trip.alpha, argb.alpha);
+
+                // Wider gamuts spread 8 bits over more colors.
+                for (channel, expected) in [
+                    (round_trip.red, argb.red),
+                    (round_trip.green, argb.green),
+                    (round_trip.blue, argb.blue),
+                ] {
+                    assert!(channel.abs_diff(expected) <= 3, "{argb:?} {gamut:?}");
+                }
+
+                if gamut == Gamut::Srgb {
+                    assert_eq!(round_trip, argb);
+                }
+            }
+        }
+
+        let red = GamutArgb::from_argb(Argb::from_u32(0xffff0000), Gamut::DisplayP3);
+
+        assert_eq!(red.argb, Argb::from_u32(0xffea3323));
+        assert_eq!(
+            GamutArgb::new(Gamut::DisplayP3, Argb::from_u32(0xffff0000)).to_argb(),
+            Argb::from_u32(0xffff0000)
+        );
+        assert!(
+            GamutArgb::new(Gamut::DisplayP3, Argb::from_u32(0xffff0000)).chroma()
+                > Cam16::from(Argb::from_u32(0xffff0000)).chroma
+        );
+    }
+
+    #[test]
+    fn test_transfer_function_enum() {
+        for function in [
+            TransferFunction::Srgb,
+            TransferFunction::Gamma(1.8),
+            TransferFunction::Linear,
+            TransferFunction::Rec2020,
+        ] {
+            for value in [0.0, 0.01, 0.25, 0.5, 1.0, 1.2] {
+                assert_approx_eq!(
+                    f64,
+                    function.encode(function.decode(value)),
+                    value,
+                    epsilon = 1e-12
+                );
+            }
+        }
+
+        assert_approx_eq!(f64, TransferFunction::Srgb.decode(0.5), srgb_decode(0.5));
+        assert_approx_eq!(f64, TransferFunction::Linear.decode(0.5), 0.5);
+        assert_approx_eq!(f64, rec2020_encode(0.01), 0.045);
+        assert_approx_eq!(f64, rec2020_encode(1.0), 1.0, epsilon = 1e-12);
+        assert_approx_eq!(f64, rec2020_decode(-0.5), -rec2020_decode(0.5));
+    }
+
+    #[test]
+    #[cfg(feature = "palette")]
+    fn test_palette_conversions() {
+        use crate::color::LinearRgb;
+        use palettes::{white_point::D65, FromColor, IntoColor, LinSrgb, Srgb, Srgba};
+
+        let argb = Argb::from_u32(0x804285f4);
+        let srgba = Srgba::<u8>::from(argb);
+
+        assert_eq!(srgba, Srgba::new(0x42, 0x85, 0xf4, 0x80));
+        assert_eq!(Argb::from(srgba), argb);
+        assert_eq!(
+            Argb::from(Srgb::<u8>::from(argb)),
+            Argb::from_u32(0xff4285f4)
+        );
+
+        // Conversions through `palette` agree with the ones of this crate.
+        let srgb = Srgb::<u8>::from(argb).into_format::<f64>();
+        let linear = LinearRgb::from(srgb.into_linear::<f64>());
+        let xyz = Xyz::from(palettes::Xyz::<D65, f64>::from_color(srgb));
+        let lab = Lab::from(IntoColor::<palettes::Lab<D65, f64>>::into_color(srgb));
+        let expected_xyz = Xyz::from(argb);
+        let expected_lab = Lab::from(argb);
+
+        assert_eq!(Argb::from(linear), Argb::from_u32(0xff4285f4));
+        assert_approx_eq!(f64, xyz.x, expected_xyz.x, epsilon = 0.01);
+        assert_approx_eq!(f64, xyz.y, expected_xyz.y, epsilon = 0.01);
+        assert_approx_eq!(f64, xyz.z, expected_xyz.z, epsilon = 0.01);
+        assert_approx_eq!(f64, lab.l, expected_lab.l, epsilon = 0.01);
+        assert_approx_eq!(f64, lab.a, expected_lab.a, epsilon = 0.05);
+        assert_approx_eq!(f64, lab.b, expected_lab.b, epsilon = 0.05);
+
+        let round_trip = Lab::from(palettes::Lab::<D65, f64>::from(expected_lab));
+
+        assert_approx_eq!(f64, round_trip.b, expected_lab.b);
+        assert_approx_eq!(
+            f64,
+            LinearRgb::from(LinSrgb::<f64>::from(linear)).green,
+            linear.green
+        );
+    }
 }
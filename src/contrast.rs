@@ -1,7 +1,15 @@
-use crate::color::{lstar_from_y, y_from_lstar};
 #[cfg(all(not(feature = "std"), feature = "libm"))]
 #[allow(unused_imports)]
 use crate::utils::no_std::FloatExt;
+use crate::{
+    color::{lstar_from_y, y_from_lstar, Argb, Xyz},
+    scheme::{Scheme, SchemeRole},
+};
+#[cfg(not(feature = "std"))]
+use alloc::{string::String, vec, vec::Vec};
+use core::ops::RangeInclusive;
+#[cfg(feature = "std")]
+use std::{string::String, vec, vec::Vec};
 
 /// Returns a contrast ratio, which ranges from 1 to 21.
 ///
@@ -14,6 +22,20 @@ pub fn ratio_of_tones(tone_a: f64, tone_b: f64) -> f64 {
     ratio_of_ys(y_from_lstar(tone_a), y_from_lstar(tone_b))
 }
 
+/// Returns the contrast ratio of two colors, which ranges from 1 to 21.
+///
+/// Unlike [`ratio_of_tones`], this works for any color, e.g. one picked by a
+/// user or read from an image, not only tones of a palette.
+pub fn ratio_of_colors(a: Argb, b: Argb) -> f64 {
+    ratio_of_ys(Xyz::from(a).y, Xyz::from(b).y)
+}
+
+/// Returns the relative luminance of `color` as defined by WCAG, from 0 for
+/// black to 1 for white.
+pub fn relative_luminance(color: Argb) -> f64 {
+    Xyz::from(color).y / 100.0
+}
+
 fn ratio_of_ys(y1: f64, y2: f64) -> f64 {
     let lighter = if y1 > y2 { y1 } else { y2 };
     let darker = if (lighter - y2).abs() < f64::EPSILON {
@@ -122,19 +144,254 @@ pub fn darker_unsafe(tone: f64, ratio: f64) -> f64 {
     }
 }
 
+/// Tone intervals a foreground may take against a fixed background tone.
+///
+/// Returned by [`feasible_tones`]. Either side is `None` when no tone on that
+/// side of the background satisfies the constraints.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeasibleTones {
+    /// Tones darker than the background, from 0 upwards.
+    pub darker: Option<RangeInclusive<f64>>,
+    /// Tones lighter than the background, up to 100.
+    pub lighter: Option<RangeInclusive<f64>>,
+}
+
+impl FeasibleTones {
+    /// Whether `tone` lies within either interval.
+    pub fn contains(&self, tone: f64) -> bool {
+        [&self.darker, &self.lighter]
+            .into_iter()
+            .flatten()
+            .any(|range| range.contains(&tone))
+    }
+
+    /// Whether no tone satisfies the constraints.
+    pub const fn is_empty(&self) -> bool {
+        self.darker.is_none() && self.lighter.is_none()
+    }
+}
+
+/// Returns the tones a foreground may take so that it reaches `ratio` against
+/// `background` while staying at least `delta` tones away from it.
+///
+/// Uses the same safety margin as [`lighter`] and [`darker`], so every tone in
+/// the returned intervals is accepted by them.
+///
+/// - `background`: Tone of the background. Range is 0 to 100; other values
+///   result in no feasible tones.
+/// - `ratio`: Minimum contrast ratio. Range is 1 to 21.
+/// - `delta`: Minimum tone difference between the two roles, as in a
+///   [`ToneDeltaPair`].
+///
+/// [`ToneDeltaPair`]: crate::dynamic_color::ToneDeltaPair
+pub fn feasible_tones(background: f64, ratio: f64, delta: f64) -> FeasibleTones {
+    let darkest = darker(background, ratio);
+    let lightest = lighter(background, ratio);
+
+    let darker = (darkest >= 0.0)
+        .then(|| darkest.min(background - delta))
+        .filter(|upper| *upper >= 0.0)
+        .map(|upper| 0.0..=upper);
+    let lighter = (lightest >= 0.0)
+        .then(|| lightest.max(background + delta))
+        .filter(|lower| *lower <= 100.0)
+        .map(|lower| lower..=100.0);
+
+    FeasibleTones { darker, lighter }
+}
+
+/// Contrast ratios between every pair of roles in a [`Scheme`].
+///
+/// Roles are indexed in the order produced by iterating a [`Scheme`]. The
+/// relative luminance of each role is computed once, so building the matrix
+/// costs a single color conversion per role.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContrastMatrix {
+    roles: Vec<String>,
+    ratios: Vec<f64>,
+}
+
+impl ContrastMatrix {
+    pub fn new(scheme: &Scheme) -> Self {
+        let (roles, ys): (Vec<String>, Vec<f64>) = scheme
+            .clone()
+            .into_iter()
+            .map(|(role, color)| (role, Xyz::from(color).y))
+            .unzip();
+
+        let size = roles.len();
+        let mut ratios = vec![1.0; size * size];
+
+        for i in 0..size {
+            for j in (i + 1)..size {
+                let ratio = ratio_of_ys(ys[i], ys[j]);
+
+                ratios[i * size + j] = ratio;
+                ratios[j * size + i] = ratio;
+            }
+        }
+
+        Self { roles, ratios }
+    }
+
+    /// Names of the roles, in matrix order.
+    pub fn roles(&self) -> &[String] {
+        &self.roles
+    }
+
+    /// Number of roles, i.e. the number of rows and columns of the matrix.
+    pub fn len(&self) -> usize {
+        self.roles.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.roles.is_empty()
+    }
+
+    /// Returns the matrix index of the role named `role`.
+    pub fn index_of(&self, role: &str) -> Option<usize> {
+        self.roles.iter().position(|name| name == role)
+    }
+
+    /// Returns the contrast ratio between the roles at indices `a` and `b`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if either index is out of bounds.
+    pub fn ratio(&self, a: usize, b: usize) -> f64 {
+        assert!(a < self.len() && b < self.len());
+
+        self.ratios[a * self.len() + b]
+    }
+
+    /// Returns the contrast ratio between the roles named `a` and `b`, or
+    /// `None` if either role does not exist.
+    pub fn get(&self, a: &str, b: &str) -> Option<f64> {
+        Some(self.ratio(self.index_of(a)?, self.index_of(b)?))
+    }
+
+    /// Returns the contrast ratios between the role at index `a` and every
+    /// role, in matrix order.
+    pub fn row(&self, a: usize) -> &[f64] {
+        &self.ratios[a * self.len()..(a + 1) * self.len()]
+    }
+}
+
+/// Contrast ratio between a foreground role and the background it is
+/// displayed on, as checked by [`ContrastReport`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ContrastCheck {
+    pub foreground: SchemeRole,
+    pub background: SchemeRole,
+    pub ratio: f64,
+}
+
+/// Contrast ratios of the foreground / background role pairs of a
+/// [`Scheme`] that carry text or icons.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContrastReport {
+    checks: Vec<ContrastCheck>,
+}
+
+impl ContrastReport {
+    /// Pairs checked by [`ContrastReport::new`], as (foreground, background).
+    pub const PAIRS: [(SchemeRole, SchemeRole); 16] = [
+        (SchemeRole::OnPrimary, SchemeRole::Primary),
+        (SchemeRole::OnPrimaryContainer, SchemeRole::PrimaryContainer),
+        (SchemeRole::OnSecondary, SchemeRole::Secondary),
+        (
+            SchemeRole::OnSecondaryContainer,
+            SchemeRole::SecondaryContainer,
+        ),
+        (SchemeRole::OnTertiary, SchemeRole::Tertiary),
+        (
+            SchemeRole::OnTertiaryContainer,
+            SchemeRole::TertiaryContainer,
+        ),
+        (SchemeRole::OnError, SchemeRole::Error),
+        (SchemeRole::OnErrorContainer, SchemeRole::ErrorContainer),
+        (SchemeRole::OnBackground, SchemeRole::Background),
+        (SchemeRole::OnSurface, SchemeRole::Surface),
+        (SchemeRole::OnSurfaceVariant, SchemeRole::SurfaceVariant),
+        (SchemeRole::InverseOnSurface, SchemeRole::InverseSurface),
+        (SchemeRole::InversePrimary, SchemeRole::InverseSurface),
+        (SchemeRole::Primary, SchemeRole::Surface),
+        (SchemeRole::Error, SchemeRole::Surface),
+        (SchemeRole::Outline, SchemeRole::Surface),
+    ];
+
+    pub fn new(scheme: &Scheme) -> Self {
+        let checks = Self::PAIRS
+            .iter()
+            .map(|&(foreground, background)| ContrastCheck {
+                foreground,
+                background,
+                ratio: ratio_of_colors(scheme[foreground], scheme[background]),
+            })
+            .collect();
+
+        Self { checks }
+    }
+
+    /// All checked pairs, in the order of [`ContrastReport::PAIRS`].
+    pub fn checks(&self) -> &[ContrastCheck] {
+        &self.checks
+    }
+
+    /// Pairs whose contrast ratio is below `min_ratio`, e.g. 4.5 for WCAG AA
+    /// body text.
+    pub fn failing(&self, min_ratio: f64) -> impl Iterator<Item = &ContrastCheck> {
+        self.checks
+            .iter()
+            .filter(move |check| check.ratio < min_ratio)
+    }
+
+    /// Whether every pair reaches `min_ratio`.
+    pub fn passes(&self, min_ratio: f64) -> bool {
+        self.failing(min_ratio).next().is_none()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use float_cmp::assert_approx_eq;
 
-    use crate::contrast::ratio_of_tones;
+    use crate::{color::Argb, contrast::ratio_of_tones, theme::ThemeBuilder};
 
-    use super::{darker, darker_unsafe, lighter, lighter_unsafe};
+    use super::{
+        darker, darker_unsafe, feasible_tones, lighter, lighter_unsafe, ratio_of_colors,
+        relative_luminance, ContrastMatrix, ContrastReport,
+    };
 
     #[test]
     fn test_ratio_of_tones_out_of_bounds_input() {
         assert_approx_eq!(f64, 21.0, ratio_of_tones(-10.0, 110.0), epsilon = 0.001);
     }
 
+    #[test]
+    fn test_ratio_of_colors() {
+        let white = Argb::from_u32(0xffffffff);
+        let black = Argb::from_u32(0xff000000);
+
+        assert_approx_eq!(f64, 21.0, ratio_of_colors(white, black), epsilon = 0.001);
+        assert_approx_eq!(f64, 21.0, ratio_of_colors(black, white), epsilon = 0.001);
+        assert_approx_eq!(f64, 1.0, ratio_of_colors(white, white), epsilon = 0.001);
+        assert_approx_eq!(
+            f64,
+            4.54,
+            ratio_of_colors(Argb::from_u32(0xff767676), white),
+            epsilon = 0.01
+        );
+        assert_approx_eq!(f64, 1.0, relative_luminance(white), epsilon = 0.001);
+        assert_approx_eq!(f64, 0.0, relative_luminance(black), epsilon = 0.001);
+        assert_approx_eq!(
+            f64,
+            0.2126,
+            relative_luminance(Argb::from_u32(0xffff0000)),
+            epsilon = 0.001
+        );
+    }
+
     #[test]
     fn test_lighter_impossible_ratio_errors() {
         assert_approx_eq!(f64, -1.0, lighter(90.0, 10.0), epsilon = 0.001);
@@ -174,4 +431,66 @@ mod tests {
     fn test_darker_unsafe_returns_min_tone() {
         assert_approx_eq!(f64, 0.0, darker_unsafe(0.0, 2.0), epsilon = 0.001);
     }
+
+    #[test]
+    fn test_feasible_tones() {
+        let tones = feasible_tones(50.0, 3.0, 10.0);
+        let darker_range = tones.darker.clone().unwrap();
+        let lighter_range = tones.lighter.clone().unwrap();
+
+        assert_approx_eq!(f64, *darker_range.end(), darker(50.0, 3.0));
+        assert_approx_eq!(f64, *lighter_range.start(), lighter(50.0, 3.0));
+        assert!(ratio_of_tones(50.0, *darker_range.end()) >= 3.0);
+        assert!(!tones.contains(50.0));
+
+        let tones = feasible_tones(50.0, 1.0, 20.0);
+
+        assert_approx_eq!(f64, *tones.darker.unwrap().end(), 30.0);
+        assert_approx_eq!(f64, *tones.lighter.unwrap().start(), 70.0);
+
+        let tones = feasible_tones(90.0, 4.5, 0.0);
+
+        assert!(tones.lighter.is_none());
+        assert!(tones.darker.is_some());
+        assert!(feasible_tones(50.0, 21.0, 0.0).is_empty());
+        assert!(feasible_tones(110.0, 1.0, 0.0).is_empty());
+    }
+
+    #[test]
+    fn test_contrast_matrix() {
+        let theme = ThemeBuilder::with_source(Argb::from_u32(0xff0000ff)).build();
+        let scheme = &theme.schemes.light;
+        let matrix = ContrastMatrix::new(scheme);
+
+        assert_eq!(matrix.len(), 49);
+
+        let primary = matrix.index_of("primary").unwrap();
+        let on_primary = matrix.index_of("on_primary").unwrap();
+
+        assert_approx_eq!(f64, matrix.ratio(primary, primary), 1.0);
+        assert_approx_eq!(
+            f64,
+            matrix.ratio(primary, on_primary),
+            matrix.ratio(on_primary, primary)
+        );
+        assert_approx_eq!(
+            f64,
+            matrix.get("primary", "on_primary").unwrap(),
+            ratio_of_tones(scheme.primary.as_lstar(), scheme.on_primary.as_lstar()),
+            epsilon = 0.001
+        );
+        assert!(matrix.get("primary", "unknown").is_none());
+        assert_eq!(matrix.row(primary).len(), 49);
+    }
+
+    #[test]
+    fn test_contrast_report() {
+        let theme = ThemeBuilder::with_source(Argb::from_u32(0xff6750a4)).build();
+        let report = ContrastReport::new(&theme.schemes.light);
+
+        assert_eq!(report.checks().len(), ContrastReport::PAIRS.len());
+        assert!(report.passes(3.0));
+        assert!(!report.passes(21.0));
+        assert_eq!(report.failing(21.0).count(), ContrastReport::PAIRS.len());
+    }
 }
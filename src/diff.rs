@@ -0,0 +1,186 @@
+//! Color differences (ΔE), to tell how far apart two colors look.
+
+#[cfg(all(not(feature = "std"), feature = "libm"))]
+#[allow(unused_imports)]
+use crate::utils::no_std::FloatExt;
+use crate::{
+    color::{Argb, Lab},
+    hct::Cam16,
+    utils::math::sanitize_degrees_double,
+};
+
+/// Returns the distance between two colors in CAM16-UCS, see
+/// [`Cam16::distance`]. A distance of about 1.0 is barely noticeable.
+pub fn delta_e_cam16(a: Argb, b: Argb) -> f64 {
+    Cam16::from(a).distance(&Cam16::from(b))
+}
+
+/// Returns the CIEDE2000 difference between two colors, the CIE
+/// recommendation for small differences between colors in `L*a*b*`.
+///
+/// Follows "The CIEDE2000 Color-Difference Formula: Implementation Notes,
+/// Supplementary Test Data, and Mathematical Observations" by Sharma, Wu and
+/// Dalal, with unit weighting factors.
+pub fn delta_e_ciede2000(a: Lab, b: Lab) -> f64 {
+    let pow7 = |value: f64| value.powi(7);
+
+    let c_bar = (a.a.hypot(a.b) + b.a.hypot(b.b)) / 2.0;
+    let g = 0.5 * (1.0 - (pow7(c_bar) / (pow7(c_bar) + pow7(25.0))).sqrt());
+
+    let a1 = (1.0 + g) * a.a;
+    let a2 = (1.0 + g) * b.a;
+    let c1 = a1.hypot(a.b);
+    let c2 = a2.hypot(b.b);
+    let hue = |b: f64, a: f64| {
+        if b == 0.0 && a == 0.0 {
+            0.0
+        } else {
+            sanitize_degrees_double(b.atan2(a).to_degrees())
+        }
+    };
+    let h1 = hue(a.b, a1);
+    let h2 = hue(b.b, a2);
+
+    let delta_l = b.l - a.l;
+    let delta_c = c2 - c1;
+    let delta_h = if c1 * c2 == 0.0 {
+        0.0
+    } else if (h2 - h1).abs() <= 180.0 {
+        h2 - h1
+    } else if h2 <= h1 {
+        h2 - h1 + 360.0
+    } else {
+        h2 - h1 - 360.0
+    };
+    let delta_big_h = 2.0 * (c1 * c2).sqrt() * (delta_h.to_radians() / 2.0).sin();
+
+    let l_bar = (a.l + b.l) / 2.0;
+    let c_bar = (c1 + c2) / 2.0;
+    let h_bar = if c1 * c2 == 0.0 {
+        h1 + h2
+    } else if (h1 - h2).abs() <= 180.0 {
+        (h1 + h2) / 2.0
+    } else if h1 + h2 < 360.0 {
+        (h1 + h2 + 360.0) / 2.0
+    } else {
+        (h1 + h2 - 360.0) / 2.0
+    };
+
+    let t = 1.0 - 0.17 * (h_bar - 30.0).to_radians().cos()
+        + 0.24 * (2.0 * h_bar).to_radians().cos()
+        + 0.32 * (3.0 * h_bar + 6.0).to_radians().cos()
+        - 0.20 * (4.0 * h_bar - 63.0).to_radians().cos();
+    let delta_theta = 30.0 * (-((h_bar - 275.0) / 25.0).powi(2)).exp();
+    let r_c = 2.0 * (pow7(c_bar) / (pow7(c_bar) + pow7(25.0))).sqrt();
+    let s_l = 1.0 + 0.015 * (l_bar - 50.0).powi(2) / (20.0 + (l_bar - 50.0).powi(2)).sqrt();
+    let s_c = 1.0 + 0.045 * c_bar;
+    let s_h = 1.0 + 0.015 * c_bar * t;
+    let r_t = -(2.0 * delta_theta).to_radians().sin() * r_c;
+
+    let l = delta_l / s_l;
+    let c = delta_c / s_c;
+    let h = delta_big_h / s_h;
+
+    (l * l + c * c + h * h + r_t * c * h).sqrt()
+}
+
+/// Returns the color of `palette` closest to `target` in CAM16-UCS, or
+/// `None` if `palette` is empty.
+///
+/// Used to map arbitrary colors, e.g. those of a legacy design, onto the
+/// colors of a generated scheme.
+pub fn closest(palette: &[Argb], target: Argb) -> Option<Argb> {
+    let target = Cam16::from(target);
+
+    palette
+        .iter()
+        .map(|color| (Cam16::from(*color).distance(&target), *color))
+        .min_by(|a, b| a.0.total_cmp(&b.0))
+        .map(|(_, color)| color)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{closest, delta_e_cam16, delta_e_ciede2000};
+    use crate::color::{Argb, Lab};
+    use float_cmp::assert_approx_eq;
+
+    const fn lab(l: f64, a: f64, b: f64) -> Lab {
+        Lab { l, a, b }
+    }
+
+    #[test]
+    fn test_delta_e_cam16() {
+        let blue = Argb::from_u32(0xff4285f4);
+
+        assert_approx_eq!(f64, delta_e_cam16(blue, blue), 0.0);
+        assert!(delta_e_cam16(blue, Argb::from_u32(0xff4385f4)) < 1.0);
+        assert!(delta_e_cam16(blue, Argb::from_u32(0xffdb4437)) > 10.0);
+    }
+
+    #[test]
+    fn test_delta_e_ciede2000() {
+        // Test data of Sharma, Wu and Dalal.
+        let pairs = [
+            (
+                lab(50.0, 2.6772, -79.7751),
+                lab(50.0, 0.0, -82.7485),
+                2.0425,
+            ),
+            (
+                lab(50.0, 3.1571, -77.2803),
+                lab(50.0, 0.0, -82.7485),
+                2.8615,
+            ),
+            (
+                lab(50.0, -1.3802, -84.2814),
+                lab(50.0, 0.0, -82.7485),
+                1.0000,
+            ),
+            (lab(50.0, 2.5, 0.0), lab(73.0, 25.0, -18.0), 27.1492),
+            (lab(50.0, 0.0, 0.0), lab(50.0, -1.0, 2.0), 2.3669),
+            (lab(50.0, 2.49, -0.001), lab(50.0, -2.49, 0.0009), 7.1792),
+            (lab(50.0, 2.49, -0.001), lab(50.0, -2.49, 0.0011), 7.2195),
+            (
+                lab(60.2574, -34.0099, 36.2677),
+                lab(60.4626, -34.1751, 39.4387),
+                1.2644,
+            ),
+            (
+                lab(2.0776, 0.0795, -1.135),
+                lab(0.9033, -0.0636, -0.5514),
+                0.9082,
+            ),
+        ];
+
+        for (a, b, expected) in pairs {
+            assert_approx_eq!(f64, delta_e_ciede2000(a, b), expected, epsilon = 0.0001);
+            assert_approx_eq!(f64, delta_e_ciede2000(b, a), expected, epsilon = 0.0001);
+        }
+
+        assert_approx_eq!(
+            f64,
+            delta_e_ciede2000(lab(50.0, 0.0, 0.0), lab(50.0, 0.0, 0.0)),
+            0.0
+        );
+    }
+
+    #[test]
+    fn test_closest() {
+        let palette = [
+            Argb::from_u32(0xff000000),
+            Argb::from_u32(0xff4285f4),
+            Argb::from_u32(0xffffffff),
+        ];
+
+        assert_eq!(
+            closest(&palette, Argb::from_u32(0xff3b78e7)),
+            Some(palette[1])
+        );
+        assert_eq!(
+            closest(&palette, Argb::from_u32(0xfff0f0f0)),
+            Some(palette[2])
+        );
+        assert_eq!(closest(&[], Argb::from_u32(0xff3b78e7)), None);
+    }
+}
@@ -0,0 +1,125 @@
+use super::{DynamicScheme, MaterialDynamicColors};
+use crate::{contrast::ratio_of_tones, scheme::SchemeRole};
+
+/// Accessibility settings of the platform honored by the color system, set
+/// on a scheme with [`DynamicScheme::with_accessibility`].
+///
+/// [`DynamicScheme::with_accessibility`]: super::DynamicScheme::with_accessibility
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AccessibilityOptions {
+    /// Resolves roles meant to be drawn translucent into opaque colors that
+    /// look the same over the surface, for "reduce transparency" settings.
+    ///
+    /// The scrim, drawn at [`AccessibilityOptions::SCRIM_OPACITY`] behind
+    /// modal surfaces, becomes the surface darkened by it.
+    pub reduce_transparency: bool,
+    /// Makes theme transitions complete at once, for "reduce motion"
+    /// settings, see [`ThemeTransition::with_accessibility`].
+    ///
+    /// [`ThemeTransition::with_accessibility`]: crate::theme::ThemeTransition::with_accessibility
+    pub reduce_motion: bool,
+    /// Resolves every role of [`AccessibilityOptions::TEXT_ROLES`] into pure
+    /// black or white, for "high contrast text" settings.
+    ///
+    /// Each role takes whichever of the two contrasts more with the
+    /// lowest-contrast background it is placed on, while surfaces and accents
+    /// keep their tint.
+    pub high_contrast_text: bool,
+}
+
+impl AccessibilityOptions {
+    /// Options of platforms without accessibility settings enabled.
+    pub const DEFAULT: Self = Self {
+        reduce_transparency: false,
+        reduce_motion: false,
+        high_contrast_text: false,
+    };
+
+    /// Opacity of the scrim behind modal surfaces, such as dialogs and
+    /// bottom sheets.
+    pub const SCRIM_OPACITY: f64 = 0.32;
+
+    /// Text roles and the backgrounds they are placed on, resolved into black
+    /// or white with [`AccessibilityOptions::high_contrast_text`].
+    pub const TEXT_ROLES: [(SchemeRole, &'static [SchemeRole]); 18] = [
+        (SchemeRole::OnPrimary, &[SchemeRole::Primary]),
+        (
+            SchemeRole::OnPrimaryContainer,
+            &[SchemeRole::PrimaryContainer],
+        ),
+        (
+            SchemeRole::OnPrimaryFixed,
+            &[SchemeRole::PrimaryFixed, SchemeRole::PrimaryFixedDim],
+        ),
+        (
+            SchemeRole::OnPrimaryFixedVariant,
+            &[SchemeRole::PrimaryFixed, SchemeRole::PrimaryFixedDim],
+        ),
+        (SchemeRole::OnSecondary, &[SchemeRole::Secondary]),
+        (
+            SchemeRole::OnSecondaryContainer,
+            &[SchemeRole::SecondaryContainer],
+        ),
+        (
+            SchemeRole::OnSecondaryFixed,
+            &[SchemeRole::SecondaryFixed, SchemeRole::SecondaryFixedDim],
+        ),
+        (
+            SchemeRole::OnSecondaryFixedVariant,
+            &[SchemeRole::SecondaryFixed, SchemeRole::SecondaryFixedDim],
+        ),
+        (SchemeRole::OnTertiary, &[SchemeRole::Tertiary]),
+        (
+            SchemeRole::OnTertiaryContainer,
+            &[SchemeRole::TertiaryContainer],
+        ),
+        (
+            SchemeRole::OnTertiaryFixed,
+            &[SchemeRole::TertiaryFixed, SchemeRole::TertiaryFixedDim],
+        ),
+        (
+            SchemeRole::OnTertiaryFixedVariant,
+            &[SchemeRole::TertiaryFixed, SchemeRole::TertiaryFixedDim],
+        ),
+        (SchemeRole::OnError, &[SchemeRole::Error]),
+        (SchemeRole::OnErrorContainer, &[SchemeRole::ErrorContainer]),
+        (SchemeRole::OnSurface, &Self::SURFACES),
+        (SchemeRole::OnSurfaceVariant, &Self::SURFACES),
+        (SchemeRole::OnBackground, &[SchemeRole::Background]),
+        (SchemeRole::InverseOnSurface, &[SchemeRole::InverseSurface]),
+    ];
+
+    /// Surfaces and containers that surface text is placed on.
+    const SURFACES: [SchemeRole; 8] = [
+        SchemeRole::SurfaceDim,
+        SchemeRole::Surface,
+        SchemeRole::SurfaceBright,
+        SchemeRole::SurfaceContainerLowest,
+        SchemeRole::SurfaceContainerLow,
+        SchemeRole::SurfaceContainer,
+        SchemeRole::SurfaceContainerHigh,
+        SchemeRole::SurfaceContainerHighest,
+    ];
+
+    /// Returns the tone of the text role `name` in `scheme` with
+    /// [`AccessibilityOptions::high_contrast_text`] set, or `None` if `name`
+    /// is not a text role.
+    pub(super) fn text_tone(name: &str, scheme: &DynamicScheme) -> Option<f64> {
+        let (_, backgrounds) = Self::TEXT_ROLES
+            .iter()
+            .find(|(text, _)| text.name() == name)?;
+        let (black, white) = backgrounds.iter().fold(
+            (f64::INFINITY, f64::INFINITY),
+            |(black, white), &background| {
+                let tone = MaterialDynamicColors::by_role(background).get_tone(scheme);
+
+                (
+                    black.min(ratio_of_tones(0.0, tone)),
+                    white.min(ratio_of_tones(100.0, tone)),
+                )
+            },
+        );
+
+        Some(if black >= white { 0.0 } else { 100.0 })
+    }
+}
@@ -0,0 +1,85 @@
+/// Band of tones that [`DynamicColor`]s are moved out of when resolved.
+///
+/// Tones around T50-59 have a contrast ratio below 4.5 against both white and
+/// black, so a background there cannot host legible text of either polarity,
+/// and two related colors on opposite sides of it look unrelated. By default
+/// such tones are pushed to `start - 1` or `end`, whichever keeps the color
+/// on the side it is expanding towards.
+///
+/// Narrowing or disabling the zone lets brand colors keep mid tones, at the
+/// cost of containers whose foregrounds may no longer reach the contrast
+/// requested by the scheme's contrast level.
+///
+/// [`DynamicColor`]: super::DynamicColor
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct AwkwardZone {
+    /// First tone of the zone, inclusive.
+    pub start: f64,
+    /// Last tone of the zone, exclusive.
+    pub end: f64,
+}
+
+impl AwkwardZone {
+    /// The T50-59 zone used by Material Design.
+    pub const STANDARD: Self = Self::new(50.0, 60.0);
+
+    /// An empty zone; tones are never adjusted.
+    pub const DISABLED: Self = Self::new(0.0, 0.0);
+
+    pub const fn new(start: f64, end: f64) -> Self {
+        Self { start, end }
+    }
+
+    /// Whether `tone` falls inside the zone.
+    pub fn contains(&self, tone: f64) -> bool {
+        (self.start..self.end).contains(&tone)
+    }
+
+    /// Whether the zone is empty, i.e. never adjusts any tone.
+    pub fn is_disabled(&self) -> bool {
+        self.start >= self.end
+    }
+
+    /// Tone used when a color leaves the zone towards darker tones.
+    pub fn darker_tone(&self) -> f64 {
+        self.start - 1.0
+    }
+
+    /// Tone used when a color leaves the zone towards lighter tones.
+    pub const fn lighter_tone(&self) -> f64 {
+        self.end
+    }
+}
+
+impl Default for AwkwardZone {
+    fn default() -> Self {
+        Self::STANDARD
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AwkwardZone;
+    use float_cmp::assert_approx_eq;
+
+    #[test]
+    fn test_standard_zone() {
+        let zone = AwkwardZone::default();
+
+        assert!(zone.contains(50.0));
+        assert!(zone.contains(59.9));
+        assert!(!zone.contains(60.0));
+        assert!(!zone.contains(49.9));
+        assert_approx_eq!(f64, zone.darker_tone(), 49.0);
+        assert_approx_eq!(f64, zone.lighter_tone(), 60.0);
+    }
+
+    #[test]
+    fn test_disabled_zone() {
+        let zone = AwkwardZone::DISABLED;
+
+        assert!(zone.is_disabled());
+        assert!(!zone.contains(0.0));
+        assert!(!zone.contains(55.0));
+    }
+}
@@ -1,13 +1,19 @@
-use super::{MaterialDynamicColors, Variant};
+use super::{AccessibilityOptions, AwkwardZone, MaterialDynamicColors, ToneTable, Variant};
+#[cfg(all(not(feature = "std"), feature = "libm"))]
+#[allow(unused_imports)]
+use crate::utils::no_std::FloatExt;
 use crate::{
     color::Argb,
+    contrast::ratio_of_colors,
     hct::Hct,
-    palette::TonalPalette,
+    palette::{Palette, TonalPalette},
     scheme::variant::{
-        SchemeContent, SchemeExpressive, SchemeFidelity, SchemeFruitSalad, SchemeMonochrome,
-        SchemeNeutral, SchemeRainbow, SchemeTonalSpot, SchemeVibrant,
+        SchemeBuilder, SchemeContent, SchemeExpressive, SchemeFidelity, SchemeFruitSalad,
+        SchemeMonochrome, SchemeNeutral, SchemeRainbow, SchemeTonalSpot, SchemeVibrant,
     },
+    theme::ColorGroup,
     utils::math::sanitize_degrees_double,
+    Error,
 };
 use core::{
     cmp::Ordering,
@@ -15,6 +21,52 @@ use core::{
     hash::{Hash, Hasher},
 };
 
+/// Builds a [`DynamicScheme`] of a variant with some of its palettes
+/// overridden, created with [`DynamicScheme::builder`].
+///
+/// ```
+/// use material_colors::{
+///     color::Argb,
+///     dynamic_color::{DynamicScheme, Variant},
+///     palette::TonalPalette,
+/// };
+///
+/// let brand = TonalPalette::from_hct(Argb::from_u32(0xff00a86b).into());
+/// let scheme = DynamicScheme::builder(Argb::from_u32(0xff4285f4), Variant::TonalSpot)
+///     .tertiary_palette(brand)
+///     .build();
+///
+/// assert_eq!(scheme.tertiary_palette, brand);
+/// ```
+pub type DynamicSchemeBuilder = SchemeBuilder;
+
+/// Source of an extension role passed to [`DynamicScheme::derive_role_family`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RoleSource {
+    /// A seed color, turned into a palette by the variant of the scheme.
+    Seed(Hct),
+    /// A palette used as-is.
+    Palette(TonalPalette),
+}
+
+impl From<Argb> for RoleSource {
+    fn from(seed: Argb) -> Self {
+        Self::Seed(Hct::new(seed))
+    }
+}
+
+impl From<Hct> for RoleSource {
+    fn from(seed: Hct) -> Self {
+        Self::Seed(seed)
+    }
+}
+
+impl From<TonalPalette> for RoleSource {
+    fn from(palette: TonalPalette) -> Self {
+        Self::Palette(palette)
+    }
+}
+
 /// Constructed by a set of values representing the current UI state (such as
 /// whether or not its dark theme, what the theme style is, etc.), and
 /// provides a set of [`TonalPalette`]s that can create colors that fit in
@@ -61,6 +113,24 @@ pub struct DynamicScheme {
 
     /// Given a tone, produces a reddish, colorful, color.
     pub error_palette: TonalPalette,
+
+    /// Tones that resolved colors are moved out of. Defaults to
+    /// [`AwkwardZone::STANDARD`]; see [`AwkwardZone`] for the accessibility
+    /// trade-offs of changing it.
+    pub awkward_zone: AwkwardZone,
+
+    /// Whether dark surfaces are lowered to pure black, for AMOLED displays.
+    /// Surface containers keep their steps of elevation above black, and
+    /// foreground roles are resolved against the darker surfaces, so their
+    /// contrast requirements still hold. Has no effect on light schemes.
+    pub pure_black: bool,
+
+    /// Accessibility settings of the platform honored when resolving colors.
+    pub accessibility: AccessibilityOptions,
+
+    /// Tones roles start from before contrast adjustments, replacing those
+    /// of the variant for the roles it contains. Empty by default.
+    pub tone_table: ToneTable,
 }
 
 impl DynamicScheme {
@@ -87,11 +157,71 @@ impl DynamicScheme {
             neutral_palette,
             neutral_variant_palette,
             error_palette: error_palette.unwrap_or_else(|| TonalPalette::of(25.0, 84.0)),
+            awkward_zone: AwkwardZone::STANDARD,
+            pure_black: false,
+            accessibility: AccessibilityOptions::DEFAULT,
+            tone_table: ToneTable::new(),
         }
     }
 
-    pub fn by_variant(
-        source: Argb,
+    /// Returns the scheme with `awkward_zone` used when resolving its colors,
+    /// e.g. [`AwkwardZone::DISABLED`] to keep brand colors at mid tones.
+    #[must_use]
+    pub const fn with_awkward_zone(mut self, awkward_zone: AwkwardZone) -> Self {
+        self.awkward_zone = awkward_zone;
+
+        self
+    }
+
+    /// Returns the scheme with [`DynamicScheme::pure_black`] set to
+    /// `pure_black`.
+    #[must_use]
+    pub const fn with_pure_black(mut self, pure_black: bool) -> Self {
+        self.pure_black = pure_black;
+
+        self
+    }
+
+    /// Returns the scheme with `accessibility` honored when resolving its
+    /// colors.
+    #[must_use]
+    pub const fn with_accessibility(mut self, accessibility: AccessibilityOptions) -> Self {
+        self.accessibility = accessibility;
+
+        self
+    }
+
+    /// Returns the scheme with its roles starting from the tones of
+    /// `tone_table`, see [`ToneTable`].
+    #[must_use]
+    pub fn with_tone_table(mut self, tone_table: ToneTable) -> Self {
+        self.tone_table = tone_table;
+
+        self
+    }
+
+    /// Returns a builder of a scheme of `variant`, to override some of its
+    /// palettes before it is constructed, see [`DynamicSchemeBuilder`].
+    pub fn builder<T: Into<Hct>>(source: T, variant: Variant) -> DynamicSchemeBuilder {
+        SchemeBuilder::new(source.into(), variant)
+    }
+
+    /// Creates a scheme of `variant` from `source`.
+    ///
+    /// Same as [`DynamicScheme::by_variant_unchecked`]; see
+    /// [`DynamicScheme::try_by_variant`] to validate `contrast_level`.
+    pub fn by_variant<T: Into<Hct>>(
+        source: T,
+        variant: &Variant,
+        is_dark: bool,
+        contrast_level: Option<f64>,
+    ) -> Self {
+        Self::by_variant_unchecked(source, variant, is_dark, contrast_level)
+    }
+
+    /// Creates a scheme of `variant` without validating `contrast_level`.
+    pub fn by_variant_unchecked<T: Into<Hct>>(
+        source: T,
         variant: &Variant,
         is_dark: bool,
         contrast_level: Option<f64>,
@@ -117,37 +247,225 @@ impl DynamicScheme {
         }
     }
 
-    /// # Panics
+    /// Like [`DynamicScheme::by_variant`], but returns an error if
+    /// `contrast_level` is not a number between -1.0 and 1.0.
+    pub fn try_by_variant<T: Into<Hct>>(
+        source: T,
+        variant: &Variant,
+        is_dark: bool,
+        contrast_level: Option<f64>,
+    ) -> Result<Self, Error> {
+        if contrast_level.map_or(false, |level| !(-1.0..=1.0).contains(&level)) {
+            return Err(Error::InvalidContrastLevel);
+        }
+
+        Ok(Self::by_variant_unchecked(
+            source,
+            variant,
+            is_dark,
+            contrast_level,
+        ))
+    }
+
+    /// Version byte written by [`DynamicScheme::encode`].
     ///
-    /// Will panic if the count of hues does not equal the count of rotations
-    pub fn get_rotated_hue(source_hue: f64, hues: &[f64], rotations: &[f64]) -> f64 {
-        assert!(hues.len() == rotations.len());
+    /// Version 2 added the pure black flag. [`DynamicScheme::decode`] still
+    /// accepts version 1, whose schemes never have pure black surfaces.
+    pub const ENCODING_VERSION: u8 = 2;
 
-        if rotations.len() == 1 {
-            return sanitize_degrees_double(source_hue + rotations[0]);
+    /// Encodes the parameters that fully determine this scheme into a
+    /// compact canonical form, suitable for IPC.
+    ///
+    /// Layout, all multi-byte values big-endian:
+    ///
+    /// | Bytes  | Value                                 |
+    /// |--------|---------------------------------------|
+    /// | 0      | [`DynamicScheme::ENCODING_VERSION`]   |
+    /// | 1      | Variant index                         |
+    /// | 2      | Flags, bit 0 is set for dark schemes  |
+    /// |        | and bit 1 for pure black surfaces     |
+    /// | 3      | Reserved, always 0                    |
+    /// | 4..8   | Source color as ARGB                  |
+    /// | 8..16  | Contrast level as `f64`               |
+    ///
+    /// Palettes, the awkward zone, accessibility options and tone tables are
+    /// not encoded, so schemes with custom palettes or zones will not survive
+    /// a round trip through [`DynamicScheme::decode`].
+    pub fn encode(&self) -> [u8; 16] {
+        let source = Argb::from(self.source_color_hct);
+        let variant = match self.variant {
+            Variant::Monochrome => 0,
+            Variant::Neutral => 1,
+            Variant::TonalSpot => 2,
+            Variant::Vibrant => 3,
+            Variant::Expressive => 4,
+            Variant::Fidelity => 5,
+            Variant::Content => 6,
+            Variant::Rainbow => 7,
+            Variant::FruitSalad => 8,
+        };
+
+        let mut bytes = [0; 16];
+
+        bytes[0] = Self::ENCODING_VERSION;
+        bytes[1] = variant;
+        bytes[2] = u8::from(self.is_dark) | u8::from(self.pure_black) << 1;
+        bytes[4..8].copy_from_slice(&[source.alpha, source.red, source.green, source.blue]);
+        bytes[8..16].copy_from_slice(&self.contrast_level.to_be_bytes());
+
+        bytes
+    }
+
+    /// Creates a scheme from the output of [`DynamicScheme::encode`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidEncoding`] if the version, variant, flags or
+    /// reserved byte are unknown, and [`Error::InvalidContrastLevel`] if the
+    /// contrast level is not a number between -1.0 and 1.0.
+    pub fn decode(bytes: &[u8; 16]) -> Result<Self, Error> {
+        let max_flags = match bytes[0] {
+            1 => 0b1,
+            Self::ENCODING_VERSION => 0b11,
+            _ => return Err(Error::InvalidEncoding),
+        };
+
+        if bytes[2] > max_flags || bytes[3] != 0 {
+            return Err(Error::InvalidEncoding);
         }
 
-        if hues.is_empty() || rotations.is_empty() {
-            return source_hue;
+        let variant = match bytes[1] {
+            0 => Variant::Monochrome,
+            1 => Variant::Neutral,
+            2 => Variant::TonalSpot,
+            3 => Variant::Vibrant,
+            4 => Variant::Expressive,
+            5 => Variant::Fidelity,
+            6 => Variant::Content,
+            7 => Variant::Rainbow,
+            8 => Variant::FruitSalad,
+            _ => return Err(Error::InvalidEncoding),
+        };
+
+        let source = Argb::new(bytes[4], bytes[5], bytes[6], bytes[7]);
+        let mut contrast_level = [0; 8];
+
+        contrast_level.copy_from_slice(&bytes[8..16]);
+
+        Self::try_by_variant(
+            source,
+            &variant,
+            bytes[2] & 1 == 1,
+            Some(f64::from_be_bytes(contrast_level)),
+        )
+        .map(|scheme| scheme.with_pure_black(bytes[2] & 0b10 != 0))
+    }
+
+    /// Derives a color, its on-color, container and on-container for an
+    /// extension role, such as "success" or "brand", with the same tone and
+    /// contrast rules as the primary roles of this scheme.
+    ///
+    /// A seed color is turned into a palette the way this scheme's variant
+    /// turns its source color into the primary palette; a [`TonalPalette`] is
+    /// used as-is. The roles are then resolved against the surfaces,
+    /// brightness and contrast level of this scheme.
+    #[doc(alias = "RoleFamily")]
+    pub fn derive_role_family<T: Into<RoleSource>>(&self, source: T) -> ColorGroup {
+        let palette = match source.into() {
+            RoleSource::Seed(seed) => {
+                TonalPalette::by_variant(&seed, &self.variant, &Palette::Primary)
+            }
+            RoleSource::Palette(palette) => palette,
+        };
+        let mut scheme = self.clone();
+
+        scheme.source_color_hct = palette.key_color();
+        scheme.primary_palette = palette;
+
+        ColorGroup {
+            color: scheme.primary(),
+            on_color: scheme.on_primary(),
+            color_container: scheme.primary_container(),
+            on_color_container: scheme.on_primary_container(),
         }
+    }
 
-        let size = hues.len();
-        let mut i = 0;
+    /// Min contrast ratio of a focus indicator against the component it
+    /// surrounds and against the surface around it, per WCAG 2.2.
+    pub const FOCUS_RING_CONTRAST: f64 = 3.0;
 
-        while i <= (size - 2) {
-            let this_hue = hues[i];
-            let next_hue = hues[i + 1];
+    /// Returns a focus ring color for a component of color `component` on
+    /// `surrounding`, reaching [`DynamicScheme::FOCUS_RING_CONTRAST`] against
+    /// both, or `None` if no color of the scheme palettes does.
+    ///
+    /// See [`DynamicScheme::contrasting_color`].
+    pub fn focus_ring(&self, component: Argb, surrounding: Argb) -> Option<Argb> {
+        self.contrasting_color(&[component, surrounding], Self::FOCUS_RING_CONTRAST)
+    }
 
-            if this_hue < source_hue && source_hue < next_hue {
-                return sanitize_degrees_double(source_hue + rotations[i]);
-            }
+    /// Returns a color of the scheme palettes reaching `ratio` against every
+    /// color of `neighbors`, e.g. for focus rings or outlines drawn between a
+    /// component and its surroundings, or `None` if there is none.
+    ///
+    /// The primary, secondary, tertiary, neutral variant and neutral palettes
+    /// are tried in this order. Within a palette, the tone nearest to the
+    /// primary role of the scheme is chosen, in steps of half a tone, so that
+    /// the result looks as much like the primary color as possible. Contrast
+    /// is measured on the actual colors, after gamut mapping.
+    pub fn contrasting_color(&self, neighbors: &[Argb], ratio: f64) -> Option<Argb> {
+        const STEPS_PER_TONE: f64 = 2.0;
+
+        let target = self.primary().as_lstar();
+        let palettes = [
+            &self.primary_palette,
+            &self.secondary_palette,
+            &self.tertiary_palette,
+            &self.neutral_variant_palette,
+            &self.neutral_palette,
+        ];
+
+        palettes.into_iter().find_map(|palette| {
+            (0..=100 * STEPS_PER_TONE as u32)
+                .map(|step| f64::from(step) / STEPS_PER_TONE)
+                .map(|tone| ((tone - target).abs(), Argb::from(palette.get_hct(tone))))
+                .filter(|(_, color)| {
+                    neighbors
+                        .iter()
+                        .all(|neighbor| ratio_of_colors(*color, *neighbor) >= ratio)
+                })
+                .min_by(|a, b| a.0.total_cmp(&b.0))
+                .map(|(_, color)| color)
+        })
+    }
 
-            i += 1;
+    /// # Panics
+    ///
+    /// Will panic if the count of hues does not equal the count of rotations
+    pub fn get_rotated_hue(source_hue: f64, hues: &[f64], rotations: &[f64]) -> f64 {
+        Self::get_hue_rotation(source_hue, hues, rotations).map_or(source_hue, |rotation| {
+            sanitize_degrees_double(source_hue + rotation)
+        })
+    }
+
+    /// Returns the rotation [`DynamicScheme::get_rotated_hue`] applies to
+    /// `source_hue`, that of the range between breakpoints `hues` it falls
+    /// strictly within, or `None` if it falls on a breakpoint, in which case
+    /// the hue is left as is.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the count of hues does not equal the count of rotations
+    pub fn get_hue_rotation(source_hue: f64, hues: &[f64], rotations: &[f64]) -> Option<f64> {
+        assert!(hues.len() == rotations.len());
+
+        if rotations.len() == 1 {
+            return Some(rotations[0]);
         }
 
-        // If this statement executes, something is wrong, there should have been a rotation
-        // found using the arrays.
-        source_hue
+        hues.windows(2)
+            .zip(rotations)
+            .find(|(range, _)| range[0] < source_hue && source_hue < range[1])
+            .map(|(_, rotation)| *rotation)
     }
 
     pub fn primary_palette_key_color(&self) -> Argb {
@@ -385,6 +703,10 @@ impl PartialEq for DynamicScheme {
             && self.neutral_palette == other.neutral_palette
             && self.neutral_variant_palette == other.neutral_variant_palette
             && self.error_palette == other.error_palette
+            && self.awkward_zone == other.awkward_zone
+            && self.pure_black == other.pure_black
+            && self.accessibility == other.accessibility
+            && self.tone_table == other.tone_table
     }
 }
 
@@ -402,6 +724,11 @@ impl Hash for DynamicScheme {
         self.neutral_palette.hash(state);
         self.neutral_variant_palette.hash(state);
         self.error_palette.hash(state);
+        self.awkward_zone.start.to_bits().hash(state);
+        self.awkward_zone.end.to_bits().hash(state);
+        self.pure_black.hash(state);
+        self.accessibility.hash(state);
+        self.tone_table.hash(state);
     }
 }
 
@@ -455,9 +782,258 @@ impl fmt::Display for DynamicScheme {
 
 #[cfg(test)]
 mod tests {
-    use crate::{dynamic_color::DynamicScheme, hct::Hct};
+    use crate::{
+        color::Argb,
+        contrast::ratio_of_colors,
+        dynamic_color::{AccessibilityOptions, AwkwardZone, DynamicScheme, Variant},
+        hct::Hct,
+        palette::TonalPalette,
+        Error,
+    };
     use float_cmp::assert_approx_eq;
 
+    #[test]
+    fn test_awkward_zone() {
+        let scheme = DynamicScheme::by_variant(
+            Argb::from_u32(0xff6750a4),
+            &Variant::TonalSpot,
+            false,
+            Some(1.0),
+        );
+        let zone = AwkwardZone::new(20.0, 45.0);
+        let adjusted = scheme.clone().with_awkward_zone(zone);
+        let tone = |argb: Argb| Hct::new(argb).get_tone().round();
+
+        assert!(zone.contains(tone(scheme.primary_container())));
+        assert_eq!(scheme.awkward_zone, AwkwardZone::STANDARD);
+        assert!(tone(adjusted.primary_container()) < zone.start);
+        assert!(scheme != adjusted);
+        assert_eq!(
+            scheme
+                .clone()
+                .with_awkward_zone(AwkwardZone::DISABLED)
+                .primary(),
+            scheme.primary()
+        );
+    }
+
+    #[test]
+    fn test_reduce_transparency() {
+        let accessibility = AccessibilityOptions {
+            reduce_transparency: true,
+            ..AccessibilityOptions::DEFAULT
+        };
+
+        for is_dark in [false, true] {
+            let scheme = DynamicScheme::by_variant(
+                Argb::from_u32(0xff6750a4),
+                &Variant::TonalSpot,
+                is_dark,
+                None,
+            );
+            let opaque = scheme.clone().with_accessibility(accessibility);
+            let surface = Hct::new(scheme.surface());
+            let scrim = Hct::new(opaque.scrim());
+
+            assert_eq!(scheme.scrim(), Argb::from_u32(0xff000000));
+            assert_eq!(opaque.surface(), scheme.surface());
+            assert!(scrim.get_tone() < surface.get_tone());
+            assert!(scrim.get_tone() > 0.0 || surface.get_tone() < 1.0);
+        }
+
+        let light =
+            DynamicScheme::by_variant(Argb::from_u32(0xff6750a4), &Variant::TonalSpot, false, None)
+                .with_accessibility(accessibility);
+
+        // 68% of the sRGB value of T98, i.e. 169 of 249, is T69.5.
+        assert_approx_eq!(f64, Hct::new(light.scrim()).get_tone(), 69.5, epsilon = 0.5);
+    }
+
+    #[test]
+    fn test_high_contrast_text() {
+        let accessibility = AccessibilityOptions {
+            high_contrast_text: true,
+            ..AccessibilityOptions::DEFAULT
+        };
+
+        for is_dark in [false, true] {
+            let scheme = DynamicScheme::by_variant(
+                Argb::from_u32(0xff6750a4),
+                &Variant::Vibrant,
+                is_dark,
+                None,
+            );
+            let accessible = scheme.clone().with_accessibility(accessibility);
+            let text = if is_dark {
+                Argb::from_u32(0xffffffff)
+            } else {
+                Argb::from_u32(0xff000000)
+            };
+
+            assert_eq!(accessible.on_surface(), text);
+            assert_eq!(accessible.on_surface_variant(), text);
+            assert_eq!(accessible.surface(), scheme.surface());
+            assert_eq!(accessible.primary(), scheme.primary());
+            assert_ne!(
+                accessible.on_primary_container(),
+                scheme.on_primary_container()
+            );
+        }
+    }
+
+    #[test]
+    fn test_derive_role_family() {
+        let source = Argb::from_u32(0xff6750a4);
+        let scheme = DynamicScheme::by_variant(source, &Variant::TonalSpot, false, None);
+        let own = scheme.derive_role_family(source);
+
+        assert_eq!(own.color, scheme.primary());
+        assert_eq!(own.on_color, scheme.on_primary());
+        assert_eq!(own.color_container, scheme.primary_container());
+        assert_eq!(own.on_color_container, scheme.on_primary_container());
+
+        let palette = TonalPalette::of(140.0, 40.0);
+        let success = scheme.derive_role_family(palette);
+
+        assert_eq!(success.color, palette.tone(40));
+        assert_eq!(success.on_color, palette.tone(100));
+        assert_eq!(success.color_container, palette.tone(90));
+        assert_eq!(success.on_color_container, palette.tone(30));
+
+        let dark = DynamicScheme::by_variant(source, &Variant::TonalSpot, true, None)
+            .derive_role_family(palette);
+
+        assert_eq!(dark.color, palette.tone(80));
+    }
+
+    #[test]
+    fn test_encoding_round_trip() {
+        let source = Argb::from_u32(0xff6750a4);
+        let scheme = DynamicScheme::by_variant(source, &Variant::Expressive, true, Some(0.5));
+        let bytes = scheme.encode();
+
+        assert_eq!(bytes[..8], [2, 4, 1, 0, 0xff, 0x67, 0x50, 0xa4]);
+
+        let decoded = DynamicScheme::decode(&bytes).unwrap();
+
+        assert!(decoded.variant == Variant::Expressive);
+        assert!(decoded.is_dark);
+        assert_approx_eq!(f64, decoded.contrast_level, 0.5);
+        assert_eq!(decoded.primary(), scheme.primary());
+        assert_eq!(decoded.encode(), bytes);
+
+        let bytes = scheme.with_pure_black(true).encode();
+
+        assert_eq!(bytes[2], 0b11);
+        assert!(DynamicScheme::decode(&bytes).unwrap().pure_black);
+    }
+
+    #[test]
+    fn test_decoding_version_1() {
+        let source = Argb::from_u32(0xff6750a4);
+        let scheme = DynamicScheme::by_variant(source, &Variant::Expressive, true, Some(0.5));
+        let mut bytes = [1, 4, 1, 0, 0xff, 0x67, 0x50, 0xa4, 0, 0, 0, 0, 0, 0, 0, 0];
+
+        bytes[8..16].copy_from_slice(&0.5f64.to_be_bytes());
+
+        let decoded = DynamicScheme::decode(&bytes).unwrap();
+
+        assert!(!decoded.pure_black);
+        assert_eq!(decoded.encode(), scheme.encode());
+
+        bytes[2] = 0b11;
+
+        assert_eq!(
+            DynamicScheme::decode(&bytes).err(),
+            Some(Error::InvalidEncoding)
+        );
+    }
+
+    #[test]
+    fn test_pure_black() {
+        let source = Argb::from_u32(0xff6750a4);
+
+        for variant in [Variant::TonalSpot, Variant::Vibrant, Variant::Monochrome] {
+            for contrast_level in [0.0, 1.0] {
+                let dark = DynamicScheme::by_variant(source, &variant, true, Some(contrast_level));
+                let black = dark.clone().with_pure_black(true);
+                let surface = black.surface();
+
+                assert_eq!(surface, Argb::from_u32(0xff000000));
+                assert_eq!(black.background(), surface);
+                assert_eq!(black.surface_dim(), surface);
+                assert_eq!(black.surface_container_lowest(), surface);
+                assert!(
+                    Hct::new(black.surface_container()).get_tone()
+                        < Hct::new(dark.surface_container()).get_tone()
+                );
+                assert!(ratio_of_colors(black.on_surface(), surface) >= 4.5);
+                assert!(ratio_of_colors(black.primary(), surface) >= 3.0);
+                assert!(
+                    ratio_of_colors(black.on_primary_container(), black.primary_container()) >= 4.5
+                );
+
+                let light =
+                    DynamicScheme::by_variant(source, &variant, false, Some(contrast_level));
+
+                assert_eq!(
+                    light.clone().with_pure_black(true).surface(),
+                    light.surface()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_decoding_errors() {
+        let bytes =
+            DynamicScheme::by_variant(Argb::from_u32(0xff0000ff), &Variant::TonalSpot, false, None)
+                .encode();
+
+        for (index, value) in [(0, 0), (0, 3), (1, 9), (2, 4), (3, 1)] {
+            let mut invalid = bytes;
+
+            invalid[index] = value;
+
+            assert_eq!(
+                DynamicScheme::decode(&invalid).err(),
+                Some(Error::InvalidEncoding)
+            );
+        }
+
+        let mut invalid = bytes;
+
+        invalid[8..16].copy_from_slice(&2.0f64.to_be_bytes());
+
+        assert_eq!(
+            DynamicScheme::decode(&invalid).err(),
+            Some(Error::InvalidContrastLevel)
+        );
+    }
+
+    #[test]
+    fn test_try_by_variant() {
+        let source = Argb::from_u32(0xff0000ff);
+
+        for contrast_level in [f64::NAN, -1.5, 2.0] {
+            assert_eq!(
+                DynamicScheme::try_by_variant(
+                    source,
+                    &Variant::TonalSpot,
+                    false,
+                    Some(contrast_level)
+                )
+                .err(),
+                Some(Error::InvalidContrastLevel)
+            );
+        }
+
+        assert!(
+            DynamicScheme::try_by_variant(source, &Variant::TonalSpot, false, Some(0.5)).is_ok()
+        );
+        assert!(DynamicScheme::try_by_variant(source, &Variant::TonalSpot, false, None).is_ok());
+    }
+
     #[test]
     fn test_0_length_input() {
         let hue = DynamicScheme::get_rotated_hue(Hct::from(43.0, 16.0, 16.0).get_hue(), &[], &[]);
@@ -465,6 +1041,21 @@ mod tests {
         assert_approx_eq!(f64, hue, 43.0, epsilon = 1.0);
     }
 
+    #[test]
+    fn test_builder() {
+        let source = Argb::from_u32(0xff4285f4);
+        let brand = TonalPalette::from_hct(Argb::from_u32(0xff00a86b).into());
+        let scheme = DynamicScheme::builder(source, Variant::TonalSpot)
+            .dark(true)
+            .tertiary_palette(brand)
+            .build();
+        let stock = DynamicScheme::by_variant(source, &Variant::TonalSpot, true, None);
+
+        assert_eq!(scheme.tertiary_palette, brand);
+        assert_eq!(scheme.primary(), stock.primary());
+        assert_ne!(scheme.tertiary(), stock.tertiary());
+    }
+
     #[test]
     fn test_1_length_input_no_rotation() {
         let hue =
@@ -494,4 +1085,27 @@ mod tests {
 
         assert_approx_eq!(f64, hue, 163.0, epsilon = 1.0);
     }
+
+    #[test]
+    fn test_focus_ring() {
+        let scheme =
+            DynamicScheme::by_variant(Argb::from_u32(0xff6750a4), &Variant::TonalSpot, false, None);
+        let component = scheme.primary();
+        let surrounding = scheme.surface();
+        let ring = scheme.focus_ring(component, surrounding).unwrap();
+
+        assert!(ratio_of_colors(ring, component) >= DynamicScheme::FOCUS_RING_CONTRAST);
+        assert!(ratio_of_colors(ring, surrounding) >= DynamicScheme::FOCUS_RING_CONTRAST);
+        assert_eq!(
+            scheme.primary_palette.tone_of(ring).map(|tone| tone > 50.0),
+            Some(false)
+        );
+
+        // Reaching 5:1 against black needs a luminance above 0.2, but against
+        // white one below 0.16.
+        let extremes = [Argb::from_u32(0xff000000), Argb::from_u32(0xffffffff)];
+
+        assert_eq!(scheme.contrasting_color(&extremes, 5.0), None);
+        assert_eq!(scheme.contrasting_color(&[], 3.0), Some(scheme.primary()));
+    }
 }
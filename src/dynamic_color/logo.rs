@@ -0,0 +1,162 @@
+use super::{DynamicScheme, MaterialDynamicColors};
+#[cfg(all(not(feature = "std"), feature = "libm"))]
+#[allow(unused_imports)]
+use crate::utils::no_std::FloatExt;
+use crate::{color::Argb, contrast::ratio_of_colors, scheme::SchemeRole};
+
+/// Variant of a logo, see [`LogoVariants`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LogoVariant {
+    /// The variant drawn in dark colors, for light backgrounds.
+    Dark,
+    /// The variant drawn in light colors, for dark backgrounds.
+    Light,
+}
+
+/// Where to draw a logo, as chosen by [`LogoVariants::place`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LogoPlacement {
+    /// The logo variant is drawn directly on the surface.
+    Surface(LogoVariant),
+    /// Neither variant contrasts enough with the surface, so the logo
+    /// variant is drawn on a container of `color`, at `tone` of the palette
+    /// of the surface.
+    Container {
+        logo: LogoVariant,
+        tone: f64,
+        color: Argb,
+    },
+}
+
+impl LogoPlacement {
+    /// The logo variant to draw.
+    pub const fn logo(&self) -> LogoVariant {
+        match self {
+            Self::Surface(logo) | Self::Container { logo, .. } => *logo,
+        }
+    }
+}
+
+/// Representative colors of the dark and light variants of a logo, e.g. the
+/// color of their marks, used to pick the variant drawn on a themed surface
+/// such as a header or footer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LogoVariants {
+    pub dark: Argb,
+    pub light: Argb,
+}
+
+impl LogoVariants {
+    /// Min contrast ratio of a logo against its background, that of
+    /// graphical objects per WCAG 2.2.
+    pub const MIN_CONTRAST: f64 = 3.0;
+
+    pub const fn new(dark: Argb, light: Argb) -> Self {
+        Self { dark, light }
+    }
+
+    /// Returns the color of `logo`.
+    pub const fn color(&self, logo: LogoVariant) -> Argb {
+        match logo {
+            LogoVariant::Dark => self.dark,
+            LogoVariant::Light => self.light,
+        }
+    }
+
+    /// Picks the variant reaching [`LogoVariants::MIN_CONTRAST`] against the
+    /// `surface` role of `scheme`, the one contrasting more if both do.
+    ///
+    /// If neither does, suggests a container to draw the logo on: the tone of
+    /// the palette of `surface` nearest to the surface itself against which
+    /// either variant reaches the ratio, again preferring the variant
+    /// contrasting more. There always is one, since every color reaches 3:1
+    /// against black or white, tones 0 and 100 of every palette.
+    pub fn place(&self, scheme: &DynamicScheme, surface: SchemeRole) -> LogoPlacement {
+        let role = MaterialDynamicColors::by_role(surface);
+        let background = role.get_argb(scheme);
+
+        if let Some(logo) = self.best_against(background) {
+            return LogoPlacement::Surface(logo);
+        }
+
+        let palette = (role.palette)(scheme);
+        let surface_tone = role.get_tone(scheme);
+
+        (0..=100)
+            .map(f64::from)
+            .filter_map(|tone| {
+                let color = Argb::from(palette.get_hct(tone));
+
+                self.best_against(color)
+                    .map(|logo| ((tone - surface_tone).abs(), tone, color, logo))
+            })
+            .min_by(|a, b| a.0.total_cmp(&b.0))
+            .map(|(_, tone, color, logo)| LogoPlacement::Container { logo, tone, color })
+            .expect("black or white contrasts with every color")
+    }
+
+    /// Returns the variant contrasting more with `background`, if it reaches
+    /// [`LogoVariants::MIN_CONTRAST`].
+    fn best_against(self, background: Argb) -> Option<LogoVariant> {
+        [LogoVariant::Dark, LogoVariant::Light]
+            .into_iter()
+            .map(|logo| (ratio_of_colors(self.color(logo), background), logo))
+            .filter(|(ratio, _)| *ratio >= Self::MIN_CONTRAST)
+            .max_by(|a, b| a.0.total_cmp(&b.0))
+            .map(|(_, logo)| logo)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{LogoPlacement, LogoVariant, LogoVariants};
+    use crate::{
+        color::Argb,
+        contrast::ratio_of_colors,
+        dynamic_color::{DynamicScheme, Variant},
+        scheme::SchemeRole,
+    };
+
+    fn scheme(is_dark: bool) -> DynamicScheme {
+        DynamicScheme::by_variant(
+            Argb::from_u32(0xff6750a4),
+            &Variant::TonalSpot,
+            is_dark,
+            None,
+        )
+    }
+
+    #[test]
+    fn test_place_on_surface() {
+        let logos = LogoVariants::new(Argb::from_u32(0xff202020), Argb::from_u32(0xfff0f0f0));
+
+        assert_eq!(
+            logos.place(&scheme(false), SchemeRole::Surface),
+            LogoPlacement::Surface(LogoVariant::Dark)
+        );
+        assert_eq!(
+            logos.place(&scheme(true), SchemeRole::Surface),
+            LogoPlacement::Surface(LogoVariant::Light)
+        );
+    }
+
+    #[test]
+    fn test_place_on_container() {
+        // Both variants are too light for a light surface.
+        let logos = LogoVariants::new(Argb::from_u32(0xff999999), Argb::from_u32(0xffa0a0a0));
+        let scheme = scheme(false);
+        let placement = logos.place(&scheme, SchemeRole::Surface);
+
+        match placement {
+            LogoPlacement::Container { logo, tone, color } => {
+                assert_eq!(logo, LogoVariant::Light);
+                assert_eq!(color, scheme.neutral_palette.tone(tone as i32));
+                assert!(ratio_of_colors(logos.light, color) >= LogoVariants::MIN_CONTRAST);
+                assert!(tone < 50.0, "{tone}");
+            }
+            LogoPlacement::Surface(_) => panic!("{placement:?}"),
+        }
+
+        assert_eq!(placement.logo(), LogoVariant::Light);
+    }
+}
@@ -1,8 +1,16 @@
-use super::{ContrastCurve, DynamicColor, DynamicScheme, ToneDeltaPair, TonePolarity, Variant};
+use super::{
+    AccessibilityOptions, ContrastCurve, DynamicColor, DynamicScheme, ToneDeltaPair, TonePolarity,
+    Variant,
+};
 #[cfg(all(not(feature = "std"), feature = "libm"))]
 #[allow(unused_imports)]
 use crate::utils::no_std::FloatExt;
-use crate::{dislike::fix_if_disliked, hct::Hct};
+use crate::{
+    color::{lstar_from_y, srgb_decode, srgb_encode, y_from_lstar},
+    dislike::fix_if_disliked,
+    hct::Hct,
+    scheme::SchemeRole,
+};
 
 const fn _is_fidelity(scheme: &DynamicScheme) -> bool {
     matches!(scheme.variant, Variant::Fidelity) || matches!(scheme.variant, Variant::Content)
@@ -12,12 +20,88 @@ const fn _is_monochrome(scheme: &DynamicScheme) -> bool {
     matches!(scheme.variant, Variant::Monochrome)
 }
 
+/// Lowers the tone of a dark surface by the tone of the standard surface
+/// when [`DynamicScheme::pure_black`] is set, so that surfaces become pure
+/// black while containers keep their steps of elevation.
+fn dark_surface_tone(scheme: &DynamicScheme, tone: f64) -> f64 {
+    if scheme.pure_black {
+        (tone - 6.0).max(0.0)
+    } else {
+        tone
+    }
+}
+
+/// Returns the tone of black drawn at `opacity` over a neutral color of
+/// `tone`, blended in sRGB like platforms composite translucent layers.
+fn darken_tone(tone: f64, opacity: f64) -> f64 {
+    let encoded = srgb_encode(y_from_lstar(tone) / 100.0) * (1.0 - opacity);
+
+    lstar_from_y(srgb_decode(encoded) * 100.0)
+}
+
 /// Tokens, or named colors, in the Material Design system.
 pub struct MaterialDynamicColors;
 
 impl MaterialDynamicColors {
     pub const CONTENT_ACCENT_TONE_DELTA: f64 = 15.0;
 
+    /// Returns the dynamic color resolved into `role` of a [`Scheme`].
+    ///
+    /// [`Scheme`]: crate::scheme::Scheme
+    pub fn by_role(role: SchemeRole) -> DynamicColor {
+        match role {
+            SchemeRole::Primary => Self::primary(),
+            SchemeRole::OnPrimary => Self::on_primary(),
+            SchemeRole::PrimaryContainer => Self::primary_container(),
+            SchemeRole::OnPrimaryContainer => Self::on_primary_container(),
+            SchemeRole::InversePrimary => Self::inverse_primary(),
+            SchemeRole::PrimaryFixed => Self::primary_fixed(),
+            SchemeRole::PrimaryFixedDim => Self::primary_fixed_dim(),
+            SchemeRole::OnPrimaryFixed => Self::on_primary_fixed(),
+            SchemeRole::OnPrimaryFixedVariant => Self::on_primary_fixed_variant(),
+            SchemeRole::Secondary => Self::secondary(),
+            SchemeRole::OnSecondary => Self::on_secondary(),
+            SchemeRole::SecondaryContainer => Self::secondary_container(),
+            SchemeRole::OnSecondaryContainer => Self::on_secondary_container(),
+            SchemeRole::SecondaryFixed => Self::secondary_fixed(),
+            SchemeRole::SecondaryFixedDim => Self::secondary_fixed_dim(),
+            SchemeRole::OnSecondaryFixed => Self::on_secondary_fixed(),
+            SchemeRole::OnSecondaryFixedVariant => Self::on_secondary_fixed_variant(),
+            SchemeRole::Tertiary => Self::tertiary(),
+            SchemeRole::OnTertiary => Self::on_tertiary(),
+            SchemeRole::TertiaryContainer => Self::tertiary_container(),
+            SchemeRole::OnTertiaryContainer => Self::on_tertiary_container(),
+            SchemeRole::TertiaryFixed => Self::tertiary_fixed(),
+            SchemeRole::TertiaryFixedDim => Self::tertiary_fixed_dim(),
+            SchemeRole::OnTertiaryFixed => Self::on_tertiary_fixed(),
+            SchemeRole::OnTertiaryFixedVariant => Self::on_tertiary_fixed_variant(),
+            SchemeRole::Error => Self::error(),
+            SchemeRole::OnError => Self::on_error(),
+            SchemeRole::ErrorContainer => Self::error_container(),
+            SchemeRole::OnErrorContainer => Self::on_error_container(),
+            SchemeRole::SurfaceDim => Self::surface_dim(),
+            SchemeRole::Surface => Self::surface(),
+            SchemeRole::SurfaceTint => Self::surface_tint(),
+            SchemeRole::SurfaceBright => Self::surface_bright(),
+            SchemeRole::SurfaceContainerLowest => Self::surface_container_lowest(),
+            SchemeRole::SurfaceContainerLow => Self::surface_container_low(),
+            SchemeRole::SurfaceContainer => Self::surface_container(),
+            SchemeRole::SurfaceContainerHigh => Self::surface_container_high(),
+            SchemeRole::SurfaceContainerHighest => Self::surface_container_highest(),
+            SchemeRole::OnSurface => Self::on_surface(),
+            SchemeRole::OnSurfaceVariant => Self::on_surface_variant(),
+            SchemeRole::Outline => Self::outline(),
+            SchemeRole::OutlineVariant => Self::outline_variant(),
+            SchemeRole::InverseSurface => Self::inverse_surface(),
+            SchemeRole::InverseOnSurface => Self::inverse_on_surface(),
+            SchemeRole::SurfaceVariant => Self::surface_variant(),
+            SchemeRole::Background => Self::background(),
+            SchemeRole::OnBackground => Self::on_background(),
+            SchemeRole::Shadow => Self::shadow(),
+            SchemeRole::Scrim => Self::scrim(),
+        }
+    }
+
     fn highest_surface(scheme: &DynamicScheme) -> DynamicColor {
         if scheme.is_dark {
             Self::surface_bright()
@@ -70,7 +154,13 @@ impl MaterialDynamicColors {
         DynamicColor::new(
             "background",
             |scheme| &scheme.neutral_palette,
-            |scheme| if scheme.is_dark { 6.0 } else { 98.0 },
+            |scheme| {
+                if scheme.is_dark {
+                    dark_surface_tone(scheme, 6.0)
+                } else {
+                    98.0
+                }
+            },
             true,
             None,
             None,
@@ -101,7 +191,13 @@ impl MaterialDynamicColors {
         DynamicColor::new(
             "surface",
             |scheme| &scheme.neutral_palette,
-            |scheme| if scheme.is_dark { 6.0 } else { 98.0 },
+            |scheme| {
+                if scheme.is_dark {
+                    dark_surface_tone(scheme, 6.0)
+                } else {
+                    98.0
+                }
+            },
             true,
             None,
             None,
@@ -116,7 +212,7 @@ impl MaterialDynamicColors {
             |scheme| &scheme.neutral_palette,
             |scheme| {
                 if scheme.is_dark {
-                    6.0
+                    dark_surface_tone(scheme, 6.0)
                 } else {
                     ContrastCurve {
                         low: 87.0,
@@ -141,13 +237,16 @@ impl MaterialDynamicColors {
             |scheme| &scheme.neutral_palette,
             |scheme| {
                 if scheme.is_dark {
-                    ContrastCurve {
-                        low: 24.0,
-                        normal: 24.0,
-                        medium: 29.0,
-                        high: 34.0,
-                    }
-                    .get(scheme.contrast_level)
+                    dark_surface_tone(
+                        scheme,
+                        ContrastCurve {
+                            low: 24.0,
+                            normal: 24.0,
+                            medium: 29.0,
+                            high: 34.0,
+                        }
+                        .get(scheme.contrast_level),
+                    )
                 } else {
                     98.0
                 }
@@ -166,13 +265,16 @@ impl MaterialDynamicColors {
             |scheme| &scheme.neutral_palette,
             |scheme| {
                 if scheme.is_dark {
-                    ContrastCurve {
-                        low: 4.0,
-                        normal: 4.0,
-                        medium: 2.0,
-                        high: 0.0,
-                    }
-                    .get(scheme.contrast_level)
+                    dark_surface_tone(
+                        scheme,
+                        ContrastCurve {
+                            low: 4.0,
+                            normal: 4.0,
+                            medium: 2.0,
+                            high: 0.0,
+                        }
+                        .get(scheme.contrast_level),
+                    )
                 } else {
                     100.0
                 }
@@ -191,13 +293,16 @@ impl MaterialDynamicColors {
             |scheme| &scheme.neutral_palette,
             |scheme| {
                 if scheme.is_dark {
-                    ContrastCurve {
-                        low: 10.0,
-                        normal: 10.0,
-                        medium: 11.0,
-                        high: 12.0,
-                    }
-                    .get(scheme.contrast_level)
+                    dark_surface_tone(
+                        scheme,
+                        ContrastCurve {
+                            low: 10.0,
+                            normal: 10.0,
+                            medium: 11.0,
+                            high: 12.0,
+                        }
+                        .get(scheme.contrast_level),
+                    )
                 } else {
                     ContrastCurve {
                         low: 96.0,
@@ -222,13 +327,16 @@ impl MaterialDynamicColors {
             |scheme| &scheme.neutral_palette,
             |scheme| {
                 if scheme.is_dark {
-                    ContrastCurve {
-                        low: 12.0,
-                        normal: 12.0,
-                        medium: 16.0,
-                        high: 20.0,
-                    }
-                    .get(scheme.contrast_level)
+                    dark_surface_tone(
+                        scheme,
+                        ContrastCurve {
+                            low: 12.0,
+                            normal: 12.0,
+                            medium: 16.0,
+                            high: 20.0,
+                        }
+                        .get(scheme.contrast_level),
+                    )
                 } else {
                     ContrastCurve {
                         low: 94.0,
@@ -253,13 +361,16 @@ impl MaterialDynamicColors {
             |scheme| &scheme.neutral_palette,
             |scheme| {
                 if scheme.is_dark {
-                    ContrastCurve {
-                        low: 17.0,
-                        normal: 17.0,
-                        medium: 21.0,
-                        high: 25.0,
-                    }
-                    .get(scheme.contrast_level)
+                    dark_surface_tone(
+                        scheme,
+                        ContrastCurve {
+                            low: 17.0,
+                            normal: 17.0,
+                            medium: 21.0,
+                            high: 25.0,
+                        }
+                        .get(scheme.contrast_level),
+                    )
                 } else {
                     ContrastCurve {
                         low: 92.0,
@@ -284,13 +395,16 @@ impl MaterialDynamicColors {
             |scheme| &scheme.neutral_palette,
             |scheme| {
                 if scheme.is_dark {
-                    ContrastCurve {
-                        low: 22.0,
-                        normal: 22.0,
-                        medium: 26.0,
-                        high: 30.0,
-                    }
-                    .get(scheme.contrast_level)
+                    dark_surface_tone(
+                        scheme,
+                        ContrastCurve {
+                            low: 22.0,
+                            normal: 22.0,
+                            medium: 26.0,
+                            high: 30.0,
+                        }
+                        .get(scheme.contrast_level),
+                    )
                 } else {
                     ContrastCurve {
                         low: 90.0,
@@ -442,7 +556,16 @@ impl MaterialDynamicColors {
         DynamicColor::new(
             "scrim",
             |scheme| &scheme.neutral_palette,
-            |_scheme| 0.0,
+            |scheme| {
+                if scheme.accessibility.reduce_transparency {
+                    darken_tone(
+                        Self::surface().get_tone(scheme),
+                        AccessibilityOptions::SCRIM_OPACITY,
+                    )
+                } else {
+                    0.0
+                }
+            },
             false,
             None,
             None,
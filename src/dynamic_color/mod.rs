@@ -15,16 +15,29 @@ use alloc::{boxed::Box, string::String, vec, vec::Vec};
 use std::{boxed::Box, string::String, vec, vec::Vec};
 
 pub use {
-    contrast_curve::ContrastCurve, dynamic_scheme::DynamicScheme,
-    material_dynamic_colors::MaterialDynamicColors, tone_delta_pair::ToneDeltaPair,
-    tone_delta_pair::TonePolarity, variant::Variant,
+    accessibility::AccessibilityOptions,
+    awkward_zone::AwkwardZone,
+    contrast_curve::ContrastCurve,
+    dynamic_scheme::{DynamicScheme, DynamicSchemeBuilder, RoleSource},
+    logo::{LogoPlacement, LogoVariant, LogoVariants},
+    material_dynamic_colors::MaterialDynamicColors,
+    tone_delta_pair::ToneDeltaPair,
+    tone_delta_pair::TonePolarity,
+    tone_table::{RoleTones, ToneTable},
+    variant::Variant,
+    wallpaper_tint::WallpaperTint,
 };
 
+pub mod accessibility;
+pub mod awkward_zone;
 pub mod contrast_curve;
 pub mod dynamic_scheme;
+pub mod logo;
 pub mod material_dynamic_colors;
 pub mod tone_delta_pair;
+pub mod tone_table;
 pub mod variant;
+pub mod wallpaper_tint;
 
 type DynamicSchemeFn<T> = fn(&DynamicScheme) -> T;
 type DynamicSchemeFnRef<T> = fn(&DynamicScheme) -> &T;
@@ -143,12 +156,31 @@ impl DynamicColor {
         (self.palette)(scheme).get_hct(self.get_tone(scheme))
     }
 
+    /// Tone this color starts from before contrast adjustments, from the
+    /// [`ToneTable`] of `scheme` if it contains this color.
+    fn initial_tone(&self, scheme: &DynamicScheme) -> f64 {
+        if scheme.tone_table.is_empty() {
+            return (self.tone)(scheme);
+        }
+
+        scheme
+            .tone_table
+            .tone_of(&self.name, scheme.is_dark)
+            .unwrap_or_else(|| (self.tone)(scheme))
+    }
+
     /// - Parameter scheme: Defines the conditions of the user interface, for example,
     ///   whether or not it is dark mode or light mode, and what the desired
     ///   contrast level is.
     /// - Returns: a tone, T in the HCT color space, that this `DynamicColor` is under
     ///   the conditions in `scheme`.
     pub fn get_tone(&self, scheme: &DynamicScheme) -> f64 {
+        if scheme.accessibility.high_contrast_text {
+            if let Some(tone) = AccessibilityOptions::text_tone(&self.name, scheme) {
+                return tone;
+            }
+        }
+
         let decreasing_contrast = scheme.contrast_level < 0.0;
 
         // Case 1: dual foreground, pair of colors with delta constraint.
@@ -185,7 +217,7 @@ impl DynamicColor {
 
             // If a color is good enough, it is not adjusted.
             // Initial and adjusted tones for `nearer`
-            let n_initial_tone = (nearer.tone)(scheme);
+            let n_initial_tone = nearer.initial_tone(scheme);
             let mut n_tone = if decreasing_contrast {
                 Self::foreground_tone(bg_tone, n_contrast)
             } else if ratio_of_tones(bg_tone, n_initial_tone) >= n_contrast {
@@ -194,7 +226,7 @@ impl DynamicColor {
                 Self::foreground_tone(bg_tone, n_contrast)
             };
             // Initial and adjusted tones for `farther`
-            let f_initial_tone = (farther.tone)(scheme);
+            let f_initial_tone = farther.initial_tone(scheme);
             let mut f_tone = if decreasing_contrast {
                 Self::foreground_tone(bg_tone, f_contrast)
             } else if ratio_of_tones(bg_tone, f_initial_tone) >= f_contrast {
@@ -217,38 +249,40 @@ impl DynamicColor {
                 }
             }
 
-            // Avoids the 50-59 awkward zone.
-            if (50.0..60.0).contains(&n_tone) {
+            // Avoids the awkward zone, T50-59 by default.
+            let zone = scheme.awkward_zone;
+
+            if zone.contains(n_tone) {
                 // If `nearer` is in the awkward zone, move it away, together with
                 // `farther`.
                 if expansion_dir > 0.0 {
-                    n_tone = 60.0;
+                    n_tone = zone.lighter_tone();
 
                     f_tone = f_tone.max(delta.mul_add(expansion_dir, n_tone));
                 } else {
-                    n_tone = 49.0;
+                    n_tone = zone.darker_tone();
 
                     f_tone = f_tone.min(delta.mul_add(expansion_dir, n_tone));
                 }
-            } else if (50.0..60.0).contains(&f_tone) {
+            } else if zone.contains(f_tone) {
                 if stay_together {
                     // Fixes both, to avoid two colors on opposite sides of the "awkward
                     // zone".
                     if expansion_dir > 0.0 {
-                        n_tone = 60.0;
+                        n_tone = zone.lighter_tone();
 
                         f_tone = f_tone.max(delta.mul_add(expansion_dir, n_tone));
                     } else {
-                        n_tone = 49.0;
+                        n_tone = zone.darker_tone();
 
                         f_tone = f_tone.min(delta.mul_add(expansion_dir, n_tone));
                     }
                 } else {
                     // Not required to stay together; fixes just one.
                     if expansion_dir > 0.0 {
-                        f_tone = 60.0;
+                        f_tone = zone.lighter_tone();
                     } else {
-                        f_tone = 49.0;
+                        f_tone = zone.darker_tone();
                     }
                 }
             }
@@ -261,7 +295,7 @@ impl DynamicColor {
             }
         } else {
             // Case 2: No contrast pair; just solve for itself.
-            let mut answer = (self.tone)(scheme);
+            let mut answer = self.initial_tone(scheme);
 
             if let Some(background) = &self.background {
                 let bg_tone = background(scheme).get_tone(scheme);
@@ -283,12 +317,14 @@ impl DynamicColor {
                     answer = Self::foreground_tone(bg_tone, desired_ratio);
                 }
 
-                if self.is_background && (50.0..60.0).contains(&answer) {
+                let zone = scheme.awkward_zone;
+
+                if self.is_background && zone.contains(answer) {
                     // Must adjust
-                    if ratio_of_tones(49.0, bg_tone) >= desired_ratio {
-                        answer = 49.0;
+                    if ratio_of_tones(zone.darker_tone(), bg_tone) >= desired_ratio {
+                        answer = zone.darker_tone();
                     } else {
-                        answer = 60.0;
+                        answer = zone.lighter_tone();
                     }
                 }
 
@@ -0,0 +1,233 @@
+use super::{DynamicScheme, MaterialDynamicColors};
+use crate::scheme::SchemeRole;
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+use core::hash::{Hash, Hasher};
+#[cfg(feature = "std")]
+use std::vec::Vec;
+
+/// Tones of a role in light and dark schemes, see [`ToneTable`].
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct RoleTones {
+    pub light: f64,
+    pub dark: f64,
+}
+
+impl RoleTones {
+    pub const fn new(light: f64, dark: f64) -> Self {
+        Self { light, dark }
+    }
+
+    /// Returns the tone for light or dark schemes.
+    pub const fn get(&self, is_dark: bool) -> f64 {
+        if is_dark {
+            self.dark
+        } else {
+            self.light
+        }
+    }
+}
+
+/// Tones roles are resolved from, before contrast adjustments, set on a
+/// scheme with [`DynamicScheme::with_tone_table`].
+///
+/// Each role of [`MaterialDynamicColors`] starts from a tone chosen by the
+/// variant and brightness of the scheme, e.g. tone 90 for the primary
+/// container of light tonal spot schemes, and is then moved to reach its
+/// contrast against its background and to keep its distance from paired
+/// roles. A table replaces the starting tone of the roles it contains, in
+/// bulk, while the contrast requirements still hold.
+///
+/// [`ToneTable::of`] returns the stock tones of a scheme as a starting point
+/// for adjustments.
+#[derive(Debug, Clone, Default, PartialEq, PartialOrd)]
+pub struct ToneTable {
+    tones: Vec<(SchemeRole, RoleTones)>,
+}
+
+impl ToneTable {
+    /// Creates a table without any overrides.
+    pub const fn new() -> Self {
+        Self { tones: Vec::new() }
+    }
+
+    /// Returns the stock tones of every role of schemes with the source,
+    /// variant, palettes and contrast level of `scheme`, in both light and
+    /// dark.
+    pub fn of(scheme: &DynamicScheme) -> Self {
+        let mut light = scheme.clone();
+        let mut dark = scheme.clone();
+
+        light.is_dark = false;
+        dark.is_dark = true;
+
+        Self {
+            tones: SchemeRole::ALL
+                .into_iter()
+                .map(|role| {
+                    let color = MaterialDynamicColors::by_role(role);
+
+                    (
+                        role,
+                        RoleTones::new((color.tone)(&light), (color.tone)(&dark)),
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    /// Returns the table with the tones of `role` set to `light` and `dark`.
+    #[must_use]
+    pub fn with(mut self, role: SchemeRole, light: f64, dark: f64) -> Self {
+        self.set(role, RoleTones::new(light, dark));
+
+        self
+    }
+
+    /// Sets the tones of `role`.
+    pub fn set(&mut self, role: SchemeRole, tones: RoleTones) {
+        match self.tones.binary_search_by_key(&role, |(role, _)| *role) {
+            Ok(index) => self.tones[index].1 = tones,
+            Err(index) => self.tones.insert(index, (role, tones)),
+        }
+    }
+
+    /// Returns the tones of `role`, if the table contains it.
+    pub fn get(&self, role: SchemeRole) -> Option<RoleTones> {
+        self.tones
+            .binary_search_by_key(&role, |(role, _)| *role)
+            .ok()
+            .map(|index| self.tones[index].1)
+    }
+
+    /// Returns the tone of the role named `name` for light or dark schemes,
+    /// if the table contains it.
+    pub(crate) fn tone_of(&self, name: &str, is_dark: bool) -> Option<f64> {
+        self.tones
+            .iter()
+            .find(|(role, _)| role.name() == name)
+            .map(|(_, tones)| tones.get(is_dark))
+    }
+
+    /// Returns an iterator over the roles of the table and their tones.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = (SchemeRole, RoleTones)> + '_ {
+        self.tones.iter().copied()
+    }
+
+    /// Count of roles in the table.
+    pub fn len(&self) -> usize {
+        self.tones.len()
+    }
+
+    /// Whether the table contains no roles.
+    pub fn is_empty(&self) -> bool {
+        self.tones.is_empty()
+    }
+}
+
+impl Eq for ToneTable {}
+
+impl Hash for ToneTable {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for (role, tones) in &self.tones {
+            role.hash(state);
+            tones.light.to_bits().hash(state);
+            tones.dark.to_bits().hash(state);
+        }
+    }
+}
+
+impl FromIterator<(SchemeRole, RoleTones)> for ToneTable {
+    fn from_iter<T: IntoIterator<Item = (SchemeRole, RoleTones)>>(iter: T) -> Self {
+        let mut table = Self::new();
+
+        for (role, tones) in iter {
+            table.set(role, tones);
+        }
+
+        table
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RoleTones, ToneTable};
+    use crate::{
+        color::Argb,
+        dynamic_color::{DynamicScheme, MaterialDynamicColors, Variant},
+        scheme::SchemeRole,
+    };
+    #[cfg(not(feature = "std"))]
+    use alloc::vec::Vec;
+    use float_cmp::assert_approx_eq;
+    #[cfg(feature = "std")]
+    use std::vec::Vec;
+
+    fn scheme(is_dark: bool) -> DynamicScheme {
+        DynamicScheme::by_variant(
+            Argb::from_u32(0xff6750a4),
+            &Variant::TonalSpot,
+            is_dark,
+            None,
+        )
+    }
+
+    #[test]
+    fn test_of() {
+        let table = ToneTable::of(&scheme(false));
+
+        assert_eq!(table.len(), SchemeRole::ALL.len());
+        assert_eq!(
+            table.get(SchemeRole::PrimaryContainer),
+            Some(RoleTones::new(90.0, 30.0))
+        );
+        assert_eq!(table, ToneTable::of(&scheme(true)));
+    }
+
+    #[test]
+    fn test_set_replaces_and_sorts() {
+        let table = ToneTable::new()
+            .with(SchemeRole::Secondary, 30.0, 70.0)
+            .with(SchemeRole::Primary, 35.0, 75.0)
+            .with(SchemeRole::Secondary, 45.0, 85.0);
+
+        assert_eq!(
+            table.iter().collect::<Vec<_>>(),
+            [
+                (SchemeRole::Primary, RoleTones::new(35.0, 75.0)),
+                (SchemeRole::Secondary, RoleTones::new(45.0, 85.0)),
+            ]
+        );
+        assert_eq!(table.get(SchemeRole::Tertiary), None);
+    }
+
+    #[test]
+    fn test_overrides_starting_tone() {
+        let table = ToneTable::new().with(SchemeRole::PrimaryContainer, 85.0, 25.0);
+
+        for is_dark in [false, true] {
+            let stock = scheme(is_dark);
+            let adjusted = stock.clone().with_tone_table(table.clone());
+            let container = MaterialDynamicColors::primary_container();
+
+            assert_approx_eq!(
+                f64,
+                container.get_tone(&adjusted),
+                if is_dark { 25.0 } else { 85.0 }
+            );
+            assert_eq!(adjusted.primary(), stock.primary());
+            assert!(adjusted != stock);
+        }
+    }
+
+    #[test]
+    fn test_contrast_still_holds() {
+        // On-primary at tone 50 would barely contrast with primary at tone
+        // 40, so it is moved away instead of staying at the requested tone.
+        let table = ToneTable::new().with(SchemeRole::OnPrimary, 50.0, 50.0);
+        let adjusted = scheme(false).with_tone_table(table);
+        let on_primary = MaterialDynamicColors::on_primary().get_tone(&adjusted);
+
+        assert!(on_primary > 50.0, "{on_primary}");
+    }
+}
@@ -1,12 +1,19 @@
+use super::DynamicScheme;
+use crate::hct::Hct;
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
 /// Set of themes supported by Dynamic Color.
 /// Instantiate the corresponding subclass, ex. [`SchemeTonalSpot`], to create
 /// colors corresponding to the theme.
 ///
 /// [`SchemeTonalSpot`]: crate::scheme::variant::SchemeTonalSpot
-#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub enum Variant {
     Monochrome,
     Neutral,
+    #[default]
     TonalSpot,
     Vibrant,
     Expressive,
@@ -15,3 +22,55 @@ pub enum Variant {
     Rainbow,
     FruitSalad,
 }
+
+impl Variant {
+    /// Every variant, in declaration order.
+    pub const ALL: [Self; 9] = [
+        Self::Monochrome,
+        Self::Neutral,
+        Self::TonalSpot,
+        Self::Vibrant,
+        Self::Expressive,
+        Self::Fidelity,
+        Self::Content,
+        Self::Rainbow,
+        Self::FruitSalad,
+    ];
+
+    /// Returns the scheme of every variant for `seed`, in the order of
+    /// [`Variant::ALL`], e.g. for previewing all styles in a theme picker.
+    ///
+    /// Schemes are generated lazily, as the iterator advances.
+    pub fn all_schemes(
+        seed: Hct,
+        is_dark: bool,
+        contrast_level: f64,
+    ) -> impl ExactSizeIterator<Item = (Self, DynamicScheme)> {
+        Self::ALL.into_iter().map(move |variant| {
+            let scheme = DynamicScheme::by_variant(seed, &variant, is_dark, Some(contrast_level));
+
+            (variant, scheme)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Variant;
+    use crate::{color::Argb, dynamic_color::DynamicScheme, hct::Hct};
+
+    #[test]
+    fn test_all_schemes() {
+        let seed = Hct::new(Argb::from_u32(0xff4285f4));
+        let schemes = Variant::all_schemes(seed, true, 0.5);
+
+        assert_eq!(schemes.len(), Variant::ALL.len());
+
+        for ((variant, scheme), expected) in schemes.zip(Variant::ALL) {
+            assert_eq!(variant, expected);
+            assert_eq!(scheme.variant, variant);
+            assert!(scheme.is_dark);
+            assert!(scheme == DynamicScheme::by_variant(seed, &variant, true, Some(0.5)));
+        }
+    }
+}
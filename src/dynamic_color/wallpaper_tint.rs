@@ -0,0 +1,141 @@
+use super::DynamicScheme;
+#[cfg(all(not(feature = "std"), feature = "libm"))]
+#[allow(unused_imports)]
+use crate::utils::no_std::FloatExt;
+use crate::{color::Argb, contrast::ratio_of_colors, utils::math::lerp};
+
+/// Overlay tinting a wallpaper to harmonize with the scheme of the UI drawn
+/// over it, e.g. darkening a wallpaper under a dark launcher.
+///
+/// The overlay is a vertical gradient of [`WallpaperTint::color`], from
+/// [`WallpaperTint::top_alpha`] under the status bar to
+/// [`WallpaperTint::bottom_alpha`] under the labels at the bottom of the
+/// screen, composited over the wallpaper in sRGB, as by compositors.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WallpaperTint {
+    pub color: Argb,
+    pub top_alpha: f64,
+    pub bottom_alpha: f64,
+}
+
+impl WallpaperTint {
+    /// Min contrast ratio of the icons of the status bar against the tinted
+    /// wallpaper, that of graphical objects per WCAG 2.2.
+    pub const ICON_CONTRAST: f64 = 3.0;
+
+    /// Min contrast ratio of labels against the tinted wallpaper, that of
+    /// text per WCAG 2.2.
+    pub const TEXT_CONTRAST: f64 = 4.5;
+
+    /// Returns the tint of a wallpaper represented by `seed`, usually the
+    /// color extracted from it, under the UI of `scheme`.
+    ///
+    /// The overlay is the surface of the scheme, which carries its hue, made
+    /// opaque enough for its on-surface color to reach
+    /// [`WallpaperTint::ICON_CONTRAST`] at the top and
+    /// [`WallpaperTint::TEXT_CONTRAST`] at the bottom against the tinted
+    /// seed, so a wallpaper already dark enough for a dark scheme is left
+    /// untouched.
+    pub fn for_scheme(seed: Argb, scheme: &DynamicScheme) -> Self {
+        let color = scheme.surface();
+        let foreground = scheme.on_surface();
+        let alpha = |ratio| {
+            (0..=100)
+                .map(|step| f64::from(step) / 100.0)
+                .find(|alpha| ratio_of_colors(foreground, over(color, seed, *alpha)) >= ratio)
+                .unwrap_or(1.0)
+        };
+
+        Self {
+            color,
+            top_alpha: alpha(Self::ICON_CONTRAST),
+            bottom_alpha: alpha(Self::TEXT_CONTRAST),
+        }
+    }
+
+    /// Alpha of the overlay at `position`, from 0.0 at the top to 1.0 at the
+    /// bottom.
+    pub fn alpha_at(&self, position: f64) -> f64 {
+        lerp(self.top_alpha, self.bottom_alpha, position.clamp(0.0, 1.0))
+    }
+
+    /// Returns `pixel` of the wallpaper tinted at `position`, from 0.0 at the
+    /// top to 1.0 at the bottom.
+    pub fn apply(&self, pixel: Argb, position: f64) -> Argb {
+        over(self.color, pixel, self.alpha_at(position))
+    }
+}
+
+/// Composites `color` with `alpha` over an opaque `background`.
+fn over(color: Argb, background: Argb, alpha: f64) -> Argb {
+    let channel =
+        |top: u8, bottom: u8| lerp(f64::from(bottom), f64::from(top), alpha).round() as u8;
+
+    Argb::new(
+        255,
+        channel(color.red, background.red),
+        channel(color.green, background.green),
+        channel(color.blue, background.blue),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WallpaperTint;
+    use crate::{
+        color::Argb,
+        contrast::ratio_of_colors,
+        dynamic_color::{DynamicScheme, Variant},
+    };
+    use float_cmp::assert_approx_eq;
+
+    fn scheme(source: Argb) -> DynamicScheme {
+        DynamicScheme::by_variant(source, &Variant::TonalSpot, true, None)
+    }
+
+    #[test]
+    fn test_bright_wallpaper() {
+        let seed = Argb::from_u32(0xfff2c94c);
+        let scheme = scheme(seed);
+        let tint = WallpaperTint::for_scheme(seed, &scheme);
+
+        assert_eq!(tint.color, scheme.surface());
+        assert!(tint.top_alpha > 0.0);
+        assert!(tint.bottom_alpha > tint.top_alpha);
+        assert!(
+            ratio_of_colors(scheme.on_surface(), tint.apply(seed, 1.0))
+                >= WallpaperTint::TEXT_CONTRAST
+        );
+        assert!(
+            ratio_of_colors(scheme.on_surface(), tint.apply(seed, 0.0))
+                >= WallpaperTint::ICON_CONTRAST
+        );
+    }
+
+    #[test]
+    fn test_dark_wallpaper() {
+        let seed = Argb::from_u32(0xff102040);
+        let tint = WallpaperTint::for_scheme(seed, &scheme(seed));
+
+        assert_approx_eq!(f64, tint.top_alpha, 0.0);
+        assert_approx_eq!(f64, tint.bottom_alpha, 0.0);
+        assert_eq!(tint.apply(seed, 0.5), seed);
+    }
+
+    #[test]
+    fn test_alpha_at() {
+        let tint = WallpaperTint {
+            color: Argb::from_u32(0xff000000),
+            top_alpha: 0.2,
+            bottom_alpha: 0.6,
+        };
+
+        assert_approx_eq!(f64, tint.alpha_at(0.0), 0.2);
+        assert_approx_eq!(f64, tint.alpha_at(0.5), 0.4);
+        assert_approx_eq!(f64, tint.alpha_at(2.0), 0.6);
+        assert_eq!(
+            tint.apply(Argb::from_u32(0xffffffff), 1.0),
+            Argb::from_u32(0xff666666)
+        );
+    }
+}
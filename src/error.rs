@@ -1,7 +1,9 @@
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
 use core::fmt;
 
 #[cfg(feature = "std")]
-use std::error::Error as Err;
+use std::{error::Error as Err, string::String};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Error {
@@ -9,12 +11,61 @@ pub enum Error {
     ///
     /// [`Argb::from_str`]: std::str::FromStr
     ParseRGB,
+    /// Error returned when a hue is NaN or infinite
+    InvalidHue,
+    /// Error returned when a chroma is NaN, infinite or negative
+    InvalidChroma,
+    /// Error returned when a contrast level is NaN or outside of `-1.0..=1.0`
+    InvalidContrastLevel,
+    /// Error returned when decoding a [`DynamicScheme`] from an unknown version
+    /// or malformed bytes
+    ///
+    /// [`DynamicScheme`]: crate::dynamic_color::DynamicScheme
+    InvalidEncoding,
+    /// Error returned when global viewing conditions are installed more than
+    /// once
+    ViewingConditionsAlreadySet,
+    /// Error returned when a string does not name a [`SchemeRole`]
+    ///
+    /// [`SchemeRole`]: crate::scheme::SchemeRole
+    UnknownRole,
+    /// Error returned when a template placeholder, included in the error,
+    /// does not name a [`SchemeRole`]
+    ///
+    /// [`SchemeRole`]: crate::scheme::SchemeRole
+    UnknownToken(String),
+    /// Error returned when a token map lacks the token of a [`SchemeRole`],
+    /// whose name is included in the error
+    ///
+    /// [`SchemeRole`]: crate::scheme::SchemeRole
+    MissingToken(String),
+    /// Error returned when HCT color parsing with the [`Hct::from_str`] fails
+    ///
+    /// [`Hct::from_str`]: crate::hct::Hct#impl-FromStr-for-Hct
+    ParseHct,
+    /// Error returned when device fixtures are malformed, with the reason
+    /// included in the error
+    InvalidFixture(String),
 }
 
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::ParseRGB => "provided string was not RGB-like".fmt(f),
+            Self::InvalidHue => "provided hue was not a finite number".fmt(f),
+            Self::InvalidChroma => "provided chroma was not a finite, non-negative number".fmt(f),
+            Self::InvalidContrastLevel => {
+                "provided contrast level was not a number between -1 and 1".fmt(f)
+            }
+            Self::InvalidEncoding => "provided bytes were not a valid scheme encoding".fmt(f),
+            Self::ViewingConditionsAlreadySet => {
+                "global viewing conditions were already set".fmt(f)
+            }
+            Self::UnknownRole => "provided string was not a scheme role".fmt(f),
+            Self::UnknownToken(token) => write!(f, "template token {token} was not a scheme role"),
+            Self::MissingToken(token) => write!(f, "token {token} of a scheme role was missing"),
+            Self::ParseHct => "provided string was not HCT-like".fmt(f),
+            Self::InvalidFixture(reason) => write!(f, "device fixtures were malformed: {reason}"),
         }
     }
 }
@@ -24,6 +75,16 @@ impl Err for Error {
     fn description(&self) -> &str {
         match self {
             Self::ParseRGB => "failed to parse RGB",
+            Self::InvalidHue => "invalid hue",
+            Self::InvalidChroma => "invalid chroma",
+            Self::InvalidContrastLevel => "invalid contrast level",
+            Self::InvalidEncoding => "invalid scheme encoding",
+            Self::ViewingConditionsAlreadySet => "viewing conditions already set",
+            Self::UnknownRole => "unknown scheme role",
+            Self::UnknownToken(_) => "unknown template token",
+            Self::MissingToken(_) => "missing scheme token",
+            Self::ParseHct => "failed to parse HCT",
+            Self::InvalidFixture(_) => "invalid device fixtures",
         }
     }
 }
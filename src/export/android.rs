@@ -0,0 +1,394 @@
+use super::{ExportOptions, Exporter};
+use crate::{
+    color::Argb,
+    scheme::{Scheme, SchemeRole},
+    theme::Theme,
+};
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
+use core::fmt::Write;
+#[cfg(feature = "std")]
+use std::string::String;
+
+/// Prefix of the color resources exported by the Material Theme Builder,
+/// e.g. `md_theme_light_primary`.
+pub const DEFAULT_PREFIX: &str = "md_theme";
+
+/// Attributes of the Material 3 themes of Material Components for Android
+/// and the roles they are mapped from.
+///
+/// `shadow`, `scrim` and `surface_tint` have no theme attribute, so only
+/// their color resources are exported.
+pub const THEME_ATTRIBUTES: [(&str, SchemeRole); 46] = [
+    ("colorPrimary", SchemeRole::Primary),
+    ("colorOnPrimary", SchemeRole::OnPrimary),
+    ("colorPrimaryContainer", SchemeRole::PrimaryContainer),
+    ("colorOnPrimaryContainer", SchemeRole::OnPrimaryContainer),
+    ("colorPrimaryInverse", SchemeRole::InversePrimary),
+    ("colorPrimaryFixed", SchemeRole::PrimaryFixed),
+    ("colorPrimaryFixedDim", SchemeRole::PrimaryFixedDim),
+    ("colorOnPrimaryFixed", SchemeRole::OnPrimaryFixed),
+    (
+        "colorOnPrimaryFixedVariant",
+        SchemeRole::OnPrimaryFixedVariant,
+    ),
+    ("colorSecondary", SchemeRole::Secondary),
+    ("colorOnSecondary", SchemeRole::OnSecondary),
+    ("colorSecondaryContainer", SchemeRole::SecondaryContainer),
+    (
+        "colorOnSecondaryContainer",
+        SchemeRole::OnSecondaryContainer,
+    ),
+    ("colorSecondaryFixed", SchemeRole::SecondaryFixed),
+    ("colorSecondaryFixedDim", SchemeRole::SecondaryFixedDim),
+    ("colorOnSecondaryFixed", SchemeRole::OnSecondaryFixed),
+    (
+        "colorOnSecondaryFixedVariant",
+        SchemeRole::OnSecondaryFixedVariant,
+    ),
+    ("colorTertiary", SchemeRole::Tertiary),
+    ("colorOnTertiary", SchemeRole::OnTertiary),
+    ("colorTertiaryContainer", SchemeRole::TertiaryContainer),
+    ("colorOnTertiaryContainer", SchemeRole::OnTertiaryContainer),
+    ("colorTertiaryFixed", SchemeRole::TertiaryFixed),
+    ("colorTertiaryFixedDim", SchemeRole::TertiaryFixedDim),
+    ("colorOnTertiaryFixed", SchemeRole::OnTertiaryFixed),
+    (
+        "colorOnTertiaryFixedVariant",
+        SchemeRole::OnTertiaryFixedVariant,
+    ),
+    ("colorError", SchemeRole::Error),
+    ("colorOnError", SchemeRole::OnError),
+    ("colorErrorContainer", SchemeRole::ErrorContainer),
+    ("colorOnErrorContainer", SchemeRole::OnErrorContainer),
+    ("android:colorBackground", SchemeRole::Background),
+    ("colorOnBackground", SchemeRole::OnBackground),
+    ("colorSurface", SchemeRole::Surface),
+    ("colorOnSurface", SchemeRole::OnSurface),
+    ("colorSurfaceVariant", SchemeRole::SurfaceVariant),
+    ("colorOnSurfaceVariant", SchemeRole::OnSurfaceVariant),
+    ("colorSurfaceInverse", SchemeRole::InverseSurface),
+    ("colorOnSurfaceInverse", SchemeRole::InverseOnSurface),
+    ("colorSurfaceBright", SchemeRole::SurfaceBright),
+    ("colorSurfaceDim", SchemeRole::SurfaceDim),
+    (
+        "colorSurfaceContainerLowest",
+        SchemeRole::SurfaceContainerLowest,
+    ),
+    ("colorSurfaceContainerLow", SchemeRole::SurfaceContainerLow),
+    ("colorSurfaceContainer", SchemeRole::SurfaceContainer),
+    (
+        "colorSurfaceContainerHigh",
+        SchemeRole::SurfaceContainerHigh,
+    ),
+    (
+        "colorSurfaceContainerHighest",
+        SchemeRole::SurfaceContainerHighest,
+    ),
+    ("colorOutline", SchemeRole::Outline),
+    ("colorOutlineVariant", SchemeRole::OutlineVariant),
+];
+
+const XML_DECLARATION: &str = "<?xml version=\"1.0\" encoding=\"utf-8\"?>";
+
+/// [`Exporter`] named `"android"`, rendering a scheme with [`to_colors`] and
+/// a whole theme with [`to_theme_colors`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AndroidExporter {
+    /// Prefix of the color resources, [`DEFAULT_PREFIX`] by default.
+    pub prefix: String,
+}
+
+impl Default for AndroidExporter {
+    fn default() -> Self {
+        Self {
+            prefix: DEFAULT_PREFIX.into(),
+        }
+    }
+}
+
+impl Exporter for AndroidExporter {
+    fn name(&self) -> &'static str {
+        "android"
+    }
+
+    fn export(&self, scheme: &Scheme, options: &ExportOptions) -> String {
+        to_colors_with_options(scheme, &self.prefix, options)
+    }
+
+    fn export_theme(&self, theme: &Theme, options: &ExportOptions) -> Option<String> {
+        Some(to_theme_colors_with_options(theme, &self.prefix, options))
+    }
+}
+
+/// Renders `scheme` as an Android `colors.xml` resource file, with one color
+/// per role named after `prefix` and the role, e.g. `md_theme_primary`:
+///
+/// ```xml
+/// <?xml version="1.0" encoding="utf-8"?>
+/// <resources>
+///     <color name="md_theme_primary">#904B40</color>
+///     ...
+/// </resources>
+/// ```
+pub fn to_colors(scheme: &Scheme, prefix: &str) -> String {
+    to_colors_with_options(scheme, prefix, &ExportOptions::default())
+}
+
+/// Like [`to_colors`], but orders colors and emits group headers as
+/// `<!-- -->` comments according to `options`.
+pub fn to_colors_with_options(scheme: &Scheme, prefix: &str, options: &ExportOptions) -> String {
+    let mut output = String::new();
+
+    _ = writeln!(output, "{XML_DECLARATION}\n<resources>");
+    write_colors(&mut output, scheme, prefix, *options);
+    _ = writeln!(output, "</resources>");
+
+    output
+}
+
+/// Renders the light and dark schemes of `theme` as one `colors.xml`
+/// resource file, as the Material Theme Builder does, with colors named
+/// e.g. `md_theme_light_primary` and `md_theme_dark_primary`.
+///
+/// The styles of [`to_themes`] refer to these colors.
+pub fn to_theme_colors(theme: &Theme, prefix: &str) -> String {
+    to_theme_colors_with_options(theme, prefix, &ExportOptions::default())
+}
+
+/// Like [`to_theme_colors`], but orders colors and emits group headers as
+/// `<!-- -->` comments according to `options`.
+pub fn to_theme_colors_with_options(
+    theme: &Theme,
+    prefix: &str,
+    options: &ExportOptions,
+) -> String {
+    let mut output = String::new();
+
+    _ = writeln!(output, "{XML_DECLARATION}\n<resources>");
+    write_colors(
+        &mut output,
+        &theme.schemes.light,
+        &mode_prefix(prefix, false),
+        *options,
+    );
+    output.push('\n');
+    write_colors(
+        &mut output,
+        &theme.schemes.dark,
+        &mode_prefix(prefix, true),
+        *options,
+    );
+    _ = writeln!(output, "</resources>");
+
+    output
+}
+
+/// Renders a `themes.xml` resource file declaring the style `name`, which
+/// sets every [`THEME_ATTRIBUTES`] entry to the colors of
+/// [`to_theme_colors`] with the same `prefix`:
+///
+/// ```xml
+/// <?xml version="1.0" encoding="utf-8"?>
+/// <resources>
+///     <style name="AppTheme" parent="Theme.Material3.Light.NoActionBar">
+///         <item name="colorPrimary">@color/md_theme_light_primary</item>
+///         ...
+///     </style>
+/// </resources>
+/// ```
+///
+/// The light style belongs in `res/values/themes.xml` and the dark one,
+/// rendered with `is_dark`, in `res/values-night/themes.xml`, so that Android
+/// switches between them with the dark theme setting.
+pub fn to_themes(name: &str, prefix: &str, is_dark: bool) -> String {
+    let mut output = String::new();
+    let prefix = mode_prefix(prefix, is_dark);
+    let parent = if is_dark {
+        "Theme.Material3.Dark.NoActionBar"
+    } else {
+        "Theme.Material3.Light.NoActionBar"
+    };
+
+    _ = writeln!(output, "{XML_DECLARATION}\n<resources>");
+    _ = writeln!(
+        output,
+        "    <style name=\"{}\" parent=\"{parent}\">",
+        escape(name)
+    );
+
+    for (attribute, role) in THEME_ATTRIBUTES {
+        _ = writeln!(
+            output,
+            "        <item name=\"{attribute}\">@color/{}</item>",
+            resource_name(&prefix, role.name())
+        );
+    }
+
+    _ = writeln!(output, "    </style>\n</resources>");
+
+    output
+}
+
+fn write_colors(output: &mut String, scheme: &Scheme, prefix: &str, options: ExportOptions) {
+    let tokens = options.tokens(scheme);
+
+    for (index, (_, role, color)) in tokens.iter().enumerate() {
+        if let Some(group) = options.header_at(&tokens, index) {
+            if index > 0 {
+                output.push('\n');
+            }
+
+            _ = writeln!(output, "    <!-- {} -->", group.label());
+        }
+
+        _ = writeln!(
+            output,
+            "    <color name=\"{}\">{}</color>",
+            resource_name(prefix, role),
+            hex(*color)
+        );
+    }
+}
+
+fn mode_prefix(prefix: &str, is_dark: bool) -> String {
+    resource_name(prefix, if is_dark { "dark" } else { "light" })
+}
+
+/// Joins `prefix` and `name` with an underscore, unless `prefix` is empty.
+fn resource_name(prefix: &str, name: &str) -> String {
+    let mut output = String::with_capacity(prefix.len() + name.len() + 1);
+
+    if !prefix.is_empty() {
+        output.push_str(&escape(prefix));
+        output.push('_');
+    }
+
+    output.push_str(name);
+
+    output
+}
+
+/// Formats `color` as Android resources do, e.g. `#904B40`.
+fn hex(color: Argb) -> String {
+    color.to_hex_with_pound().to_uppercase()
+}
+
+/// Escapes `value` for an XML attribute.
+fn escape(value: &str) -> String {
+    let mut output = String::with_capacity(value.len());
+
+    for character in value.chars() {
+        match character {
+            '&' => output.push_str("&amp;"),
+            '<' => output.push_str("&lt;"),
+            '>' => output.push_str("&gt;"),
+            '"' => output.push_str("&quot;"),
+            character => output.push(character),
+        }
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        escape, to_colors, to_colors_with_options, to_theme_colors, to_themes, DEFAULT_PREFIX,
+        THEME_ATTRIBUTES,
+    };
+    use crate::{
+        color::Argb,
+        export::{ExportOptions, ExporterRegistry, TokenOrder},
+        scheme::SchemeRole,
+        theme::ThemeBuilder,
+    };
+
+    #[test]
+    fn test_to_colors() {
+        let theme = ThemeBuilder::with_source(Argb::from_u32(0xffff0000)).build();
+        let output = to_colors(&theme.schemes.light, DEFAULT_PREFIX);
+
+        assert!(output.starts_with(
+            "<?xml version=\"1.0\" encoding=\"utf-8\"?>\n<resources>\n    <color name=\"md_theme_primary\">#904B40</color>\n"
+        ));
+        assert!(output.contains("    <color name=\"md_theme_on_primary_container\">"));
+        assert!(output.ends_with("</color>\n</resources>\n"));
+        assert_eq!(output.lines().count(), 2 + 49 + 1);
+        assert!(to_colors(&theme.schemes.light, "")
+            .contains("    <color name=\"primary\">#904B40</color>\n"));
+    }
+
+    #[test]
+    fn test_group_headers() {
+        let theme = ThemeBuilder::with_source(Argb::from_u32(0xffff0000)).build();
+        let options = ExportOptions {
+            order: TokenOrder::Grouped,
+            group_headers: true,
+        };
+        let output = to_colors_with_options(&theme.schemes.light, "app", &options);
+
+        assert!(output.contains(
+            "<resources>\n    <!-- Primary -->\n    <color name=\"app_primary\">#904B40</color>\n"
+        ));
+        assert!(output.contains("</color>\n\n    <!-- Secondary -->\n"));
+    }
+
+    #[test]
+    fn test_to_theme_colors() {
+        let theme = ThemeBuilder::with_source(Argb::from_u32(0xffff0000)).build();
+        let output = to_theme_colors(&theme, DEFAULT_PREFIX);
+
+        assert!(output.contains("    <color name=\"md_theme_light_primary\">#904B40</color>\n"));
+        assert!(output
+            .contains("</color>\n\n    <color name=\"md_theme_dark_primary\">#FFB4A8</color>\n"));
+        assert_eq!(output.lines().count(), 2 + 49 + 1 + 49 + 1);
+        assert_eq!(
+            ExporterRegistry::with_builtin()
+                .get("android")
+                .and_then(|exporter| exporter.export_theme(&theme, &ExportOptions::default())),
+            Some(output)
+        );
+    }
+
+    #[test]
+    fn test_to_themes() {
+        let light = to_themes("AppTheme", DEFAULT_PREFIX, false);
+        let dark = to_themes("AppTheme", DEFAULT_PREFIX, true);
+
+        assert!(light.contains(
+            "    <style name=\"AppTheme\" parent=\"Theme.Material3.Light.NoActionBar\">\n        <item name=\"colorPrimary\">@color/md_theme_light_primary</item>\n"
+        ));
+        assert!(light.contains(
+            "        <item name=\"android:colorBackground\">@color/md_theme_light_background</item>\n"
+        ));
+        assert!(dark.contains("parent=\"Theme.Material3.Dark.NoActionBar\""));
+        assert!(dark.contains(
+            "        <item name=\"colorSurfaceInverse\">@color/md_theme_dark_inverse_surface</item>\n"
+        ));
+        assert!(light.ends_with("</item>\n    </style>\n</resources>\n"));
+        assert_eq!(
+            light.lines().filter(|line| line.contains("<item")).count(),
+            THEME_ATTRIBUTES.len()
+        );
+        assert!(to_themes("A&B", DEFAULT_PREFIX, false).contains("<style name=\"A&amp;B\""));
+    }
+
+    #[test]
+    fn test_theme_attributes() {
+        for role in SchemeRole::ALL {
+            let count = THEME_ATTRIBUTES
+                .iter()
+                .filter(|(_, attribute_role)| *attribute_role == role)
+                .count();
+            let expected = usize::from(!matches!(
+                role,
+                SchemeRole::Shadow | SchemeRole::Scrim | SchemeRole::SurfaceTint
+            ));
+
+            assert_eq!(count, expected, "{role}");
+        }
+
+        assert_eq!(escape("<a href=\"\">"), "&lt;a href=&quot;&quot;&gt;");
+    }
+}
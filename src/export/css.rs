@@ -0,0 +1,209 @@
+use super::{ExportOptions, Exporter};
+use crate::{scheme::Scheme, theme::Theme};
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
+use core::fmt::Write;
+#[cfg(feature = "std")]
+use std::string::String;
+
+/// Prefix of the custom properties of Material Design, e.g.
+/// `--md-sys-color-primary`.
+pub const DEFAULT_PREFIX: &str = "md-sys-color";
+
+/// [`Exporter`] named `"css"`, rendering a scheme with [`to_variables`] and a
+/// whole theme with [`to_stylesheet`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CssExporter {
+    /// Prefix of the custom properties, [`DEFAULT_PREFIX`] by default.
+    pub prefix: String,
+}
+
+impl Default for CssExporter {
+    fn default() -> Self {
+        Self {
+            prefix: DEFAULT_PREFIX.into(),
+        }
+    }
+}
+
+impl Exporter for CssExporter {
+    fn name(&self) -> &'static str {
+        "css"
+    }
+
+    fn export(&self, scheme: &Scheme, options: &ExportOptions) -> String {
+        to_variables_with_options(scheme, &self.prefix, options)
+    }
+
+    fn export_theme(&self, theme: &Theme, options: &ExportOptions) -> Option<String> {
+        Some(to_stylesheet_with_options(theme, &self.prefix, options))
+    }
+}
+
+impl Scheme {
+    /// Renders the scheme as CSS custom properties, see [`to_variables`].
+    pub fn to_css_variables(&self, prefix: &str) -> String {
+        to_variables(self, prefix)
+    }
+}
+
+impl Theme {
+    /// Renders the light and dark schemes of the theme as CSS custom
+    /// properties, see [`to_stylesheet`].
+    pub fn to_css_variables(&self, prefix: &str) -> String {
+        to_stylesheet(self, prefix)
+    }
+}
+
+/// Renders `scheme` as CSS custom properties on `:root`.
+///
+/// Each role becomes a property named after `prefix` and the kebab case name
+/// of the role, e.g. `on_primary_container` becomes
+/// `--md-sys-color-on-primary-container` with the [`DEFAULT_PREFIX`]:
+///
+/// ```css
+/// :root {
+///   --md-sys-color-primary: #904b40;
+///   ...
+/// }
+/// ```
+///
+/// An empty `prefix` leaves the bare role, e.g. `--primary`.
+pub fn to_variables(scheme: &Scheme, prefix: &str) -> String {
+    to_variables_with_options(scheme, prefix, &ExportOptions::default())
+}
+
+/// Like [`to_variables`], but orders properties and emits group headers as
+/// `/* */` comments according to `options`.
+pub fn to_variables_with_options(scheme: &Scheme, prefix: &str, options: &ExportOptions) -> String {
+    let mut output = String::new();
+
+    write_rule(&mut output, scheme, prefix, *options, "");
+
+    output
+}
+
+/// Renders the light scheme of `theme` as CSS custom properties on `:root`,
+/// followed by the dark scheme in a `prefers-color-scheme` media query, so
+/// that pages follow the dark mode setting of the system:
+///
+/// ```css
+/// :root {
+///   --md-sys-color-primary: #904b40;
+///   ...
+/// }
+///
+/// @media (prefers-color-scheme: dark) {
+///   :root {
+///     --md-sys-color-primary: #ffb4a8;
+///     ...
+///   }
+/// }
+/// ```
+pub fn to_stylesheet(theme: &Theme, prefix: &str) -> String {
+    to_stylesheet_with_options(theme, prefix, &ExportOptions::default())
+}
+
+/// Like [`to_stylesheet`], but orders properties and emits group headers as
+/// `/* */` comments according to `options`.
+pub fn to_stylesheet_with_options(theme: &Theme, prefix: &str, options: &ExportOptions) -> String {
+    let mut output = String::new();
+
+    write_rule(&mut output, &theme.schemes.light, prefix, *options, "");
+    output.push_str("\n@media (prefers-color-scheme: dark) {\n");
+    write_rule(&mut output, &theme.schemes.dark, prefix, *options, "  ");
+    output.push_str("}\n");
+
+    output
+}
+
+fn write_rule(
+    output: &mut String,
+    scheme: &Scheme,
+    prefix: &str,
+    options: ExportOptions,
+    indent: &str,
+) {
+    let tokens = options.tokens(scheme);
+
+    _ = writeln!(output, "{indent}:root {{");
+
+    for (index, (_, role, color)) in tokens.iter().enumerate() {
+        if let Some(group) = options.header_at(&tokens, index) {
+            if index > 0 {
+                output.push('\n');
+            }
+
+            _ = writeln!(output, "{indent}  /* {} */", group.label());
+        }
+
+        let role = role.replace('_', "-");
+
+        if prefix.is_empty() {
+            _ = write!(output, "{indent}  --{role}");
+        } else {
+            _ = write!(output, "{indent}  --{prefix}-{role}");
+        }
+
+        _ = writeln!(output, ": {};", color.to_hex_with_pound());
+    }
+
+    _ = writeln!(output, "{indent}}}");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{to_stylesheet, to_variables, to_variables_with_options, DEFAULT_PREFIX};
+    use crate::{
+        color::Argb,
+        export::{ExportOptions, ExporterRegistry, TokenOrder},
+        theme::ThemeBuilder,
+    };
+
+    #[test]
+    fn test_to_variables() {
+        let theme = ThemeBuilder::with_source(Argb::from_u32(0xffff0000)).build();
+        let output = to_variables(&theme.schemes.light, DEFAULT_PREFIX);
+
+        assert!(output.starts_with(":root {\n  --md-sys-color-primary: #904b40;\n"));
+        assert!(output.contains("  --md-sys-color-on-primary-container: "));
+        assert!(output.ends_with(";\n}\n"));
+        assert_eq!(output.lines().count(), 51);
+        assert_eq!(theme.schemes.light.to_css_variables(DEFAULT_PREFIX), output);
+        assert!(
+            to_variables(&theme.schemes.light, "").starts_with(":root {\n  --primary: #904b40;\n")
+        );
+    }
+
+    #[test]
+    fn test_group_headers() {
+        let theme = ThemeBuilder::with_source(Argb::from_u32(0xffff0000)).build();
+        let options = ExportOptions {
+            order: TokenOrder::Grouped,
+            group_headers: true,
+        };
+        let output = to_variables_with_options(&theme.schemes.light, "app", &options);
+
+        assert!(output.starts_with(":root {\n  /* Primary */\n  --app-primary: #904b40;\n"));
+        assert!(output.contains(";\n\n  /* Secondary */\n"));
+    }
+
+    #[test]
+    fn test_to_stylesheet() {
+        let theme = ThemeBuilder::with_source(Argb::from_u32(0xffff0000)).build();
+        let output = to_stylesheet(&theme, DEFAULT_PREFIX);
+
+        assert!(output.starts_with(":root {\n  --md-sys-color-primary: #904b40;\n"));
+        assert!(output.contains(
+            "}\n\n@media (prefers-color-scheme: dark) {\n  :root {\n    --md-sys-color-primary: #ffb4a8;\n"
+        ));
+        assert!(output.ends_with(";\n  }\n}\n"));
+        assert_eq!(theme.to_css_variables(DEFAULT_PREFIX), output);
+        assert_eq!(
+            ExporterRegistry::with_builtin()
+                .get("css")
+                .and_then(|exporter| exporter.export_theme(&theme, &ExportOptions::default())),
+            Some(output)
+        );
+    }
+}
@@ -0,0 +1,216 @@
+//! Colors of a theme as RGBA floats for game engines such as Bevy and ggez,
+//! which consume colors as values rather than as files.
+//!
+//! [`ThemeColors`] holds the colors of the light and dark schemes of a theme
+//! and swaps them when the theme or the brightness changes. Wrap it in a
+//! Bevy resource and rebuild the UI colors when it changes:
+//!
+//! ```ignore
+//! #[derive(Resource)]
+//! struct Colors(ThemeColors);
+//!
+//! fn on_wallpaper(mut colors: ResMut<Colors>, wallpaper: Res<Wallpaper>) {
+//!     let theme = ThemeBuilder::with_source(wallpaper.source).build();
+//!
+//!     colors.0.set_theme(&theme);
+//! }
+//!
+//! fn paint(colors: Res<Colors>, mut panels: Query<&mut BackgroundColor, With<Panel>>) {
+//!     if colors.is_changed() {
+//!         let [r, g, b, a] = colors.0.linear(SchemeRole::SurfaceContainer);
+//!
+//!         for mut background in &mut panels {
+//!             background.0 = Color::linear_rgba(r, g, b, a);
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! Bevy's `Color::linear_rgba` takes [`ThemeColors::linear`], while ggez's
+//! `Color::new` takes the gamma-encoded [`ThemeColors::srgb`].
+
+use crate::{
+    color::{linearized, Argb},
+    scheme::{Scheme, SchemeRole},
+    theme::Theme,
+};
+
+/// Colors of every role of a scheme, in linear light, indexed by
+/// [`SchemeRole`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SchemeColors {
+    scheme: Scheme,
+    linear: [[f32; 4]; 49],
+}
+
+impl SchemeColors {
+    pub fn new(scheme: &Scheme) -> Self {
+        Self {
+            scheme: scheme.clone(),
+            linear: SchemeRole::ALL.map(|role| to_linear(scheme[role])),
+        }
+    }
+
+    /// Returns the scheme the colors were taken from.
+    pub const fn scheme(&self) -> &Scheme {
+        &self.scheme
+    }
+
+    /// Returns the color of `role` as linear RGBA, with channels and alpha
+    /// between 0.0 and 1.0.
+    pub const fn linear(&self, role: SchemeRole) -> [f32; 4] {
+        self.linear[role as usize]
+    }
+
+    /// Returns the color of `role` as gamma-encoded sRGB RGBA, with channels
+    /// and alpha between 0.0 and 1.0.
+    pub fn srgb(&self, role: SchemeRole) -> [f32; 4] {
+        let color = self.scheme[role];
+
+        [color.red, color.green, color.blue, color.alpha].map(|channel| f32::from(channel) / 255.0)
+    }
+}
+
+/// Colors of the light and dark schemes of a theme, one of which is current,
+/// swapped when the theme or the brightness changes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ThemeColors {
+    light: SchemeColors,
+    dark: SchemeColors,
+    is_dark: bool,
+    generation: u64,
+}
+
+impl ThemeColors {
+    pub fn new(theme: &Theme, is_dark: bool) -> Self {
+        Self {
+            light: SchemeColors::new(&theme.schemes.light),
+            dark: SchemeColors::new(&theme.schemes.dark),
+            is_dark,
+            generation: 0,
+        }
+    }
+
+    /// Returns the colors of the current scheme.
+    pub const fn current(&self) -> &SchemeColors {
+        if self.is_dark {
+            &self.dark
+        } else {
+            &self.light
+        }
+    }
+
+    /// Whether the dark scheme is current.
+    pub const fn is_dark(&self) -> bool {
+        self.is_dark
+    }
+
+    /// Counter increased whenever the current colors change, for engines
+    /// without change detection.
+    pub const fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    /// Returns the color of `role` in the current scheme as linear RGBA, see
+    /// [`SchemeColors::linear`].
+    pub const fn linear(&self, role: SchemeRole) -> [f32; 4] {
+        self.current().linear(role)
+    }
+
+    /// Returns the color of `role` in the current scheme as gamma-encoded
+    /// sRGB RGBA, see [`SchemeColors::srgb`].
+    pub fn srgb(&self, role: SchemeRole) -> [f32; 4] {
+        self.current().srgb(role)
+    }
+
+    /// Replaces the colors with those of `theme`. Returns whether the
+    /// current colors changed.
+    pub fn set_theme(&mut self, theme: &Theme) -> bool {
+        if self.light.scheme == theme.schemes.light && self.dark.scheme == theme.schemes.dark {
+            return false;
+        }
+
+        let changed = if self.is_dark {
+            self.dark.scheme != theme.schemes.dark
+        } else {
+            self.light.scheme != theme.schemes.light
+        };
+
+        self.light = SchemeColors::new(&theme.schemes.light);
+        self.dark = SchemeColors::new(&theme.schemes.dark);
+
+        self.bump(changed)
+    }
+
+    /// Makes the dark or light scheme current. Returns whether the current
+    /// colors changed.
+    pub fn set_dark(&mut self, is_dark: bool) -> bool {
+        let changed = self.is_dark != is_dark && self.light != self.dark;
+
+        self.is_dark = is_dark;
+
+        self.bump(changed)
+    }
+
+    fn bump(&mut self, changed: bool) -> bool {
+        if changed {
+            self.generation += 1;
+        }
+
+        changed
+    }
+}
+
+fn to_linear(color: Argb) -> [f32; 4] {
+    [
+        (linearized(color.red) / 100.0) as f32,
+        (linearized(color.green) / 100.0) as f32,
+        (linearized(color.blue) / 100.0) as f32,
+        f32::from(color.alpha) / 255.0,
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ThemeColors;
+    use crate::{color::Argb, scheme::SchemeRole, theme::ThemeBuilder};
+    use float_cmp::assert_approx_eq;
+
+    #[test]
+    fn test_colors() {
+        let theme = ThemeBuilder::with_source(Argb::from_u32(0xff4285f4)).build();
+        let colors = ThemeColors::new(&theme, false);
+        let [r, g, b, a] = colors.linear(SchemeRole::OnPrimary);
+
+        assert_eq!(theme.schemes.light.on_primary, Argb::from_u32(0xffffffff));
+        assert_approx_eq!(f32, r, 1.0);
+        assert_approx_eq!(f32, g, 1.0);
+        assert_approx_eq!(f32, b, 1.0);
+        assert_approx_eq!(f32, a, 1.0);
+
+        let primary = theme.schemes.light.primary;
+        let [r, _, _, _] = colors.srgb(SchemeRole::Primary);
+        let [linear_r, _, _, _] = colors.linear(SchemeRole::Primary);
+
+        assert_approx_eq!(f32, r, f32::from(primary.red) / 255.0);
+        assert!(linear_r < r);
+        assert_eq!(colors.current().scheme(), &theme.schemes.light);
+    }
+
+    #[test]
+    fn test_hot_swap() {
+        let blue = ThemeBuilder::with_source(Argb::from_u32(0xff4285f4)).build();
+        let red = ThemeBuilder::with_source(Argb::from_u32(0xffdb4437)).build();
+        let mut colors = ThemeColors::new(&blue, false);
+
+        assert!(!colors.set_theme(&blue));
+        assert_eq!(colors.generation(), 0);
+        assert!(colors.set_theme(&red));
+        assert_eq!(colors.generation(), 1);
+        assert_eq!(colors.current().scheme(), &red.schemes.light);
+        assert!(colors.set_dark(true));
+        assert!(!colors.set_dark(true));
+        assert_eq!(colors.generation(), 2);
+        assert_eq!(colors.current().scheme(), &red.schemes.dark);
+    }
+}
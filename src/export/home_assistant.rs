@@ -0,0 +1,246 @@
+use super::{ExportOptions, Exporter};
+use crate::{
+    scheme::{Scheme, SchemeRole},
+    theme::Theme,
+};
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
+use core::fmt::Write;
+#[cfg(feature = "std")]
+use std::string::String;
+
+/// Home Assistant theme variables and the roles they are mapped onto.
+///
+/// Cards sit on `surface_container_low` and headers on `surface_container`,
+/// so they stay distinguishable from the `background` of the dashboard.
+pub const VARIABLES: [(&str, SchemeRole); 26] = [
+    ("primary-color", SchemeRole::Primary),
+    ("accent-color", SchemeRole::Secondary),
+    ("light-primary-color", SchemeRole::PrimaryFixed),
+    ("dark-primary-color", SchemeRole::OnPrimaryFixedVariant),
+    ("text-primary-color", SchemeRole::OnPrimary),
+    ("text-accent-color", SchemeRole::OnSecondary),
+    ("primary-text-color", SchemeRole::OnSurface),
+    ("secondary-text-color", SchemeRole::OnSurfaceVariant),
+    ("disabled-text-color", SchemeRole::Outline),
+    ("divider-color", SchemeRole::OutlineVariant),
+    ("primary-background-color", SchemeRole::Background),
+    ("secondary-background-color", SchemeRole::SurfaceContainer),
+    ("card-background-color", SchemeRole::SurfaceContainerLow),
+    ("ha-card-background", SchemeRole::SurfaceContainerLow),
+    ("app-header-background-color", SchemeRole::SurfaceContainer),
+    ("app-header-text-color", SchemeRole::OnSurface),
+    ("sidebar-background-color", SchemeRole::SurfaceContainerLow),
+    ("sidebar-text-color", SchemeRole::OnSurfaceVariant),
+    ("sidebar-icon-color", SchemeRole::OnSurfaceVariant),
+    (
+        "sidebar-selected-text-color",
+        SchemeRole::OnSecondaryContainer,
+    ),
+    (
+        "sidebar-selected-icon-color",
+        SchemeRole::OnSecondaryContainer,
+    ),
+    ("state-icon-color", SchemeRole::OnSurfaceVariant),
+    ("error-color", SchemeRole::Error),
+    ("mdc-theme-primary", SchemeRole::Primary),
+    ("mdc-theme-secondary", SchemeRole::Secondary),
+    ("mdc-theme-surface", SchemeRole::Surface),
+];
+
+/// [`Exporter`] named `"home-assistant"`, rendering a scheme with
+/// [`to_theme`] and a whole theme with [`to_theme_with_modes`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HomeAssistantExporter {
+    /// Name of the theme, `material` by default.
+    pub name: String,
+}
+
+impl Default for HomeAssistantExporter {
+    fn default() -> Self {
+        Self {
+            name: "material".into(),
+        }
+    }
+}
+
+impl Exporter for HomeAssistantExporter {
+    fn name(&self) -> &'static str {
+        "home-assistant"
+    }
+
+    fn export(&self, scheme: &Scheme, options: &ExportOptions) -> String {
+        to_theme_with_options(scheme, &self.name, options)
+    }
+
+    fn export_theme(&self, theme: &Theme, options: &ExportOptions) -> Option<String> {
+        Some(to_theme_with_modes(
+            &theme.schemes.light,
+            &theme.schemes.dark,
+            &self.name,
+            options,
+        ))
+    }
+}
+
+/// Renders `scheme` as a Home Assistant theme named `name`, for a file in the
+/// `themes` directory of the configuration.
+///
+/// The [`VARIABLES`] of the Home Assistant theme schema come first, followed
+/// by every role as an `md-sys-color-*` variable for use in custom cards:
+///
+/// ```yaml
+/// material:
+///   primary-color: "#904b40"
+///   ...
+///   md-sys-color-primary: "#904b40"
+///   ...
+/// ```
+///
+/// `name` is written as-is, so it must be a valid YAML key.
+pub fn to_theme(scheme: &Scheme, name: &str) -> String {
+    to_theme_with_options(scheme, name, &ExportOptions::default())
+}
+
+/// Like [`to_theme`], but orders the `md-sys-color-*` variables and emits
+/// group headers as `#` comments according to `options`.
+pub fn to_theme_with_options(scheme: &Scheme, name: &str, options: &ExportOptions) -> String {
+    let mut output = String::new();
+
+    _ = writeln!(output, "{name}:");
+    write_variables(&mut output, scheme, *options, "  ");
+
+    output
+}
+
+/// Renders `light` and `dark` as a single Home Assistant theme named `name`,
+/// which follows the dark mode setting of the frontend.
+///
+/// Variables are nested under `modes`, as in [`to_theme_with_options`]:
+///
+/// ```yaml
+/// material:
+///   modes:
+///     light:
+///       primary-color: "#904b40"
+///       ...
+///     dark:
+///       primary-color: "#ffb4a8"
+///       ...
+/// ```
+pub fn to_theme_with_modes(
+    light: &Scheme,
+    dark: &Scheme,
+    name: &str,
+    options: &ExportOptions,
+) -> String {
+    let mut output = String::new();
+
+    _ = writeln!(output, "{name}:\n  modes:");
+
+    for (mode, scheme) in [("light", light), ("dark", dark)] {
+        _ = writeln!(output, "    {mode}:");
+        write_variables(&mut output, scheme, *options, "      ");
+    }
+
+    output
+}
+
+fn write_variables(output: &mut String, scheme: &Scheme, options: ExportOptions, indent: &str) {
+    for (variable, role) in VARIABLES {
+        _ = writeln!(
+            output,
+            "{indent}{variable}: \"{}\"",
+            scheme.role(role).to_hex_with_pound()
+        );
+    }
+
+    let tokens = options.tokens(scheme);
+
+    for (index, (_, role, color)) in tokens.iter().enumerate() {
+        if let Some(group) = options.header_at(&tokens, index) {
+            _ = writeln!(output, "{indent}# {}", group.label());
+        }
+
+        _ = writeln!(
+            output,
+            "{indent}md-sys-color-{}: \"{}\"",
+            role.replace('_', "-"),
+            color.to_hex_with_pound()
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{to_theme, to_theme_with_modes, VARIABLES};
+    use crate::{
+        color::Argb,
+        export::{ExportOptions, ExporterRegistry, TokenOrder},
+        theme::ThemeBuilder,
+    };
+    #[cfg(not(feature = "std"))]
+    use alloc::format;
+    #[cfg(feature = "std")]
+    use std::format;
+
+    #[test]
+    fn test_to_theme() {
+        let theme = ThemeBuilder::with_source(Argb::from_u32(0xffff0000)).build();
+        let output = to_theme(&theme.schemes.light, "material");
+
+        assert!(output.starts_with("material:\n  primary-color: \"#904b40\"\n"));
+        assert!(output.contains(&format!(
+            "  card-background-color: \"{}\"\n",
+            theme
+                .schemes
+                .light
+                .surface_container_low
+                .to_hex_with_pound()
+        )));
+        assert!(output.contains("  md-sys-color-on-primary-container: \""));
+        assert_eq!(output.lines().count(), 1 + VARIABLES.len() + 49);
+    }
+
+    #[test]
+    fn test_to_theme_with_modes() {
+        let theme = ThemeBuilder::with_source(Argb::from_u32(0xffff0000)).build();
+        let options = ExportOptions {
+            order: TokenOrder::Grouped,
+            group_headers: true,
+        };
+        let output = to_theme_with_modes(
+            &theme.schemes.light,
+            &theme.schemes.dark,
+            "material",
+            &options,
+        );
+
+        assert!(output
+            .starts_with("material:\n  modes:\n    light:\n      primary-color: \"#904b40\"\n"));
+        assert!(output.contains("    dark:\n      primary-color: \"#ffb4a8\"\n"));
+        assert!(output.contains("      # Primary\n      md-sys-color-primary: "));
+    }
+
+    #[test]
+    fn test_exporter() {
+        let theme = ThemeBuilder::with_source(Argb::from_u32(0xffff0000)).build();
+        let registry = ExporterRegistry::with_builtin();
+        let exporter = registry.get("home-assistant").unwrap();
+        let options = ExportOptions::default();
+
+        assert_eq!(
+            exporter.export(&theme.schemes.light, &options),
+            to_theme(&theme.schemes.light, "material")
+        );
+        assert_eq!(
+            exporter.export_theme(&theme, &options),
+            Some(to_theme_with_modes(
+                &theme.schemes.light,
+                &theme.schemes.dark,
+                "material",
+                &options
+            ))
+        );
+    }
+}
@@ -0,0 +1,247 @@
+use super::{ExportOptions, Exporter};
+use crate::scheme::{Scheme, SchemeRole};
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
+use core::fmt::Write;
+#[cfg(feature = "std")]
+use std::string::String;
+
+/// Roles a KDE color set is rendered from.
+///
+/// Material has no success role, so `ForegroundPositive` uses `secondary`,
+/// while `ForegroundNeutral` and `ForegroundVisited` use `tertiary` and
+/// `ForegroundNegative` uses `error`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ColorSet {
+    /// Name of the set, the section is named `Colors:<name>`.
+    pub name: &'static str,
+    /// `BackgroundNormal`.
+    pub background: SchemeRole,
+    /// `BackgroundAlternate`, used for alternating rows.
+    pub alternate: SchemeRole,
+    /// `ForegroundNormal`.
+    pub foreground: SchemeRole,
+    /// `ForegroundInactive`.
+    pub inactive: SchemeRole,
+    /// `ForegroundActive`, `ForegroundLink`, `DecorationFocus` and
+    /// `DecorationHover`.
+    pub accent: SchemeRole,
+}
+
+/// KDE color sets and the roles they are mapped from.
+///
+/// Views sit on `surface_container_low` and buttons on
+/// `surface_container_high`, so they stand out from the `surface` of windows,
+/// while tooltips and the complementary set of panels use the inverse roles.
+pub const COLOR_SETS: [ColorSet; 7] = [
+    ColorSet {
+        name: "Button",
+        background: SchemeRole::SurfaceContainerHigh,
+        alternate: SchemeRole::SurfaceContainerHighest,
+        foreground: SchemeRole::OnSurface,
+        inactive: SchemeRole::OnSurfaceVariant,
+        accent: SchemeRole::Primary,
+    },
+    ColorSet {
+        name: "Complementary",
+        background: SchemeRole::InverseSurface,
+        alternate: SchemeRole::InverseSurface,
+        foreground: SchemeRole::InverseOnSurface,
+        inactive: SchemeRole::InverseOnSurface,
+        accent: SchemeRole::InversePrimary,
+    },
+    ColorSet {
+        name: "Header",
+        background: SchemeRole::SurfaceContainer,
+        alternate: SchemeRole::SurfaceContainerHigh,
+        foreground: SchemeRole::OnSurface,
+        inactive: SchemeRole::OnSurfaceVariant,
+        accent: SchemeRole::Primary,
+    },
+    ColorSet {
+        name: "Selection",
+        background: SchemeRole::Primary,
+        alternate: SchemeRole::PrimaryContainer,
+        foreground: SchemeRole::OnPrimary,
+        inactive: SchemeRole::OnPrimary,
+        accent: SchemeRole::OnPrimary,
+    },
+    ColorSet {
+        name: "Tooltip",
+        background: SchemeRole::InverseSurface,
+        alternate: SchemeRole::InverseSurface,
+        foreground: SchemeRole::InverseOnSurface,
+        inactive: SchemeRole::InverseOnSurface,
+        accent: SchemeRole::InversePrimary,
+    },
+    ColorSet {
+        name: "View",
+        background: SchemeRole::SurfaceContainerLow,
+        alternate: SchemeRole::SurfaceContainer,
+        foreground: SchemeRole::OnSurface,
+        inactive: SchemeRole::OnSurfaceVariant,
+        accent: SchemeRole::Primary,
+    },
+    ColorSet {
+        name: "Window",
+        background: SchemeRole::Surface,
+        alternate: SchemeRole::SurfaceContainer,
+        foreground: SchemeRole::OnSurface,
+        inactive: SchemeRole::OnSurfaceVariant,
+        accent: SchemeRole::Primary,
+    },
+];
+
+/// Window decoration colors of the `WM` section and the roles they are
+/// mapped from.
+pub const WINDOW_MANAGER: [(&str, SchemeRole); 6] = [
+    ("activeBackground", SchemeRole::SurfaceContainer),
+    ("activeBlend", SchemeRole::OnSurface),
+    ("activeForeground", SchemeRole::OnSurface),
+    ("inactiveBackground", SchemeRole::Surface),
+    ("inactiveBlend", SchemeRole::OnSurfaceVariant),
+    ("inactiveForeground", SchemeRole::OnSurfaceVariant),
+];
+
+/// [`Exporter`] named `"kde"`, rendering a scheme with [`to_color_scheme`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KdeExporter {
+    /// Name of the color scheme, `Material` by default.
+    pub name: String,
+}
+
+impl Default for KdeExporter {
+    fn default() -> Self {
+        Self {
+            name: "Material".into(),
+        }
+    }
+}
+
+impl Exporter for KdeExporter {
+    fn name(&self) -> &'static str {
+        "kde"
+    }
+
+    fn export(&self, scheme: &Scheme, _options: &ExportOptions) -> String {
+        to_color_scheme(scheme, &self.name)
+    }
+}
+
+/// Renders `scheme` as a KDE Plasma color scheme named `name`, for a
+/// `.colors` file in `~/.local/share/color-schemes`.
+///
+/// Every [`COLOR_SETS`] entry becomes a `Colors:*` section, followed by the
+/// [`WINDOW_MANAGER`] colors and the name of the scheme. Colors are written
+/// as `r,g,b`:
+///
+/// ```ini
+/// [Colors:Button]
+/// BackgroundAlternate=245,222,218
+/// BackgroundNormal=247,228,225
+/// ...
+///
+/// [General]
+/// ColorScheme=Material
+/// Name=Material
+/// ```
+///
+/// Plasma schemes have no light and dark modes, so a theme is exported as
+/// one scheme per mode. Sections and keys are sorted, as written by the
+/// System Settings, and [`ExportOptions`] do not apply.
+pub fn to_color_scheme(scheme: &Scheme, name: &str) -> String {
+    let mut output = String::new();
+
+    for set in COLOR_SETS {
+        _ = writeln!(output, "[Colors:{}]", set.name);
+
+        for (key, role) in [
+            ("BackgroundAlternate", set.alternate),
+            ("BackgroundNormal", set.background),
+            ("DecorationFocus", set.accent),
+            ("DecorationHover", set.accent),
+            ("ForegroundActive", set.accent),
+            ("ForegroundInactive", set.inactive),
+            ("ForegroundLink", set.accent),
+            ("ForegroundNegative", SchemeRole::Error),
+            ("ForegroundNeutral", SchemeRole::Tertiary),
+            ("ForegroundNormal", set.foreground),
+            ("ForegroundPositive", SchemeRole::Secondary),
+            ("ForegroundVisited", SchemeRole::Tertiary),
+        ] {
+            write_color(&mut output, key, scheme, role);
+        }
+
+        output.push('\n');
+    }
+
+    _ = writeln!(output, "[General]\nColorScheme={name}\nName={name}\n");
+    output.push_str("[WM]\n");
+
+    for (key, role) in WINDOW_MANAGER {
+        write_color(&mut output, key, scheme, role);
+    }
+
+    output
+}
+
+fn write_color(output: &mut String, key: &str, scheme: &Scheme, role: SchemeRole) {
+    let color = scheme.role(role);
+
+    _ = writeln!(output, "{key}={},{},{}", color.red, color.green, color.blue);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{to_color_scheme, COLOR_SETS, WINDOW_MANAGER};
+    use crate::{
+        color::Argb,
+        export::{ExportOptions, ExporterRegistry},
+        theme::ThemeBuilder,
+    };
+    #[cfg(not(feature = "std"))]
+    use alloc::format;
+    #[cfg(feature = "std")]
+    use std::format;
+
+    #[test]
+    fn test_to_color_scheme() {
+        let theme = ThemeBuilder::with_source(Argb::from_u32(0xffff0000)).build();
+        let scheme = &theme.schemes.light;
+        let output = to_color_scheme(scheme, "Material");
+        let surface = scheme.surface;
+
+        assert!(output.starts_with("[Colors:Button]\nBackgroundAlternate="));
+        assert!(output.contains(&format!(
+            "[Colors:Window]\nBackgroundAlternate={},{},{}\nBackgroundNormal={},{},{}\n",
+            scheme.surface_container.red,
+            scheme.surface_container.green,
+            scheme.surface_container.blue,
+            surface.red,
+            surface.green,
+            surface.blue
+        )));
+        assert!(output.contains("ForegroundNegative=186,26,26\n"));
+        assert!(output.contains("\n[General]\nColorScheme=Material\nName=Material\n"));
+        assert_eq!(
+            output.lines().filter(|line| line.contains('=')).count(),
+            COLOR_SETS.len() * 12 + 2 + WINDOW_MANAGER.len()
+        );
+    }
+
+    #[test]
+    fn test_exporter() {
+        let theme = ThemeBuilder::with_source(Argb::from_u32(0xffff0000)).build();
+        let registry = ExporterRegistry::with_builtin();
+        let exporter = registry.get("kde").unwrap();
+
+        assert_eq!(
+            exporter.export(&theme.schemes.dark, &ExportOptions::default()),
+            to_color_scheme(&theme.schemes.dark, "Material")
+        );
+        assert_eq!(
+            exporter.export_theme(&theme, &ExportOptions::default()),
+            None
+        );
+    }
+}
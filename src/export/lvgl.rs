@@ -0,0 +1,139 @@
+use super::{ExportOptions, Exporter};
+use crate::scheme::Scheme;
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
+use core::fmt::Write;
+#[cfg(feature = "std")]
+use std::string::String;
+
+/// [`Exporter`] named `"lvgl"`, rendering a scheme with
+/// [`to_palette_table`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LvglExporter {
+    /// Prefix of the table and its indices, `material` by default.
+    pub prefix: String,
+}
+
+impl Default for LvglExporter {
+    fn default() -> Self {
+        Self {
+            prefix: "material".into(),
+        }
+    }
+}
+
+impl Exporter for LvglExporter {
+    fn name(&self) -> &'static str {
+        "lvgl"
+    }
+
+    fn export(&self, scheme: &Scheme, options: &ExportOptions) -> String {
+        to_palette_table_with_options(scheme, &self.prefix, options)
+    }
+}
+
+/// Renders `scheme` as a C palette table for LVGL.
+///
+/// The output declares an enum of role indices (`PREFIX_PRIMARY`, ...,
+/// terminated by `PREFIX_COLOR_COUNT`) and a `static const lv_color_t` array
+/// named `prefix_colors` indexed by it. `prefix` is used as-is for the array
+/// and upper-cased for the enum constants.
+///
+/// ```c
+/// enum {
+///     MATERIAL_PRIMARY,
+///     ...
+///     MATERIAL_COLOR_COUNT
+/// };
+///
+/// static const lv_color_t material_colors[MATERIAL_COLOR_COUNT] = {
+///     [MATERIAL_PRIMARY] = LV_COLOR_MAKE(0x90, 0x4b, 0x40),
+///     ...
+/// };
+/// ```
+pub fn to_palette_table(scheme: &Scheme, prefix: &str) -> String {
+    to_palette_table_with_options(scheme, prefix, &ExportOptions::default())
+}
+
+/// Like [`to_palette_table`], but orders roles and emits group headers as
+/// `/* */` comments in the enum according to `options`.
+pub fn to_palette_table_with_options(
+    scheme: &Scheme,
+    prefix: &str,
+    options: &ExportOptions,
+) -> String {
+    let tokens = options.tokens(scheme);
+    let upper_prefix = prefix.to_uppercase();
+    let mut output = String::from("enum {\n");
+
+    for (index, (_, role, _)) in tokens.iter().enumerate() {
+        if let Some(group) = options.header_at(&tokens, index) {
+            _ = writeln!(output, "    /* {} */", group.label());
+        }
+
+        _ = writeln!(output, "    {upper_prefix}_{},", role.to_uppercase());
+    }
+
+    _ = writeln!(output, "    {upper_prefix}_COLOR_COUNT");
+    output.push_str("};\n\n");
+
+    _ = writeln!(
+        output,
+        "static const lv_color_t {prefix}_colors[{upper_prefix}_COLOR_COUNT] = {{"
+    );
+
+    for (_, role, color) in &tokens {
+        _ = writeln!(
+            output,
+            "    [{upper_prefix}_{}] = LV_COLOR_MAKE(0x{:02x}, 0x{:02x}, 0x{:02x}),",
+            role.to_uppercase(),
+            color.red,
+            color.green,
+            color.blue
+        );
+    }
+
+    output.push_str("};\n");
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{to_palette_table, to_palette_table_with_options};
+    use crate::{
+        color::Argb,
+        export::{ExportOptions, TokenOrder},
+        theme::ThemeBuilder,
+    };
+
+    #[test]
+    fn test_to_palette_table() {
+        let theme = ThemeBuilder::with_source(Argb::from_u32(0xffff0000)).build();
+        let output = to_palette_table(&theme.schemes.light, "material");
+
+        assert!(output.starts_with("enum {\n    MATERIAL_PRIMARY,\n"));
+        assert!(output.contains("    MATERIAL_COLOR_COUNT\n};\n"));
+        assert!(
+            output.contains("static const lv_color_t material_colors[MATERIAL_COLOR_COUNT] = {\n")
+        );
+        assert!(output.contains("    [MATERIAL_PRIMARY] = LV_COLOR_MAKE(0x90, 0x4b, 0x40),\n"));
+        assert!(output.ends_with("};\n"));
+    }
+
+    #[test]
+    fn test_to_palette_table_with_options() {
+        let theme = ThemeBuilder::with_source(Argb::from_u32(0xffff0000)).build();
+        let options = ExportOptions {
+            order: TokenOrder::Alphabetical,
+            group_headers: false,
+        };
+        let output = to_palette_table_with_options(&theme.schemes.light, "material", &options);
+
+        assert!(output.starts_with("enum {\n    MATERIAL_BACKGROUND,\n"));
+        assert!(
+            output.contains("material_colors[MATERIAL_COLOR_COUNT] = {\n    [MATERIAL_BACKGROUND]")
+        );
+        assert!(!output.contains("/*"));
+    }
+}
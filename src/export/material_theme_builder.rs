@@ -0,0 +1,310 @@
+use super::{ExportOptions, Exporter};
+use crate::{
+    color::Argb,
+    palette::TonalPalette,
+    scheme::Scheme,
+    theme::{ColorGroup, Theme},
+};
+#[cfg(not(feature = "std"))]
+use alloc::string::{String, ToString};
+use core::fmt::Write;
+#[cfg(feature = "std")]
+use std::string::{String, ToString};
+
+/// Tones of each palette listed by the Material Theme Builder.
+pub const PALETTE_TONES: [i32; 18] = [
+    0, 5, 10, 15, 20, 25, 30, 35, 40, 50, 60, 70, 80, 90, 95, 98, 99, 100,
+];
+
+/// [`Exporter`] named `"material-theme-builder"`, rendering a scheme with
+/// [`to_scheme`] and a whole theme with [`to_json`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct MaterialThemeBuilderExporter;
+
+impl Exporter for MaterialThemeBuilderExporter {
+    fn name(&self) -> &'static str {
+        "material-theme-builder"
+    }
+
+    fn export(&self, scheme: &Scheme, _options: &ExportOptions) -> String {
+        to_scheme(scheme)
+    }
+
+    fn export_theme(&self, theme: &Theme, _options: &ExportOptions) -> Option<String> {
+        Some(to_json(theme))
+    }
+}
+
+/// Renders `scheme` as a scheme object of the Material Theme Builder, with
+/// roles in camel case, in declaration order:
+///
+/// ```json
+/// {
+///   "primary": "#904B40",
+///   "onPrimary": "#FFFFFF",
+///   ...
+/// }
+/// ```
+///
+/// The exporter ignores [`ExportOptions`], since the Material Theme Builder
+/// always lists roles in the same order.
+pub fn to_scheme(scheme: &Scheme) -> String {
+    let mut output = String::new();
+
+    write_scheme(&mut output, scheme, "");
+
+    output
+}
+
+/// Renders `theme` as the JSON file exported by the Material Theme Builder,
+/// which Android Studio and the Figma plugin import:
+///
+/// ```json
+/// {
+///   "seed": "#FF0000",
+///   "schemes": {
+///     "light": { ... },
+///     "dark": { ... }
+///   },
+///   "palettes": {
+///     "primary": { "0": "#000000", ..., "100": "#FFFFFF" },
+///     ...
+///   },
+///   "customColors": [
+///     {
+///       "name": "brand",
+///       "value": "#00A86B",
+///       "blend": true,
+///       "light": { "color": ..., "onColor": ..., "colorContainer": ..., "onColorContainer": ... },
+///       "dark": { ... }
+///     }
+///   ]
+/// }
+/// ```
+///
+/// Palettes are those of [`Theme::palettes`], except the error palette, which
+/// the Material Theme Builder does not list, each at the [`PALETTE_TONES`].
+pub fn to_json(theme: &Theme) -> String {
+    let mut output = String::new();
+
+    _ = writeln!(output, "{{");
+    _ = writeln!(output, "  \"seed\": \"{}\",", hex(theme.source));
+    _ = writeln!(output, "  \"schemes\": {{");
+    _ = write!(output, "    \"light\": ");
+    write_scheme(&mut output, &theme.schemes.light, "    ");
+    _ = write!(output, ",\n    \"dark\": ");
+    write_scheme(&mut output, &theme.schemes.dark, "    ");
+    _ = writeln!(output, "\n  }},");
+    _ = writeln!(output, "  \"palettes\": {{");
+
+    let palettes = [
+        ("primary", &theme.palettes.primary),
+        ("secondary", &theme.palettes.secondary),
+        ("tertiary", &theme.palettes.tertiary),
+        ("neutral", &theme.palettes.neutral),
+        ("neutral-variant", &theme.palettes.neutral_variant),
+    ];
+
+    for (index, (name, palette)) in palettes.iter().enumerate() {
+        _ = write!(output, "    \"{name}\": ");
+        write_palette(&mut output, palette, "    ");
+        _ = writeln!(output, "{}", separator(index, palettes.len()));
+    }
+
+    _ = writeln!(output, "  }},");
+    _ = write!(output, "  \"customColors\": [");
+
+    for (index, group) in theme.custom_colors.iter().enumerate() {
+        _ = writeln!(output, "{}", if index == 0 { "" } else { "," });
+        _ = writeln!(output, "    {{");
+        _ = writeln!(output, "      \"name\": {},", string(&group.color.name));
+        _ = writeln!(output, "      \"value\": \"{}\",", hex(group.value));
+        _ = writeln!(output, "      \"blend\": {},", group.color.blend);
+        _ = write!(output, "      \"light\": ");
+        write_color_group(&mut output, &group.light, "      ");
+        _ = write!(output, ",\n      \"dark\": ");
+        write_color_group(&mut output, &group.dark, "      ");
+        _ = write!(output, "\n    }}");
+    }
+
+    if theme.custom_colors.is_empty() {
+        _ = writeln!(output, "]");
+    } else {
+        _ = writeln!(output, "\n  ]");
+    }
+
+    _ = writeln!(output, "}}");
+
+    output
+}
+
+fn write_scheme(output: &mut String, scheme: &Scheme, indent: &str) {
+    let len = scheme.iter().len();
+
+    _ = writeln!(output, "{{");
+
+    for (index, (role, color)) in scheme.iter().enumerate() {
+        _ = writeln!(
+            output,
+            "{indent}  \"{}\": \"{}\"{}",
+            camel_case(&role.to_string()),
+            hex(color),
+            separator(index, len)
+        );
+    }
+
+    _ = write!(output, "{indent}}}");
+}
+
+fn write_palette(output: &mut String, palette: &TonalPalette, indent: &str) {
+    _ = writeln!(output, "{{");
+
+    for (index, tone) in PALETTE_TONES.iter().enumerate() {
+        _ = writeln!(
+            output,
+            "{indent}  \"{tone}\": \"{}\"{}",
+            hex(palette.tone(*tone)),
+            separator(index, PALETTE_TONES.len())
+        );
+    }
+
+    _ = write!(output, "{indent}}}");
+}
+
+fn write_color_group(output: &mut String, group: &ColorGroup, indent: &str) {
+    _ = writeln!(output, "{{");
+    _ = writeln!(output, "{indent}  \"color\": \"{}\",", hex(group.color));
+    _ = writeln!(
+        output,
+        "{indent}  \"onColor\": \"{}\",",
+        hex(group.on_color)
+    );
+    _ = writeln!(
+        output,
+        "{indent}  \"colorContainer\": \"{}\",",
+        hex(group.color_container)
+    );
+    _ = writeln!(
+        output,
+        "{indent}  \"onColorContainer\": \"{}\"",
+        hex(group.on_color_container)
+    );
+    _ = write!(output, "{indent}}}");
+}
+
+const fn separator(index: usize, len: usize) -> &'static str {
+    if index + 1 == len {
+        ""
+    } else {
+        ","
+    }
+}
+
+/// Formats `color` as the Material Theme Builder does, e.g. `#904B40`.
+fn hex(color: Argb) -> String {
+    color.to_hex_with_pound().to_uppercase()
+}
+
+/// Converts a role name, e.g. `on_primary_container`, to camel case.
+fn camel_case(name: &str) -> String {
+    let mut output = String::with_capacity(name.len());
+    let mut upper = false;
+
+    for character in name.chars() {
+        if character == '_' {
+            upper = true;
+        } else if upper {
+            output.extend(character.to_uppercase());
+            upper = false;
+        } else {
+            output.push(character);
+        }
+    }
+
+    output
+}
+
+/// Quotes `value` as a JSON string.
+fn string(value: &str) -> String {
+    let mut output = String::with_capacity(value.len() + 2);
+
+    output.push('"');
+
+    for character in value.chars() {
+        match character {
+            '"' => output.push_str("\\\""),
+            '\\' => output.push_str("\\\\"),
+            '\n' => output.push_str("\\n"),
+            '\r' => output.push_str("\\r"),
+            '\t' => output.push_str("\\t"),
+            character if character.is_control() => {
+                _ = write!(output, "\\u{:04x}", u32::from(character));
+            }
+            character => output.push(character),
+        }
+    }
+
+    output.push('"');
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{camel_case, string, to_json, to_scheme};
+    use crate::{
+        color::Argb,
+        theme::{CustomColor, ThemeBuilder},
+    };
+    #[cfg(not(feature = "std"))]
+    use alloc::vec;
+    #[cfg(feature = "std")]
+    use std::vec;
+
+    #[test]
+    fn test_camel_case() {
+        assert_eq!(camel_case("primary"), "primary");
+        assert_eq!(
+            camel_case("on_primary_fixed_variant"),
+            "onPrimaryFixedVariant"
+        );
+        assert_eq!(string("a \"b\"\n"), r#""a \"b\"\n""#);
+    }
+
+    #[test]
+    fn test_to_scheme() {
+        let theme = ThemeBuilder::with_source(Argb::from_u32(0xffff0000)).build();
+        let output = to_scheme(&theme.schemes.light);
+
+        assert!(output.starts_with("{\n  \"primary\": \"#904B40\",\n"));
+        assert!(output.contains("  \"surfaceTint\": \"#904B40\",\n"));
+        assert!(output.contains("  \"onPrimaryContainer\": "));
+        assert!(output.ends_with("\"\n}"));
+        assert_eq!(output.lines().count(), 51);
+    }
+
+    #[test]
+    fn test_to_json() {
+        let theme = ThemeBuilder::with_source(Argb::from_u32(0xffff0000))
+            .custom_colors(vec![CustomColor::new(
+                Argb::from_u32(0xff00a86b),
+                "brand \"green\"",
+                true,
+            )])
+            .build();
+        let output = to_json(&theme);
+
+        assert!(output.starts_with("{\n  \"seed\": \"#FF0000\",\n  \"schemes\": {\n"));
+        assert!(output.contains("    \"light\": {\n      \"primary\": \"#904B40\","));
+        assert!(output.contains("    \"dark\": {\n      \"primary\": \"#FFB4A8\","));
+        assert!(output.contains("    \"neutral-variant\": {\n      \"0\": \"#000000\","));
+        assert!(output.contains("      \"100\": \"#FFFFFF\"\n    }\n  },\n"));
+        assert!(output.contains("      \"name\": \"brand \\\"green\\\"\",\n"));
+        assert!(output.contains("        \"onColorContainer\": "));
+        assert!(output.ends_with("    }\n  ]\n}\n"));
+
+        let theme = ThemeBuilder::with_source(Argb::from_u32(0xffff0000)).build();
+
+        assert!(to_json(&theme).ends_with("  \"customColors\": []\n}\n"));
+    }
+}
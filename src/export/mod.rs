@@ -0,0 +1,347 @@
+//! Adapters that render a [`Scheme`] in formats consumed directly by UI
+//! toolkits.
+//!
+//! Every exporter is behind its own feature flag. Exporters accept
+//! [`ExportOptions`] to control the order of tokens and whether group headers
+//! are emitted as comments.
+//!
+//! Each built-in exporter implements [`Exporter`], which other crates can
+//! implement too, so that their formats can be looked up in an
+//! [`ExporterRegistry`] and rendered by [`ThemeBundle`] like the built-in
+//! ones.
+//!
+//! [`Scheme`]: crate::scheme::Scheme
+//! [`ThemeBundle`]: crate::bundle::ThemeBundle
+
+use crate::{color::Argb, scheme::Scheme, theme::Theme};
+#[cfg(not(feature = "std"))]
+use alloc::{boxed::Box, string::String, vec::Vec};
+use core::fmt;
+#[cfg(feature = "std")]
+use std::{boxed::Box, string::String, vec::Vec};
+
+#[cfg(feature = "android")]
+pub mod android;
+#[cfg(feature = "css")]
+pub mod css;
+#[cfg(feature = "game-engine")]
+pub mod game_engine;
+#[cfg(feature = "home-assistant")]
+pub mod home_assistant;
+#[cfg(feature = "kde")]
+pub mod kde;
+#[cfg(feature = "lvgl")]
+pub mod lvgl;
+#[cfg(feature = "material-theme-builder")]
+pub mod material_theme_builder;
+#[cfg(feature = "slint")]
+pub mod slint;
+pub mod template;
+
+/// Group of related roles, declared in the order used by
+/// [`TokenOrder::Grouped`]: accents first, surfaces last.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TokenGroup {
+    Primary,
+    Secondary,
+    Tertiary,
+    Error,
+    Outline,
+    Utility,
+    Surface,
+}
+
+impl TokenGroup {
+    /// Returns the group `role` belongs to.
+    ///
+    /// Roles that match no accent, outline or utility group, such as
+    /// `background` or `surface_tint`, are considered surfaces.
+    pub fn of(role: &str) -> Self {
+        if role.contains("primary") {
+            Self::Primary
+        } else if role.contains("secondary") {
+            Self::Secondary
+        } else if role.contains("tertiary") {
+            Self::Tertiary
+        } else if role.contains("error") {
+            Self::Error
+        } else if role.contains("outline") {
+            Self::Outline
+        } else if role == "shadow" || role == "scrim" {
+            Self::Utility
+        } else {
+            Self::Surface
+        }
+    }
+
+    /// Human-readable name, used for group headers.
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Primary => "Primary",
+            Self::Secondary => "Secondary",
+            Self::Tertiary => "Tertiary",
+            Self::Error => "Error",
+            Self::Outline => "Outline",
+            Self::Utility => "Utility",
+            Self::Surface => "Surface",
+        }
+    }
+}
+
+/// Order in which exporters emit tokens.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TokenOrder {
+    /// The order produced by iterating a [`Scheme`].
+    #[default]
+    Declaration,
+    /// Grouped by [`TokenGroup`], keeping the declaration order within a
+    /// group.
+    Grouped,
+    /// Sorted by role name.
+    Alphabetical,
+}
+
+/// Options shared by all exporters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ExportOptions {
+    pub order: TokenOrder,
+    /// Emit a comment naming the group before the first token of each group.
+    ///
+    /// Only has an effect with [`TokenOrder::Grouped`].
+    pub group_headers: bool,
+}
+
+impl ExportOptions {
+    /// Returns the roles of `scheme` in the configured order, each with its
+    /// group.
+    pub fn tokens(&self, scheme: &Scheme) -> Vec<(TokenGroup, String, Argb)> {
+        let mut tokens = scheme
+            .clone()
+            .into_iter()
+            .map(|(role, color)| (TokenGroup::of(&role), role, color))
+            .collect::<Vec<_>>();
+
+        match self.order {
+            TokenOrder::Declaration => {}
+            TokenOrder::Grouped => tokens.sort_by_key(|(group, ..)| *group),
+            TokenOrder::Alphabetical => tokens.sort_by(|(_, a, _), (_, b, _)| a.cmp(b)),
+        }
+
+        tokens
+    }
+
+    /// Returns the group whose header should be emitted before the token at
+    /// `index` of `tokens`, if any.
+    pub fn header_at(
+        &self,
+        tokens: &[(TokenGroup, String, Argb)],
+        index: usize,
+    ) -> Option<TokenGroup> {
+        if !self.group_headers || self.order != TokenOrder::Grouped {
+            return None;
+        }
+
+        let group = tokens[index].0;
+
+        (index == 0 || tokens[index - 1].0 != group).then_some(group)
+    }
+}
+
+/// A format schemes can be rendered in.
+pub trait Exporter: fmt::Debug + Send + Sync {
+    /// Name of the format, unique within an [`ExporterRegistry`], e.g.
+    /// `"slint"`.
+    fn name(&self) -> &str;
+
+    /// Renders `scheme`.
+    fn export(&self, scheme: &Scheme, options: &ExportOptions) -> String;
+
+    /// Renders `scheme` as bytes.
+    ///
+    /// Returns the UTF-8 encoded output of [`Exporter::export`] by default;
+    /// binary formats override it.
+    fn export_bytes(&self, scheme: &Scheme, options: &ExportOptions) -> Vec<u8> {
+        self.export(scheme, options).into_bytes()
+    }
+
+    /// Renders the light and dark schemes of `theme` as a single document.
+    ///
+    /// Returns [`None`] by default, for formats that describe a single
+    /// scheme.
+    fn export_theme(&self, _theme: &Theme, _options: &ExportOptions) -> Option<String> {
+        None
+    }
+}
+
+/// A set of [`Exporter`]s looked up by name, e.g. to pick a format from a
+/// command line argument.
+#[derive(Debug, Default)]
+pub struct ExporterRegistry {
+    exporters: Vec<Box<dyn Exporter>>,
+}
+
+impl ExporterRegistry {
+    /// Creates an empty registry.
+    pub const fn new() -> Self {
+        Self {
+            exporters: Vec::new(),
+        }
+    }
+
+    /// Creates a registry with the built-in exporters enabled by features,
+    /// with their default settings.
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn with_builtin() -> Self {
+        #[allow(unused_mut)]
+        let mut registry = Self::new();
+
+        #[cfg(feature = "slint")]
+        registry.register(slint::SlintExporter::default());
+        #[cfg(feature = "lvgl")]
+        registry.register(lvgl::LvglExporter::default());
+        #[cfg(feature = "home-assistant")]
+        registry.register(home_assistant::HomeAssistantExporter::default());
+        #[cfg(feature = "kde")]
+        registry.register(kde::KdeExporter::default());
+        #[cfg(feature = "material-theme-builder")]
+        registry.register(material_theme_builder::MaterialThemeBuilderExporter);
+        #[cfg(feature = "css")]
+        registry.register(css::CssExporter::default());
+        #[cfg(feature = "android")]
+        registry.register(android::AndroidExporter::default());
+
+        registry
+    }
+
+    /// Adds `exporter`, replacing and returning the exporter with the same
+    /// name, if any.
+    pub fn register<E: Exporter + 'static>(&mut self, exporter: E) -> Option<Box<dyn Exporter>> {
+        let exporter: Box<dyn Exporter> = Box::new(exporter);
+
+        if let Some(other) = self
+            .exporters
+            .iter_mut()
+            .find(|other| other.name() == exporter.name())
+        {
+            return Some(core::mem::replace(other, exporter));
+        }
+
+        self.exporters.push(exporter);
+
+        None
+    }
+
+    /// Returns the exporter named `name`.
+    pub fn get(&self, name: &str) -> Option<&dyn Exporter> {
+        self.exporters
+            .iter()
+            .find(|exporter| exporter.name() == name)
+            .map(AsRef::as_ref)
+    }
+
+    /// Removes and returns the exporter named `name`, e.g. to move it into
+    /// [`BundleOptions::exports`].
+    ///
+    /// [`BundleOptions::exports`]: crate::bundle::BundleOptions::exports
+    pub fn remove(&mut self, name: &str) -> Option<Box<dyn Exporter>> {
+        let index = self
+            .exporters
+            .iter()
+            .position(|exporter| exporter.name() == name)?;
+
+        Some(self.exporters.remove(index))
+    }
+
+    /// Returns the names of the exporters, in registration order.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.exporters.iter().map(|exporter| exporter.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ExportOptions, Exporter, ExporterRegistry, TokenGroup, TokenOrder};
+    use crate::{color::Argb, scheme::Scheme, theme::ThemeBuilder};
+    #[cfg(not(feature = "std"))]
+    use alloc::string::{String, ToString};
+    #[cfg(feature = "std")]
+    use std::string::{String, ToString};
+
+    #[test]
+    fn test_token_order() {
+        let theme = ThemeBuilder::with_source(Argb::from_u32(0xffff0000)).build();
+        let scheme = &theme.schemes.light;
+
+        let declared = ExportOptions::default().tokens(scheme);
+
+        assert_eq!(declared.len(), 49);
+        assert_eq!(declared[0].1, "primary");
+
+        let options = ExportOptions {
+            order: TokenOrder::Grouped,
+            group_headers: true,
+        };
+        let grouped = options.tokens(scheme);
+
+        assert!(grouped.windows(2).all(|pair| pair[0].0 <= pair[1].0));
+        assert_eq!(grouped.last().unwrap().0, TokenGroup::Surface);
+        assert_eq!(options.header_at(&grouped, 0), Some(TokenGroup::Primary));
+        assert_eq!(options.header_at(&grouped, 1), None);
+
+        let headers = (0..grouped.len())
+            .filter_map(|index| options.header_at(&grouped, index))
+            .count();
+
+        assert_eq!(headers, 7);
+
+        let alphabetical = ExportOptions {
+            order: TokenOrder::Alphabetical,
+            group_headers: true,
+        };
+        let sorted = alphabetical.tokens(scheme);
+
+        assert!(sorted.windows(2).all(|pair| pair[0].1 <= pair[1].1));
+        assert_eq!(alphabetical.header_at(&sorted, 0), None);
+    }
+
+    #[derive(Debug)]
+    struct Hex(&'static str);
+
+    impl Exporter for Hex {
+        fn name(&self) -> &str {
+            self.0
+        }
+
+        fn export(&self, scheme: &Scheme, _options: &ExportOptions) -> String {
+            scheme.primary.to_hex()
+        }
+    }
+
+    #[test]
+    fn test_registry() {
+        let theme = ThemeBuilder::with_source(Argb::from_u32(0xffff0000)).build();
+        let mut registry = ExporterRegistry::new();
+
+        assert!(registry.register(Hex("hex")).is_none());
+        assert!(registry.register(Hex("other")).is_none());
+        assert_eq!(registry.register(Hex("hex")).unwrap().name(), "hex");
+        assert!(registry.names().eq(["hex", "other"]));
+        assert!(registry.get("missing").is_none());
+
+        let exporter = registry.get("hex").unwrap();
+        let options = ExportOptions::default();
+
+        assert_eq!(
+            exporter.export(&theme.schemes.light, &options),
+            "904b40".to_string()
+        );
+        assert_eq!(
+            exporter.export_bytes(&theme.schemes.light, &options),
+            b"904b40"
+        );
+        assert!(exporter.export_theme(&theme, &options).is_none());
+        assert_eq!(registry.remove("hex").unwrap().name(), "hex");
+        assert!(registry.remove("hex").is_none());
+        assert!(registry.names().eq(["other"]));
+    }
+}
@@ -0,0 +1,113 @@
+use super::{ExportOptions, Exporter};
+use crate::scheme::Scheme;
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
+use core::fmt::Write;
+#[cfg(feature = "std")]
+use std::string::String;
+
+/// [`Exporter`] named `"slint"`, rendering a scheme with [`to_global`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SlintExporter {
+    /// Name of the global, `Palette` by default.
+    pub name: String,
+}
+
+impl Default for SlintExporter {
+    fn default() -> Self {
+        Self {
+            name: "Palette".into(),
+        }
+    }
+}
+
+impl Exporter for SlintExporter {
+    fn name(&self) -> &'static str {
+        "slint"
+    }
+
+    fn export(&self, scheme: &Scheme, options: &ExportOptions) -> String {
+        to_global_with_options(scheme, &self.name, options)
+    }
+}
+
+/// Renders `scheme` as a Slint global singleton named `name`.
+///
+/// Each role becomes an `out property <color>` with a kebab-case name, e.g.
+/// `on_primary_container` becomes `on-primary-container`:
+///
+/// ```slint
+/// export global Palette {
+///     out property <color> primary: #904b40;
+///     ...
+/// }
+/// ```
+pub fn to_global(scheme: &Scheme, name: &str) -> String {
+    to_global_with_options(scheme, name, &ExportOptions::default())
+}
+
+/// Like [`to_global`], but orders properties and emits group headers as
+/// `//` comments according to `options`.
+pub fn to_global_with_options(scheme: &Scheme, name: &str, options: &ExportOptions) -> String {
+    let tokens = options.tokens(scheme);
+    let mut output = String::new();
+
+    _ = writeln!(output, "export global {name} {{");
+
+    for (index, (_, role, color)) in tokens.iter().enumerate() {
+        if let Some(group) = options.header_at(&tokens, index) {
+            if index > 0 {
+                output.push('\n');
+            }
+
+            _ = writeln!(output, "    // {}", group.label());
+        }
+
+        _ = writeln!(
+            output,
+            "    out property <color> {}: {};",
+            role.replace('_', "-"),
+            color.to_hex_with_pound()
+        );
+    }
+
+    output.push_str("}\n");
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{to_global, to_global_with_options};
+    use crate::{
+        color::Argb,
+        export::{ExportOptions, TokenOrder},
+        theme::ThemeBuilder,
+    };
+
+    #[test]
+    fn test_to_global() {
+        let theme = ThemeBuilder::with_source(Argb::from_u32(0xffff0000)).build();
+        let output = to_global(&theme.schemes.light, "Palette");
+
+        assert!(output.starts_with("export global Palette {\n"));
+        assert!(output.contains("    out property <color> primary: #904b40;\n"));
+        assert!(output.contains("    out property <color> on-primary-container: "));
+        assert!(output.ends_with("}\n"));
+        assert_eq!(output.lines().count(), 49 + 2);
+    }
+
+    #[test]
+    fn test_to_global_with_options() {
+        let theme = ThemeBuilder::with_source(Argb::from_u32(0xffff0000)).build();
+        let options = ExportOptions {
+            order: TokenOrder::Grouped,
+            group_headers: true,
+        };
+        let output = to_global_with_options(&theme.schemes.light, "Palette", &options);
+
+        assert!(output.starts_with("export global Palette {\n    // Primary\n"));
+        assert!(output.contains("\n\n    // Surface\n"));
+        assert_eq!(output.lines().count(), 49 + 2 + 7 * 2 - 1);
+    }
+}
@@ -0,0 +1,136 @@
+//! Substitution of scheme colors into SVG, CSS or any other text template.
+
+use crate::{
+    scheme::{Scheme, SchemeRole},
+    Error,
+};
+#[cfg(not(feature = "std"))]
+use alloc::string::{String, ToString};
+#[cfg(feature = "std")]
+use std::string::{String, ToString};
+
+/// Prefix of the CSS custom properties substituted by [`render`].
+pub const CSS_VARIABLE_PREFIX: &str = "var(--md-sys-color-";
+
+/// Replaces the color placeholders of `template` with the colors of `scheme`,
+/// as `#rrggbb`.
+///
+/// Two kinds of placeholders are recognized:
+///
+/// - `{{role}}`, with the snake case name of a [`SchemeRole`], e.g.
+///   `{{on_primary_container}}`; whitespace inside the braces is ignored.
+/// - `var(--md-sys-color-role)`, with the kebab case name of a role, e.g.
+///   `var(--md-sys-color-on-primary-container)`, including a fallback value
+///   if any. Fallbacks must not contain parentheses.
+///
+/// Other `var()` calls are left as-is, as are `{{` without a matching `}}`.
+///
+/// ```
+/// use material_colors::{color::Argb, export::template, theme::ThemeBuilder};
+///
+/// let theme = ThemeBuilder::with_source(Argb::from_u32(0xffff0000)).build();
+/// let svg = r#"<path fill="{{primary}}" stroke="var(--md-sys-color-outline)"/>"#;
+///
+/// assert_eq!(
+///     template::render(svg, &theme.schemes.light).unwrap(),
+///     r##"<path fill="#904b40" stroke="#857370"/>"##
+/// );
+/// ```
+///
+/// # Errors
+///
+/// Returns [`Error::UnknownToken`] with the whole placeholder if it does not
+/// name a role, so that typos don't silently leave placeholders behind.
+pub fn render(template: &str, scheme: &Scheme) -> Result<String, Error> {
+    let mut output = String::with_capacity(template.len());
+    let mut rest = template;
+
+    loop {
+        let (start, open, close) = match (rest.find("{{"), rest.find(CSS_VARIABLE_PREFIX)) {
+            (Some(braces), Some(variable)) if variable < braces => {
+                (variable, CSS_VARIABLE_PREFIX, ")")
+            }
+            (Some(braces), _) => (braces, "{{", "}}"),
+            (None, Some(variable)) => (variable, CSS_VARIABLE_PREFIX, ")"),
+            (None, None) => break,
+        };
+
+        let end = match rest[start + open.len()..].find(close) {
+            Some(end) => start + open.len() + end,
+            None => break,
+        };
+
+        let placeholder = &rest[start..end + close.len()];
+        let inner = &rest[start + open.len()..end];
+        let name = if close == ")" {
+            let name = inner.split(',').next().unwrap_or_default().trim();
+
+            (!name.contains('_')).then(|| name.replace('-', "_"))
+        } else {
+            Some(inner.trim().to_string())
+        };
+        let role = name
+            .and_then(|name| SchemeRole::try_from(name.as_str()).ok())
+            .ok_or_else(|| Error::UnknownToken(placeholder.to_string()))?;
+
+        output.push_str(&rest[..start]);
+        output.push_str(&scheme.role(role).to_hex_with_pound());
+        rest = &rest[end + close.len()..];
+    }
+
+    output.push_str(rest);
+
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::render;
+    use crate::{color::Argb, theme::ThemeBuilder, Error};
+    #[cfg(not(feature = "std"))]
+    use alloc::format;
+    #[cfg(feature = "std")]
+    use std::format;
+
+    #[test]
+    fn test_render() {
+        let theme = ThemeBuilder::with_source(Argb::from_u32(0xff4285f4)).build();
+        let scheme = &theme.schemes.dark;
+        let primary = scheme.primary.to_hex_with_pound();
+        let on_primary_container = scheme.on_primary_container.to_hex_with_pound();
+
+        assert_eq!(
+            render(
+                "<circle fill=\"{{ primary }}\" stroke=\"{{on_primary_container}}\"/>",
+                scheme
+            ),
+            Ok(format!(
+                "<circle fill=\"{primary}\" stroke=\"{on_primary_container}\"/>"
+            ))
+        );
+        assert_eq!(
+            render(
+                ".a { color: var(--md-sys-color-primary, #fff); margin: var(--gap); }",
+                scheme
+            ),
+            Ok(format!(".a {{ color: {primary}; margin: var(--gap); }}"))
+        );
+        assert_eq!(render("{{primary", scheme), Ok("{{primary".into()));
+        assert!(render("", scheme).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_unknown_token() {
+        let theme = ThemeBuilder::with_source(Argb::from_u32(0xff4285f4)).build();
+        let scheme = &theme.schemes.light;
+
+        assert_eq!(
+            render("{{primary}} {{primry}}", scheme),
+            Err(Error::UnknownToken("{{primry}}".into()))
+        );
+        assert_eq!(
+            render("var(--md-sys-color-on_primary)", scheme),
+            Err(Error::UnknownToken("var(--md-sys-color-on_primary)".into()))
+        );
+    }
+}
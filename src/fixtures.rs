@@ -0,0 +1,305 @@
+//! Golden fixtures captured from real devices, for device-parity tests.
+//!
+//! Integrators capture the source color and scheme colors a device derives
+//! from a wallpaper and store them as JSON, keyed by a hash of the wallpaper
+//! of their choice:
+//!
+//! ```json
+//! {
+//!   "3f2a9c": {
+//!     "seed": "#4285f4",
+//!     "light": { "primary": "#415f91", "on_primary": "#ffffff" },
+//!     "dark": { "primary": "#aac7ff" }
+//!   }
+//! }
+//! ```
+//!
+//! Schemes may list any subset of roles, named as the fields of [`Scheme`].
+//! [`DeviceFixtures::assert_matches`] then compares colors generated by the
+//! crate against a fixture, up to a distance in CAM16-UCS, since devices
+//! round colors differently.
+//!
+//! Requires the `test-utils` feature.
+
+use crate::{
+    color::Argb,
+    hct::Cam16,
+    scheme::{Scheme, SchemeRole},
+    theme::Schemes,
+    Error,
+};
+use serde::Deserialize;
+use std::{
+    collections::BTreeMap,
+    fmt, iter,
+    str::FromStr,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+/// Colors a device derived from a wallpaper.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeviceFixture {
+    pub seed: Argb,
+    /// Captured roles of the light scheme.
+    pub light: Vec<(SchemeRole, Argb)>,
+    /// Captured roles of the dark scheme.
+    pub dark: Vec<(SchemeRole, Argb)>,
+}
+
+/// Difference between a captured color and a generated one, see
+/// [`DeviceFixtures::mismatches`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Mismatch {
+    /// Role of the color, or `None` for the source color.
+    pub role: Option<SchemeRole>,
+    /// Whether the color belongs to the dark scheme.
+    pub is_dark: bool,
+    pub expected: Argb,
+    pub actual: Argb,
+    /// Distance between both colors in CAM16-UCS (ΔE).
+    pub distance: f64,
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.role {
+            Some(role) => write!(f, "{} {role}", if self.is_dark { "dark" } else { "light" })?,
+            None => "seed".fmt(f)?,
+        }
+
+        write!(
+            f,
+            ": expected {}, got {} (ΔE {:.2})",
+            self.expected.to_hex_with_pound(),
+            self.actual.to_hex_with_pound(),
+            self.distance
+        )
+    }
+}
+
+/// Fixtures keyed by wallpaper hash, see the [module documentation](self).
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeviceFixtures {
+    fixtures: BTreeMap<String, DeviceFixture>,
+    tolerance: f64,
+}
+
+#[derive(Deserialize)]
+struct RawFixture {
+    seed: String,
+    #[serde(default)]
+    light: BTreeMap<String, String>,
+    #[serde(default)]
+    dark: BTreeMap<String, String>,
+}
+
+impl DeviceFixtures {
+    /// Default max distance in CAM16-UCS (ΔE) between a captured color and a
+    /// generated one, about the distance between colors that are barely told
+    /// apart side by side.
+    pub const TOLERANCE: f64 = 2.0;
+
+    /// Parses fixtures from JSON, in the format of the
+    /// [module documentation](self).
+    ///
+    /// Returns [`Error::InvalidFixture`] for malformed JSON,
+    /// [`Error::ParseRGB`] for malformed colors and [`Error::UnknownRole`] for
+    /// unknown roles.
+    pub fn from_json(json: &str) -> Result<Self, Error> {
+        let raw: BTreeMap<String, RawFixture> =
+            serde_json::from_str(json).map_err(|error| Error::InvalidFixture(error.to_string()))?;
+        let roles = |colors: BTreeMap<String, String>| {
+            colors
+                .iter()
+                .map(|(role, color)| {
+                    Ok((SchemeRole::try_from(role.as_str())?, Argb::from_str(color)?))
+                })
+                .collect::<Result<Vec<_>, Error>>()
+        };
+        let fixtures = raw
+            .into_iter()
+            .map(|(wallpaper, fixture)| {
+                Ok((
+                    wallpaper,
+                    DeviceFixture {
+                        seed: Argb::from_str(&fixture.seed)?,
+                        light: roles(fixture.light)?,
+                        dark: roles(fixture.dark)?,
+                    },
+                ))
+            })
+            .collect::<Result<_, Error>>()?;
+
+        Ok(Self {
+            fixtures,
+            tolerance: Self::TOLERANCE,
+        })
+    }
+
+    /// Sets the max distance in CAM16-UCS (ΔE) between a captured color and a
+    /// generated one.
+    #[must_use]
+    pub const fn with_tolerance(mut self, tolerance: f64) -> Self {
+        self.tolerance = tolerance;
+
+        self
+    }
+
+    /// Returns the fixture of `wallpaper`, if any.
+    pub fn get(&self, wallpaper: &str) -> Option<&DeviceFixture> {
+        self.fixtures.get(wallpaper)
+    }
+
+    /// Returns an iterator over the wallpaper hashes and their fixtures.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = (&str, &DeviceFixture)> {
+        self.fixtures
+            .iter()
+            .map(|(wallpaper, fixture)| (wallpaper.as_str(), fixture))
+    }
+
+    /// Returns the colors of the fixture of `wallpaper` farther than the
+    /// tolerance from `seed` and `schemes`, or `None` if there is no such
+    /// fixture.
+    pub fn mismatches(
+        &self,
+        wallpaper: &str,
+        seed: Argb,
+        schemes: &Schemes,
+    ) -> Option<Vec<Mismatch>> {
+        let fixture = self.get(wallpaper)?;
+        let roles = |is_dark: bool, expected: &[(SchemeRole, Argb)], actual: &Scheme| {
+            expected
+                .iter()
+                .map(move |(role, expected)| (Some(*role), is_dark, *expected, actual.role(*role)))
+                .collect::<Vec<_>>()
+        };
+
+        Some(
+            iter::once((None, false, fixture.seed, seed))
+                .chain(roles(false, &fixture.light, &schemes.light))
+                .chain(roles(true, &fixture.dark, &schemes.dark))
+                .map(|(role, is_dark, expected, actual)| Mismatch {
+                    role,
+                    is_dark,
+                    expected,
+                    actual,
+                    distance: Cam16::from(expected).distance(&Cam16::from(actual)),
+                })
+                .filter(|mismatch| mismatch.distance > self.tolerance)
+                .collect(),
+        )
+    }
+
+    /// Asserts that `seed` and `schemes` match the fixture of `wallpaper`
+    /// within the tolerance.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no fixture for `wallpaper`, or listing every
+    /// mismatching color.
+    #[track_caller]
+    pub fn assert_matches(&self, wallpaper: &str, seed: Argb, schemes: &Schemes) {
+        let mismatches = self
+            .mismatches(wallpaper, seed, schemes)
+            .unwrap_or_else(|| panic!("no fixture for wallpaper {wallpaper}"));
+
+        if !mismatches.is_empty() {
+            let report = mismatches
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join("\n  ");
+
+            panic!("wallpaper {wallpaper} does not match its fixture:\n  {report}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DeviceFixtures;
+    use crate::{color::Argb, scheme::SchemeRole, theme::ThemeBuilder, Error};
+    use std::{format, string::ToString};
+
+    fn fixtures() -> DeviceFixtures {
+        let theme = ThemeBuilder::with_source(Argb::from_u32(0xff4285f4)).build();
+
+        DeviceFixtures::from_json(&format!(
+            r##"{{
+                "blue": {{
+                    "seed": "#4285f4",
+                    "light": {{ "primary": "{}", "surface": "{}" }},
+                    "dark": {{ "primary": "{}" }}
+                }},
+                "off": {{ "seed": "#4285f4", "light": {{ "primary": "#ff0000" }} }}
+            }}"##,
+            theme.schemes.light.primary.to_hex_with_pound(),
+            theme.schemes.light.surface.to_hex_with_pound(),
+            theme.schemes.dark.primary.to_hex_with_pound(),
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_from_json() {
+        let fixtures = fixtures();
+        let fixture = fixtures.get("blue").unwrap();
+
+        assert_eq!(fixtures.iter().len(), 2);
+        assert_eq!(fixture.seed, Argb::from_u32(0xff4285f4));
+        assert_eq!(fixture.light.len(), 2);
+        assert_eq!(fixture.dark[0].0, SchemeRole::Primary);
+        assert!(fixtures.get("missing").is_none());
+    }
+
+    #[test]
+    fn test_from_json_errors() {
+        assert!(matches!(
+            DeviceFixtures::from_json("[]"),
+            Err(Error::InvalidFixture(_))
+        ));
+        assert_eq!(
+            DeviceFixtures::from_json(r#"{ "a": { "seed": "blue" } }"#),
+            Err(Error::ParseRGB)
+        );
+        assert_eq!(
+            DeviceFixtures::from_json(
+                r##"{ "a": { "seed": "#fff", "dark": { "accent": "#fff" } } }"##
+            ),
+            Err(Error::UnknownRole)
+        );
+    }
+
+    #[test]
+    fn test_matches_within_tolerance() {
+        let theme = ThemeBuilder::with_source(Argb::from_u32(0xff4285f4)).build();
+        // One unit off in a channel, as devices rounding differently do.
+        let seed = Argb::from_u32(0xff4385f4);
+        let fixtures = fixtures();
+
+        fixtures.assert_matches("blue", seed, &theme.schemes);
+
+        let mismatches = fixtures.mismatches("off", seed, &theme.schemes).unwrap();
+
+        assert_eq!(mismatches.len(), 1);
+        assert_eq!(mismatches[0].role, Some(SchemeRole::Primary));
+        assert!(mismatches[0]
+            .to_string()
+            .starts_with("light primary: expected #ff0000"));
+        assert!(fixtures
+            .with_tolerance(0.0)
+            .mismatches("blue", seed, &theme.schemes)
+            .unwrap()
+            .iter()
+            .any(|mismatch| mismatch.role.is_none()));
+    }
+
+    #[test]
+    #[should_panic(expected = "does not match its fixture")]
+    fn test_assert_matches_panics() {
+        let theme = ThemeBuilder::with_source(Argb::from_u32(0xff4285f4)).build();
+
+        fixtures().assert_matches("off", Argb::from_u32(0xff4285f4), &theme.schemes);
+    }
+}
@@ -38,6 +38,7 @@ use core::f64::consts::PI;
 /// For example, white under the traditional assumption of a midday sun white
 /// point is accurately measured as a slightly chromatic blue by
 /// (roughly, hue 203, chroma 3, lightness 100)
+#[derive(Debug, Clone, Copy)]
 pub struct Cam16 {
     /// Like red, orange, yellow, green, etc.
     pub hue: f64,
@@ -195,7 +196,7 @@ impl Cam16 {
     /// Create a CAM16 color from lightness `j`, chroma `c`, and hue `h`,
     /// assuming the color was viewed in default viewing conditions.
     pub fn from_jch(j: f64, c: f64, h: f64) -> Self {
-        Self::from_jch_in_viewing_conditions(j, c, h, &ViewingConditions::s_rgb())
+        Self::from_jch_in_viewing_conditions(j, c, h, &ViewingConditions::current())
     }
 
     /// Create a CAM16 color from lightness `j`, chroma `c`, and hue `h`,
@@ -238,7 +239,7 @@ impl Cam16 {
     /// Create a CAM16 color from CAM16-UCS coordinates `jstar`, `astar`, `bstar`.
     /// assuming the color was viewed in default viewing conditions.
     pub fn from_ucs(jstar: f64, astar: f64, bstar: f64) -> Self {
-        Self::from_ucs_in_viewing_conditions(jstar, astar, bstar, &ViewingConditions::standard())
+        Self::from_ucs_in_viewing_conditions(jstar, astar, bstar, &ViewingConditions::current())
     }
 
     /// Create a CAM16 color from CAM16-UCS coordinates `jstar`, `astar`, `bstar`.
@@ -327,15 +328,17 @@ impl Cam16 {
     }
 }
 
+/// Drops the alpha of the color, which CAM16 does not model.
 impl From<Argb> for Cam16 {
     fn from(argb: Argb) -> Self {
-        Self::fromi32_in_viewing_conditions(argb, &ViewingConditions::s_rgb())
+        Self::fromi32_in_viewing_conditions(argb, &ViewingConditions::current())
     }
 }
 
+/// The result is opaque.
 impl From<Cam16> for Argb {
     fn from(val: Cam16) -> Self {
-        val.viewed(&ViewingConditions::s_rgb())
+        val.viewed(&ViewingConditions::current())
     }
 }
 
@@ -1,14 +1,21 @@
 #[cfg(all(not(feature = "std"), feature = "libm"))]
 #[allow(unused_imports)]
 use crate::utils::no_std::FloatExt;
+#[cfg(feature = "serde")]
+use crate::utils::precision;
 use crate::{
-    color::{lstar_from_y, Argb},
-    utils::FromRef,
+    color::{lstar_from_y, Argb, Gamut, GamutArgb, Lab, Xyz},
+    utils::{
+        math::{difference_degrees, sanitize_degrees_double},
+        FromRef,
+    },
+    Error,
 };
 use core::{
     cmp::Ordering,
     fmt,
     hash::{Hash, Hasher},
+    str::FromStr,
 };
 #[cfg(feature = "serde")]
 use serde::Serialize;
@@ -18,11 +25,14 @@ pub mod cam16;
 pub mod solver;
 pub mod viewing_conditions;
 
-#[derive(Default, Clone, Copy, Debug, PartialOrd)]
+#[derive(Clone, Copy, Debug, PartialOrd)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct Hct {
+    #[cfg_attr(feature = "serde", serde(serialize_with = "precision::serialize"))]
     _hue: f64,
+    #[cfg_attr(feature = "serde", serde(serialize_with = "precision::serialize"))]
     _chroma: f64,
+    #[cfg_attr(feature = "serde", serde(serialize_with = "precision::serialize"))]
     _tone: f64,
     _argb: Argb,
 }
@@ -47,9 +57,10 @@ impl Hct {
     /// After setting hue, the color is mapped from HCT to the more
     /// limited sRgb gamut for display. This will change its Argb/integer
     /// representation. If the HCT color is outside of the sRgb gamut, chroma
-    /// will decrease until it is inside the gamut.
+    /// will decrease until it is inside the gamut. Alpha is kept.
     pub fn set_hue(&mut self, value: f64) {
-        self._argb = HctSolver::solve_to_argb(value, self.get_chroma(), self.get_tone());
+        self._argb = HctSolver::solve_to_argb(value, self.get_chroma(), self.get_tone())
+            .with_alpha(self._argb.alpha);
 
         let cam16 = Cam16::from(self._argb);
 
@@ -71,9 +82,10 @@ impl Hct {
     /// After setting chroma, the color is mapped from HCT to the more
     /// limited sRgb gamut for display. This will change its Argb/integer
     /// representation. If the HCT color is outside of the sRgb gamut, chroma
-    /// will decrease until it is inside the gamut.
+    /// will decrease until it is inside the gamut. Alpha is kept.
     pub fn set_chroma(&mut self, value: f64) {
-        self._argb = HctSolver::solve_to_argb(self.get_hue(), value, self.get_tone());
+        self._argb = HctSolver::solve_to_argb(self.get_hue(), value, self.get_tone())
+            .with_alpha(self._argb.alpha);
 
         let cam16 = Cam16::from(self._argb);
 
@@ -99,9 +111,10 @@ impl Hct {
     /// After setting tone, the color is mapped from HCT to the more
     /// limited sRgb gamut for display. This will change its Argb/integer
     /// representation. If the HCT color is outside of the sRgb gamut, chroma
-    /// will decrease until it is inside the gamut.
+    /// will decrease until it is inside the gamut. Alpha is kept.
     pub fn set_tone(&mut self, value: f64) {
-        self._argb = HctSolver::solve_to_argb(self.get_hue(), self.get_chroma(), value);
+        self._argb = HctSolver::solve_to_argb(self.get_hue(), self.get_chroma(), value)
+            .with_alpha(self._argb.alpha);
 
         let cam16 = Cam16::from(self._argb);
 
@@ -110,6 +123,10 @@ impl Hct {
         self._tone = self._argb.as_lstar();
     }
 
+    /// Alpha, which HCT does not model, is kept as is: it is returned by the
+    /// conversion back to [`Argb`] and kept by the setters and
+    /// [`Hct::in_viewing_conditions`], while colors created from hue, chroma
+    /// and tone with [`Hct::from`] are opaque.
     pub fn new(argb: Argb) -> Self {
         let cam16 = Cam16::from(argb);
 
@@ -121,6 +138,11 @@ impl Hct {
         }
     }
 
+    /// Alpha of the color, from 0 (transparent) to 255 (opaque).
+    pub const fn get_alpha(&self) -> u8 {
+        self._argb.alpha
+    }
+
     /// 0 <= `hue` < 360; invalid values are corrected.
     /// 0 <= `chroma` <= ?; Informally, colorfulness. The color returned may be
     ///    lower than the requested chroma. Chroma has a different maximum for any
@@ -132,6 +154,63 @@ impl Hct {
         Self::new(argb)
     }
 
+    /// Like [`Hct::from`], but maps the color into `gamut` instead of sRgb,
+    /// e.g. [`Gamut::DisplayP3`] to reach the higher chroma of wide-gamut
+    /// displays. The result is opaque.
+    ///
+    /// Hct itself stays in sRgb, so the result is a [`GamutArgb`] rather
+    /// than an Hct.
+    pub fn from_in_gamut(hue: f64, chroma: f64, tone: f64, gamut: Gamut) -> GamutArgb {
+        HctSolver::solve_to_gamut(hue, chroma, tone, gamut)
+    }
+
+    /// Whether `hue`, `chroma` and `tone` describe a color inside the sRgb
+    /// gamut, which [`Hct::from`] returns without reducing chroma.
+    pub fn is_in_srgb_gamut(hue: f64, chroma: f64, tone: f64) -> bool {
+        HctSolver::is_in_gamut(hue, chroma, tone)
+    }
+
+    /// Maximum chroma of a color with `hue` and `tone` inside the sRgb gamut.
+    pub fn max_chroma(hue: f64, tone: f64) -> f64 {
+        HctSolver::max_chroma(hue, tone)
+    }
+
+    /// How far `chroma` is outside the sRgb gamut at `hue` and `tone`, i.e.
+    /// how much [`Hct::from`] reduces it by. Returns 0 inside the gamut.
+    pub fn chroma_deficit(hue: f64, chroma: f64, tone: f64) -> f64 {
+        if Self::is_in_srgb_gamut(hue, chroma, tone) {
+            return 0.0;
+        }
+
+        (chroma - Self::max_chroma(hue, tone)).max(0.0)
+    }
+
+    /// Rotates the hue to the nearest of `allowed_hues`, in degrees, if it is
+    /// at most `max_delta` degrees away, e.g. to keep a seed extracted from an
+    /// image within the hue families of a brand.
+    ///
+    /// Chroma and tone are kept, unless the rotated color is outside the sRgb
+    /// gamut, which reduces chroma as with [`Hct::set_hue`]. Returns whether
+    /// the hue was snapped; if not, the color is left unchanged.
+    pub fn snap_hue_to(&mut self, allowed_hues: &[f64], max_delta: f64) -> bool {
+        let hue = self.get_hue();
+        let nearest = allowed_hues
+            .iter()
+            .map(|allowed| sanitize_degrees_double(*allowed))
+            .map(|allowed| (difference_degrees(hue, allowed), allowed))
+            .filter(|(delta, _)| *delta <= max_delta)
+            .min_by(|a, b| a.0.total_cmp(&b.0));
+
+        match nearest {
+            Some((_, allowed)) => {
+                self.set_hue(allowed);
+
+                true
+            }
+            None => false,
+        }
+    }
+
     /// Translate a color into different [`ViewingConditions`].
     ///
     /// Colors change appearance. They look different with lights on versus off,
@@ -155,17 +234,53 @@ impl Hct {
             viewed_in_vc.x,
             viewed_in_vc.y,
             viewed_in_vc.z,
-            &ViewingConditions::standard(),
+            &ViewingConditions::current(),
         );
 
         // 3. Create HCT from:
         // - CAM16 using default VC with Xyz coordinates in specified VC.
         // - L* converted from Y in Xyz coordinates in specified VC.
-        Self::from(
+        let mut hct = Self::from(
             recast_in_vc.hue,
             recast_in_vc.chroma,
             lstar_from_y(viewed_in_vc.y),
-        )
+        );
+
+        hct._argb = hct._argb.with_alpha(self._argb.alpha);
+
+        hct
+    }
+
+    /// Inverse of [`Hct::in_viewing_conditions`]: interprets this color as
+    /// seen in `vc` and returns the color that looks the same in the default
+    /// [`ViewingConditions`].
+    ///
+    /// Use it to undo adaptation of colors measured in other environments, ex.
+    /// a swatch photographed on a dark background.
+    ///
+    /// Round trips are exact up to the precision of [`Argb`], as long as both
+    /// colors are inside the sRgb gamut.
+    #[must_use]
+    pub fn from_viewing_conditions(self, vc: &ViewingConditions) -> Self {
+        // 1. Use CAM16 to find the appearance of the color in specified VC.
+        let xyz = Xyz::from(Argb::from(self));
+        let seen_in_vc = Cam16::from_xyz_in_viewing_conditions(xyz.x, xyz.y, xyz.z, vc);
+
+        // 2. Find Xyz coordinates with that appearance in default VC.
+        let viewed_in_default = seen_in_vc.xyz_in_viewing_conditions(&ViewingConditions::current());
+
+        // 3. Create HCT from:
+        // - CAM16 of the color in specified VC, which matches default VC.
+        // - L* converted from Y in Xyz coordinates in default VC.
+        let mut hct = Self::from(
+            seen_in_vc.hue,
+            seen_in_vc.chroma,
+            lstar_from_y(viewed_in_default.y),
+        );
+
+        hct._argb = hct._argb.with_alpha(self._argb.alpha);
+
+        hct
     }
 }
 
@@ -181,6 +296,83 @@ impl fmt::Display for Hct {
     }
 }
 
+impl FromStr for Hct {
+    type Err = Error;
+
+    /// Parses an HCT color.
+    ///
+    /// Accepted forms:
+    ///
+    /// - `H240 C48 T40`, as written by [`Display`], with the components
+    ///   separated by whitespace in this order
+    /// - `hct(240, 48, 40)`, with optional whitespace around the components
+    ///
+    /// Prefixes and the function name are case-insensitive, and components
+    /// may be fractional. Malformed input yields [`Error::ParseHct`], while
+    /// a hue that is not finite yields [`Error::InvalidHue`] and a chroma
+    /// that is not finite or is negative yields [`Error::InvalidChroma`].
+    ///
+    /// [`Display`]: fmt::Display
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let mut components = [0.0; 3];
+
+        if let Some(args) = strip_prefix_ignore_case(s, "hct(").and_then(|s| s.strip_suffix(')')) {
+            let mut args = args.split(',');
+
+            for component in &mut components {
+                *component = parse_component(args.next())?;
+            }
+
+            if args.next().is_some() {
+                return Err(Error::ParseHct);
+            }
+        } else {
+            let mut words = s.split_whitespace();
+
+            for (component, prefix) in components.iter_mut().zip(["h", "c", "t"]) {
+                *component = parse_component(
+                    words
+                        .next()
+                        .and_then(|word| strip_prefix_ignore_case(word, prefix)),
+                )?;
+            }
+
+            if words.next().is_some() {
+                return Err(Error::ParseHct);
+            }
+        }
+
+        let [hue, chroma, tone] = components;
+
+        if !hue.is_finite() {
+            return Err(Error::InvalidHue);
+        }
+
+        if !chroma.is_finite() || chroma < 0.0 {
+            return Err(Error::InvalidChroma);
+        }
+
+        if !tone.is_finite() {
+            return Err(Error::ParseHct);
+        }
+
+        Ok(Self::from(hue, chroma, tone))
+    }
+}
+
+fn strip_prefix_ignore_case<'a>(s: &'a str, prefix: &str) -> Option<&'a str> {
+    s.get(..prefix.len())
+        .filter(|head| head.eq_ignore_ascii_case(prefix))
+        .map(|_| &s[prefix.len()..])
+}
+
+fn parse_component(component: Option<&str>) -> Result<f64, Error> {
+    component
+        .and_then(|component| component.trim().parse().ok())
+        .ok_or(Error::ParseHct)
+}
+
 impl Ord for Hct {
     fn cmp(&self, other: &Self) -> Ordering {
         self.partial_cmp(other).unwrap()
@@ -204,12 +396,41 @@ impl Hash for Hct {
     }
 }
 
+/// Opaque black, so that setting the hue, chroma or tone of the default
+/// yields an opaque color.
+impl Default for Hct {
+    fn default() -> Self {
+        Self {
+            _hue: 0.0,
+            _chroma: 0.0,
+            _tone: 0.0,
+            _argb: Argb::new(255, 0, 0, 0),
+        }
+    }
+}
+
 impl From<Argb> for Hct {
     fn from(value: Argb) -> Self {
         Self::new(value)
     }
 }
 
+/// Converts a Lab color into HCT, taking the tone directly from L* instead of
+/// the 8-bit Argb approximation of the color.
+impl From<Lab> for Hct {
+    fn from(lab: Lab) -> Self {
+        let xyz = Xyz::from(lab);
+        let cam16 = Cam16::from_xyz_in_viewing_conditions(
+            xyz.x,
+            xyz.y,
+            xyz.z,
+            &ViewingConditions::current(),
+        );
+
+        Self::from(cam16.hue, cam16.chroma, lab.l)
+    }
+}
+
 impl From<Hct> for Argb {
     fn from(value: Hct) -> Self {
         value._argb
@@ -224,8 +445,11 @@ impl FromRef<Hct> for Argb {
 
 #[cfg(test)]
 mod tests {
-    use super::{Cam16, Hct, ViewingConditions};
-    use crate::color::{y_from_lstar, Argb};
+    use super::{Cam16, Hct, HctSolver, ViewingConditions};
+    use crate::{
+        color::{lstar_from_y, y_from_lstar, Argb, Gamut, Xyz},
+        Error,
+    };
     use ahash::AHasher;
     #[cfg(not(feature = "std"))]
     use alloc::format;
@@ -270,7 +494,7 @@ mod tests {
     #[test]
     fn test_conversions_are_reflexive() {
         let cam = Cam16::from(RED);
-        let color = cam.viewed(&ViewingConditions::standard());
+        let color = cam.viewed(&ViewingConditions::STANDARD);
 
         assert_eq!(color, RED);
     }
@@ -445,7 +669,7 @@ mod tests {
     fn test_cam16_to_xyz_without_array() {
         let color_to_test = RED;
         let cam = Cam16::from(color_to_test);
-        let xyz = cam.xyz_in_viewing_conditions(&ViewingConditions::s_rgb());
+        let xyz = cam.xyz_in_viewing_conditions(&ViewingConditions::STANDARD);
 
         assert_approx_eq!(f64, xyz.x, 41.23, epsilon = 0.01);
         assert_approx_eq!(f64, xyz.y, 21.26, epsilon = 0.01);
@@ -613,4 +837,249 @@ mod tests {
 
         assert_eq!(Argb::from(result), Argb::from_u32(0xFF000000));
     }
+
+    #[test]
+    fn test_srgb_gamut() {
+        let hct = Hct::new(Argb::from_u32(0xFF6750A4));
+
+        assert!(Hct::is_in_srgb_gamut(
+            hct.get_hue(),
+            hct.get_chroma(),
+            hct.get_tone()
+        ));
+        assert_approx_eq!(
+            f64,
+            Hct::chroma_deficit(hct.get_hue(), hct.get_chroma(), hct.get_tone()),
+            0.0
+        );
+
+        assert!(Hct::is_in_srgb_gamut(0.0, 0.0, 0.0));
+        assert!(!Hct::is_in_srgb_gamut(0.0, 10.0, 0.0));
+        assert!(!Hct::is_in_srgb_gamut(0.0, 0.0, 101.0));
+        assert_approx_eq!(f64, Hct::chroma_deficit(0.0, 10.0, 100.0), 10.0);
+    }
+
+    #[test]
+    fn test_max_chroma() {
+        for hue in (0..360).step_by(30).map(f64::from) {
+            for tone in (10..=90).step_by(20).map(f64::from) {
+                let max_chroma = Hct::max_chroma(hue, tone);
+                let realized = Hct::from(hue, 200.0, tone).get_chroma();
+
+                assert_approx_eq!(f64, max_chroma, realized, epsilon = 1.5);
+                assert!(Hct::is_in_srgb_gamut(hue, max_chroma - 1.0, tone));
+                assert!(!Hct::is_in_srgb_gamut(hue, max_chroma + 1.0, tone));
+                assert_approx_eq!(
+                    f64,
+                    Hct::chroma_deficit(hue, max_chroma + 10.0, tone),
+                    10.0,
+                    epsilon = 0.001
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_from_in_gamut() {
+        for hue in (0..360).step_by(30).map(f64::from) {
+            for tone in (10..=90).step_by(20).map(f64::from) {
+                let srgb = Hct::max_chroma(hue, tone);
+                let p3 = HctSolver::max_chroma_in_gamut(hue, tone, Gamut::DisplayP3);
+                let rec2020 = HctSolver::max_chroma_in_gamut(hue, tone, Gamut::Rec2020);
+
+                assert_approx_eq!(
+                    f64,
+                    HctSolver::max_chroma_in_gamut(hue, tone, Gamut::Srgb),
+                    srgb
+                );
+                // Display P3 is not quite inside Rec. 2020 near red, but sRgb
+                // is inside both.
+                assert!(p3 >= srgb - 0.5, "{hue} {tone}");
+                assert!(rec2020 >= srgb - 0.5, "{hue} {tone}");
+
+                for gamut in [Gamut::DisplayP3, Gamut::Rec2020] {
+                    let color = Hct::from_in_gamut(hue, 200.0, tone, gamut);
+                    let Xyz { y, .. } = color.to_xyz();
+
+                    assert_eq!(color.gamut, gamut);
+                    assert!(color.argb.is_opaque());
+                    assert_approx_eq!(f64, lstar_from_y(y), tone, epsilon = 1.0);
+                    assert_approx_eq!(
+                        f64,
+                        color.chroma(),
+                        HctSolver::max_chroma_in_gamut(hue, tone, gamut),
+                        epsilon = 2.5
+                    );
+                }
+            }
+        }
+
+        let green = Hct::from_in_gamut(140.0, 120.0, 80.0, Gamut::DisplayP3);
+
+        assert!(green.chroma() > Hct::from(140.0, 120.0, 80.0).get_chroma() + 10.0);
+        assert_eq!(
+            Hct::from_in_gamut(140.0, 30.0, 80.0, Gamut::Srgb).argb,
+            Argb::from(Hct::from(140.0, 30.0, 80.0))
+        );
+
+        let gray = Hct::from_in_gamut(0.0, 0.0, 50.0, Gamut::Rec2020);
+
+        assert_eq!(gray.argb.red, gray.argb.green);
+        assert_eq!(gray.argb.green, gray.argb.blue);
+        assert_eq!(gray.to_argb(), Argb::from_lstar(50.0));
+    }
+
+    fn viewing_conditions() -> [ViewingConditions; 3] {
+        [
+            ViewingConditions::make(None, None, Some(0.0), None, None),
+            ViewingConditions::make(None, None, Some(100.0), None, None),
+            ViewingConditions::make(None, Some(50.0), Some(30.0), Some(1.0), None),
+        ]
+    }
+
+    fn assert_round_trip(hct: Hct, result: Hct) {
+        assert_approx_eq!(f64, result.get_tone(), hct.get_tone(), epsilon = 0.5);
+        assert_approx_eq!(f64, result.get_chroma(), hct.get_chroma(), epsilon = 1.5);
+
+        // Hue of near-neutral colors is dominated by rounding to Argb.
+        if hct.get_chroma() > 5.0 {
+            let hue_difference = (result.get_hue() - hct.get_hue()).abs();
+
+            assert!(hue_difference.min(360.0 - hue_difference) < 2.0);
+        }
+    }
+
+    #[test]
+    fn test_in_viewing_conditions_round_trip() {
+        for argb in [0xFF777777, 0xFF6750A4, 0xFFB3261E, 0xFF386A20, 0xFF7D5260] {
+            let hct = Hct::new(Argb::from_u32(argb));
+
+            for vc in &viewing_conditions() {
+                assert_round_trip(
+                    hct,
+                    hct.in_viewing_conditions(vc).from_viewing_conditions(vc),
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_from_viewing_conditions_round_trip() {
+        // Undoing adaptation to a dark background raises chroma, so only
+        // muted colors stay inside the sRgb gamut on the way.
+        for argb in [0xFF777777, 0xFF7D5260, 0xFF625B71] {
+            let hct = Hct::new(Argb::from_u32(argb));
+
+            for vc in &viewing_conditions() {
+                assert_round_trip(
+                    hct,
+                    hct.from_viewing_conditions(vc).in_viewing_conditions(vc),
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_from_viewing_conditions_default_is_identity() {
+        let hct = Hct::new(Argb::from_u32(0xFF6750A4));
+
+        assert_eq!(
+            Argb::from(hct.from_viewing_conditions(&ViewingConditions::current())),
+            Argb::from(hct)
+        );
+    }
+
+    #[test]
+    fn test_from_str_round_trips_display() {
+        let hct = Hct::new(Argb::from_u32(0xFF6750A4));
+        let parsed: Hct = format!("{hct}").parse().unwrap();
+
+        assert_eq!(format!("{parsed}"), format!("{hct}"));
+        assert_eq!(format!("{parsed}"), "H299 C48 T40");
+    }
+
+    #[test]
+    fn test_from_str_forms() {
+        let expected = Hct::from(240.0, 48.0, 40.0);
+
+        assert_eq!("H240 C48 T40".parse(), Ok(expected));
+        assert_eq!("  h240.0  c48\tt40 ".parse(), Ok(expected));
+        assert_eq!("hct(240, 48, 40)".parse(), Ok(expected));
+        assert_eq!("HCT( 240 ,48,40.0 )".parse(), Ok(expected));
+    }
+
+    #[test]
+    fn test_from_str_errors() {
+        for input in [
+            "",
+            "H240 C48",
+            "C48 H240 T40",
+            "H240 C48 T40 A1",
+            "H 240 C48 T40",
+            "hct(240, 48)",
+            "hct(240, 48, 40, 1)",
+            "hct(240, 48, 40",
+            "rgb(240, 48, 40)",
+            "H240 C48 Tinf",
+        ] {
+            assert_eq!(input.parse::<Hct>(), Err(Error::ParseHct), "{input}");
+        }
+
+        assert_eq!("HNaN C48 T40".parse::<Hct>(), Err(Error::InvalidHue));
+        assert_eq!("hct(240, -1, 40)".parse::<Hct>(), Err(Error::InvalidChroma));
+    }
+
+    #[test]
+    fn test_alpha() {
+        let mut hct = Hct::new(Argb::from_u32(0x804285f4));
+
+        assert_eq!(hct.get_alpha(), 0x80);
+        assert_eq!(Argb::from(hct).alpha, 0x80);
+
+        hct.set_hue(120.0);
+        hct.set_chroma(30.0);
+        hct.set_tone(70.0);
+
+        assert_eq!(hct.get_alpha(), 0x80);
+        assert_eq!(
+            hct.in_viewing_conditions(&ViewingConditions::standard())
+                .get_alpha(),
+            0x80
+        );
+        assert_eq!(Hct::from(120.0, 30.0, 70.0).get_alpha(), 255);
+
+        let mut default = Hct::default();
+
+        default.set_tone(50.0);
+
+        assert_eq!(default.get_alpha(), 255);
+    }
+
+    #[test]
+    fn test_snap_hue_to() {
+        let mut color = Hct::from(250.0, 40.0, 50.0);
+
+        assert!(color.snap_hue_to(&[30.0, 270.0], 30.0));
+        assert_approx_eq!(f64, color.get_hue(), 270.0, epsilon = 1.0);
+        assert_approx_eq!(f64, color.get_chroma(), 40.0, epsilon = 1.0);
+        assert_approx_eq!(f64, color.get_tone(), 50.0, epsilon = 0.5);
+    }
+
+    #[test]
+    fn test_snap_hue_to_wraps_around() {
+        let mut color = Hct::from(350.0, 40.0, 50.0);
+
+        assert!(color.snap_hue_to(&[120.0, 370.0], 30.0));
+        assert_approx_eq!(f64, color.get_hue(), 10.0, epsilon = 1.0);
+    }
+
+    #[test]
+    fn test_snap_hue_to_out_of_reach() {
+        let mut color = Hct::from(200.0, 40.0, 50.0);
+        let original = color;
+
+        assert!(!color.snap_hue_to(&[30.0, 270.0], 30.0));
+        assert!(!color.snap_hue_to(&[], 180.0));
+        assert_eq!(color, original);
+    }
 }
@@ -1,15 +1,22 @@
+// Literals keep full precision, so that they are exact with the Float solver.
+#![cfg_attr(feature = "f32", allow(clippy::excessive_precision))]
+
 use super::{Cam16, ViewingConditions};
 #[cfg(all(not(feature = "std"), feature = "libm"))]
 #[allow(unused_imports)]
 use crate::utils::no_std::FloatExt;
 use crate::{
-    color::{y_from_lstar, Argb, LinearRgb},
-    utils::math::{matrix_multiply, sanitize_degrees_double, signum},
+    color::{y_from_lstar, Argb, Gamut, GamutArgb, LinearRgb},
+    utils::{
+        math::{self, sanitize_degrees_double},
+        Float,
+    },
 };
-use core::f64::consts::PI;
+
+const PI: Float = core::f64::consts::PI as Float;
 
 /// A struct that solves the HCT equation.
-const SCALED_DISCOUNT_FROM_LINRGB: [[f64; 3]; 3] = [
+const SCALED_DISCOUNT_FROM_LINRGB: [[Float; 3]; 3] = [
     [
         0.001200833568784504,
         0.002389694492170889,
@@ -27,15 +34,90 @@ const SCALED_DISCOUNT_FROM_LINRGB: [[f64; 3]; 3] = [
     ],
 ];
 
-const LINRGB_FROM_SCALED_DISCOUNT: [[f64; 3]; 3] = [
+const LINRGB_FROM_SCALED_DISCOUNT: [[Float; 3]; 3] = [
     [1373.2198709594231, -1100.4251190754821, -7.278681089101213],
     [-271.815969077903, 559.6580465940733, -32.46047482791194],
     [1.9622899599665666, -57.173814538844006, 308.7233197812385],
 ];
 
-const Y_FROM_LINRGB: [f64; 3] = [0.2126, 0.7152, 0.0722];
+const Y_FROM_LINRGB: [Float; 3] = [0.2126, 0.7152, 0.0722];
+
+/// Constants of the solver for the primaries of a [`Gamut`].
+struct Primaries {
+    scaled_discount_from_linrgb: [[Float; 3]; 3],
+    linrgb_from_scaled_discount: [[Float; 3]; 3],
+    y_from_linrgb: [Float; 3],
+}
+
+const SRGB: Primaries = Primaries {
+    scaled_discount_from_linrgb: SCALED_DISCOUNT_FROM_LINRGB,
+    linrgb_from_scaled_discount: LINRGB_FROM_SCALED_DISCOUNT,
+    y_from_linrgb: Y_FROM_LINRGB,
+};
+
+const DISPLAY_P3: Primaries = Primaries {
+    scaled_discount_from_linrgb: [
+        [
+            0.0013653591262210096,
+            0.0021978764721995113,
+            0.0003068667372430467,
+        ],
+        [
+            0.0005901716562754954,
+            0.0029453949544075266,
+            0.00035915893003166674,
+        ],
+        [
+            0.000037002752984205616,
+            0.0002768624072359815,
+            0.003621963343179569,
+        ],
+    ],
+    linrgb_from_scaled_discount: [
+        [1080.8841504506981, -805.4637804107577, -11.705773642015846],
+        [-217.2563408314935, 504.60461813537387, -31.630499656477358],
+        [5.564502557479035, -30.343120998737398, 278.63077190660016],
+    ],
+    y_from_linrgb: [0.22900360000000003, 0.6917267249999999, 0.07926967499999998],
+};
+
+const REC_2020: Primaries = Primaries {
+    scaled_discount_from_linrgb: [
+        [
+            0.0016917206281254593,
+            0.001973220500934446,
+            0.0002051612066036617,
+        ],
+        [
+            0.0006015741286147635,
+            0.0029950816470330475,
+            0.0002980697650668772,
+        ],
+        [
+            0.000041857131246407415,
+            0.00021640693707127652,
+            0.0036775644350820723,
+        ],
+    ],
+    linrgb_from_scaled_discount: [
+        [771.9651498595969, -508.4522342893562, -1.8553212864812612],
+        [-155.08610014824777, 437.99445908652723, -26.848001123994123],
+        [0.33975239377633093, -19.98678438935003, 273.5200891439646],
+    ],
+    y_from_linrgb: [0.26272171736164046, 0.6779892755022618, 0.0592890071360975],
+};
+
+impl Primaries {
+    const fn of(gamut: Gamut) -> &'static Self {
+        match gamut {
+            Gamut::Srgb => &SRGB,
+            Gamut::DisplayP3 => &DISPLAY_P3,
+            Gamut::Rec2020 => &REC_2020,
+        }
+    }
+}
 
-const CRITICAL_PLANES: [f64; 255] = [
+const CRITICAL_PLANES: [Float; 255] = [
     0.015176349177441876,
     0.045529047532325624,
     0.07588174588720938,
@@ -293,6 +375,59 @@ const CRITICAL_PLANES: [f64; 255] = [
     99.55452497210776,
 ];
 
+/// Converts to the float type of the solver.
+#[allow(clippy::unnecessary_cast, clippy::missing_const_for_fn)]
+fn float(value: f64) -> Float {
+    value as Float
+}
+
+/// Converts from the float type of the solver.
+#[allow(
+    clippy::unnecessary_cast,
+    clippy::cast_lossless,
+    clippy::missing_const_for_fn
+)]
+fn double(value: Float) -> f64 {
+    value as f64
+}
+
+fn signum(value: Float) -> Float {
+    if value < 0.0 {
+        -1.0
+    } else if value == 0.0 {
+        0.0
+    } else {
+        1.0
+    }
+}
+
+fn matrix_multiply(row: [Float; 3], matrix: [[Float; 3]; 3]) -> [Float; 3] {
+    [
+        row[2].mul_add(
+            matrix[0][2],
+            row[0].mul_add(matrix[0][0], row[1] * matrix[0][1]),
+        ),
+        row[2].mul_add(
+            matrix[1][2],
+            row[0].mul_add(matrix[1][0], row[1] * matrix[1][1]),
+        ),
+        row[2].mul_add(
+            matrix[2][2],
+            row[0].mul_add(matrix[2][0], row[1] * matrix[2][1]),
+        ),
+    ]
+}
+
+/// Converts a linear RGB color of the solver into [`Argb`].
+fn to_argb([red, green, blue]: [Float; 3]) -> Argb {
+    LinearRgb {
+        red: double(red),
+        green: double(green),
+        blue: double(blue),
+    }
+    .into()
+}
+
 pub struct HctSolver;
 
 impl HctSolver {
@@ -301,7 +436,7 @@ impl HctSolver {
     /// - `angle`: An angle in radians; must not deviate too much from 0.
     ///
     /// Returns A coterminal angle between 0 and 2pi.
-    fn sanitize_radians(angle: f64) -> f64 {
+    fn sanitize_radians(angle: Float) -> Float {
         PI.mul_add(8.0, angle) % (PI * 2.0)
     }
 
@@ -311,41 +446,41 @@ impl HctSolver {
     /// 0.0 <= `rgb_component` <= 100.0 represents linear R/G/B channel.
     /// 0.0 <= output <= 255.0, color channel converted to regular Rgb
     /// space.
-    fn true_delinearized(rgb_component: f64) -> f64 {
+    fn true_delinearized(rgb_component: Float) -> Float {
         let normalized = rgb_component / 100.0;
         let delinearized = if normalized <= 0.0031308 {
             normalized * 12.92
         } else {
-            1.055f64.mul_add(normalized.powf(1.0 / 2.4), -0.055)
+            Float::mul_add(1.055, normalized.powf(1.0 / 2.4), -0.055)
         };
 
         delinearized * 255.0
     }
 
-    fn chromatic_adaptation(component: f64) -> f64 {
+    fn chromatic_adaptation(component: Float) -> Float {
         let af = component.abs().powf(0.42);
         signum(component) * 400.0 * af / (af + 27.13)
     }
 
     /// Returns the hue of `linrgb`, a linear Rgb color, in CAM16, in
     /// radians.
-    fn hue_of(linrgb: [f64; 3]) -> f64 {
-        let scaled_discount = matrix_multiply(linrgb, SCALED_DISCOUNT_FROM_LINRGB);
+    fn hue_of(linrgb: [Float; 3], primaries: &Primaries) -> Float {
+        let scaled_discount = matrix_multiply(linrgb, primaries.scaled_discount_from_linrgb);
 
         let r_a = Self::chromatic_adaptation(scaled_discount[0]);
         let g_a = Self::chromatic_adaptation(scaled_discount[1]);
         let b_a = Self::chromatic_adaptation(scaled_discount[2]);
 
         // redness-greenness
-        let a = (11.0f64.mul_add(r_a, -12.0 * g_a) + b_a) / 11.0;
+        let a = (Float::mul_add(11.0, r_a, -12.0 * g_a) + b_a) / 11.0;
 
         // yellowness-blueness
-        let b = 2.0f64.mul_add(-b_a, r_a + g_a) / 9.0;
+        let b = Float::mul_add(2.0, -b_a, r_a + g_a) / 9.0;
 
         b.atan2(a)
     }
 
-    fn are_in_cyclic_order(a: f64, b: f64, c: f64) -> bool {
+    fn are_in_cyclic_order(a: Float, b: Float, c: Float) -> bool {
         let delta_ab = Self::sanitize_radians(b - a);
         let delta_ac = Self::sanitize_radians(c - a);
 
@@ -356,11 +491,11 @@ impl HctSolver {
     ///
     /// Returns a number t such that lerp(`source`, `target`, t) =
     /// `mid`.
-    fn intercept(source: f64, mid: f64, target: f64) -> f64 {
+    fn intercept(source: Float, mid: Float, target: Float) -> Float {
         (mid - source) / (target - source)
     }
 
-    fn lerp_point(source: [f64; 3], t: f64, target: [f64; 3]) -> [f64; 3] {
+    fn lerp_point(source: [Float; 3], t: Float, target: [Float; 3]) -> [Float; 3] {
         [
             (target[0] - source[0]).mul_add(t, source[0]),
             (target[1] - source[1]).mul_add(t, source[1]),
@@ -377,17 +512,17 @@ impl HctSolver {
     ///   ... G = `coordinate` if `axis` == 1
     ///   ... B = `coordinate` if `axis` == 2
     fn set_coordinate(
-        source: [f64; 3],
-        coordinate: f64,
-        target: [f64; 3],
+        source: [Float; 3],
+        coordinate: Float,
+        target: [Float; 3],
         axis: usize,
-    ) -> [f64; 3] {
+    ) -> [Float; 3] {
         let t = Self::intercept(source[axis], coordinate, target[axis]);
 
         Self::lerp_point(source, t, target)
     }
 
-    fn is_bounded(x: f64) -> bool {
+    fn is_bounded(x: Float) -> bool {
         (0.0..=100.0).contains(&x)
     }
 
@@ -399,17 +534,15 @@ impl HctSolver {
     /// coordinates, if it exists.
     /// If this possible vertex lies outside of the cube, `[-1.0, -1.0,
     /// -1.0]` is returned.
-    fn nth_vertex(y: f64, n: i32) -> [f64; 3] {
-        let k_r = Y_FROM_LINRGB[0];
-        let k_g = Y_FROM_LINRGB[1];
-        let k_b = Y_FROM_LINRGB[2];
+    fn nth_vertex(y: Float, n: i32, primaries: &Primaries) -> [Float; 3] {
+        let [k_r, k_g, k_b] = primaries.y_from_linrgb;
 
         let coord_a = if n % 4 <= 1 { 0.0 } else { 100.0 };
         let coord_b = if n % 2 == 0 { 0.0 } else { 100.0 };
 
         if n < 4 {
-            let g: f64 = coord_a;
-            let b: f64 = coord_b;
+            let g: Float = coord_a;
+            let b: Float = coord_b;
             let r = b.mul_add(-k_b, g.mul_add(-k_g, y)) / k_r;
 
             if Self::is_bounded(r) {
@@ -445,7 +578,7 @@ impl HctSolver {
     /// Given a plane Y = `y` and a desired `target_hue`, returns the
     /// segment containing the desired color, represented as an array of
     /// its two endpoints.
-    fn bisect_to_segment(y: f64, target_hue: f64) -> [[f64; 3]; 2] {
+    fn bisect_to_segment(y: Float, target_hue: Float, primaries: &Primaries) -> [[Float; 3]; 2] {
         let mut left = [-1.0; 3];
         let mut right = left;
         let mut left_hue = 0.0;
@@ -454,13 +587,13 @@ impl HctSolver {
         let mut uncut = true;
 
         for n in 0..12 {
-            let mid = Self::nth_vertex(y, n);
+            let mid = Self::nth_vertex(y, n, primaries);
 
             if mid[0] < 0.0 {
                 continue;
             }
 
-            let mid_hue = Self::hue_of(mid);
+            let mid_hue = Self::hue_of(mid, primaries);
 
             if !initialized {
                 left = mid;
@@ -487,7 +620,7 @@ impl HctSolver {
         [left, right]
     }
 
-    fn mid_point(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+    fn mid_point(a: [Float; 3], b: [Float; 3]) -> [Float; 3] {
         [
             (a[0] + b[0]) / 2.0,
             (a[1] + b[1]) / 2.0,
@@ -495,11 +628,11 @@ impl HctSolver {
         ]
     }
 
-    fn critical_plane_below(x: f64) -> i16 {
+    fn critical_plane_below(x: Float) -> i16 {
         (x - 0.5).floor() as i16
     }
 
-    fn critical_plane_above(x: f64) -> i16 {
+    fn critical_plane_above(x: Float) -> i16 {
         (x - 0.5).ceil() as i16
     }
 
@@ -508,14 +641,14 @@ impl HctSolver {
     ///
     /// Returns the color with the desired Y value `y` and hue
     /// `target_hue`, in linear Rgb coordinates.
-    fn bisect_to_limit(y: f64, target_hue: f64) -> [f64; 3] {
-        let segment = Self::bisect_to_segment(y, target_hue);
+    fn bisect_to_limit(y: Float, target_hue: Float, primaries: &Primaries) -> [Float; 3] {
+        let segment = Self::bisect_to_segment(y, target_hue, primaries);
         let mut left = segment[0];
-        let mut left_hue = Self::hue_of(left);
+        let mut left_hue = Self::hue_of(left, primaries);
         let mut right = segment[1];
 
         for axis in 0..3 {
-            if (left[axis] - right[axis]).abs() > f64::EPSILON {
+            if (left[axis] - right[axis]).abs() > Float::EPSILON {
                 let [mut l_plane, mut r_plane] = if left[axis] < right[axis] {
                     [
                         Self::critical_plane_below(Self::true_delinearized(left[axis])),
@@ -533,10 +666,11 @@ impl HctSolver {
                         break;
                     }
 
-                    let m_plane = ((f64::from(l_plane) + f64::from(r_plane)) / 2.0).floor() as i16;
+                    let m_plane =
+                        ((Float::from(l_plane) + Float::from(r_plane)) / 2.0).floor() as i16;
                     let mid_plane_coordinate = CRITICAL_PLANES[m_plane as usize];
                     let mid = Self::set_coordinate(left, mid_plane_coordinate, right, axis);
-                    let mid_hue = Self::hue_of(mid);
+                    let mid_hue = Self::hue_of(mid, primaries);
 
                     if Self::are_in_cyclic_order(left_hue, target_hue, mid_hue) {
                         right = mid;
@@ -553,7 +687,7 @@ impl HctSolver {
         Self::mid_point(left, right)
     }
 
-    fn inverse_chromatic_adaptation(adapted: f64) -> f64 {
+    fn inverse_chromatic_adaptation(adapted: Float) -> Float {
         let adapted_abs = adapted.abs();
         let base = (27.13 * adapted_abs / (400.0 - adapted_abs)).max(0.0);
 
@@ -563,18 +697,27 @@ impl HctSolver {
     /// Finds a color with the given hue, chroma, and Y.
     ///
     /// Returns a color with the desired `hue_radians`, `chroma`, and
-    /// `y` as a hexadecimal integer, if found; and returns 0 otherwise.
-    fn find_result_by_j(hue_radians: f64, chroma: f64, y: f64) -> Argb {
+    /// `y` in linear Rgb coordinates, if found.
+    fn find_result_by_j(
+        hue_radians: Float,
+        chroma: Float,
+        y: Float,
+        primaries: &Primaries,
+    ) -> Option<[Float; 3]> {
         // Initial estimate of j.
         let mut j = y.sqrt() * 11.0;
         // ===========================================================
         // Operations inlined from Cam16 to avoid repeated calculation
         // ===========================================================
-        let viewing_conditions = ViewingConditions::standard();
-        let t_inner_coeff =
-            1.0 / (1.64 - 0.29f64.powf(viewing_conditions.background_ytowhite_point_y)).powf(0.73);
+        let viewing_conditions = ViewingConditions::STANDARD;
+        let t_inner_coeff = 1.0
+            / (1.64 - Float::powf(0.29, float(viewing_conditions.background_ytowhite_point_y)))
+                .powf(0.73);
         let e_hue = 0.25 * ((hue_radians + 2.0).cos() + 3.8);
-        let p1 = e_hue * (50000.0 / 13.0) * viewing_conditions.n_c * viewing_conditions.ncb;
+        let p1 = e_hue
+            * (50000.0 / 13.0)
+            * float(viewing_conditions.n_c)
+            * float(viewing_conditions.ncb);
         let (h_sin, h_cos) = (hue_radians.sin(), hue_radians.cos());
 
         for iteration_round in 0..5 {
@@ -589,47 +732,47 @@ impl HctSolver {
             };
 
             let t = (alpha * t_inner_coeff).powf(1.0 / 0.9);
-            let ac = viewing_conditions.aw
-                * j_normalized.powf(1.0 / viewing_conditions.c / viewing_conditions.z);
-            let p2 = ac / viewing_conditions.nbb;
+            let ac = float(viewing_conditions.aw)
+                * j_normalized
+                    .powf(1.0 / float(viewing_conditions.c) / float(viewing_conditions.z));
+            let p2 = ac / float(viewing_conditions.nbb);
             let gamma = 23.0 * (p2 + 0.305) * t
-                / (108.0 * t).mul_add(h_sin, 23.0f64.mul_add(p1, 11.0 * t * h_cos));
+                / (108.0 * t).mul_add(h_sin, Float::mul_add(23.0, p1, 11.0 * t * h_cos));
             let a = gamma * h_cos;
             let b = gamma * h_sin;
             let (r_a, g_a, b_a) = (
-                288.0f64.mul_add(b, 460.0f64.mul_add(p2, 451.0 * a)) / 1403.0,
-                261.0f64.mul_add(-b, 460.0f64.mul_add(p2, -891.0 * a)) / 1403.0,
-                6300.0f64.mul_add(-b, 460.0f64.mul_add(p2, -220.0 * a)) / 1403.0,
+                Float::mul_add(288.0, b, Float::mul_add(460.0, p2, 451.0 * a)) / 1403.0,
+                Float::mul_add(261.0, -b, Float::mul_add(460.0, p2, -891.0 * a)) / 1403.0,
+                Float::mul_add(6300.0, -b, Float::mul_add(460.0, p2, -220.0 * a)) / 1403.0,
             );
 
             let r_cscaled = Self::inverse_chromatic_adaptation(r_a);
             let g_cscaled = Self::inverse_chromatic_adaptation(g_a);
             let b_cscaled = Self::inverse_chromatic_adaptation(b_a);
-            let [red, green, blue] = matrix_multiply(
+            let linrgb = matrix_multiply(
                 [r_cscaled, g_cscaled, b_cscaled],
-                LINRGB_FROM_SCALED_DISCOUNT,
+                primaries.linrgb_from_scaled_discount,
             );
-
-            let linrgb = LinearRgb { red, green, blue };
+            let [red, green, blue] = linrgb;
             // ===========================================================
             // Operations inlined from Cam16 to avoid repeated calculation
             // ===========================================================
-            if linrgb.red < 0.0 || linrgb.green < 0.0 || linrgb.blue < 0.0 {
-                return Argb::default();
+            if red < 0.0 || green < 0.0 || blue < 0.0 {
+                return None;
             }
 
-            let [k_r, k_g, k_b] = Y_FROM_LINRGB;
-            let fnj = k_b.mul_add(linrgb.blue, k_r.mul_add(linrgb.red, k_g * linrgb.green));
+            let [k_r, k_g, k_b] = primaries.y_from_linrgb;
+            let fnj = k_b.mul_add(blue, k_r.mul_add(red, k_g * green));
             if fnj <= 0.0 {
-                return Argb::default();
+                return None;
             }
 
             if iteration_round == 4 || (fnj - y).abs() < 0.002 {
-                if linrgb.red > 100.01 || linrgb.green > 100.01 || linrgb.blue > 100.01 {
-                    return Argb::default();
+                if red > 100.01 || green > 100.01 || blue > 100.01 {
+                    return None;
                 }
 
-                return linrgb.into();
+                return Some(linrgb);
             }
 
             // Iterates with Newton method,
@@ -637,7 +780,29 @@ impl HctSolver {
             j = j - (fnj - y) * j / (2.0 * fnj);
         }
 
-        Argb::default()
+        None
+    }
+
+    /// Finds a color with the given hue, chroma, and L* in the gamut of
+    /// `primaries`, in linear Rgb coordinates, or `None` for grays and L*
+    /// too close to black or white, which are the same in every gamut.
+    fn solve_to_linrgb(
+        hue_degrees: f64,
+        chroma: f64,
+        lstar: f64,
+        primaries: &Primaries,
+    ) -> Option<[Float; 3]> {
+        if chroma < 0.0001 || !(0.0001..=99.9999).contains(&lstar) {
+            return None;
+        }
+
+        let hue_degrees = sanitize_degrees_double(hue_degrees);
+        let hue_radians = float(hue_degrees.to_radians());
+
+        let y = float(y_from_lstar(lstar));
+
+        Self::find_result_by_j(hue_radians, float(chroma), y, primaries)
+            .or_else(|| Some(Self::bisect_to_limit(y, hue_radians, primaries)))
     }
 
     /// Finds an sRgb color with the given hue, chroma, and L*, if
@@ -649,26 +814,74 @@ impl HctSolver {
     /// constraints, the hue and L* will be sufficiently close, and the
     /// chroma will be maximized.
     pub fn solve_to_argb(hue_degrees: f64, chroma: f64, lstar: f64) -> Argb {
-        if chroma < 0.0001 || !(0.0001..=99.9999).contains(&lstar) {
-            return Argb::from_lstar(lstar);
+        Self::solve_to_linrgb(hue_degrees, chroma, lstar, &SRGB)
+            .map_or_else(|| Argb::from_lstar(lstar), to_argb)
+    }
+
+    /// Like [`HctSolver::solve_to_argb`], but maps the color into `gamut`
+    /// instead of sRgb, so that wide-gamut displays can show chroma that
+    /// sRgb can't.
+    pub fn solve_to_gamut(hue_degrees: f64, chroma: f64, lstar: f64, gamut: Gamut) -> GamutArgb {
+        if gamut == Gamut::Srgb {
+            return GamutArgb::new(gamut, Self::solve_to_argb(hue_degrees, chroma, lstar));
         }
 
-        let hue_degrees = sanitize_degrees_double(hue_degrees);
-        let hue_radians = hue_degrees.to_radians();
+        let [red, green, blue] =
+            Self::solve_to_linrgb(hue_degrees, chroma, lstar, Primaries::of(gamut))
+                .map_or_else(|| [y_from_lstar(lstar); 3], |linrgb| linrgb.map(double));
 
-        let y = y_from_lstar(lstar);
+        GamutArgb::from_linear_rgb(LinearRgb { red, green, blue }, gamut)
+    }
 
-        let exact_answer = Self::find_result_by_j(hue_radians, chroma, y);
+    /// Whether a color with the given hue, chroma, and L* exists in sRgb,
+    /// i.e. [`HctSolver::solve_to_argb`] finds it without reducing chroma.
+    pub fn is_in_gamut(hue_degrees: f64, chroma: f64, lstar: f64) -> bool {
+        if !(0.0..=100.0).contains(&lstar) {
+            return false;
+        }
 
-        if exact_answer != Argb::default() {
-            return exact_answer;
+        if chroma < 0.0001 || !(0.0001..=99.9999).contains(&lstar) {
+            return chroma < 0.0001;
         }
 
-        let [red, green, blue] = Self::bisect_to_limit(y, hue_radians);
+        let hue_radians = float(sanitize_degrees_double(hue_degrees).to_radians());
+
+        Self::find_result_by_j(
+            hue_radians,
+            float(chroma),
+            float(y_from_lstar(lstar)),
+            &SRGB,
+        )
+        .is_some()
+    }
 
-        let linrgb = LinearRgb { red, green, blue };
+    /// Finds the maximum chroma of an sRgb color with the given hue and L*.
+    ///
+    /// Unlike the chroma of the color returned by
+    /// [`HctSolver::solve_to_argb`], this is not affected by rounding to
+    /// [`Argb`].
+    pub fn max_chroma(hue_degrees: f64, lstar: f64) -> f64 {
+        Self::max_chroma_in_gamut(hue_degrees, lstar, Gamut::Srgb)
+    }
+
+    /// Like [`HctSolver::max_chroma`], in `gamut` instead of sRgb.
+    pub fn max_chroma_in_gamut(hue_degrees: f64, lstar: f64, gamut: Gamut) -> f64 {
+        if !(0.0001..=99.9999).contains(&lstar) {
+            return 0.0;
+        }
 
-        linrgb.into()
+        let hue_radians = float(sanitize_degrees_double(hue_degrees).to_radians());
+        let [x, y, z] = math::matrix_multiply(
+            Self::bisect_to_limit(
+                float(y_from_lstar(lstar)),
+                hue_radians,
+                Primaries::of(gamut),
+            )
+            .map(double),
+            gamut.to_xyz(),
+        );
+
+        Cam16::from_xyz_in_viewing_conditions(x, y, z, &ViewingConditions::STANDARD).chroma
     }
 
     /// Finds a CAM16 object with the given hue, chroma, and L*, if
@@ -1,11 +1,38 @@
 #[cfg(all(not(feature = "std"), feature = "libm"))]
 #[allow(unused_imports)]
 use crate::utils::no_std::FloatExt;
+#[cfg(feature = "global-viewing-conditions")]
+use crate::Error;
 use crate::{
     color::{y_from_lstar, WHITE_POINT_D65},
     utils::math::lerp,
 };
 use core::f64::consts::PI;
+#[cfg(feature = "global-viewing-conditions")]
+use core::{
+    cell::UnsafeCell,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+/// Set by the only [`ViewingConditions::set_global`] call allowed to write
+/// [`GLOBAL`].
+#[cfg(feature = "global-viewing-conditions")]
+static GLOBAL_IS_CLAIMED: AtomicBool = AtomicBool::new(false);
+/// Set once [`GLOBAL`] is written, after which it is never written again.
+#[cfg(feature = "global-viewing-conditions")]
+static GLOBAL_IS_SET: AtomicBool = AtomicBool::new(false);
+#[cfg(feature = "global-viewing-conditions")]
+static GLOBAL: GlobalCell = GlobalCell(UnsafeCell::new(ViewingConditions::STANDARD));
+
+/// Write-once cell of the global override, read without locking.
+#[cfg(feature = "global-viewing-conditions")]
+struct GlobalCell(UnsafeCell<ViewingConditions>);
+
+// SAFETY: The cell is written once, by the thread that claimed it with
+// `GLOBAL_IS_CLAIMED`, and only read after `GLOBAL_IS_SET` is observed with
+// acquire ordering, which happens after that write.
+#[cfg(feature = "global-viewing-conditions")]
+unsafe impl Sync for GlobalCell {}
 
 /// In traditional color spaces, a color can be identified solely by the
 /// observer's measurement of the color. Color appearance models such as CAM16
@@ -18,7 +45,7 @@ use core::f64::consts::PI;
 ///
 /// This class caches intermediate values of the CAM16 conversion process that
 /// depend only on viewing conditions, enabling speed ups.
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 pub struct ViewingConditions {
     pub white_point: [f64; 3],
     pub adapting_luminance: f64,
@@ -40,12 +67,102 @@ pub struct ViewingConditions {
 }
 
 impl ViewingConditions {
-    pub fn standard() -> Self {
-        Self::s_rgb()
+    /// sRGB-like viewing conditions, i.e. the result of
+    /// `ViewingConditions::make(None, None, None, None, None)`.
+    ///
+    /// The values are precomputed, so using this constant costs no floating
+    /// point math. Prefer it over [`ViewingConditions::make`] in hot paths.
+    pub const STANDARD: Self = Self {
+        white_point: WHITE_POINT_D65,
+        adapting_luminance: 11.725677948856951,
+        background_lstar: 50.0,
+        surround: 2.0,
+        discounting_illuminant: false,
+        background_ytowhite_point_y: 0.18418651851244416,
+        aw: 29.980997194447337,
+        nbb: 1.0169191804458755,
+        ncb: 1.0169191804458755,
+        c: 0.69,
+        n_c: 1.0,
+        drgb_inverse: [0.0, 0.0, 0.0],
+        rgb_d: [1.02117770275752, 0.9863077294280124, 0.9339605082802299],
+        fl: 0.3884814537800353,
+        f_lroot: 0.7894826179304937,
+        z: 1.909169568483652,
+    };
+
+    /// Viewing conditions used by conversions that don't take them
+    /// explicitly, such as [`Cam16::from`] and [`Hct::new`].
+    ///
+    /// This is [`ViewingConditions::STANDARD`] unless an override was
+    /// installed with [`ViewingConditions::set_global`].
+    ///
+    /// [`Cam16::from`]: super::Cam16
+    /// [`Hct::new`]: super::Hct::new
+    #[cfg(not(feature = "global-viewing-conditions"))]
+    pub const fn current() -> Self {
+        Self::STANDARD
+    }
+
+    /// Viewing conditions used by conversions that don't take them
+    /// explicitly, such as [`Cam16::from`] and [`Hct::new`].
+    ///
+    /// This is [`ViewingConditions::STANDARD`] unless an override was
+    /// installed with [`ViewingConditions::set_global`].
+    ///
+    /// [`Cam16::from`]: super::Cam16
+    /// [`Hct::new`]: super::Hct::new
+    #[cfg(feature = "global-viewing-conditions")]
+    pub fn current() -> Self {
+        if GLOBAL_IS_SET.load(Ordering::Acquire) {
+            // SAFETY: The cell is no longer written once `GLOBAL_IS_SET` is set.
+            unsafe { *GLOBAL.0.get() }
+        } else {
+            Self::STANDARD
+        }
+    }
+
+    /// Installs process-wide default viewing conditions, returned by
+    /// [`ViewingConditions::current`] from then on.
+    ///
+    /// The override can be installed only once, ideally at startup before
+    /// any color is converted; colors converted earlier keep the values
+    /// computed under the standard conditions. It is safe to call from any
+    /// thread, and concurrent readers always observe either the standard
+    /// conditions or the override, never a partially written value. Reading
+    /// the override takes no lock, only an atomic load.
+    ///
+    /// The HCT solver behind [`Hct::from`] is calibrated for the standard
+    /// conditions, so with an override its results have the requested tone
+    /// but only approximately the requested hue and chroma.
+    ///
+    /// [`Hct::from`]: super::Hct::from
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::ViewingConditionsAlreadySet`] if an override was
+    /// already installed.
+    #[cfg(feature = "global-viewing-conditions")]
+    pub fn set_global(conditions: Self) -> Result<(), Error> {
+        if GLOBAL_IS_CLAIMED.swap(true, Ordering::AcqRel) {
+            return Err(Error::ViewingConditionsAlreadySet);
+        }
+
+        // SAFETY: Only the call that claimed the cell above gets here, and
+        // readers don't access the cell until `GLOBAL_IS_SET` is set below.
+        unsafe { *GLOBAL.0.get() = conditions };
+
+        GLOBAL_IS_SET.store(true, Ordering::Release);
+
+        Ok(())
+    }
+
+    pub const fn standard() -> Self {
+        Self::STANDARD
     }
 
-    pub fn s_rgb() -> Self {
-        Self::make(None, None, None, None, None)
+    pub const fn s_rgb() -> Self {
+        Self::STANDARD
     }
 
     /// Convenience constructor for [`ViewingConditions`].
@@ -200,4 +317,31 @@ mod tests {
 
         assert!(!result1.discounting_illuminant);
     }
+
+    #[test]
+    fn test_standard_matches_make() {
+        let made = ViewingConditions::make(None, None, None, None, None);
+        let standard = ViewingConditions::STANDARD;
+
+        assert_approx_eq!(&[f64], &standard.white_point, &made.white_point);
+        assert_approx_eq!(f64, standard.adapting_luminance, made.adapting_luminance);
+        assert_approx_eq!(f64, standard.background_lstar, made.background_lstar);
+        assert_approx_eq!(f64, standard.surround, made.surround);
+        assert_eq!(standard.discounting_illuminant, made.discounting_illuminant);
+        assert_approx_eq!(
+            f64,
+            standard.background_ytowhite_point_y,
+            made.background_ytowhite_point_y
+        );
+        assert_approx_eq!(f64, standard.aw, made.aw);
+        assert_approx_eq!(f64, standard.nbb, made.nbb);
+        assert_approx_eq!(f64, standard.ncb, made.ncb);
+        assert_approx_eq!(f64, standard.c, made.c);
+        assert_approx_eq!(f64, standard.n_c, made.n_c);
+        assert_approx_eq!(&[f64], &standard.drgb_inverse, &made.drgb_inverse);
+        assert_approx_eq!(&[f64], &standard.rgb_d, &made.rgb_d);
+        assert_approx_eq!(f64, standard.fl, made.fl);
+        assert_approx_eq!(f64, standard.f_lroot, made.f_lroot);
+        assert_approx_eq!(f64, standard.z, made.z);
+    }
 }
@@ -1,9 +1,21 @@
-use crate::{color::Argb, quantize::Quantizer, quantize::QuantizerCelebi, score::Score};
+use crate::{
+    color::{delinearized, linearized, Argb},
+    dynamic_color::Variant,
+    hct::{Cam16, Hct},
+    palette::TonalPalette,
+    quantize::Quantizer,
+    quantize::{QuantizerCelebi, QuantizerMap, QuantizerResult, QuantizerStream},
+    score::{Score, ScoreOptions, SeedConfidence},
+    trace::{SeedTrace, Stage, Stopwatch},
+    utils::math::{difference_degrees, lerp},
+    Map,
+};
 pub use images::imageops::FilterType;
-use images::{imageops::resize, ImageReader as Reader, RgbaImage};
+use images::{imageops::resize, ImageReader as Reader, Rgba, Rgba32FImage, RgbaImage};
 use std::{
     io::{Cursor, Result},
     path::Path,
+    vec,
     vec::Vec,
 };
 
@@ -11,6 +23,19 @@ pub struct Image {
     image: RgbaImage,
 }
 
+/// Color space images are filtered in when resized.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ResizeColorSpace {
+    /// Filters the gamma-encoded sRGB values as-is, which is fast, but
+    /// darkens the average of contrasting colors, e.g. a fine pattern of red
+    /// and green blends into a dark olive.
+    #[default]
+    Gamma,
+    /// Converts pixels to linear light before filtering and back after, so
+    /// that averages keep the brightness the eye perceives.
+    Linear,
+}
+
 impl Image {
     pub const fn new(image: RgbaImage) -> Self {
         Self { image }
@@ -21,28 +46,545 @@ impl Image {
 
         self
     }
+
+    /// Like [`Image::resize`], but filters the pixels in `space`.
+    pub fn resize_in(
+        &mut self,
+        width: u32,
+        height: u32,
+        filter_type: FilterType,
+        space: ResizeColorSpace,
+    ) -> &mut Self {
+        match space {
+            ResizeColorSpace::Gamma => self.resize(width, height, filter_type),
+            ResizeColorSpace::Linear => {
+                let linear =
+                    Rgba32FImage::from_fn(self.image.width(), self.image.height(), |x, y| {
+                        let [red, green, blue, alpha] = self.image.get_pixel(x, y).0;
+
+                        Rgba([
+                            (linearized(red) / 100.0) as f32,
+                            (linearized(green) / 100.0) as f32,
+                            (linearized(blue) / 100.0) as f32,
+                            f32::from(alpha) / 255.0,
+                        ])
+                    });
+                let resized = resize(&linear, width, height, filter_type);
+
+                self.image = RgbaImage::from_fn(width, height, |x, y| {
+                    let [red, green, blue, alpha] = resized.get_pixel(x, y).0;
+
+                    Rgba([
+                        delinearized(f64::from(red) * 100.0),
+                        delinearized(f64::from(green) * 100.0),
+                        delinearized(f64::from(blue) * 100.0),
+                        (alpha.clamp(0.0, 1.0) * 255.0).round() as u8,
+                    ])
+                });
+
+                self
+            }
+        }
+    }
+
+    /// Borrows the pixel data of this image as an [`ImageView`].
+    pub fn view(&self) -> ImageView<'_> {
+        ImageView::from(&self.image)
+    }
+}
+
+/// A borrowed view over RGBA8 pixel data.
+///
+/// Lets frame buffers owned by other libraries be passed to the extraction
+/// entry points of [`ImageReader`] without copying them into an [`Image`].
+#[derive(Debug, Clone, Copy)]
+pub struct ImageView<'a> {
+    data: &'a [u8],
+    width: u32,
+    height: u32,
+}
+
+impl<'a> ImageView<'a> {
+    /// Creates a view over tightly packed RGBA8 pixels.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `data` is not exactly `width * height * 4` bytes long.
+    pub fn new(data: &'a [u8], width: u32, height: u32) -> Self {
+        assert_eq!(
+            data.len(),
+            width as usize * height as usize * 4,
+            "RGBA8 buffer does not match image dimensions"
+        );
+
+        Self {
+            data,
+            width,
+            height,
+        }
+    }
+
+    pub const fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub const fn height(&self) -> u32 {
+        self.height
+    }
+
+    pub const fn as_bytes(&self) -> &'a [u8] {
+        self.data
+    }
+
+    /// Returns an iterator over the pixels, for [`Quantizer::quantize_iter`]
+    /// and other consumers that don't need them collected.
+    pub fn pixels(&self) -> impl ExactSizeIterator<Item = Argb> + 'a {
+        self.data
+            .chunks_exact(4)
+            .map(|pixel| Argb::new(pixel[3], pixel[0], pixel[1], pixel[2]))
+    }
+}
+
+impl<'a> From<&'a RgbaImage> for ImageView<'a> {
+    fn from(image: &'a RgbaImage) -> Self {
+        Self::new(image.as_raw(), image.width(), image.height())
+    }
 }
 
 pub trait AsPixels {
     fn as_pixels(&self) -> Vec<Argb>;
+
+    /// Width and height of the image the pixels are laid out in, row by row.
+    ///
+    /// `None` by default, in which case [`ExtractionStrategy::excluded_areas`]
+    /// cannot be located and is ignored.
+    fn dimensions(&self) -> Option<(u32, u32)> {
+        None
+    }
+}
+
+impl AsPixels for ImageView<'_> {
+    fn as_pixels(&self) -> Vec<Argb> {
+        self.pixels().collect()
+    }
+
+    fn dimensions(&self) -> Option<(u32, u32)> {
+        Some((self.width, self.height))
+    }
 }
 
 impl AsPixels for Image {
     fn as_pixels(&self) -> Vec<Argb> {
-        self.image
-            .pixels()
-            .map(|pixel| {
-                let [a, r, g, b] = u32::from_be_bytes(pixel.0).rotate_right(8).to_be_bytes();
+        self.view().as_pixels()
+    }
 
-                Argb::new(a, r, g, b)
-            })
-            .collect()
+    fn dimensions(&self) -> Option<(u32, u32)> {
+        Some(self.image.dimensions())
+    }
+}
+
+/// Area of an image in pixels, see [`ExtractionStrategy::excluded_areas`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rect {
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl Rect {
+    pub const fn new(x: u32, y: u32, width: u32, height: u32) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    /// Whether the pixel at `x`, `y` lies within the area.
+    pub const fn contains(&self, x: u32, y: u32) -> bool {
+        x >= self.x && x - self.x < self.width && y >= self.y && y - self.y < self.height
+    }
+}
+
+/// Kind of image content, as reported by an [`ImageClassifier`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ImageKind {
+    /// Photographs, with smooth gradients and noise.
+    Photo,
+    /// Illustrations and artwork, with large flat areas of few colors.
+    Illustration,
+    /// Screenshots of user interfaces, mostly neutral with small accents.
+    Screenshot,
+}
+
+/// Hook for plugging a content classifier into the extraction pipeline.
+///
+/// Implemented for closures taking the pixels of the image, so that any
+/// classifier, such as a machine learning model, can be wrapped in one.
+pub trait ImageClassifier {
+    fn classify(&self, pixels: &[Argb]) -> ImageKind;
+}
+
+impl<F> ImageClassifier for F
+where
+    F: Fn(&[Argb]) -> ImageKind,
+{
+    fn classify(&self, pixels: &[Argb]) -> ImageKind {
+        self(pixels)
+    }
+}
+
+/// Quantizer used by an [`ExtractionStrategy`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum QuantizerChoice {
+    /// [`QuantizerCelebi`], best for photos.
+    Celebi,
+    /// [`QuantizerMap`], which counts exact colors, best for flat content
+    /// where small, distinct accents must not be merged away.
+    Map,
+}
+
+/// How colors are extracted from an image and turned into a theme.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExtractionStrategy {
+    pub quantizer: QuantizerChoice,
+    /// Max count of clusters produced by the quantizer, ignored by
+    /// [`QuantizerChoice::Map`].
+    pub max_colors: usize,
+    /// Whether scoring filters out colors with too little chroma or
+    /// population, see [`Score::score`].
+    pub filter: bool,
+    /// Weights and cutoffs used to rank the quantized colors.
+    pub score: ScoreOptions,
+    /// Color returned when no quantized color is suitable, defaults to the
+    /// fallback of [`Score::score`].
+    pub fallback: Option<Argb>,
+    /// Variant recommended for schemes generated from the extracted color.
+    pub variant: Variant,
+    /// Colors masked out before quantization, e.g. of UI chrome or
+    /// watermarks, along with pixels within
+    /// [`ExtractionStrategy::exclusion_distance`] of them.
+    pub excluded_colors: Vec<Argb>,
+    /// Max distance in CAM16-UCS (ΔE) between a pixel and an excluded color
+    /// for the pixel to be masked out.
+    pub exclusion_distance: f64,
+    /// Areas masked out before quantization, e.g. status bars. Ignored for
+    /// images without [`AsPixels::dimensions`].
+    pub excluded_areas: Vec<Rect>,
+    /// Whether to stretch the histogram of every channel of the pixels left
+    /// after masking over the full range before quantization, see
+    /// [`ExtractionStrategy::equalize`].
+    pub equalize: bool,
+}
+
+impl ExtractionStrategy {
+    /// Default [`ExtractionStrategy::exclusion_distance`], about the distance
+    /// between colors that are barely told apart side by side.
+    pub const EXCLUSION_DISTANCE: f64 = 2.0;
+
+    /// Masks out `colors`, and pixels within `distance` of them, before
+    /// quantization.
+    #[must_use]
+    pub fn exclude_colors(mut self, colors: &[Argb], distance: f64) -> Self {
+        self.excluded_colors.extend_from_slice(colors);
+        self.exclusion_distance = distance;
+
+        self
+    }
+
+    /// Masks out `area` before quantization.
+    #[must_use]
+    pub fn exclude_area(mut self, area: Rect) -> Self {
+        self.excluded_areas.push(area);
+
+        self
+    }
+
+    /// Share of the darkest and of the lightest values of each channel
+    /// clipped when equalizing, so that a few outliers do not prevent the
+    /// stretch.
+    pub const EQUALIZATION_CLIP: f64 = 0.01;
+
+    /// Sets whether to stretch the histogram of every channel over the full
+    /// range before quantization.
+    ///
+    /// Low-contrast images, such as foggy or washed-out photos, only use a
+    /// narrow range of each channel, which quantizes into muted colors and
+    /// produces timid palettes. Stretching the range, ignoring
+    /// [`ExtractionStrategy::EQUALIZATION_CLIP`] of the values at each end,
+    /// restores their contrast and chroma. Images already using the full
+    /// range are barely changed.
+    #[must_use]
+    pub const fn equalize(mut self, equalize: bool) -> Self {
+        self.equalize = equalize;
+
+        self
     }
+
+    /// Returns the pixels of `image` left after masking out the excluded
+    /// colors and areas.
+    fn pixels<I>(&self, image: &I) -> Vec<Argb>
+    where
+        I: AsPixels,
+    {
+        let mut pixels = image.as_pixels();
+
+        if let (false, Some((width, _))) = (self.excluded_areas.is_empty(), image.dimensions()) {
+            let mut index = 0;
+
+            pixels.retain(|_| {
+                let (x, y) = (index % width, index / width);
+
+                index += 1;
+
+                !self.excluded_areas.iter().any(|area| area.contains(x, y))
+            });
+        }
+
+        if !self.excluded_colors.is_empty() {
+            let excluded = self
+                .excluded_colors
+                .iter()
+                .map(|color| Cam16::from(*color))
+                .collect::<Vec<_>>();
+            let mut is_excluded = Map::default();
+
+            pixels.retain(|pixel| {
+                !*is_excluded.entry(*pixel).or_insert_with(|| {
+                    let cam = Cam16::from(*pixel);
+
+                    excluded
+                        .iter()
+                        .any(|color| cam.distance(color) <= self.exclusion_distance)
+                })
+            });
+        }
+
+        if self.equalize {
+            equalize(&mut pixels);
+        }
+
+        pixels
+    }
+
+    /// Returns the built-in preset for `kind`.
+    ///
+    /// - Photos use the default pipeline of [`ImageReader::extract_color`]
+    ///   and [`Variant::TonalSpot`].
+    /// - Illustrations use fewer clusters, since their colors are flat and
+    ///   deliberate, and [`Variant::Fidelity`] to stay close to them.
+    /// - Screenshots use [`QuantizerMap`], so that small accents on mostly
+    ///   neutral UIs are not merged into the surrounding grays, and
+    ///   [`Variant::TonalSpot`].
+    pub const fn for_kind(kind: ImageKind) -> Self {
+        match kind {
+            ImageKind::Photo => Self {
+                quantizer: QuantizerChoice::Celebi,
+                max_colors: 128,
+                filter: true,
+                score: ScoreOptions::DEFAULT,
+                fallback: None,
+                variant: Variant::TonalSpot,
+                excluded_colors: Vec::new(),
+                exclusion_distance: Self::EXCLUSION_DISTANCE,
+                excluded_areas: Vec::new(),
+                equalize: false,
+            },
+            ImageKind::Illustration => Self {
+                quantizer: QuantizerChoice::Celebi,
+                max_colors: 32,
+                filter: true,
+                score: ScoreOptions::DEFAULT,
+                fallback: None,
+                variant: Variant::Fidelity,
+                excluded_colors: Vec::new(),
+                exclusion_distance: Self::EXCLUSION_DISTANCE,
+                excluded_areas: Vec::new(),
+                equalize: false,
+            },
+            ImageKind::Screenshot => Self {
+                quantizer: QuantizerChoice::Map,
+                max_colors: 0,
+                filter: true,
+                score: ScoreOptions::DEFAULT,
+                fallback: None,
+                variant: Variant::TonalSpot,
+                excluded_colors: Vec::new(),
+                exclusion_distance: Self::EXCLUSION_DISTANCE,
+                excluded_areas: Vec::new(),
+                equalize: false,
+            },
+        }
+    }
+}
+
+impl Default for ExtractionStrategy {
+    fn default() -> Self {
+        Self::for_kind(ImageKind::Photo)
+    }
+}
+
+/// Stretches the histogram of every channel of `pixels` over the full
+/// range, see [`ExtractionStrategy::equalize`].
+fn equalize(pixels: &mut [Argb]) {
+    if pixels.is_empty() {
+        return;
+    }
+
+    let clip = (pixels.len() as f64 * ExtractionStrategy::EQUALIZATION_CLIP) as usize;
+    let mut histograms = [[0_usize; 256]; 3];
+
+    for pixel in pixels.iter() {
+        histograms[0][pixel.red as usize] += 1;
+        histograms[1][pixel.green as usize] += 1;
+        histograms[2][pixel.blue as usize] += 1;
+    }
+
+    let tables = histograms.map(|histogram| {
+        let low = clipped_bound(&histogram, clip, 0..256);
+        let high = clipped_bound(&histogram, clip, (0..256).rev());
+        let mut table = [0_u8; 256];
+
+        for (value, entry) in table.iter_mut().enumerate() {
+            *entry = if high > low {
+                let stretched = (value as f64 - low as f64) / (high - low) as f64 * 255.0;
+
+                stretched.round().clamp(0.0, 255.0) as u8
+            } else {
+                value as u8
+            };
+        }
+
+        table
+    });
+
+    for pixel in pixels.iter_mut() {
+        pixel.red = tables[0][pixel.red as usize];
+        pixel.green = tables[1][pixel.green as usize];
+        pixel.blue = tables[2][pixel.blue as usize];
+    }
+}
+
+/// Returns the first of `values` after which more than `clip` pixels of
+/// `histogram` were counted.
+fn clipped_bound(
+    histogram: &[usize; 256],
+    clip: usize,
+    values: impl Iterator<Item = usize>,
+) -> usize {
+    let mut count = 0;
+
+    for value in values {
+        count += histogram[value];
+
+        if count > clip {
+            return value;
+        }
+    }
+
+    0
+}
+
+/// Presets reproducing the wallpaper color extraction of Android releases,
+/// so that apps can derive the same source color as the system UI.
+///
+/// Android downscales wallpapers to at most [`ExtractionPreset::MAX_AREA`]
+/// pixels, quantizes them with [`QuantizerCelebi`] into 128 colors, and ranks
+/// them with the scoring of SystemUI's `ColorScheme`, which weighs hue
+/// proportion fully and spreads each hue over ±15 degrees. Use
+/// [`ImageReader::extract_color_with_preset`] to run the whole pipeline.
+///
+/// Android 12 and 13 generated their schemes from an older tonal spec that
+/// is not available here, so only their source color matches; Android 14
+/// moved to the [`Variant::TonalSpot`] scheme of this crate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ExtractionPreset {
+    Android12,
+    Android13,
+    Android14,
+}
+
+impl ExtractionPreset {
+    /// Max count of pixels Android extracts colors from.
+    pub const MAX_AREA: u32 = 112 * 112;
+
+    /// Fallback color of SystemUI, used when no color is suitable.
+    pub const FALLBACK: Argb = Argb::from_u32(0xff1b6ef3);
+
+    /// Scoring of SystemUI's `ColorScheme`.
+    pub const SCORE: ScoreOptions = ScoreOptions {
+        target_chroma: 48.0,
+        weight_proportion: 1.0,
+        weight_chroma_above: 0.3,
+        weight_chroma_below: 0.1,
+        cutoff_chroma: 5.0,
+        cutoff_excited_proportion: 0.01,
+        excitation_start: -15,
+        excitation_end: 16,
+        temperature_bias: 0.0,
+    };
+
+    /// Returns the extraction strategy of this release.
+    pub const fn strategy(self) -> ExtractionStrategy {
+        match self {
+            Self::Android12 | Self::Android13 | Self::Android14 => ExtractionStrategy {
+                quantizer: QuantizerChoice::Celebi,
+                max_colors: 128,
+                filter: true,
+                score: Self::SCORE,
+                fallback: Some(Self::FALLBACK),
+                variant: Variant::TonalSpot,
+                excluded_colors: Vec::new(),
+                exclusion_distance: ExtractionStrategy::EXCLUSION_DISTANCE,
+                excluded_areas: Vec::new(),
+                equalize: false,
+            },
+        }
+    }
+
+    /// Size Android scales an image of `width` by `height` to before
+    /// extracting colors, keeping its aspect ratio.
+    pub fn extraction_size(width: u32, height: u32) -> (u32, u32) {
+        let area = f64::from(width) * f64::from(height);
+
+        if area <= f64::from(Self::MAX_AREA) {
+            return (width, height);
+        }
+
+        let scale = (f64::from(Self::MAX_AREA) / area).sqrt();
+
+        (
+            ((f64::from(width) * scale) as u32).max(1),
+            ((f64::from(height) * scale) as u32).max(1),
+        )
+    }
+}
+
+impl From<ExtractionPreset> for ExtractionStrategy {
+    fn from(preset: ExtractionPreset) -> Self {
+        preset.strategy()
+    }
+}
+
+/// Result of [`ImageReader::extract_classified`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Extraction {
+    pub kind: ImageKind,
+    /// Source color most suitable for creating a UI theme.
+    pub source: Argb,
+    /// Variant recommended for the kind of image.
+    pub variant: Variant,
 }
 
 pub struct ImageReader;
 
 impl ImageReader {
+    /// Median tone below which [`ImageReader::recommends_dark`] recommends a
+    /// dark theme.
+    pub const DARK_MEDIAN_TONE_THRESHOLD: f64 = 50.0;
+
     pub fn read<T>(data: T) -> Result<Image>
     where
         T: AsRef<[u8]>,
@@ -84,4 +626,646 @@ impl ImageReader {
 
         ranked[0]
     }
+
+    /// Like [`ImageReader::extract_color`], but takes the pixels row by row,
+    /// e.g. scanlines of a large wallpaper decoded in chunks, quantizing them
+    /// with a [`QuantizerStream`] without holding the whole image in memory.
+    pub fn extract_color_from_rows<'a, R>(rows: R) -> Argb
+    where
+        R: IntoIterator<Item = &'a [Argb]>,
+    {
+        let mut stream = QuantizerStream::new(128);
+
+        for row in rows {
+            stream.push_pixels(row);
+        }
+
+        let ranked = Score::score(&stream.finish().color_to_count, None, None, None);
+
+        ranked[0]
+    }
+
+    /// Get the source color from an image using `strategy`.
+    ///
+    /// `image` A struct that implements the [`AsPixels`] trait
+    pub fn extract_color_with_strategy<I>(image: &I, strategy: &ExtractionStrategy) -> Argb
+    where
+        I: AsPixels,
+    {
+        Self::extract_pixels_with_strategy(&strategy.pixels(image), strategy)
+    }
+
+    /// Like [`ImageReader::extract_color_with_strategy`], but also returns how
+    /// confident the choice of the source color is, see
+    /// [`Score::score_with_confidence`].
+    ///
+    /// `image` A struct that implements the [`AsPixels`] trait
+    pub fn extract_color_with_confidence<I>(
+        image: &I,
+        strategy: &ExtractionStrategy,
+    ) -> (Argb, SeedConfidence)
+    where
+        I: AsPixels,
+    {
+        let pixels = strategy.pixels(image);
+
+        Score::score_with_confidence(
+            &Self::quantize_with_strategy(&pixels, strategy).color_to_count,
+            strategy.fallback,
+            Some(strategy.filter),
+            &strategy.score,
+        )
+    }
+
+    /// Like [`ImageReader::extract_color_with_confidence`], but returns a
+    /// trace of how the source color was chosen, see [`SeedTrace`].
+    ///
+    /// `image` A struct that implements the [`AsPixels`] trait
+    pub fn extract_color_traced<I>(image: &I, strategy: &ExtractionStrategy) -> (Argb, SeedTrace)
+    where
+        I: AsPixels,
+    {
+        let mut stopwatch = Stopwatch::new(true);
+        let pixels = strategy.pixels(image);
+        let result = Self::quantize_with_strategy(&pixels, strategy);
+
+        stopwatch.finish(Stage::Quantize);
+
+        let candidates = Score::score_with_options(
+            &result.color_to_count,
+            None,
+            strategy.fallback,
+            Some(strategy.filter),
+            &strategy.score,
+        );
+        let (_, confidence) = Score::score_with_confidence(
+            &result.color_to_count,
+            strategy.fallback,
+            Some(strategy.filter),
+            &strategy.score,
+        );
+
+        stopwatch.finish(Stage::Score);
+
+        let trace = SeedTrace {
+            pixels: pixels.len(),
+            clusters: result.color_to_count.len(),
+            candidates,
+            confidence,
+            stages: stopwatch.into_stages(),
+        };
+
+        (trace.candidates[0], trace)
+    }
+
+    /// Get the source color from an image the way the Android release of
+    /// `preset` does, including its downscaling.
+    pub fn extract_color_with_preset(image: &Image, preset: ExtractionPreset) -> Argb {
+        Self::extract_color_with_preset_in(image, preset, ResizeColorSpace::Gamma)
+    }
+
+    /// Like [`ImageReader::extract_color_with_preset`], but downscales the
+    /// image in `space`. [`ResizeColorSpace::Linear`] keeps fine, contrasting
+    /// details from biasing the source color towards darker tones, at the
+    /// cost of speed; Android itself resizes in [`ResizeColorSpace::Gamma`].
+    pub fn extract_color_with_preset_in(
+        image: &Image,
+        preset: ExtractionPreset,
+        space: ResizeColorSpace,
+    ) -> Argb {
+        let (width, height) =
+            ExtractionPreset::extraction_size(image.image.width(), image.image.height());
+        let pixels = if (width, height) == image.image.dimensions() {
+            image.as_pixels()
+        } else {
+            Image::new(image.image.clone())
+                .resize_in(width, height, FilterType::Triangle, space)
+                .as_pixels()
+        };
+
+        Self::extract_pixels_with_strategy(&pixels, &preset.strategy())
+    }
+
+    /// Classifies an image with `classifier`, then extracts its source color
+    /// with the [`ExtractionStrategy::for_kind`] preset of the resulting kind.
+    ///
+    /// `image` A struct that implements the [`AsPixels`] trait
+    pub fn extract_classified<I, C>(image: &I, classifier: &C) -> Extraction
+    where
+        I: AsPixels,
+        C: ImageClassifier,
+    {
+        let pixels = image.as_pixels();
+        let kind = classifier.classify(&pixels);
+        let strategy = ExtractionStrategy::for_kind(kind);
+
+        Extraction {
+            kind,
+            source: Self::extract_pixels_with_strategy(&pixels, &strategy),
+            variant: strategy.variant,
+        }
+    }
+
+    fn quantize_with_strategy(pixels: &[Argb], strategy: &ExtractionStrategy) -> QuantizerResult {
+        match strategy.quantizer {
+            QuantizerChoice::Celebi => QuantizerCelebi::quantize(pixels, strategy.max_colors),
+            QuantizerChoice::Map => QuantizerMap::quantize(pixels, strategy.max_colors),
+        }
+    }
+
+    fn extract_pixels_with_strategy(pixels: &[Argb], strategy: &ExtractionStrategy) -> Argb {
+        let result = Self::quantize_with_strategy(pixels, strategy);
+        let ranked = Score::score_with_options(
+            &result.color_to_count,
+            None,
+            strategy.fallback,
+            Some(strategy.filter),
+            &strategy.score,
+        );
+
+        ranked[0]
+    }
+
+    /// Like [`ImageReader::extract_color`], but merges quantized clusters
+    /// within `max_distance` of each other in CAM16-UCS before scoring.
+    ///
+    /// See [`Score::merge_similar`].
+    pub fn extract_color_merged<I>(image: &I, max_distance: f64) -> Argb
+    where
+        I: AsPixels,
+    {
+        let pixels = image.as_pixels();
+        let result = QuantizerCelebi::quantize(&pixels, 128);
+        let ranked = Score::score_merged(&result.color_to_count, max_distance, None, None, None);
+
+        ranked[0]
+    }
+
+    /// Get a tonal palette for each of the most prominent hue clusters of an
+    /// image.
+    ///
+    /// `image` A struct that implements the [`AsPixels`] trait
+    /// `count` Max count of palettes to be returned
+    ///
+    /// Returns palettes built from the best-scoring colors, ordered by
+    /// suitability, each paired with the count of pixels whose hue is closest
+    /// to it. Always returns at least one palette; if no color is suitable,
+    /// a palette of the fallback color is returned with a population of 0.
+    pub fn palettes_from_image<I>(image: &I, count: usize) -> Vec<(TonalPalette, u32)>
+    where
+        I: AsPixels,
+    {
+        let pixels = image.as_pixels();
+        let result = QuantizerCelebi::quantize(&pixels, 128);
+        let desired = i32::try_from(count.max(1)).unwrap_or(i32::MAX);
+        let seeds = Score::score(&result.color_to_count, Some(desired), None, None)
+            .into_iter()
+            .map(Hct::new)
+            .collect::<Vec<_>>();
+        let mut populations = vec![0; seeds.len()];
+
+        for (color, population) in &result.color_to_count {
+            let hue = Hct::new(*color).get_hue();
+            let closest = seeds
+                .iter()
+                .map(|seed| difference_degrees(seed.get_hue(), hue))
+                .enumerate()
+                .min_by(|(_, a), (_, b)| a.total_cmp(b))
+                .map(|(index, _)| index);
+
+            if let Some(index) = closest {
+                populations[index] += population;
+            }
+        }
+
+        seeds
+            .into_iter()
+            .map(TonalPalette::from_hct)
+            .zip(populations)
+            .collect()
+    }
+
+    /// Get the tones (L*) found at the given percentiles of an image.
+    ///
+    /// `image` A struct that implements the [`AsPixels`] trait, ideally
+    /// downscaled beforehand
+    /// `percentiles` Percentiles between 0.0 and 100.0; values outside of that
+    /// range are clamped
+    ///
+    /// Returns one tone per requested percentile, linearly interpolated between
+    /// the closest pixels. Returns an empty list if the image has no pixels.
+    pub fn tone_percentiles<I>(image: &I, percentiles: &[f64]) -> Vec<f64>
+    where
+        I: AsPixels,
+    {
+        let mut tones = image
+            .as_pixels()
+            .iter()
+            .map(Argb::as_lstar)
+            .collect::<Vec<_>>();
+
+        if tones.is_empty() {
+            return Vec::new();
+        }
+
+        tones.sort_by(f64::total_cmp);
+
+        let last = (tones.len() - 1) as f64;
+
+        percentiles
+            .iter()
+            .map(|percentile| {
+                let position = percentile.clamp(0.0, 100.0) / 100.0 * last;
+                let lower = tones[position.floor() as usize];
+                let upper = tones[position.ceil() as usize];
+
+                lerp(lower, upper, position.fract())
+            })
+            .collect()
+    }
+
+    /// Whether an image is better suited for a dark theme.
+    ///
+    /// `image` A struct that implements the [`AsPixels`] trait
+    ///
+    /// Returns `true` if the median tone of the image is below
+    /// [`ImageReader::DARK_MEDIAN_TONE_THRESHOLD`].
+    pub fn recommends_dark<I>(image: &I) -> bool
+    where
+        I: AsPixels,
+    {
+        Self::tone_percentiles(image, &[50.0])
+            .first()
+            .map_or(false, |median| *median < Self::DARK_MEDIAN_TONE_THRESHOLD)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        equalize, AsPixels, ExtractionPreset, ExtractionStrategy, FilterType, Image, ImageKind,
+        ImageReader, ImageView, QuantizerChoice, Rect, ResizeColorSpace,
+    };
+    use crate::color::Argb;
+    use crate::dynamic_color::Variant;
+    use crate::hct::Hct;
+    use crate::trace::Stage;
+    use float_cmp::assert_approx_eq;
+    use images::{Rgba, RgbaImage};
+    use std::{vec, vec::Vec};
+
+    fn gradient() -> Image {
+        Image::new(RgbaImage::from_fn(256, 1, |x, _| {
+            let value = x as u8;
+
+            Rgba([value, value, value, 255])
+        }))
+    }
+
+    #[test]
+    fn test_tone_percentiles() {
+        let tones = ImageReader::tone_percentiles(&gradient(), &[0.0, 50.0, 100.0, 150.0]);
+
+        assert_eq!(tones.len(), 4);
+        assert_approx_eq!(f64, tones[0], 0.0, epsilon = 0.01);
+        assert_approx_eq!(f64, tones[2], 100.0, epsilon = 0.01);
+        assert_approx_eq!(f64, tones[3], 100.0, epsilon = 0.01);
+        assert!(tones[0] < tones[1] && tones[1] < tones[2]);
+    }
+
+    #[test]
+    fn test_extraction_preset() {
+        assert_eq!(ExtractionPreset::extraction_size(100, 100), (100, 100));
+        assert_eq!(ExtractionPreset::extraction_size(1920, 1080), (149, 84));
+        assert_eq!(ExtractionPreset::extraction_size(100_000, 1), (35417, 1));
+
+        let image = Image::new(RgbaImage::from_fn(400, 300, |x, _| {
+            if x < 300 {
+                Rgba([0, 90, 200, 255])
+            } else {
+                Rgba([200, 40, 40, 255])
+            }
+        }));
+        let source = ImageReader::extract_color_with_preset(&image, ExtractionPreset::Android14);
+        let hue = crate::hct::Hct::new(source).get_hue();
+
+        assert!((250.0..290.0).contains(&hue), "hue = {hue}");
+        assert_eq!(
+            ImageReader::extract_color_with_preset(&gradient(), ExtractionPreset::Android12),
+            ExtractionPreset::FALLBACK
+        );
+    }
+
+    #[test]
+    fn test_resize_in_linear_light() {
+        let checkerboard = || {
+            Image::new(RgbaImage::from_fn(64, 64, |x, y| {
+                if (x + y) % 2 == 0 {
+                    Rgba([255, 0, 0, 255])
+                } else {
+                    Rgba([0, 255, 0, 255])
+                }
+            }))
+        };
+        let mut gamma = checkerboard();
+        let mut linear = checkerboard();
+
+        gamma.resize_in(8, 8, FilterType::Triangle, ResizeColorSpace::Gamma);
+        linear.resize_in(8, 8, FilterType::Triangle, ResizeColorSpace::Linear);
+
+        assert_eq!(gamma.as_pixels()[9], Argb::new(255, 128, 128, 0));
+        assert_eq!(linear.as_pixels()[9], Argb::new(255, 188, 188, 0));
+
+        let image = Image::new(RgbaImage::from_fn(400, 300, |x, y| {
+            if (x + y) % 2 == 0 {
+                Rgba([255, 0, 0, 255])
+            } else {
+                Rgba([0, 255, 0, 255])
+            }
+        }));
+        let tone = |space| {
+            crate::hct::Hct::new(ImageReader::extract_color_with_preset_in(
+                &image,
+                ExtractionPreset::Android14,
+                space,
+            ))
+            .get_tone()
+        };
+
+        assert!(tone(ResizeColorSpace::Linear) > tone(ResizeColorSpace::Gamma) + 10.0);
+        assert_eq!(
+            ImageReader::extract_color_with_preset_in(
+                &image,
+                ExtractionPreset::Android14,
+                ResizeColorSpace::Gamma
+            ),
+            ImageReader::extract_color_with_preset(&image, ExtractionPreset::Android14)
+        );
+    }
+
+    #[test]
+    fn test_extract_color_with_confidence() {
+        let image = Image::new(RgbaImage::from_fn(64, 64, |x, _| {
+            if x < 48 {
+                Rgba([0, 90, 200, 255])
+            } else {
+                Rgba([128, 128, 128, 255])
+            }
+        }));
+        let strategy = ExtractionStrategy::default();
+        let (source, confidence) = ImageReader::extract_color_with_confidence(&image, &strategy);
+
+        assert_eq!(
+            source,
+            ImageReader::extract_color_with_strategy(&image, &strategy)
+        );
+        assert!(confidence.value() > 0.9, "{confidence:?}");
+
+        let (_, confidence) = ImageReader::extract_color_with_confidence(&gradient(), &strategy);
+
+        assert_approx_eq!(f64, confidence.value(), 0.0);
+    }
+
+    #[test]
+    fn test_excluded_colors_and_areas() {
+        // A blue status bar over a mostly green screenshot.
+        let image = Image::new(RgbaImage::from_fn(64, 64, |_, y| {
+            if y < 40 {
+                Rgba([20, 60, 220, 255])
+            } else {
+                Rgba([40, 160, 60, 255])
+            }
+        }));
+        let strategy = ExtractionStrategy::for_kind(ImageKind::Screenshot);
+        let blue = ImageReader::extract_color_with_strategy(&image, &strategy);
+        let green = Argb::new(255, 40, 160, 60);
+
+        assert_eq!(blue, Argb::new(255, 20, 60, 220));
+        assert_eq!(
+            ImageReader::extract_color_with_strategy(
+                &image,
+                &strategy
+                    .clone()
+                    .exclude_colors(&[Argb::new(255, 21, 61, 219)], 2.0)
+            ),
+            green
+        );
+        assert_eq!(
+            ImageReader::extract_color_with_strategy(
+                &image,
+                &strategy.clone().exclude_area(Rect::new(0, 0, 64, 40))
+            ),
+            green
+        );
+
+        let (_, trace) = ImageReader::extract_color_traced(
+            &image,
+            &strategy.exclude_area(Rect::new(0, 0, 64, 40)),
+        );
+
+        assert_eq!(trace.pixels, 64 * 24);
+    }
+
+    #[test]
+    fn test_rect_contains() {
+        let rect = Rect::new(2, 3, 4, 5);
+
+        assert!(rect.contains(2, 3));
+        assert!(rect.contains(5, 7));
+        assert!(!rect.contains(6, 7));
+        assert!(!rect.contains(5, 8));
+        assert!(!rect.contains(1, 3));
+        assert!(!Rect::new(0, 0, 0, 0).contains(0, 0));
+    }
+
+    #[test]
+    fn test_extract_color_traced() {
+        let image = Image::new(RgbaImage::from_fn(64, 64, |x, _| {
+            if x < 48 {
+                Rgba([0, 90, 200, 255])
+            } else {
+                Rgba([128, 128, 128, 255])
+            }
+        }));
+        let strategy = ExtractionStrategy::default();
+        let (source, trace) = ImageReader::extract_color_traced(&image, &strategy);
+
+        assert_eq!(
+            source,
+            ImageReader::extract_color_with_strategy(&image, &strategy)
+        );
+        assert_eq!(trace.pixels, 64 * 64);
+        assert_eq!(trace.clusters, 2);
+        assert_eq!(trace.candidates[0], source);
+        assert_eq!(
+            trace.confidence,
+            ImageReader::extract_color_with_confidence(&image, &strategy).1
+        );
+        assert_eq!(
+            trace
+                .stages
+                .iter()
+                .map(|timing| timing.stage)
+                .collect::<Vec<_>>(),
+            [Stage::Quantize, Stage::Score]
+        );
+    }
+
+    #[test]
+    fn test_image_view() {
+        let data = [255, 0, 0, 255, 0, 0, 255, 128];
+        let view = ImageView::new(&data, 2, 1);
+
+        assert_eq!(
+            view.as_pixels(),
+            [Argb::new(255, 255, 0, 0), Argb::new(128, 0, 0, 255)]
+        );
+
+        let image = gradient();
+
+        assert_eq!(image.view().as_pixels(), image.as_pixels());
+        assert_eq!(
+            ImageReader::extract_color(&image.view()),
+            ImageReader::extract_color(&image)
+        );
+        assert_eq!(
+            ImageReader::extract_color_merged(&image, 0.0),
+            ImageReader::extract_color(&image)
+        );
+    }
+
+    #[test]
+    fn test_extract_color_from_rows() {
+        let image = Image::new(RgbaImage::from_fn(64, 16, |x, y| {
+            Rgba([(x * 4) as u8, 96, (y * 16) as u8, 255])
+        }));
+        let pixels = image.as_pixels();
+
+        assert_eq!(
+            ImageReader::extract_color_from_rows(pixels.chunks(64)),
+            ImageReader::extract_color(&image)
+        );
+    }
+
+    #[test]
+    #[should_panic = "RGBA8 buffer does not match image dimensions"]
+    fn test_image_view_size_mismatch() {
+        let _ = ImageView::new(&[0; 7], 2, 1);
+    }
+
+    #[test]
+    fn test_extract_classified() {
+        let image = gradient();
+        let photo = ImageReader::extract_classified(&image, &|_: &[_]| ImageKind::Photo);
+
+        assert_eq!(photo.kind, ImageKind::Photo);
+        assert_eq!(photo.source, ImageReader::extract_color(&image));
+        assert_eq!(photo.variant, Variant::TonalSpot);
+
+        let screenshot = Image::new(RgbaImage::from_fn(8, 8, |x, y| {
+            if x == 0 && y == 0 {
+                Rgba([0, 90, 200, 255])
+            } else {
+                Rgba([240, 240, 240, 255])
+            }
+        }));
+        let classifier = |pixels: &[Argb]| {
+            if pixels.iter().filter(|pixel| pixel.red == 240).count() * 2 > pixels.len() {
+                ImageKind::Screenshot
+            } else {
+                ImageKind::Photo
+            }
+        };
+        let extraction = ImageReader::extract_classified(&screenshot, &classifier);
+        let strategy = ExtractionStrategy::for_kind(ImageKind::Screenshot);
+
+        assert_eq!(extraction.kind, ImageKind::Screenshot);
+        assert_eq!(strategy.quantizer, QuantizerChoice::Map);
+        assert_eq!(extraction.source, Argb::from_u32(0xff005ac8));
+        assert_eq!(
+            extraction.source,
+            ImageReader::extract_color_with_strategy(&screenshot, &strategy)
+        );
+        assert_eq!(
+            ExtractionStrategy::default(),
+            ExtractionStrategy::for_kind(ImageKind::Photo)
+        );
+    }
+
+    #[test]
+    fn test_palettes_from_image() {
+        let image = Image::new(RgbaImage::from_fn(4, 4, |x, _| match x {
+            0..=2 => Rgba([220, 30, 30, 255]),
+            _ => Rgba([30, 60, 220, 255]),
+        }));
+        let palettes = ImageReader::palettes_from_image(&image, 2);
+
+        assert_eq!(palettes.len(), 2);
+        assert_eq!(palettes[0].1, 12);
+        assert_eq!(palettes[1].1, 4);
+        assert!(palettes[0].0.hue() < 60.0 || palettes[0].0.hue() > 330.0);
+        assert!((240.0..300.0).contains(&palettes[1].0.hue()));
+
+        let empty = ImageReader::palettes_from_image(&Image::new(RgbaImage::new(0, 0)), 3);
+
+        assert_eq!(empty.len(), 1);
+        assert_eq!(empty[0].1, 0);
+    }
+
+    #[test]
+    fn test_recommends_dark() {
+        let dark = Image::new(RgbaImage::from_pixel(4, 4, Rgba([20, 20, 30, 255])));
+        let light = Image::new(RgbaImage::from_pixel(4, 4, Rgba([230, 230, 220, 255])));
+
+        assert!(ImageReader::recommends_dark(&dark));
+        assert!(!ImageReader::recommends_dark(&light));
+        assert!(
+            ImageReader::tone_percentiles(&Image::new(RgbaImage::new(0, 0)), &[50.0]).is_empty()
+        );
+    }
+
+    #[test]
+    fn test_equalize_foggy_image() {
+        // Red, green and blue fields behind a dense light gray fog.
+        let image = Image::new(RgbaImage::from_fn(96, 32, |x, _| {
+            let color = match x / 32 {
+                0 => [200.0, 40.0, 40.0],
+                1 => [40.0, 160.0, 60.0],
+                _ => [40.0, 80.0, 200.0],
+            };
+
+            let [red, green, blue] = color.map(|channel: f64| (channel * 0.3 + 190.0 * 0.7) as u8);
+
+            Rgba([red, green, blue, 255])
+        }));
+        let strategy = ExtractionStrategy::default();
+        let chroma = |strategy: &ExtractionStrategy| {
+            Hct::new(ImageReader::extract_color_with_strategy(&image, strategy)).get_chroma()
+        };
+        let foggy = chroma(&strategy);
+        let equalized = chroma(&strategy.equalize(true));
+
+        assert!(equalized > foggy * 2.0, "{foggy} {equalized}");
+    }
+
+    #[test]
+    fn test_equalize_keeps_full_range() {
+        let mut pixels = [
+            vec![Argb::new(255, 0, 0, 0); 100],
+            vec![Argb::new(255, 255, 255, 255); 100],
+            vec![Argb::new(255, 90, 30, 200); 50],
+        ]
+        .concat();
+        let original = pixels.clone();
+
+        equalize(&mut pixels);
+
+        assert_eq!(pixels, original);
+
+        let mut flat = [Argb::new(255, 90, 90, 90); 4];
+
+        equalize(&mut flat);
+
+        assert_eq!(flat, [Argb::new(255, 90, 90, 90); 4]);
+    }
 }
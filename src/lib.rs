@@ -41,20 +41,30 @@ extern crate alloc;
 #[cfg(feature = "std")]
 extern crate std;
 
-#[cfg(feature = "std")]
+#[cfg(all(feature = "std", feature = "fast-maps"))]
 pub(crate) use ahash::HashMap as Map;
 #[cfg(not(feature = "std"))]
 pub(crate) use alloc::collections::BTreeMap as Map;
+#[cfg(all(feature = "std", not(feature = "fast-maps")))]
+pub(crate) use std::collections::HashMap as Map;
 
+#[cfg(feature = "fast-maps")]
 pub(crate) type IndexMap<K, V> =
     indexmap::IndexMap<K, V, core::hash::BuildHasherDefault<ahash::AHasher>>;
+#[cfg(not(feature = "fast-maps"))]
+pub(crate) type IndexMap<K, V> = utils::vec_map::VecMap<K, V>;
 
 pub mod blend;
+pub mod bundle;
 pub mod color;
 pub mod contrast;
+pub mod diff;
 pub mod dislike;
 pub mod dynamic_color;
 pub mod error;
+pub mod export;
+#[cfg(feature = "test-utils")]
+pub mod fixtures;
 pub mod hct;
 #[cfg(feature = "image")]
 pub mod image;
@@ -64,6 +74,8 @@ pub mod scheme;
 pub mod score;
 pub mod temperature;
 pub mod theme;
+pub mod trace;
 pub mod utils;
+pub mod watch;
 
 pub use error::Error;
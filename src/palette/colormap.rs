@@ -0,0 +1,190 @@
+use super::TonalPalette;
+#[cfg(all(not(feature = "std"), feature = "libm"))]
+#[allow(unused_imports)]
+use crate::utils::no_std::FloatExt;
+use crate::{
+    color::Argb,
+    dynamic_color::DynamicScheme,
+    hct::{Cam16, Hct},
+    utils::math::{difference_degrees, lerp, rotate_direction, sanitize_degrees_double},
+};
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::vec::Vec;
+
+/// Generates colormaps for data visualization from tonal palettes.
+///
+/// Stops are spaced evenly by distance in CAM16-UCS rather than by tone, so
+/// neighbouring stops are equally distinguishable along the whole ramp.
+/// Lightness always changes monotonically along each ramp, which keeps the
+/// colormaps readable for colorblind viewers and in grayscale.
+pub struct Colormap;
+
+impl Colormap {
+    /// Tone of the lightest stop.
+    pub const LIGHTEST_TONE: f64 = 95.0;
+    /// Tone of the darkest stop.
+    pub const DARKEST_TONE: f64 = 25.0;
+    /// Count of points sampled along a ramp to measure its length.
+    const SAMPLES: usize = 128;
+
+    /// Creates a sequential colormap going from light to dark.
+    ///
+    /// - `start`: Palette whose hue and chroma are used for the lightest stop
+    /// - `end`: Palette whose hue and chroma are used for the darkest stop;
+    ///   pass `start` again for a single-hue ramp
+    /// - `stops`: Count of colors to return
+    pub fn sequential(start: &TonalPalette, end: &TonalPalette, stops: usize) -> Vec<Argb> {
+        Self::resample(|t| Self::ramp(start, end, start.chroma(), t), stops)
+    }
+
+    /// Creates a diverging colormap going from dark `low` through a light
+    /// neutral midpoint to dark `high`.
+    ///
+    /// With an odd count of `stops`, the middle stop is the neutral midpoint.
+    pub fn diverging(low: &TonalPalette, high: &TonalPalette, stops: usize) -> Vec<Argb> {
+        Self::resample(
+            |t| {
+                if t < 0.5 {
+                    Self::ramp(low, low, 0.0, 2.0f64.mul_add(-t, 1.0))
+                } else {
+                    Self::ramp(high, high, 0.0, 2.0f64.mul_add(t, -1.0))
+                }
+            },
+            stops,
+        )
+    }
+
+    /// Creates a sequential colormap from the primary to the tertiary hue of
+    /// `scheme`.
+    pub fn sequential_from_scheme(scheme: &DynamicScheme, stops: usize) -> Vec<Argb> {
+        Self::sequential(&scheme.primary_palette, &scheme.tertiary_palette, stops)
+    }
+
+    /// Creates a diverging colormap from the primary to the tertiary hue of
+    /// `scheme`.
+    pub fn diverging_from_scheme(scheme: &DynamicScheme, stops: usize) -> Vec<Argb> {
+        Self::diverging(&scheme.primary_palette, &scheme.tertiary_palette, stops)
+    }
+
+    /// Color at `t` of a ramp from the lightest (`t = 0`) to the darkest
+    /// (`t = 1`) tone, interpolating hue along the shortest path.
+    fn ramp(start: &TonalPalette, end: &TonalPalette, start_chroma: f64, t: f64) -> Hct {
+        let hue = sanitize_degrees_double(
+            rotate_direction(start.hue(), end.hue())
+                .mul_add(difference_degrees(start.hue(), end.hue()) * t, start.hue()),
+        );
+
+        Hct::from(
+            hue,
+            lerp(start_chroma, end.chroma(), t),
+            lerp(Self::LIGHTEST_TONE, Self::DARKEST_TONE, t),
+        )
+    }
+
+    /// Picks `stops` values of `t` in `0.0..=1.0` evenly spaced by CAM16-UCS
+    /// distance along `color_at`.
+    fn resample<F>(color_at: F, stops: usize) -> Vec<Argb>
+    where
+        F: Fn(f64) -> Hct,
+    {
+        if stops < 2 {
+            return (0..stops).map(|_| color_at(0.0).into()).collect();
+        }
+
+        let cams = (0..=Self::SAMPLES)
+            .map(|index| Cam16::from(Argb::from(color_at(index as f64 / Self::SAMPLES as f64))))
+            .collect::<Vec<_>>();
+        let mut lengths = Vec::with_capacity(cams.len());
+        let mut length = 0.0;
+
+        lengths.push(length);
+
+        for pair in cams.windows(2) {
+            length += pair[0].distance(&pair[1]);
+
+            lengths.push(length);
+        }
+
+        let mut segment = 0;
+
+        (0..stops)
+            .map(|stop| {
+                let target = length * stop as f64 / (stops - 1) as f64;
+
+                while segment < Self::SAMPLES - 1 && lengths[segment + 1] < target {
+                    segment += 1;
+                }
+
+                let span = lengths[segment + 1] - lengths[segment];
+                let fraction = if span > 0.0 {
+                    ((target - lengths[segment]) / span).clamp(0.0, 1.0)
+                } else {
+                    0.0
+                };
+
+                color_at((segment as f64 + fraction) / Self::SAMPLES as f64).into()
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Colormap;
+    use crate::{
+        color::Argb,
+        dynamic_color::{DynamicScheme, Variant},
+        hct::Cam16,
+        palette::TonalPalette,
+    };
+    #[cfg(not(feature = "std"))]
+    use alloc::vec::Vec;
+    #[cfg(feature = "std")]
+    use std::vec::Vec;
+
+    fn steps(colors: &[Argb]) -> Vec<f64> {
+        colors
+            .windows(2)
+            .map(|pair| Cam16::from(pair[0]).distance(&Cam16::from(pair[1])))
+            .collect()
+    }
+
+    #[test]
+    fn test_sequential() {
+        let palette = TonalPalette::of(260.0, 40.0);
+        let colors = Colormap::sequential(&palette, &palette, 7);
+
+        assert_eq!(colors.len(), 7);
+        assert!(colors
+            .windows(2)
+            .all(|pair| pair[0].as_lstar() > pair[1].as_lstar()));
+
+        let steps = steps(&colors);
+        let min = steps.iter().copied().fold(f64::INFINITY, f64::min);
+        let max = steps.iter().copied().fold(0.0, f64::max);
+
+        assert!(max / min < 1.25, "uneven steps: {steps:?}");
+        assert!(Colormap::sequential(&palette, &palette, 0).is_empty());
+        assert_eq!(Colormap::sequential(&palette, &palette, 1).len(), 1);
+    }
+
+    #[test]
+    fn test_diverging() {
+        let scheme =
+            DynamicScheme::by_variant(Argb::from_u32(0xff6750a4), &Variant::TonalSpot, false, None);
+        let colors = Colormap::diverging_from_scheme(&scheme, 9);
+        let tones = colors.iter().map(Argb::as_lstar).collect::<Vec<_>>();
+
+        assert_eq!(colors.len(), 9);
+        assert!(tones[..5].windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(tones[4..].windows(2).all(|pair| pair[0] > pair[1]));
+        assert!((tones[4] - Colormap::LIGHTEST_TONE).abs() < 1.0);
+        assert!(Cam16::from(colors[4]).chroma < 2.0);
+
+        let sequential = Colormap::sequential_from_scheme(&scheme, 5);
+
+        assert_eq!(sequential.len(), 5);
+    }
+}
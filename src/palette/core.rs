@@ -1,7 +1,7 @@
 #![allow(deprecated)]
 
 use super::TonalPalette;
-use crate::{color::Argb, hct::Cam16};
+use crate::{color::Argb, hct::Hct};
 use core::fmt;
 
 /// An intermediate concept between the key color for a UI theme, and a full
@@ -39,8 +39,12 @@ impl CorePalette {
 
     /// Create a [`CorePalette`] from a source Argb color.
     pub fn of(argb: Argb) -> Self {
-        let cam = Cam16::from(argb);
-        let (hue, chroma) = (cam.hue, cam.chroma);
+        Self::from_hct(Hct::new(argb))
+    }
+
+    /// Create a [`CorePalette`] from the hue and chroma of `hct`.
+    pub fn from_hct(hct: Hct) -> Self {
+        let (hue, chroma) = (hct.get_hue(), hct.get_chroma());
 
         Self::new(
             TonalPalette::of(hue, 48.0_f64.max(chroma)),
@@ -54,8 +58,8 @@ impl CorePalette {
 
     /// Create a content [`CorePalette`] from a source Argb color.
     pub fn content_of(argb: Argb) -> Self {
-        let cam = Cam16::from(argb);
-        let (hue, chroma) = (cam.hue, cam.chroma);
+        let hct = Hct::new(argb);
+        let (hue, chroma) = (hct.get_hue(), hct.get_chroma());
 
         Self::new(
             TonalPalette::of(hue, chroma),
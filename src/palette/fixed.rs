@@ -0,0 +1,304 @@
+use super::TonalPalette;
+use crate::color::Argb;
+#[cfg(feature = "std")]
+use crate::{
+    hct::Hct,
+    scheme::{Scheme, SchemeRole},
+    theme::ThemeBuilder,
+};
+use core::fmt;
+#[cfg(feature = "std")]
+use std::{format, fs, io, path::Path, string::ToString};
+
+/// A [`TonalPalette`] with its common tones computed ahead of time, so that
+/// firmware can theme itself without any color math at runtime.
+///
+/// Floating point math is not available in constants on the Rust versions
+/// this crate supports, so palettes cannot be computed by `const fn`s.
+/// Instead, a build script renders one with [`Display`](fmt::Display) as a
+/// Rust expression, to be included as a constant. [`write_fixed_palettes`]
+/// and [`palette!`](crate::palette!) do this for seed colors:
+///
+/// ```no_run
+/// // build.rs
+/// use material_colors::{
+///     argb,
+///     hct::Hct,
+///     palette::{FixedTonalPalette, TonalPalette},
+/// };
+///
+/// let palette = FixedTonalPalette::from(TonalPalette::from_hct(Hct::new(argb!("#4285f4"))));
+/// let path = std::path::Path::new(&std::env::var("OUT_DIR").unwrap()).join("primary.rs");
+///
+/// std::fs::write(path, palette.to_string()).unwrap();
+/// ```
+///
+/// ```ignore
+/// // src/main.rs
+/// const PRIMARY: FixedTonalPalette = include!(concat!(env!("OUT_DIR"), "/primary.rs"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FixedTonalPalette {
+    tones: [Argb; 13],
+}
+
+impl FixedTonalPalette {
+    /// Tones stored by the palette, in order.
+    pub const TONES: [i32; 13] = TonalPalette::COMMON_TONES;
+
+    /// Creates a palette from the colors of [`FixedTonalPalette::TONES`].
+    pub const fn new(tones: [Argb; 13]) -> Self {
+        Self { tones }
+    }
+
+    /// Returns the colors of [`FixedTonalPalette::TONES`].
+    pub const fn tones(&self) -> &[Argb; 13] {
+        &self.tones
+    }
+
+    /// Returns the color of `tone`, or `None` if it is not one of
+    /// [`FixedTonalPalette::TONES`].
+    pub const fn tone(&self, tone: i32) -> Option<Argb> {
+        let mut index = 0;
+
+        while index < Self::TONES.len() {
+            if Self::TONES[index] == tone {
+                return Some(self.tones[index]);
+            }
+
+            index += 1;
+        }
+
+        None
+    }
+}
+
+impl From<TonalPalette> for FixedTonalPalette {
+    fn from(palette: TonalPalette) -> Self {
+        Self::new(Self::TONES.map(|tone| palette.tone(tone)))
+    }
+}
+
+/// Writes the palette as a Rust expression with absolute paths, for
+/// `include!` in constants.
+impl fmt::Display for FixedTonalPalette {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "::material_colors::palette::FixedTonalPalette::new([")?;
+
+        for color in self.tones {
+            writeln!(f, "    {},", ArgbSource(color))?;
+        }
+
+        write!(f, "])")
+    }
+}
+
+/// Writes a color as a Rust expression with absolute paths.
+struct ArgbSource(Argb);
+
+impl fmt::Display for ArgbSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Argb {
+            alpha,
+            red,
+            green,
+            blue,
+        } = self.0;
+
+        write!(
+            f,
+            "::material_colors::color::Argb::from_u32(0x{alpha:02x}{red:02x}{green:02x}{blue:02x})"
+        )
+    }
+}
+
+/// Writes the constants expanded by [`palette!`](crate::palette!) for each
+/// of `seeds` into `out_dir`, from a build script.
+///
+/// Every seed gets the [`FixedTonalPalette`] of its hue and chroma, and the
+/// light and dark schemes of the default theme built from it with
+/// [`ThemeBuilder::with_source`]. Seeds are written as hexadecimal strings
+/// parsed by [`Argb::from_str`](core::str::FromStr::from_str), and must be
+/// spelled the same way in the build script and in [`palette!`](crate::palette!).
+///
+/// # Errors
+///
+/// Returns an error of kind [`io::ErrorKind::InvalidInput`] if a seed is not a
+/// hexadecimal color, or the error of writing the files.
+///
+/// ```no_run
+/// // build.rs
+/// material_colors::palette::write_fixed_palettes(
+///     std::env::var("OUT_DIR").unwrap(),
+///     &["#4285f4"],
+/// )
+/// .unwrap();
+/// ```
+#[cfg(feature = "std")]
+pub fn write_fixed_palettes<P: AsRef<Path>>(out_dir: P, seeds: &[&str]) -> io::Result<()> {
+    let dir = out_dir.as_ref().join("material_colors");
+
+    fs::create_dir_all(&dir)?;
+
+    for &seed in seeds {
+        let source: Argb = seed
+            .parse()
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidInput, error))?;
+        let palette = FixedTonalPalette::from(TonalPalette::from_hct(Hct::new(source)));
+        let schemes = ThemeBuilder::with_source(source).build().schemes;
+
+        fs::write(dir.join(format!("palette-{seed}.rs")), palette.to_string())?;
+        fs::write(
+            dir.join(format!("scheme-light-{seed}.rs")),
+            SchemeSource(&schemes.light).to_string(),
+        )?;
+        fs::write(
+            dir.join(format!("scheme-dark-{seed}.rs")),
+            SchemeSource(&schemes.dark).to_string(),
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Writes a scheme as a Rust expression with absolute paths, for `include!`
+/// in constants.
+#[cfg(feature = "std")]
+struct SchemeSource<'a>(&'a Scheme);
+
+#[cfg(feature = "std")]
+impl fmt::Display for SchemeSource<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "::material_colors::scheme::Scheme::new(")?;
+
+        for role in SchemeRole::ALL {
+            writeln!(f, "    {}, // {}", ArgbSource(self.0[role]), role.name())?;
+        }
+
+        write!(f, ")")
+    }
+}
+
+/// Expands to a constant precomputed from a seed color by
+/// [`write_fixed_palettes`](crate::palette::write_fixed_palettes) in the
+/// build script of the calling crate.
+///
+/// - `palette!("#4285f4")` is the [`FixedTonalPalette`] of the seed.
+/// - `palette!("#4285f4", light)` and `palette!("#4285f4", dark)` are the
+///   [`Scheme`](crate::scheme::Scheme)s of the default theme built from it.
+///
+/// The seed must be spelled as in the build script, and a seed that was not
+/// written fails the build.
+///
+/// ```ignore
+/// // src/main.rs
+/// use material_colors::{palette, palette::FixedTonalPalette, scheme::Scheme};
+///
+/// const PRIMARY: FixedTonalPalette = palette!("#4285f4");
+/// const LIGHT: Scheme = palette!("#4285f4", light);
+/// ```
+#[macro_export]
+macro_rules! palette {
+    ($seed:literal) => {{
+        const PALETTE: $crate::palette::FixedTonalPalette = include!(concat!(
+            env!("OUT_DIR"),
+            "/material_colors/palette-",
+            $seed,
+            ".rs"
+        ));
+
+        PALETTE
+    }};
+    ($seed:literal, light) => {{
+        const SCHEME: $crate::scheme::Scheme = include!(concat!(
+            env!("OUT_DIR"),
+            "/material_colors/scheme-light-",
+            $seed,
+            ".rs"
+        ));
+
+        SCHEME
+    }};
+    ($seed:literal, dark) => {{
+        const SCHEME: $crate::scheme::Scheme = include!(concat!(
+            env!("OUT_DIR"),
+            "/material_colors/scheme-dark-",
+            $seed,
+            ".rs"
+        ));
+
+        SCHEME
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FixedTonalPalette;
+    #[cfg(feature = "std")]
+    use super::{write_fixed_palettes, ArgbSource};
+    #[cfg(feature = "std")]
+    use crate::theme::ThemeBuilder;
+    use crate::{color::Argb, hct::Hct, palette::TonalPalette};
+    #[cfg(not(feature = "std"))]
+    use alloc::string::ToString;
+    #[cfg(feature = "std")]
+    use std::string::ToString;
+
+    #[test]
+    fn test_fixed_tonal_palette() {
+        let palette = TonalPalette::from_hct(Hct::new(Argb::from_u32(0xff4285f4)));
+        let fixed = FixedTonalPalette::from(palette);
+
+        for tone in FixedTonalPalette::TONES {
+            assert_eq!(fixed.tone(tone), Some(palette.tone(tone)));
+        }
+
+        assert_eq!(fixed.tone(45), None);
+
+        let source = fixed.to_string();
+
+        assert!(source.starts_with("::material_colors::palette::FixedTonalPalette::new([\n"));
+        assert!(source.contains("    ::material_colors::color::Argb::from_u32(0xff000000),\n"));
+        assert!(source.ends_with("0xffffffff),\n])"));
+        assert_eq!(source.lines().count(), 15);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_write_fixed_palettes() {
+        let out_dir = std::env::temp_dir().join("material-colors-test-write-fixed-palettes");
+        let dir = out_dir.join("material_colors");
+        let schemes = ThemeBuilder::with_source(Argb::from_u32(0xff4285f4))
+            .build()
+            .schemes;
+
+        write_fixed_palettes(&out_dir, &["#4285f4"]).unwrap();
+
+        let palette = std::fs::read_to_string(dir.join("palette-#4285f4.rs")).unwrap();
+        let light = std::fs::read_to_string(dir.join("scheme-light-#4285f4.rs")).unwrap();
+        let dark = std::fs::read_to_string(dir.join("scheme-dark-#4285f4.rs")).unwrap();
+
+        assert_eq!(
+            palette,
+            FixedTonalPalette::from(TonalPalette::from_hct(Hct::new(Argb::from_u32(0xff4285f4))))
+                .to_string()
+        );
+        assert!(light.starts_with("::material_colors::scheme::Scheme::new(\n"));
+        assert!(light.contains(&std::format!(
+            "    {}, // primary\n",
+            ArgbSource(schemes.light.primary)
+        )));
+        assert!(dark.contains(&std::format!(
+            "    {}, // scrim\n",
+            ArgbSource(schemes.dark.scrim)
+        )));
+        assert!(dark.ends_with(')'));
+        assert_eq!(dark.lines().count(), 51);
+
+        let error = write_fixed_palettes(&out_dir, &["#4285g4"]).unwrap_err();
+
+        assert_eq!(error.kind(), std::io::ErrorKind::InvalidInput);
+
+        std::fs::remove_dir_all(out_dir).unwrap();
+    }
+}
@@ -1,8 +1,14 @@
 #[allow(deprecated)]
 pub use self::core::{CorePalette, CorePalettes};
-pub use tonal::TonalPalette;
+pub use colormap::Colormap;
+#[cfg(feature = "std")]
+pub use fixed::write_fixed_palettes;
+pub use fixed::FixedTonalPalette;
+pub use tonal::{LutSpace, TonalPalette};
 
+mod colormap;
 mod core;
+mod fixed;
 mod tonal;
 
 #[derive(PartialEq, Eq)]
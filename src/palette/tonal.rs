@@ -2,15 +2,18 @@ use super::Palette;
 #[cfg(all(not(feature = "std"), feature = "libm"))]
 #[allow(unused_imports)]
 use crate::utils::no_std::FloatExt;
+#[cfg(feature = "serde")]
+use crate::utils::precision;
 use crate::{
-    color::Argb,
-    dynamic_color::Variant,
-    hct::Hct,
+    color::{linearized, Argb},
+    contrast::{darker, lighter, ratio_of_tones},
+    dynamic_color::{TonePolarity, Variant},
+    hct::{Cam16, Hct},
     scheme::variant::{
         SchemeContent, SchemeExpressive, SchemeFidelity, SchemeFruitSalad, SchemeMonochrome,
         SchemeNeutral, SchemeRainbow, SchemeTonalSpot, SchemeVibrant,
     },
-    Map,
+    Error, Map,
 };
 #[cfg(not(feature = "std"))]
 use alloc::vec::Vec;
@@ -18,23 +21,28 @@ use core::{
     cmp::Ordering,
     fmt,
     hash::{Hash, Hasher},
+    iter::FusedIterator,
 };
 #[cfg(feature = "serde")]
 use serde::Serialize;
+#[cfg(feature = "std")]
+use std::vec::Vec;
 
 /// A convenience class for retrieving colors that are constant in hue and
 /// chroma, but vary in tone.
 #[derive(Clone, Copy, Debug, PartialOrd)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct TonalPalette {
+    #[cfg_attr(feature = "serde", serde(serialize_with = "precision::serialize"))]
     _hue: f64,
+    #[cfg_attr(feature = "serde", serde(serialize_with = "precision::serialize"))]
     _chroma: f64,
     _key_color: Hct,
 }
 
 impl TonalPalette {
     /// Commonly-used tone values.
-    const COMMON_TONES: [i32; 13] = [0, 10, 20, 30, 40, 50, 60, 70, 80, 90, 95, 99, 100];
+    pub(crate) const COMMON_TONES: [i32; 13] = [0, 10, 20, 30, 40, 50, 60, 70, 80, 90, 95, 99, 100];
 
     pub const fn common_size() -> usize {
         Self::COMMON_TONES.len()
@@ -80,13 +88,57 @@ impl TonalPalette {
     }
 
     /// Create a Tonal Palette from `hue` and `chroma`, which generates a key color.
+    ///
+    /// Same as [`TonalPalette::from_hue_and_chroma_unchecked`]; see
+    /// [`TonalPalette::try_from_hue_and_chroma`] to validate the values.
     pub fn from_hue_and_chroma(hue: f64, chroma: f64) -> Self {
+        Self::from_hue_and_chroma_unchecked(hue, chroma)
+    }
+
+    /// Create a Tonal Palette from `hue` and `chroma`, which generates a key color.
+    ///
+    /// The values are not validated, so a NaN or negative chroma produces
+    /// meaningless colors rather than an error.
+    pub fn from_hue_and_chroma_unchecked(hue: f64, chroma: f64) -> Self {
         Self::new(hue, chroma, KeyColor::new(hue, chroma).create())
     }
 
+    /// Create a Tonal Palette from `hue` and `chroma`, which generates a key color.
+    ///
+    /// Returns an error if `hue` is not finite, or if `chroma` is not finite
+    /// or is negative.
+    pub fn try_from_hue_and_chroma(hue: f64, chroma: f64) -> Result<Self, Error> {
+        if !hue.is_finite() {
+            return Err(Error::InvalidHue);
+        }
+
+        if !chroma.is_finite() || chroma < 0.0 {
+            return Err(Error::InvalidChroma);
+        }
+
+        Ok(Self::from_hue_and_chroma_unchecked(hue, chroma))
+    }
+
     /// Create colors using `hue` and `chroma`.
+    ///
+    /// Same as [`TonalPalette::of_unchecked`]; see [`TonalPalette::try_of`] to
+    /// validate the values.
     pub fn of(hue: f64, chroma: f64) -> Self {
-        Self::from_hue_and_chroma(hue, chroma)
+        Self::of_unchecked(hue, chroma)
+    }
+
+    /// Create colors using `hue` and `chroma`, without validating them.
+    ///
+    /// See [`TonalPalette::from_hue_and_chroma_unchecked`].
+    pub fn of_unchecked(hue: f64, chroma: f64) -> Self {
+        Self::from_hue_and_chroma_unchecked(hue, chroma)
+    }
+
+    /// Create colors using `hue` and `chroma`, failing on invalid values.
+    ///
+    /// See [`TonalPalette::try_from_hue_and_chroma`].
+    pub fn try_of(hue: f64, chroma: f64) -> Result<Self, Error> {
+        Self::try_from_hue_and_chroma(hue, chroma)
     }
 
     /// Returns the Argb representation of an HCT color.
@@ -99,9 +151,221 @@ impl TonalPalette {
         Hct::from(self.hue(), self.chroma(), f64::from(tone)).into()
     }
 
+    /// Returns an iterator over the commonly-used tones (0, 10, ..., 90, 95,
+    /// 99, 100) and their colors.
+    pub fn common_tones(
+        &self,
+    ) -> impl ExactSizeIterator<Item = (i32, Argb)> + DoubleEndedIterator + FusedIterator + '_ {
+        Self::COMMON_TONES
+            .iter()
+            .map(move |tone| (*tone, self.tone(*tone)))
+    }
+
     pub fn get_hct(&self, tone: f64) -> Hct {
         Hct::from(self.hue(), self.chroma(), tone)
     }
+
+    /// Returns the chroma actually achieved at every integer tone from 0 to
+    /// 100, indexed by tone.
+    ///
+    /// Colors are gamut-mapped by reducing chroma, so tones where the
+    /// requested [`TonalPalette::chroma`] is out of the sRGB gamut achieve
+    /// less. Comparing the profile against the requested chroma shows how
+    /// evenly colorful a ramp is; hues such as yellow lose most of their
+    /// chroma at dark tones.
+    pub fn chroma_profile(&self) -> [f64; 101] {
+        let mut profile = [0.0; 101];
+
+        for (tone, chroma) in profile.iter_mut().enumerate() {
+            *chroma = self.get_hct(tone as f64).get_chroma();
+        }
+
+        profile
+    }
+
+    /// Returns the tone of this palette nearest to `base_tone` whose color
+    /// reaches `ratio` against `base_tone`, or `None` if there is none in
+    /// `direction`.
+    ///
+    /// Starts from the tone computed by [`lighter`] or [`darker`] and moves
+    /// away from `base_tone` in steps of 0.1 until the actual color, after
+    /// the chroma of this palette has been gamut-mapped, reaches `ratio`.
+    ///
+    /// `direction` selects lighter or darker tones; [`TonePolarity::Nearer`]
+    /// and [`TonePolarity::Farther`] pick whichever of the two candidates is
+    /// nearer to or farther from `base_tone`.
+    pub fn tone_with_contrast(
+        &self,
+        base_tone: f64,
+        ratio: f64,
+        direction: &TonePolarity,
+    ) -> Option<f64> {
+        let lighter_tone =
+            || self.contrasting_tone(base_tone, ratio, lighter(base_tone, ratio), 0.1);
+        let darker_tone =
+            || self.contrasting_tone(base_tone, ratio, darker(base_tone, ratio), -0.1);
+
+        match direction {
+            TonePolarity::Lighter => lighter_tone(),
+            TonePolarity::Darker => darker_tone(),
+            TonePolarity::Nearer | TonePolarity::Farther => {
+                let candidates = [lighter_tone(), darker_tone()];
+                let distances = candidates
+                    .into_iter()
+                    .flatten()
+                    .map(|tone| ((tone - base_tone).abs(), tone));
+
+                if *direction == TonePolarity::Nearer {
+                    distances.min_by(|a, b| a.0.total_cmp(&b.0))
+                } else {
+                    distances.max_by(|a, b| a.0.total_cmp(&b.0))
+                }
+                .map(|(_, tone)| tone)
+            }
+        }
+    }
+
+    fn contrasting_tone(&self, base_tone: f64, ratio: f64, start: f64, step: f64) -> Option<f64> {
+        if start < 0.0 {
+            return None;
+        }
+
+        let mut tone = start;
+
+        loop {
+            let actual = Argb::from(self.get_hct(tone)).as_lstar();
+
+            if ratio_of_tones(actual, base_tone) >= ratio {
+                return Some(tone);
+            }
+
+            let next = (tone + step).clamp(0.0, 100.0);
+
+            if (next - tone).abs() < f64::EPSILON {
+                return None;
+            }
+
+            tone = next;
+        }
+    }
+
+    /// Max CAM16-UCS distance between a color and this palette within which
+    /// [`TonalPalette::tone_of`] finds its tone.
+    pub const TONE_OF_TOLERANCE: f64 = 2.0;
+
+    /// Returns the tone of this palette matching `color`, or `None` if
+    /// `color` is not within [`TonalPalette::TONE_OF_TOLERANCE`] of the
+    /// palette.
+    ///
+    /// See [`TonalPalette::tone_of_within`].
+    pub fn tone_of(&self, color: Argb) -> Option<f64> {
+        self.tone_of_within(color, Self::TONE_OF_TOLERANCE)
+    }
+
+    /// Returns the tone of this palette matching `color`, or `None` if the
+    /// color of the palette at that tone is farther than `tolerance` from
+    /// `color` in CAM16-UCS.
+    ///
+    /// Tone is L*, so the matching tone is found directly instead of
+    /// searching all tones. Colors returned by [`TonalPalette::tone`] map
+    /// back to their integer tone exactly, other colors to their L*.
+    pub fn tone_of_within(&self, color: Argb, tolerance: f64) -> Option<f64> {
+        let lstar = color.as_lstar().clamp(0.0, 100.0);
+        let rounded = lstar.round();
+
+        if self.tone(rounded as i32) == color {
+            return Some(rounded);
+        }
+
+        let distance = Cam16::from(color).distance(&Cam16::from(Argb::from(self.get_hct(lstar))));
+
+        (distance <= tolerance).then_some(lstar)
+    }
+
+    /// Maps every pixel onto this palette by its luminance.
+    ///
+    /// The tone of each output pixel equals the L* of the input pixel, rounded
+    /// to the nearest half tone, so the image keeps its detail while taking on
+    /// the hue and chroma of the palette. Alpha is preserved.
+    ///
+    /// Useful for rendering a "themed" version of a wallpaper, for example by
+    /// passing the neutral or primary palette of a scheme.
+    pub fn tone_map(&self, pixels: &[Argb]) -> Vec<Argb> {
+        const STEPS_PER_TONE: f64 = 2.0;
+
+        let mut table: [Option<Argb>; 201] = [None; 201];
+
+        pixels
+            .iter()
+            .map(|pixel| {
+                let index = (pixel.as_lstar().clamp(0.0, 100.0) * STEPS_PER_TONE).round() as usize;
+                let color = *table[index]
+                    .get_or_insert_with(|| self.get_hct(index as f64 / STEPS_PER_TONE).into());
+
+                Argb::new(pixel.alpha, color.red, color.green, color.blue)
+            })
+            .collect()
+    }
+
+    /// Returns `size` colors of this palette at tones spaced evenly from 0 to
+    /// 100, e.g. for baking a ramp into a 1D lookup texture.
+    ///
+    /// Tone never decreases along the table: where gamut mapping and rounding
+    /// would make a color darker than the previous one, the previous color is
+    /// repeated, so shaders interpolating between entries never invert
+    /// lightness.
+    pub fn to_lut_argb(&self, size: usize) -> Vec<Argb> {
+        let step = if size > 1 {
+            100.0 / (size - 1) as f64
+        } else {
+            0.0
+        };
+        let mut lut: Vec<Argb> = Vec::with_capacity(size);
+
+        for index in 0..size {
+            let color = Argb::from(self.get_hct(index as f64 * step));
+
+            match lut.last() {
+                Some(previous) if color.as_lstar() < previous.as_lstar() => lut.push(*previous),
+                _ => lut.push(color),
+            }
+        }
+
+        lut
+    }
+
+    /// Like [`TonalPalette::to_lut_argb`], but returns normalized RGB
+    /// triplets from 0 to 1 encoded in `space`, ready for uploading as a
+    /// float texture.
+    pub fn to_lut(&self, size: usize, space: LutSpace) -> Vec<[f32; 3]> {
+        let channel = |value: u8| match space {
+            LutSpace::Srgb => f32::from(value) / 255.0,
+            LutSpace::Linear => (linearized(value) / 100.0) as f32,
+        };
+
+        self.to_lut_argb(size)
+            .into_iter()
+            .map(|color| {
+                [
+                    channel(color.red),
+                    channel(color.green),
+                    channel(color.blue),
+                ]
+            })
+            .collect()
+    }
+}
+
+/// Encoding of the channels of a lookup table, see [`TonalPalette::to_lut`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum LutSpace {
+    /// Gamma-encoded sRGB, for textures sampled as `*_SRGB` formats or
+    /// written to the framebuffer as-is.
+    #[default]
+    Srgb,
+    /// Linear-light sRGB, for shaders that blend or light colors before
+    /// encoding them.
+    Linear,
 }
 
 impl Ord for TonalPalette {
@@ -213,9 +477,92 @@ impl KeyColor {
 
 #[cfg(test)]
 mod tests {
-    use float_cmp::assert_approx_eq;
+    use float_cmp::{approx_eq, assert_approx_eq};
+
+    use crate::{
+        color::Argb,
+        contrast::ratio_of_tones,
+        dynamic_color::TonePolarity,
+        hct::Hct,
+        palette::{LutSpace, TonalPalette},
+        Error,
+    };
+
+    #[test]
+    fn test_common_tones() {
+        let palette = TonalPalette::of(270.0, 36.0);
+        let tones = palette.common_tones();
+
+        assert_eq!(tones.len(), TonalPalette::common_size());
+        assert_eq!(
+            palette.common_tones().next_back(),
+            Some((100, palette.tone(100)))
+        );
+        assert!(palette
+            .common_tones()
+            .all(|(tone, color)| color == palette.tone(tone)));
+    }
 
-    use crate::{color::Argb, hct::Hct, palette::TonalPalette};
+    #[test]
+    fn test_chroma_profile() {
+        let blue = TonalPalette::of(282.0, 30.0).chroma_profile();
+
+        assert!(blue[0] < 5.0 && blue[100] < 5.0);
+        assert!(blue.iter().all(|chroma| *chroma <= 30.0 + 0.5));
+        assert_approx_eq!(f64, blue[50], 30.0, epsilon = 0.5);
+
+        let yellow = TonalPalette::of(100.0, 60.0).chroma_profile();
+
+        assert!(yellow[20] < 30.0);
+        assert!(yellow[90] > yellow[20]);
+    }
+
+    #[test]
+    fn test_tone_with_contrast() {
+        let palette = TonalPalette::of(120.0, 60.0);
+
+        for (base_tone, ratio) in [(50.0, 3.0), (30.0, 4.5), (90.0, 7.0), (10.0, 4.5)] {
+            for direction in [
+                TonePolarity::Lighter,
+                TonePolarity::Darker,
+                TonePolarity::Nearer,
+                TonePolarity::Farther,
+            ] {
+                if let Some(tone) = palette.tone_with_contrast(base_tone, ratio, &direction) {
+                    let actual = Argb::from(palette.get_hct(tone)).as_lstar();
+
+                    assert!(ratio_of_tones(actual, base_tone) >= ratio);
+                }
+            }
+        }
+
+        let lighter = palette
+            .tone_with_contrast(50.0, 3.0, &TonePolarity::Lighter)
+            .unwrap();
+        let darker = palette
+            .tone_with_contrast(50.0, 3.0, &TonePolarity::Darker)
+            .unwrap();
+
+        assert!(lighter > 50.0 && darker < 50.0);
+        assert_eq!(
+            palette.tone_with_contrast(50.0, 3.0, &TonePolarity::Farther),
+            Some(if lighter - 50.0 > 50.0 - darker {
+                lighter
+            } else {
+                darker
+            })
+        );
+        assert!(palette
+            .tone_with_contrast(90.0, 4.5, &TonePolarity::Lighter)
+            .is_none());
+        assert_eq!(
+            palette.tone_with_contrast(90.0, 4.5, &TonePolarity::Nearer),
+            palette.tone_with_contrast(90.0, 4.5, &TonePolarity::Darker)
+        );
+        assert!(palette
+            .tone_with_contrast(50.0, 21.0, &TonePolarity::Nearer)
+            .is_none());
+    }
 
     #[test]
     fn test_exact_chroma_available() {
@@ -285,4 +632,107 @@ mod tests {
         assert_eq!(tones_a, tones_b);
         assert!(tones_b != tones_c);
     }
+
+    #[test]
+    fn test_try_of_rejects_invalid_values() {
+        assert_eq!(TonalPalette::try_of(f64::NAN, 16.0), Err(Error::InvalidHue));
+        assert_eq!(
+            TonalPalette::try_of(f64::INFINITY, 16.0),
+            Err(Error::InvalidHue)
+        );
+        assert_eq!(TonalPalette::try_of(270.0, -1.0), Err(Error::InvalidChroma));
+        assert_eq!(
+            TonalPalette::try_of(270.0, f64::NAN),
+            Err(Error::InvalidChroma)
+        );
+        assert_eq!(
+            TonalPalette::try_of(270.0, 16.0),
+            Ok(TonalPalette::of(270.0, 16.0))
+        );
+        assert_eq!(
+            TonalPalette::of(270.0, 16.0),
+            TonalPalette::of_unchecked(270.0, 16.0)
+        );
+    }
+
+    #[test]
+    fn test_tone_of() {
+        let palette = TonalPalette::of(270.0, 36.0);
+
+        for tone in [0, 10, 35, 40, 87, 99, 100] {
+            assert_eq!(palette.tone_of(palette.tone(tone)), Some(f64::from(tone)));
+        }
+
+        let near = palette.tone(40);
+        let near = Argb::new(255, near.red, near.green, near.blue + 2);
+        let tone = palette.tone_of(near).unwrap();
+
+        assert_approx_eq!(f64, tone, 40.0, epsilon = 0.5);
+        assert_eq!(palette.tone_of(Argb::from_u32(0xffff0000)), None);
+        assert_eq!(TonalPalette::of(90.0, 36.0).tone_of(palette.tone(50)), None);
+        assert!(palette
+            .tone_of_within(Argb::from_u32(0xff777777), 50.0)
+            .is_some());
+    }
+
+    #[test]
+    fn test_tone_map() {
+        let palette = TonalPalette::of(270.0, 16.0);
+        let pixels = [
+            Argb::from_u32(0xff000000),
+            Argb::from_u32(0x80ff0000),
+            Argb::from_u32(0xff00ff00),
+            Argb::from_u32(0xffffffff),
+        ];
+        let mapped = palette.tone_map(&pixels);
+
+        assert_eq!(mapped.len(), pixels.len());
+        assert_eq!(mapped[0], Argb::from_u32(0xff000000));
+        assert_eq!(mapped[1].alpha, 0x80);
+        assert_eq!(mapped[3], Argb::from_u32(0xffffffff));
+
+        for (pixel, color) in pixels.iter().zip(&mapped) {
+            assert_approx_eq!(f64, color.as_lstar(), pixel.as_lstar(), epsilon = 1.0);
+        }
+
+        let hct: Hct = mapped[2].into();
+
+        assert_approx_eq!(f64, hct.get_hue(), 270.0, epsilon = 2.0);
+    }
+
+    #[test]
+    fn test_to_lut_argb() {
+        let palette = TonalPalette::of(60.0, 80.0);
+        let lut = palette.to_lut_argb(256);
+
+        assert_eq!(lut.len(), 256);
+        assert_eq!(lut[0], palette.tone(0));
+        assert_eq!(lut[255], palette.tone(100));
+        assert!(lut
+            .windows(2)
+            .all(|pair| pair[0].as_lstar() <= pair[1].as_lstar()));
+
+        assert!(palette.to_lut_argb(0).is_empty());
+        assert_eq!(palette.to_lut_argb(1), [palette.tone(0)]);
+    }
+
+    #[test]
+    fn test_to_lut() {
+        let palette = TonalPalette::of(270.0, 36.0);
+        let srgb = palette.to_lut(11, LutSpace::Srgb);
+        let linear = palette.to_lut(11, LutSpace::Linear);
+
+        assert!(srgb[0].iter().all(|channel| approx_eq!(f32, *channel, 0.0)));
+        assert!(srgb[10]
+            .iter()
+            .all(|channel| approx_eq!(f32, *channel, 1.0)));
+        assert!(linear[10]
+            .iter()
+            .all(|channel| approx_eq!(f32, *channel, 1.0)));
+
+        let mid = palette.tone(50);
+
+        assert_approx_eq!(f32, srgb[5][2], f32::from(mid.blue) / 255.0);
+        assert!(linear[5][2] < srgb[5][2]);
+    }
 }
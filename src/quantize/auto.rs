@@ -0,0 +1,141 @@
+use super::{Quantizer, QuantizerCelebi, QuantizerResult, QuantizerWu};
+use crate::color::Argb;
+use std::{
+    time::{Duration, Instant},
+    vec::Vec,
+};
+
+/// Quantizer chosen by [`QuantizerAuto`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AutoQuantizer {
+    /// [`QuantizerCelebi`], the slower one producing better clusters.
+    Celebi,
+    /// [`QuantizerWu`], the faster one.
+    Wu,
+}
+
+impl AutoQuantizer {
+    /// Quantizes `pixels` with this quantizer.
+    pub fn quantize(self, pixels: &[Argb], max_colors: usize) -> QuantizerResult {
+        match self {
+            Self::Celebi => QuantizerCelebi::quantize(pixels, max_colors),
+            Self::Wu => QuantizerWu::quantize(pixels, max_colors),
+        }
+    }
+}
+
+/// Outcome of [`QuantizerAuto::calibrate`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Calibration {
+    /// Quantizer meeting the latency budget.
+    pub quantizer: AutoQuantizer,
+    /// Estimated time [`QuantizerCelebi`] takes on the whole input.
+    pub celebi: Duration,
+    /// Estimated time [`QuantizerWu`] takes on the whole input.
+    pub wu: Duration,
+}
+
+/// Picks a quantizer at runtime by timing [`QuantizerCelebi`] and
+/// [`QuantizerWu`] on a sample of the actual input, for fleets of devices
+/// too different for a single static choice.
+///
+/// [`QuantizerCelebi`] is chosen whenever its estimated time fits the
+/// latency budget, and [`QuantizerWu`] otherwise. Estimates scale the time
+/// taken on the sample linearly with the count of pixels, which overestimates
+/// the fixed costs of both quantizers, so the choice errs on the fast side.
+///
+/// Calibrating takes about as long as quantizing [`QuantizerAuto::SAMPLE_SIZE`]
+/// pixels twice and is not counted against the budget; keep the
+/// [`Calibration`] to reuse it for inputs of similar size on the same device.
+pub struct QuantizerAuto;
+
+impl QuantizerAuto {
+    /// Count of pixels the quantizers are timed on.
+    pub const SAMPLE_SIZE: usize = 4096;
+
+    /// Times both quantizers on a sample of `pixels` and picks the one
+    /// expected to quantize all of them within `latency`.
+    pub fn calibrate(pixels: &[Argb], max_colors: usize, latency: Duration) -> Calibration {
+        let stride = (pixels.len() / Self::SAMPLE_SIZE).max(1);
+        let sample = pixels.iter().step_by(stride).copied().collect::<Vec<_>>();
+        let scale = if sample.is_empty() {
+            0.0
+        } else {
+            pixels.len() as f64 / sample.len() as f64
+        };
+        let time = |quantizer: AutoQuantizer| {
+            let start = Instant::now();
+
+            quantizer.quantize(&sample, max_colors);
+
+            start.elapsed().mul_f64(scale)
+        };
+        let celebi = time(AutoQuantizer::Celebi);
+        let wu = time(AutoQuantizer::Wu);
+
+        Calibration {
+            quantizer: if celebi <= latency {
+                AutoQuantizer::Celebi
+            } else {
+                AutoQuantizer::Wu
+            },
+            celebi,
+            wu,
+        }
+    }
+
+    /// Calibrates on `pixels`, see [`QuantizerAuto::calibrate`], then
+    /// quantizes them with the chosen quantizer.
+    ///
+    /// Returns the result along with the calibration, which tells the
+    /// quantizer used.
+    pub fn quantize(
+        pixels: &[Argb],
+        max_colors: usize,
+        latency: Duration,
+    ) -> (QuantizerResult, Calibration) {
+        let calibration = Self::calibrate(pixels, max_colors, latency);
+
+        (
+            calibration.quantizer.quantize(pixels, max_colors),
+            calibration,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AutoQuantizer, QuantizerAuto};
+    use crate::color::Argb;
+    use core::time::Duration;
+    use std::vec::Vec;
+
+    fn pixels() -> Vec<Argb> {
+        (0..20_000_u32)
+            .map(|index| Argb::from_u32(0xff000000 | (index.wrapping_mul(2654435761) >> 8)))
+            .collect()
+    }
+
+    #[test]
+    fn test_tight_budget_picks_wu() {
+        let pixels = pixels();
+        let (result, calibration) = QuantizerAuto::quantize(&pixels, 16, Duration::ZERO);
+
+        assert_eq!(calibration.quantizer, AutoQuantizer::Wu);
+        assert!(calibration.celebi > Duration::ZERO);
+        assert!(!result.color_to_count.is_empty());
+        assert!(result.color_to_count.len() <= 16);
+    }
+
+    #[test]
+    fn test_loose_budget_picks_celebi() {
+        let pixels = pixels();
+        let calibration = QuantizerAuto::calibrate(&pixels, 16, Duration::from_secs(3600));
+
+        assert_eq!(calibration.quantizer, AutoQuantizer::Celebi);
+        assert_eq!(
+            QuantizerAuto::calibrate(&[], 16, Duration::ZERO).quantizer,
+            AutoQuantizer::Celebi
+        );
+    }
+}
@@ -0,0 +1,237 @@
+use super::{PointProvider, PointProviderLab, QuantizerResult};
+#[cfg(all(not(feature = "std"), feature = "libm"))]
+#[allow(unused_imports)]
+use crate::utils::no_std::FloatExt;
+use crate::{
+    color::{Argb, Lab},
+    score::Score,
+    IndexMap,
+};
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::vec::Vec;
+
+/// Keeps the clusters of a [`QuantizerResult`] up to date as parts of an
+/// image change, e.g. tiles of a live wallpaper, without quantizing the whole
+/// image again.
+///
+/// Changed pixels are removed from and added to the population of their
+/// nearest cluster; the clusters themselves never move. The ranking from
+/// [`Score::score`] is only recomputed by [`IncrementalSampler::refresh`]
+/// once the cluster weights have shifted by more than the threshold since the
+/// last scoring, so that small changes don't make the theme flicker.
+///
+/// Colors that were not in the quantized image are matched to their nearest
+/// cluster on every change rather than cached, so memory use stays bounded
+/// however long the image keeps changing.
+pub struct IncrementalSampler {
+    result: QuantizerResult,
+    points: Vec<Lab>,
+    scored_counts: Vec<u32>,
+    threshold: f64,
+    ranked: Vec<Argb>,
+}
+
+impl IncrementalSampler {
+    /// Creates a sampler from the result of quantizing the full image, and
+    /// scores it.
+    ///
+    /// - `threshold`: Total variation distance, between 0 and 1, that the
+    ///   proportions of the clusters must exceed to trigger a new scoring.
+    pub fn new(result: QuantizerResult, threshold: f64) -> Self {
+        let points = result
+            .color_to_count
+            .keys()
+            .map(PointProviderLab::lab_from_int)
+            .collect();
+
+        let mut sampler = Self {
+            points,
+            scored_counts: Vec::new(),
+            threshold,
+            ranked: Vec::new(),
+            result,
+        };
+
+        sampler.rescore();
+
+        sampler
+    }
+
+    /// The clusters with their current populations.
+    pub const fn result(&self) -> &QuantizerResult {
+        &self.result
+    }
+
+    /// Colors ranked by the last scoring, most suitable first.
+    pub fn ranked(&self) -> &[Argb] {
+        &self.ranked
+    }
+
+    /// Adds `pixels` to the population of their nearest clusters.
+    pub fn add_pixels(&mut self, pixels: &[Argb]) {
+        self.add_histogram(pixels.iter().map(|pixel| (*pixel, 1)));
+    }
+
+    /// Removes `pixels` from the population of their nearest clusters.
+    pub fn remove_pixels(&mut self, pixels: &[Argb]) {
+        self.remove_histogram(pixels.iter().map(|pixel| (*pixel, 1)));
+    }
+
+    /// Adds a histogram of colors and their counts, such as the
+    /// [`QuantizerResult::color_to_count`] of a [`QuantizerMap`] run on a tile.
+    ///
+    /// [`QuantizerMap`]: super::QuantizerMap
+    pub fn add_histogram<I: IntoIterator<Item = (Argb, u32)>>(&mut self, histogram: I) {
+        for (color, count) in histogram {
+            if let Some(population) = self.population_of(color) {
+                *population = population.saturating_add(count);
+            }
+        }
+    }
+
+    /// Removes a histogram of colors and their counts, saturating the
+    /// population of each cluster at 0.
+    pub fn remove_histogram<I: IntoIterator<Item = (Argb, u32)>>(&mut self, histogram: I) {
+        for (color, count) in histogram {
+            if let Some(population) = self.population_of(color) {
+                *population = population.saturating_sub(count);
+            }
+        }
+    }
+
+    /// Total variation distance between the proportions of the clusters now
+    /// and at the last scoring, between 0 and 1.
+    pub fn shift(&self) -> f64 {
+        let scored_total = self
+            .scored_counts
+            .iter()
+            .map(|&count| u64::from(count))
+            .sum::<u64>();
+        let total = self
+            .result
+            .color_to_count
+            .values()
+            .map(|&count| u64::from(count))
+            .sum::<u64>();
+
+        if scored_total == 0 || total == 0 {
+            return if scored_total == total { 0.0 } else { 1.0 };
+        }
+
+        let (scored_total, total) = (scored_total as f64, total as f64);
+
+        self.scored_counts
+            .iter()
+            .zip(self.result.color_to_count.values())
+            .map(|(&scored, &count)| {
+                (f64::from(scored) / scored_total - f64::from(count) / total).abs()
+            })
+            .sum::<f64>()
+            / 2.0
+    }
+
+    /// Scores the clusters again if their weights shifted by more than the
+    /// threshold since the last scoring.
+    ///
+    /// Returns whether [`IncrementalSampler::ranked`] was updated.
+    pub fn refresh(&mut self) -> bool {
+        if self.shift() <= self.threshold {
+            return false;
+        }
+
+        self.rescore();
+
+        true
+    }
+
+    fn rescore(&mut self) {
+        let populated = self
+            .result
+            .color_to_count
+            .iter()
+            .filter(|(_, &count)| count > 0)
+            .map(|(&color, &count)| (color, count))
+            .collect::<IndexMap<_, _>>();
+
+        self.ranked = Score::score(&populated, None, None, None);
+        self.scored_counts = self.result.color_to_count.values().copied().collect();
+    }
+
+    fn population_of(&mut self, color: Argb) -> Option<&mut u32> {
+        let cluster = if let Some(cluster) = self.result.input_pixel_to_cluster_pixel.get(&color) {
+            *cluster
+        } else {
+            let point = PointProviderLab::lab_from_int(&color);
+            let (index, _) = self
+                .points
+                .iter()
+                .enumerate()
+                .map(|(index, other)| (index, PointProviderLab::distance(&point, other)))
+                .min_by(|(_, a), (_, b)| a.total_cmp(b))?;
+
+            *self.result.color_to_count.get_index(index)?.0
+        };
+
+        self.result.color_to_count.get_mut(&cluster)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::IncrementalSampler;
+    use crate::{
+        color::Argb,
+        quantize::{Quantizer, QuantizerCelebi},
+    };
+    #[cfg(not(feature = "std"))]
+    use alloc::vec;
+    use float_cmp::assert_approx_eq;
+    #[cfg(feature = "std")]
+    use std::vec;
+
+    const RED: Argb = Argb::from_u32(0xffd01010);
+    const BLUE: Argb = Argb::from_u32(0xff1030d0);
+
+    fn sampler() -> IncrementalSampler {
+        let mut pixels = vec![RED; 300];
+
+        pixels.extend([BLUE; 100]);
+
+        IncrementalSampler::new(QuantizerCelebi::quantize(&pixels, 128), 0.2)
+    }
+
+    #[test]
+    fn test_small_changes_keep_ranking() {
+        let mut sampler = sampler();
+
+        assert_eq!(sampler.ranked()[0], RED);
+
+        sampler.remove_pixels(&[RED; 20]);
+        sampler.add_pixels(&[BLUE; 20]);
+
+        assert!(sampler.shift() > 0.0);
+        assert!(!sampler.refresh());
+        assert_eq!(sampler.ranked()[0], RED);
+    }
+
+    #[test]
+    fn test_large_changes_rescore() {
+        let mut sampler = sampler();
+        let known_colors = sampler.result().input_pixel_to_cluster_pixel.len();
+
+        sampler.remove_histogram([(RED, 250)]);
+        sampler.add_histogram([(Argb::from_u32(0xff1232d2), 250)]);
+
+        assert_eq!(sampler.result().color_to_count.get(&RED), Some(&50));
+        assert_eq!(sampler.result().color_to_count.get(&BLUE), Some(&350));
+        assert!(sampler.refresh());
+        assert_eq!(sampler.ranked()[0], BLUE);
+        assert_approx_eq!(f64, sampler.shift(), 0.0);
+        assert_eq!(
+            sampler.result().input_pixel_to_cluster_pixel.len(),
+            known_colors
+        );
+    }
+}
@@ -1,12 +1,20 @@
+#[cfg(feature = "std")]
+pub use auto::{AutoQuantizer, Calibration, QuantizerAuto};
+pub use incremental::IncrementalSampler;
 pub use point_provider::PointProvider;
 pub use point_provider_lab::PointProviderLab;
 pub use quantizer::Quantizer;
+pub use quantizer::QuantizerBudget;
 pub use quantizer::QuantizerResult;
 pub use quantizer_celebi::QuantizerCelebi;
 pub use quantizer_map::QuantizerMap;
 pub use quantizer_wsmeans::QuantizerWsmeans;
 pub use quantizer_wu::QuantizerWu;
+pub use stream::QuantizerStream;
 
+#[cfg(feature = "std")]
+pub mod auto;
+pub mod incremental;
 pub mod point_provider;
 pub mod point_provider_lab;
 pub mod quantizer;
@@ -14,3 +22,4 @@ pub mod quantizer_celebi;
 pub mod quantizer_map;
 pub mod quantizer_wsmeans;
 pub mod quantizer_wu;
+pub mod stream;
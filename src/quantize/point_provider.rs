@@ -1,7 +1,59 @@
 use crate::color::{Argb, Lab};
 
+/// Converts colors into the points quantizers cluster, and measures the
+/// distance between them.
+///
+/// Besides quantization, a provider answers nearest-color queries, e.g. to
+/// map icon colors onto the entries of a palette:
+///
+/// ```
+/// use material_colors::{
+///     color::Argb,
+///     quantize::{PointProvider, PointProviderLab},
+/// };
+///
+/// let palette = [
+///     Argb::from_u32(0xffffffff),
+///     Argb::from_u32(0xff4285f4),
+///     Argb::from_u32(0xffea4335),
+/// ];
+///
+/// assert_eq!(
+///     PointProviderLab::nearest(&Argb::from_u32(0xffcc2200), &palette),
+///     Some(2)
+/// );
+/// ```
 pub trait PointProvider {
+    /// Converts a color into a point.
+    #[doc(alias = "to_point")]
     fn lab_from_int(argb: &Argb) -> Lab;
+
+    /// Converts a point back into a color.
+    #[doc(alias = "from_point")]
     fn lab_to_int(lab: &Lab) -> Argb;
+
+    /// Returns a measure of the distance between two points.
+    ///
+    /// Only the ordering of distances is meaningful, so providers may return
+    /// e.g. the squared distance to save a square root.
     fn distance(one: &Lab, two: &Lab) -> f64;
+
+    /// Returns the index of the color in `colors` closest to `color`, or
+    /// `None` if `colors` is empty. The first of equally close colors wins.
+    fn nearest(color: &Argb, colors: &[Argb]) -> Option<usize> {
+        let point = Self::lab_from_int(color);
+
+        colors
+            .iter()
+            .map(|other| Self::distance(&point, &Self::lab_from_int(other)))
+            .enumerate()
+            .fold(
+                None,
+                |nearest: Option<(usize, f64)>, (index, distance)| match nearest {
+                    Some((_, nearest_distance)) if nearest_distance <= distance => nearest,
+                    _ => Some((index, distance)),
+                },
+            )
+            .map(|(index, _)| index)
+    }
 }
@@ -4,6 +4,21 @@ use crate::color::{Argb, Lab};
 #[allow(unused_imports)]
 use crate::utils::no_std::FloatExt;
 
+/// [`PointProvider`] using points in the L*a*b* color space, where distance
+/// is the square of the CIE 1976 color difference.
+///
+/// ```
+/// use material_colors::{
+///     color::Argb,
+///     quantize::{PointProvider, PointProviderLab},
+/// };
+///
+/// let red = PointProviderLab::lab_from_int(&Argb::from_u32(0xffff0000));
+/// let black = PointProviderLab::lab_from_int(&Argb::from_u32(0xff000000));
+///
+/// assert!((PointProviderLab::distance(&red, &black).sqrt() - 117.3).abs() < 0.1);
+/// assert_eq!(PointProviderLab::lab_to_int(&red), Argb::from_u32(0xffff0000));
+/// ```
 pub struct PointProviderLab;
 
 impl PointProvider for PointProviderLab {
@@ -28,3 +43,46 @@ impl PointProvider for PointProviderLab {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{PointProvider, PointProviderLab};
+    use crate::color::Argb;
+    use float_cmp::assert_approx_eq;
+
+    #[test]
+    fn test_distance() {
+        let white = PointProviderLab::lab_from_int(&Argb::from_u32(0xffffffff));
+        let black = PointProviderLab::lab_from_int(&Argb::from_u32(0xff000000));
+
+        assert_approx_eq!(
+            f64,
+            PointProviderLab::distance(&white, &black),
+            10000.0,
+            epsilon = 1e-6
+        );
+        assert_approx_eq!(f64, PointProviderLab::distance(&white, &white), 0.0);
+    }
+
+    #[test]
+    fn test_nearest() {
+        let colors = [
+            Argb::from_u32(0xff000000),
+            Argb::from_u32(0xff808080),
+            Argb::from_u32(0xff808080),
+        ];
+
+        assert_eq!(
+            PointProviderLab::nearest(&Argb::from_u32(0xff777777), &colors),
+            Some(1)
+        );
+        assert_eq!(
+            PointProviderLab::nearest(&Argb::from_u32(0xff111111), &colors),
+            Some(0)
+        );
+        assert_eq!(
+            PointProviderLab::nearest(&Argb::from_u32(0xff111111), &[]),
+            None
+        );
+    }
+}
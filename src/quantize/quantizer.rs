@@ -1,10 +1,128 @@
-use crate::{color::Argb, IndexMap};
+use crate::{color::Argb, hct::Hct, IndexMap};
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+use core::time::Duration;
+#[cfg(feature = "std")]
+use std::vec::Vec;
+
+#[cfg(feature = "fast-maps")]
+type Iter<'a> = indexmap::map::Iter<'a, Argb, u32>;
+#[cfg(not(feature = "fast-maps"))]
+type Iter<'a> = crate::utils::vec_map::Iter<'a, Argb, u32>;
 
 pub trait Quantizer {
     fn quantize(pixels: &[Argb], max_colors: usize) -> QuantizerResult;
+
+    /// Like [`Quantizer::quantize`], but takes the pixels from an iterator,
+    /// e.g. straight from an image decoder.
+    ///
+    /// The default implementation collects the pixels first.
+    /// [`QuantizerMap`], [`QuantizerWu`] and [`QuantizerCelebi`] count them
+    /// into a histogram as they arrive instead, so memory use grows with the
+    /// number of distinct colors rather than the size of the image.
+    ///
+    /// [`QuantizerMap`]: super::QuantizerMap
+    /// [`QuantizerWu`]: super::QuantizerWu
+    /// [`QuantizerCelebi`]: super::QuantizerCelebi
+    fn quantize_iter<I: IntoIterator<Item = Argb>>(
+        pixels: I,
+        max_colors: usize,
+    ) -> QuantizerResult {
+        Self::quantize(&pixels.into_iter().collect::<Vec<_>>(), max_colors)
+    }
+
+    /// Like [`Quantizer::quantize`], but takes palettized pixels, such as
+    /// those of GIF or PNG8 images: each pixel is an index into `palette`.
+    ///
+    /// The default implementation expands the pixels into their colors.
+    /// [`QuantizerMap`], [`QuantizerWu`] and [`QuantizerCelebi`] count the
+    /// indices instead, which is nearly free, and then work on the histogram
+    /// of at most 256 colors.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if an index is out of the bounds of `palette`.
+    ///
+    /// [`QuantizerMap`]: super::QuantizerMap
+    /// [`QuantizerWu`]: super::QuantizerWu
+    /// [`QuantizerCelebi`]: super::QuantizerCelebi
+    fn quantize_indexed(indices: &[u8], palette: &[Argb], max_colors: usize) -> QuantizerResult {
+        Self::quantize_iter(
+            indices.iter().map(|index| palette[usize::from(*index)]),
+            max_colors,
+        )
+    }
 }
 
 pub struct QuantizerResult {
+    /// Map of cluster colors to the number of pixels they represent.
+    ///
+    /// Quantizers that document it (e.g. [`QuantizerCelebi`]) return the
+    /// clusters in the order defined by [`QuantizerResult::sort`].
+    ///
+    /// [`QuantizerCelebi`]: super::QuantizerCelebi
     pub color_to_count: IndexMap<Argb, u32>,
     pub input_pixel_to_cluster_pixel: IndexMap<Argb, Argb>,
+    /// Whether an iterative quantizer ran out of a [`QuantizerBudget`] set by
+    /// the caller before converging. The clusters are still valid, but may be
+    /// less refined.
+    ///
+    /// Never set with [`QuantizerBudget::default`], which bounds the
+    /// iterations the same way the unbudgeted quantizers always have.
+    pub terminated_early: bool,
+}
+
+/// Limits on the work done by iterative quantizers such as
+/// [`QuantizerWsmeans`].
+///
+/// [`QuantizerWsmeans`]: super::QuantizerWsmeans
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QuantizerBudget {
+    /// Maximum number of iterations. At least one iteration is always run.
+    pub max_iterations: usize,
+    /// Maximum time to spend iterating, checked between iterations.
+    ///
+    /// Only enforced with the `std` feature, as measuring time requires a
+    /// clock.
+    pub time_limit: Option<Duration>,
+}
+
+impl<'a> IntoIterator for &'a QuantizerResult {
+    type Item = (&'a Argb, &'a u32);
+    type IntoIter = Iter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl Default for QuantizerBudget {
+    fn default() -> Self {
+        Self {
+            max_iterations: 10,
+            time_limit: None,
+        }
+    }
+}
+
+impl QuantizerResult {
+    /// Returns an iterator over [`QuantizerResult::color_to_count`]. The
+    /// iterator is exact size, double ended and fused.
+    pub fn iter(&self) -> Iter<'_> {
+        self.color_to_count.iter()
+    }
+
+    /// Sorts clusters into a stable, deterministic order.
+    ///
+    /// Clusters are ordered by population in descending order. Clusters with
+    /// equal population are ordered by hue in ascending order, and clusters
+    /// that also share a hue are ordered by their Argb value.
+    pub fn sort(&mut self) {
+        self.color_to_count.sort_by(|a, a_count, b, b_count| {
+            b_count
+                .cmp(a_count)
+                .then_with(|| Hct::new(*a).get_hue().total_cmp(&Hct::new(*b).get_hue()))
+                .then_with(|| a.cmp(b))
+        });
+    }
 }
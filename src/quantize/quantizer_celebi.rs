@@ -1,21 +1,96 @@
-use super::{Quantizer, QuantizerResult, QuantizerWsmeans, QuantizerWu};
-use crate::color::Argb;
+use super::{
+    Quantizer, QuantizerBudget, QuantizerMap, QuantizerResult, QuantizerWsmeans, QuantizerWu,
+};
+use crate::{color::Argb, IndexMap};
 #[cfg(not(feature = "std"))]
 use alloc::vec::Vec;
 #[cfg(feature = "std")]
 use std::vec::Vec;
 
+/// Image quantizer that runs [`QuantizerWu`] to produce starting clusters
+/// and refines them with [`QuantizerWsmeans`].
+///
+/// The returned clusters are always in the order defined by
+/// [`QuantizerResult::sort`]: population descending, then hue ascending.
 #[derive(Default)]
 pub struct QuantizerCelebi;
 
+impl QuantizerCelebi {
+    /// Like [`Quantizer::quantize`], but limits the refinement done by
+    /// [`QuantizerWsmeans`] to `budget`.
+    ///
+    /// See [`QuantizerWsmeans::quantize_with_budget`].
+    pub fn quantize_with_budget(
+        pixels: &[Argb],
+        max_colors: usize,
+        budget: &QuantizerBudget,
+    ) -> QuantizerResult {
+        Self::quantize_iter_with_budget(pixels.iter().copied(), max_colors, budget)
+    }
+
+    /// Like [`Quantizer::quantize_iter`], but limits the refinement done by
+    /// [`QuantizerWsmeans`] to `budget`.
+    pub fn quantize_iter_with_budget<I: IntoIterator<Item = Argb>>(
+        pixels: I,
+        max_colors: usize,
+        budget: &QuantizerBudget,
+    ) -> QuantizerResult {
+        let histogram = QuantizerMap::quantize_iter(pixels, max_colors).color_to_count;
+
+        Self::quantize_histogram(&histogram, max_colors, budget)
+    }
+
+    /// Like [`Quantizer::quantize_indexed`], but limits the refinement done
+    /// by [`QuantizerWsmeans`] to `budget`.
+    pub fn quantize_indexed_with_budget(
+        indices: &[u8],
+        palette: &[Argb],
+        max_colors: usize,
+        budget: &QuantizerBudget,
+    ) -> QuantizerResult {
+        let histogram = QuantizerMap::quantize_indexed(indices, palette, max_colors).color_to_count;
+
+        Self::quantize_histogram(&histogram, max_colors, budget)
+    }
+
+    pub(crate) fn quantize_histogram(
+        histogram: &IndexMap<Argb, u32>,
+        max_colors: usize,
+        budget: &QuantizerBudget,
+    ) -> QuantizerResult {
+        let wu_result = QuantizerWu::quantize_histogram(histogram.clone(), max_colors);
+
+        let mut result = QuantizerWsmeans::quantize_histogram(
+            histogram,
+            max_colors,
+            &wu_result.color_to_count.into_keys().collect::<Vec<_>>(),
+            budget,
+        );
+
+        result.sort();
+
+        result
+    }
+}
+
 impl Quantizer for QuantizerCelebi {
     fn quantize(pixels: &[Argb], max_colors: usize) -> QuantizerResult {
-        let wu_result = QuantizerWu::quantize(pixels, max_colors);
+        Self::quantize_with_budget(pixels, max_colors, &QuantizerBudget::default())
+    }
+
+    fn quantize_iter<I: IntoIterator<Item = Argb>>(
+        pixels: I,
+        max_colors: usize,
+    ) -> QuantizerResult {
+        Self::quantize_iter_with_budget(pixels, max_colors, &QuantizerBudget::default())
+    }
 
-        QuantizerWsmeans::quantize(
-            pixels,
+    fn quantize_indexed(indices: &[u8], palette: &[Argb], max_colors: usize) -> QuantizerResult {
+        Self::quantize_indexed_with_budget(
+            indices,
+            palette,
             max_colors,
-            &wu_result.color_to_count.into_keys().collect::<Vec<_>>(),
+            &QuantizerBudget::default(),
         )
     }
 }
@@ -195,4 +270,79 @@ mod tests {
 
         assert_eq!(result1, result2);
     }
+
+    #[test]
+    fn test_ordering() {
+        let result = QuantizerCelebi::quantize(&[BLUE, GREEN, RED, GREEN, GREEN], MAX_COLORS);
+        let colors = result.color_to_count.keys().collect::<Vec<_>>();
+
+        // GREEN has the largest population; RED and BLUE are ordered by hue.
+        assert_eq!(colors, [&GREEN, &RED, &BLUE]);
+
+        let result = QuantizerCelebi::quantize(&IMAGE_PIXELS, 16);
+        let counts = result.color_to_count.values().collect::<Vec<_>>();
+
+        assert!(counts.windows(2).all(|pair| pair[0] >= pair[1]));
+    }
+
+    #[test]
+    fn test_quantize_iter() {
+        let pixels = IMAGE_PIXELS.repeat(3);
+        let expected = QuantizerCelebi::quantize(&pixels, 16);
+        let result =
+            QuantizerCelebi::quantize_iter(IMAGE_PIXELS.iter().copied().cycle().take(84 * 3), 16);
+
+        assert_eq!(result.color_to_count, expected.color_to_count);
+        assert_eq!(
+            result.input_pixel_to_cluster_pixel,
+            expected.input_pixel_to_cluster_pixel
+        );
+    }
+
+    #[test]
+    fn test_quantize_indexed() {
+        let mut palette = Vec::new();
+        let indices = IMAGE_PIXELS
+            .iter()
+            .map(|pixel| {
+                let index = palette
+                    .iter()
+                    .position(|color| color == pixel)
+                    .unwrap_or_else(|| {
+                        palette.push(*pixel);
+
+                        palette.len() - 1
+                    });
+
+                index as u8
+            })
+            .collect::<Vec<_>>();
+        let expected = QuantizerCelebi::quantize(&IMAGE_PIXELS, 16);
+        let result = QuantizerCelebi::quantize_indexed(&indices, &palette, 16);
+
+        assert_eq!(result.color_to_count, expected.color_to_count);
+    }
+
+    #[test]
+    fn test_deterministic() {
+        // Same clusters with and without the `rayon` feature.
+        let pixels = (0..20_000_u32)
+            .map(|index| Argb::from_u32(0xff000000 | (index.wrapping_mul(2654435761) >> 8)))
+            .collect::<Vec<_>>();
+        let result = QuantizerCelebi::quantize(&pixels, 8);
+
+        assert_eq!(
+            result.color_to_count.into_iter().collect::<Vec<_>>(),
+            [
+                (Argb::from_u32(0xff5dcd51), 3346),
+                (Argb::from_u32(0xff585a77), 2844),
+                (Argb::from_u32(0xff653ccb), 2818),
+                (Argb::from_u32(0xffb8b14e), 2789),
+                (Argb::from_u32(0xffc44d3f), 2499),
+                (Argb::from_u32(0xffc548ac), 2337),
+                (Argb::from_u32(0xff5cbcae), 2169),
+                (Argb::from_u32(0xffb3b8ce), 1198),
+            ]
+        );
+    }
 }
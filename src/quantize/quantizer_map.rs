@@ -5,12 +5,19 @@ use crate::{color::Argb, IndexMap};
 pub struct QuantizerMap;
 
 impl Quantizer for QuantizerMap {
-    fn quantize(pixels: &[Argb], _max_colors: usize) -> QuantizerResult {
+    fn quantize(pixels: &[Argb], max_colors: usize) -> QuantizerResult {
+        Self::quantize_iter(pixels.iter().copied(), max_colors)
+    }
+
+    fn quantize_iter<I: IntoIterator<Item = Argb>>(
+        pixels: I,
+        _max_colors: usize,
+    ) -> QuantizerResult {
         let mut color_to_count = IndexMap::<Argb, u32>::default();
 
         for pixel in pixels {
             color_to_count
-                .entry(*pixel)
+                .entry(pixel)
                 .and_modify(|current_pixel_count| *current_pixel_count += 1)
                 .or_insert(1);
         }
@@ -18,6 +25,69 @@ impl Quantizer for QuantizerMap {
         QuantizerResult {
             color_to_count,
             input_pixel_to_cluster_pixel: IndexMap::default(),
+            terminated_early: false,
         }
     }
+
+    fn quantize_indexed(indices: &[u8], palette: &[Argb], _max_colors: usize) -> QuantizerResult {
+        let mut counts = [0u32; 256];
+
+        for index in indices {
+            counts[usize::from(*index)] += 1;
+        }
+
+        let mut color_to_count = IndexMap::<Argb, u32>::default();
+
+        for (index, count) in counts.into_iter().enumerate() {
+            if count == 0 {
+                continue;
+            }
+
+            // Palettes may repeat a color under several indices.
+            color_to_count
+                .entry(palette[index])
+                .and_modify(|current_pixel_count| *current_pixel_count += count)
+                .or_insert(count);
+        }
+
+        QuantizerResult {
+            color_to_count,
+            input_pixel_to_cluster_pixel: IndexMap::default(),
+            terminated_early: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::QuantizerMap;
+    use crate::{color::Argb, quantize::Quantizer};
+    #[cfg(not(feature = "std"))]
+    use alloc::vec::Vec;
+    #[cfg(feature = "std")]
+    use std::vec::Vec;
+
+    const RED: Argb = Argb::from_u32(0xffff0000);
+    const BLUE: Argb = Argb::from_u32(0xff0000ff);
+
+    #[test]
+    fn test_quantize_indexed() {
+        let palette = [RED, BLUE, RED, Argb::from_u32(0xff00ff00)];
+        let indices = [0, 1, 1, 2, 0, 1];
+        let result = QuantizerMap::quantize_indexed(&indices, &palette, 0);
+
+        assert_eq!(
+            result
+                .iter()
+                .map(|(color, count)| (*color, *count))
+                .collect::<Vec<_>>(),
+            [(RED, 3), (BLUE, 3)]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn test_quantize_indexed_out_of_bounds() {
+        let _ = QuantizerMap::quantize_indexed(&[2], &[RED, BLUE], 0);
+    }
 }
@@ -1,12 +1,16 @@
-use super::{PointProvider, PointProviderLab, QuantizerResult};
+use super::{PointProvider, PointProviderLab, QuantizerBudget, QuantizerResult};
 use crate::{
     color::{Argb, Lab},
-    utils::random::Random,
+    utils::random::{Random, RandomSource},
     IndexMap,
 };
 #[cfg(not(feature = "std"))]
 use alloc::{vec, vec::Vec};
 use core::cmp::Ordering;
+#[cfg(feature = "rayon")]
+use rayon::iter::{
+    IndexedParallelIterator, IntoParallelRefIterator, IntoParallelRefMutIterator, ParallelIterator,
+};
 #[cfg(feature = "std")]
 use std::{
     format,
@@ -73,23 +77,99 @@ impl QuantizerWsmeans {
         input_pixels: &[Argb],
         max_colors: usize,
         starting_clusters: &[Argb],
+    ) -> QuantizerResult {
+        Self::quantize_with_budget(
+            input_pixels,
+            max_colors,
+            starting_clusters,
+            &QuantizerBudget::default(),
+        )
+    }
+
+    /// Like [`QuantizerWsmeans::quantize`], but stops iterating once `budget`
+    /// is exhausted and returns the clusters found so far.
+    ///
+    /// [`QuantizerResult::terminated_early`] is set if the time limit or a
+    /// non-default iteration limit of `budget` stopped the clusters from
+    /// converging.
+    pub fn quantize_with_budget(
+        input_pixels: &[Argb],
+        max_colors: usize,
+        starting_clusters: &[Argb],
+        budget: &QuantizerBudget,
+    ) -> QuantizerResult {
+        Self::quantize_with_random(
+            input_pixels,
+            max_colors,
+            starting_clusters,
+            budget,
+            &mut Random::default(),
+        )
+    }
+
+    /// Like [`QuantizerWsmeans::quantize_with_budget`], but picks the
+    /// additional starting clusters with `random` instead of the default
+    /// [`Random`] source.
+    pub fn quantize_with_random<R: RandomSource>(
+        input_pixels: &[Argb],
+        max_colors: usize,
+        starting_clusters: &[Argb],
+        budget: &QuantizerBudget,
+        random: &mut R,
     ) -> QuantizerResult {
         let mut pixel_to_count: IndexMap<Argb, u32> = IndexMap::default();
-        let mut points: Vec<Lab> = vec![];
-        let mut pixels: Vec<Argb> = vec![];
 
         for input_pixel in input_pixels {
-            let pixel_count = pixel_to_count.get_mut(input_pixel);
-
-            if let Some(pixel_count) = pixel_count {
-                *pixel_count += 1;
-            } else {
-                pixels.push(*input_pixel);
-                points.push(PointProviderLab::lab_from_int(input_pixel));
-                pixel_to_count.insert(*input_pixel, 1);
-            }
+            pixel_to_count
+                .entry(*input_pixel)
+                .and_modify(|pixel_count| *pixel_count += 1)
+                .or_insert(1);
         }
 
+        Self::quantize_histogram_with_random(
+            &pixel_to_count,
+            max_colors,
+            starting_clusters,
+            budget,
+            random,
+        )
+    }
+
+    /// Like [`QuantizerWsmeans::quantize_with_budget`], but takes a histogram
+    /// of the pixels, such as the [`QuantizerResult::color_to_count`] of
+    /// [`QuantizerMap`], instead of the pixels themselves.
+    ///
+    /// [`QuantizerMap`]: super::QuantizerMap
+    pub fn quantize_histogram(
+        pixel_to_count: &IndexMap<Argb, u32>,
+        max_colors: usize,
+        starting_clusters: &[Argb],
+        budget: &QuantizerBudget,
+    ) -> QuantizerResult {
+        Self::quantize_histogram_with_random(
+            pixel_to_count,
+            max_colors,
+            starting_clusters,
+            budget,
+            &mut Random::default(),
+        )
+    }
+
+    fn quantize_histogram_with_random<R: RandomSource>(
+        pixel_to_count: &IndexMap<Argb, u32>,
+        max_colors: usize,
+        starting_clusters: &[Argb],
+        budget: &QuantizerBudget,
+        random: &mut R,
+    ) -> QuantizerResult {
+        #[cfg(feature = "std")]
+        let budget_start_time = Instant::now();
+        let pixels = pixel_to_count.keys().copied().collect::<Vec<_>>();
+        let points = pixels
+            .iter()
+            .map(PointProviderLab::lab_from_int)
+            .collect::<Vec<_>>();
+
         let cluster_count = max_colors.min(points.len());
 
         let mut clusters = starting_clusters
@@ -99,7 +179,6 @@ impl QuantizerWsmeans {
         let additional_clusters_needed = cluster_count - clusters.len();
 
         if additional_clusters_needed > 0 {
-            let mut seed_generator = Random::new(0x42688);
             let mut indices = vec![];
 
             for _ in 0..additional_clusters_needed {
@@ -119,10 +198,10 @@ impl QuantizerWsmeans {
                 // Rather than generate random centroids, we'll pick centroids that
                 // are actual pixels in the image, and avoid duplicating centroids.
 
-                let mut index = seed_generator.next_range(points.len() as i32) as usize;
+                let mut index = random.next_range(points.len() as i32) as usize;
 
                 while indices.contains(&index) {
-                    index = seed_generator.next_range(points.len() as i32) as usize;
+                    index = random.next_range(points.len() as i32) as usize;
                 }
 
                 indices.push(index);
@@ -148,8 +227,25 @@ impl QuantizerWsmeans {
                 fill_array(cluster_count, |index| DistanceAndIndex::new(0.0, index))
             });
         let mut pixel_count_sums = vec![0; cluster_count];
+        let mut converged = false;
+        let mut terminated_early = false;
+
+        for iteration in 0..budget.max_iterations.max(1) {
+            #[cfg(feature = "std")]
+            if iteration > 0
+                && budget
+                    .time_limit
+                    .map_or(false, |limit| budget_start_time.elapsed() >= limit)
+            {
+                Self::debug_log(format!(
+                    "ran out of time after {iteration} k-means iterations"
+                ));
+
+                terminated_early = true;
+
+                break;
+            }
 
-        for iteration in 0..10 {
             if Self::DEBUG {
                 for i in pixel_count_sums.iter_mut().take(cluster_count) {
                     *i = 0;
@@ -177,8 +273,6 @@ impl QuantizerWsmeans {
                 }
             }
 
-            let mut points_moved = 0;
-
             for i in 0..cluster_count {
                 for j in (i + 1)..cluster_count {
                     let distance = PointProviderLab::distance(&clusters[i], &clusters[j]);
@@ -196,11 +290,11 @@ impl QuantizerWsmeans {
                 }
             }
 
-            for i in 0..points.len() {
-                let point = points[i];
-                let previous_cluster_index = cluster_indices[i];
+            // Moves a point to its nearest cluster, returning whether it moved.
+            let reassign = |point: &Lab, cluster_index: &mut usize| {
+                let previous_cluster_index = *cluster_index;
                 let previous_cluster = clusters[previous_cluster_index];
-                let previous_distance = PointProviderLab::distance(&point, &previous_cluster);
+                let previous_distance = PointProviderLab::distance(point, &previous_cluster);
 
                 let mut minimum_distance = previous_distance;
                 let mut new_cluster_index = None;
@@ -212,7 +306,7 @@ impl QuantizerWsmeans {
                         continue;
                     }
 
-                    let distance = PointProviderLab::distance(&point, cluster);
+                    let distance = PointProviderLab::distance(point, cluster);
 
                     if distance < minimum_distance {
                         minimum_distance = distance;
@@ -220,16 +314,36 @@ impl QuantizerWsmeans {
                     }
                 }
 
-                if let Some(new_cluster_index) = new_cluster_index {
-                    points_moved += 1;
-                    cluster_indices[i] = new_cluster_index;
-                }
-            }
+                new_cluster_index.map_or(false, |new_cluster_index| {
+                    *cluster_index = new_cluster_index;
+
+                    true
+                })
+            };
+
+            // Points are reassigned independently of each other, so the
+            // parallel path gives the same clusters as the sequential one.
+            #[cfg(feature = "rayon")]
+            let points_moved = points
+                .par_iter()
+                .zip(cluster_indices.par_iter_mut())
+                .map(|(point, cluster_index)| reassign(point, cluster_index))
+                .filter(|moved| *moved)
+                .count();
+            #[cfg(not(feature = "rayon"))]
+            let points_moved = points
+                .iter()
+                .zip(cluster_indices.iter_mut())
+                .map(|(point, cluster_index)| reassign(point, cluster_index))
+                .filter(|moved| *moved)
+                .count();
 
             if points_moved == 0 && iteration > 0 {
                 #[cfg(feature = "std")]
                 Self::debug_log(format!("terminated after {iteration} k-means iterations"));
 
+                converged = true;
+
                 break;
             }
 
@@ -330,9 +444,16 @@ impl QuantizerWsmeans {
             color_to_count.insert(key, value);
         }
 
+        // Running out of the default iterations is how the algorithm always
+        // ended, so only an iteration limit set by the caller counts.
+        if !converged && budget.max_iterations != QuantizerBudget::default().max_iterations {
+            terminated_early = true;
+        }
+
         QuantizerResult {
             color_to_count,
             input_pixel_to_cluster_pixel,
+            terminated_early,
         }
     }
 }
@@ -350,9 +471,14 @@ fn fill_array<T>(count: usize, callback: impl Fn(usize) -> T) -> Vec<T> {
 #[cfg(test)]
 mod tests {
     use super::QuantizerWsmeans;
-    use crate::color::Argb;
+    use crate::{
+        color::Argb,
+        quantize::{Quantizer, QuantizerBudget, QuantizerMap},
+        utils::random::{Random, RandomSource},
+    };
     #[cfg(not(feature = "std"))]
     use alloc::vec::Vec;
+    use core::time::Duration;
     #[cfg(feature = "std")]
     use std::vec::Vec;
 
@@ -398,6 +524,101 @@ mod tests {
         assert_eq!(colors[0], &BLUE);
     }
 
+    struct Sequential(i32);
+
+    impl RandomSource for Sequential {
+        fn next_range(&mut self, range: i32) -> i32 {
+            self.0 += 1;
+
+            (self.0 - 1) % range
+        }
+    }
+
+    #[test]
+    fn test_random_source() {
+        let pixels = (0..64)
+            .map(|index| Argb::from_u32(0xff000000 | (index * 0x2f1b3d) & 0xffffff))
+            .collect::<Vec<_>>();
+        let budget = QuantizerBudget::default();
+
+        let default = QuantizerWsmeans::quantize_with_budget(&pixels, 8, &[], &budget);
+        let seeded = QuantizerWsmeans::quantize_with_random(
+            &pixels,
+            8,
+            &[],
+            &budget,
+            &mut Random::new(Random::DEFAULT_SEED),
+        );
+
+        assert_eq!(default.color_to_count, seeded.color_to_count);
+
+        let custom =
+            QuantizerWsmeans::quantize_with_random(&pixels, 8, &[], &budget, &mut Sequential(0));
+
+        assert_eq!(custom.color_to_count.values().sum::<u32>(), 64);
+        assert_eq!(
+            custom.color_to_count,
+            QuantizerWsmeans::quantize_with_random(&pixels, 8, &[], &budget, &mut Sequential(0))
+                .color_to_count
+        );
+    }
+
+    #[test]
+    fn test_budget() {
+        let pixels = (0..512)
+            .map(|index| Argb::from_u32(0xff000000 | (index * 0x2f1b3d) & 0xffffff))
+            .collect::<Vec<_>>();
+
+        let result = QuantizerWsmeans::quantize_with_budget(
+            &pixels,
+            16,
+            &[],
+            &QuantizerBudget {
+                max_iterations: 1,
+                time_limit: None,
+            },
+        );
+
+        assert!(result.terminated_early);
+        assert!(!result.color_to_count.is_empty());
+
+        let result = QuantizerWsmeans::quantize_with_budget(
+            &pixels,
+            16,
+            &[],
+            &QuantizerBudget {
+                max_iterations: 100,
+                time_limit: Some(Duration::ZERO),
+            },
+        );
+
+        #[cfg(feature = "std")]
+        assert!(result.terminated_early);
+        assert!(!result.color_to_count.is_empty());
+
+        let result = QuantizerWsmeans::quantize(&[RED, RED, GREEN, BLUE], MAX_COLORS, &[]);
+
+        assert!(!result.terminated_early);
+
+        let result = QuantizerWsmeans::quantize(&pixels, 16, &[]);
+
+        assert!(!result.terminated_early);
+    }
+
+    #[test]
+    fn test_quantize_histogram() {
+        let pixels = (0..512)
+            .map(|index| Argb::from_u32(0xff000000 | (index % 97 * 0x2f1b3d) & 0xffffff))
+            .collect::<Vec<_>>();
+        let histogram = QuantizerMap::quantize(&pixels, MAX_COLORS).color_to_count;
+        let budget = QuantizerBudget::default();
+
+        let expected = QuantizerWsmeans::quantize_with_budget(&pixels, 16, &[], &budget);
+        let result = QuantizerWsmeans::quantize_histogram(&histogram, 16, &[], &budget);
+
+        assert_eq!(result.color_to_count, expected.color_to_count);
+    }
+
     #[test]
     fn test_5b() {
         let result = QuantizerWsmeans::quantize(&[BLUE, BLUE, BLUE, BLUE, BLUE], MAX_COLORS, &[]);
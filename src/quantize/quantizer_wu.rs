@@ -10,7 +10,12 @@ use crate::{
 };
 #[cfg(not(feature = "std"))]
 use alloc::{vec, vec::Vec};
-use core::fmt;
+use core::{
+    fmt,
+    ops::{Add, AddAssign},
+};
+#[cfg(feature = "rayon")]
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
 #[cfg(feature = "std")]
 use std::{vec, vec::Vec};
 
@@ -53,13 +58,38 @@ impl QuantizerWu {
 
 impl Quantizer for QuantizerWu {
     fn quantize(pixels: &[Argb], max_colors: usize) -> QuantizerResult {
-        let mut result = QuantizerMap::quantize(pixels, max_colors);
+        Self::quantize_iter(pixels.iter().copied(), max_colors)
+    }
+
+    fn quantize_iter<I: IntoIterator<Item = Argb>>(
+        pixels: I,
+        max_colors: usize,
+    ) -> QuantizerResult {
+        let result = QuantizerMap::quantize_iter(pixels, max_colors);
 
-        result.color_to_count.sort_by(|_, a, _, b| a.cmp(b));
+        Self::quantize_histogram(result.color_to_count, max_colors)
+    }
+
+    fn quantize_indexed(indices: &[u8], palette: &[Argb], max_colors: usize) -> QuantizerResult {
+        let result = QuantizerMap::quantize_indexed(indices, palette, max_colors);
+
+        Self::quantize_histogram(result.color_to_count, max_colors)
+    }
+}
+
+impl QuantizerWu {
+    /// Like [`Quantizer::quantize`], but takes a histogram of the pixels,
+    /// such as the [`QuantizerResult::color_to_count`] of [`QuantizerMap`],
+    /// instead of the pixels themselves.
+    pub fn quantize_histogram(
+        mut pixels: IndexMap<Argb, u32>,
+        max_colors: usize,
+    ) -> QuantizerResult {
+        pixels.sort_by(|_, a, _, b| a.cmp(b));
 
         let mut quantizer = Self::new(max_colors);
 
-        quantizer.construct_histogram(result.color_to_count);
+        quantizer.construct_histogram(pixels);
         quantizer.compute_moments();
 
         let create_boxes_result = quantizer.create_boxes(max_colors);
@@ -68,6 +98,7 @@ impl Quantizer for QuantizerWu {
         QuantizerResult {
             color_to_count,
             input_pixel_to_cluster_pixel: IndexMap::default(),
+            terminated_early: false,
         }
     }
 }
@@ -107,45 +138,32 @@ impl QuantizerWu {
         }
     }
 
+    /// Turns the histogram into cumulative moments. With the `rayon`
+    /// feature, the five moments are accumulated in parallel, each in the
+    /// same order as sequentially, so results are identical.
     pub fn compute_moments(&mut self) {
-        for r in 1..SIDE_LENGTH {
-            let mut area = [0; SIDE_LENGTH];
-            let mut area_r = [0; SIDE_LENGTH];
-            let mut area_g = [0; SIDE_LENGTH];
-            let mut area_b = [0; SIDE_LENGTH];
-            let mut area2 = [0.0; SIDE_LENGTH];
-
-            for g in 1..SIDE_LENGTH {
-                let mut line = 0;
-                let mut line_r = 0;
-                let mut line_g = 0;
-                let mut line_b = 0;
-                let mut line2 = 0.0;
-
-                for b in 1..SIDE_LENGTH {
-                    let index = Self::get_index(r, g, b);
-
-                    line += self.weights[index];
-                    line_r += self.moments_r[index];
-                    line_g += self.moments_g[index];
-                    line_b += self.moments_b[index];
-                    line2 += self.moments[index];
-
-                    area[b] += line;
-                    area_r[b] += line_r;
-                    area_g[b] += line_g;
-                    area_b[b] += line_b;
-                    area2[b] += line2;
-
-                    let previous_index = Self::get_index(r - 1, g, b);
-
-                    self.weights[index] = self.weights[previous_index] + area[b];
-                    self.moments_r[index] = self.moments_r[previous_index] + area_r[b];
-                    self.moments_g[index] = self.moments_g[previous_index] + area_g[b];
-                    self.moments_b[index] = self.moments_b[previous_index] + area_b[b];
-                    self.moments[index] = self.moments[previous_index] + area2[b];
-                }
-            }
+        #[cfg(feature = "rayon")]
+        rayon::join(
+            || {
+                [
+                    &mut self.weights,
+                    &mut self.moments_r,
+                    &mut self.moments_g,
+                    &mut self.moments_b,
+                ]
+                .into_par_iter()
+                .for_each(|moment| accumulate(moment));
+            },
+            || accumulate(&mut self.moments),
+        );
+
+        #[cfg(not(feature = "rayon"))]
+        {
+            accumulate(&mut self.weights);
+            accumulate(&mut self.moments_r);
+            accumulate(&mut self.moments_g);
+            accumulate(&mut self.moments_b);
+            accumulate(&mut self.moments);
         }
     }
 
@@ -506,6 +524,26 @@ impl Cube {
     }
 }
 
+/// Replaces every cell of `moment` with the sum of the cells before it
+/// along the three axes of the histogram.
+fn accumulate<T: Copy + Default + Add<Output = T> + AddAssign>(moment: &mut [T]) {
+    for r in 1..SIDE_LENGTH {
+        let mut area = [T::default(); SIDE_LENGTH];
+
+        for g in 1..SIDE_LENGTH {
+            let mut line = T::default();
+
+            for (b, area) in area.iter_mut().enumerate().skip(1) {
+                let index = QuantizerWu::get_index(r, g, b);
+
+                line += moment[index];
+                *area += line;
+                moment[index] = moment[QuantizerWu::get_index(r - 1, g, b)] + *area;
+            }
+        }
+    }
+}
+
 impl fmt::Display for Cube {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
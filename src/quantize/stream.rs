@@ -0,0 +1,116 @@
+use super::{QuantizerBudget, QuantizerCelebi, QuantizerResult};
+use crate::{color::Argb, IndexMap};
+
+/// Quantizes an image with [`QuantizerCelebi`] as its pixels arrive in
+/// chunks, e.g. scanlines of a large wallpaper decoded a few rows at a time,
+/// without holding every pixel in memory.
+///
+/// Pushed pixels are counted into a histogram, so memory use grows with the
+/// number of distinct colors rather than the size of the image. The result
+/// of [`QuantizerStream::finish`] is the one [`QuantizerCelebi`] returns for
+/// all the pushed pixels at once.
+///
+/// ```
+/// use material_colors::{color::Argb, quantize::QuantizerStream};
+///
+/// let mut stream = QuantizerStream::new(128);
+///
+/// for row in [[Argb::from_u32(0xff4285f4); 64], [Argb::from_u32(0xffdb4437); 64]] {
+///     stream.push_pixels(&row);
+/// }
+///
+/// assert_eq!(stream.pixel_count(), 128);
+/// assert_eq!(stream.finish().color_to_count.len(), 2);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QuantizerStream {
+    histogram: IndexMap<Argb, u32>,
+    pixel_count: u64,
+    max_colors: usize,
+    budget: QuantizerBudget,
+}
+
+impl QuantizerStream {
+    /// Creates a stream quantizing into at most `max_colors` clusters.
+    pub fn new(max_colors: usize) -> Self {
+        Self {
+            histogram: IndexMap::default(),
+            pixel_count: 0,
+            max_colors,
+            budget: QuantizerBudget::default(),
+        }
+    }
+
+    /// Limits the refinement done by [`QuantizerStream::finish`], see
+    /// [`QuantizerCelebi::quantize_with_budget`].
+    #[must_use]
+    pub const fn with_budget(mut self, budget: QuantizerBudget) -> Self {
+        self.budget = budget;
+
+        self
+    }
+
+    /// Adds `pixels` to the image.
+    pub fn push_pixels(&mut self, pixels: &[Argb]) {
+        for pixel in pixels {
+            self.histogram
+                .entry(*pixel)
+                .and_modify(|count| *count = count.saturating_add(1))
+                .or_insert(1);
+        }
+
+        self.pixel_count += pixels.len() as u64;
+    }
+
+    /// Count of pixels pushed so far.
+    pub const fn pixel_count(&self) -> u64 {
+        self.pixel_count
+    }
+
+    /// Count of distinct colors pushed so far, which memory use grows with.
+    pub fn distinct_colors(&self) -> usize {
+        self.histogram.len()
+    }
+
+    /// Quantizes the pushed pixels.
+    pub fn finish(self) -> QuantizerResult {
+        QuantizerCelebi::quantize_histogram(&self.histogram, self.max_colors, &self.budget)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::QuantizerStream;
+    use crate::{
+        color::Argb,
+        quantize::{Quantizer, QuantizerCelebi},
+    };
+    #[cfg(not(feature = "std"))]
+    use alloc::vec::Vec;
+    #[cfg(feature = "std")]
+    use std::vec::Vec;
+
+    #[test]
+    fn test_matches_celebi() {
+        let pixels = (0..4096_u32)
+            .map(|index| Argb::from_u32(0xff000000 | (index.wrapping_mul(2654435761) >> 8)))
+            .collect::<Vec<_>>();
+        let mut stream = QuantizerStream::new(16);
+
+        for row in pixels.chunks(100) {
+            stream.push_pixels(row);
+        }
+
+        assert_eq!(stream.pixel_count(), 4096);
+        assert_eq!(stream.distinct_colors(), 4096);
+        assert_eq!(
+            stream.finish().color_to_count,
+            QuantizerCelebi::quantize(&pixels, 16).color_to_count
+        );
+    }
+
+    #[test]
+    fn test_empty() {
+        assert!(QuantizerStream::new(16).finish().color_to_count.is_empty());
+    }
+}
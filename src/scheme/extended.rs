@@ -0,0 +1,149 @@
+use super::{Scheme, SchemeRole};
+use crate::{color::Argb, Error};
+#[cfg(not(feature = "std"))]
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+};
+use core::str::FromStr;
+#[cfg(feature = "std")]
+use std::{
+    collections::BTreeMap,
+    string::{String, ToString},
+};
+
+/// A [`Scheme`] imported from a token map, along with the tokens that are
+/// not scheme roles, so that design system extensions survive an
+/// import, modify and export round trip.
+///
+/// ```
+/// use material_colors::{color::Argb, scheme::ExtendedScheme, theme::ThemeBuilder};
+///
+/// let theme = ThemeBuilder::with_source(Argb::from_u32(0xff4285f4)).build();
+/// let mut tokens = ExtendedScheme::from(theme.schemes.light).to_tokens();
+///
+/// tokens.insert("brand_gradient_end".into(), "#ff00aa".into());
+///
+/// let mut scheme = ExtendedScheme::from_tokens(tokens).unwrap();
+///
+/// scheme.scheme.primary = Argb::from_u32(0xff0b57d0);
+///
+/// let tokens = scheme.to_tokens();
+///
+/// assert_eq!(tokens["primary"], "#0b57d0");
+/// assert_eq!(tokens["brand_gradient_end"], "#ff00aa");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExtendedScheme {
+    pub scheme: Scheme,
+    /// Tokens whose names are not scheme roles, with their values as-is.
+    pub extra: BTreeMap<String, String>,
+}
+
+impl ExtendedScheme {
+    /// Creates a scheme from tokens named after the snake case names of
+    /// [`SchemeRole`]s, with colors in any form accepted by
+    /// [`Argb::from_str`]. Other tokens are kept in
+    /// [`ExtendedScheme::extra`], whatever their values.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::ParseRGB`] if the color of a role is invalid, and
+    /// [`Error::MissingToken`] with the name of the first role without a
+    /// token.
+    pub fn from_tokens<I, K, V>(tokens: I) -> Result<Self, Error>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<String>,
+    {
+        let mut scheme = Scheme::default();
+        let mut found = [false; SchemeRole::ALL.len()];
+        let mut extra = BTreeMap::new();
+
+        for (name, value) in tokens {
+            let (name, value) = (name.into(), value.into());
+
+            if let Ok(role) = SchemeRole::try_from(name.as_str()) {
+                scheme[role] = Argb::from_str(&value)?;
+                found[role as usize] = true;
+            } else {
+                extra.insert(name, value);
+            }
+        }
+
+        if let Some(index) = found.iter().position(|found| !found) {
+            return Err(Error::MissingToken(
+                SchemeRole::ALL[index].name().to_string(),
+            ));
+        }
+
+        Ok(Self { scheme, extra })
+    }
+
+    /// Returns the colors of every role as `#rrggbb`, merged with the extra
+    /// tokens.
+    pub fn to_tokens(&self) -> BTreeMap<String, String> {
+        let mut tokens = self.extra.clone();
+
+        tokens.extend(
+            self.scheme
+                .clone()
+                .into_iter()
+                .map(|(role, color)| (role, color.to_hex_with_pound())),
+        );
+
+        tokens
+    }
+}
+
+impl From<Scheme> for ExtendedScheme {
+    fn from(scheme: Scheme) -> Self {
+        Self {
+            scheme,
+            extra: BTreeMap::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ExtendedScheme;
+    use crate::{color::Argb, theme::ThemeBuilder, Error};
+
+    #[test]
+    fn test_round_trip() {
+        let theme = ThemeBuilder::with_source(Argb::from_u32(0xff4285f4)).build();
+        let mut tokens = ExtendedScheme::from(theme.schemes.dark.clone()).to_tokens();
+
+        tokens.insert("success".into(), "#1b873f".into());
+        tokens.insert("elevation_shadow".into(), "rgba(0, 0, 0, 0.3)".into());
+
+        let scheme = ExtendedScheme::from_tokens(tokens.clone()).unwrap();
+
+        assert_eq!(scheme.scheme, theme.schemes.dark);
+        assert_eq!(scheme.extra.len(), 2);
+        assert_eq!(scheme.extra["elevation_shadow"], "rgba(0, 0, 0, 0.3)");
+        assert_eq!(scheme.to_tokens(), tokens);
+    }
+
+    #[test]
+    fn test_invalid_tokens() {
+        let theme = ThemeBuilder::with_source(Argb::from_u32(0xff4285f4)).build();
+        let mut tokens = ExtendedScheme::from(theme.schemes.light).to_tokens();
+
+        tokens.insert("primary".into(), "blue".into());
+
+        assert_eq!(
+            ExtendedScheme::from_tokens(tokens.clone()),
+            Err(Error::ParseRGB)
+        );
+
+        tokens.remove("primary");
+
+        assert_eq!(
+            ExtendedScheme::from_tokens(tokens),
+            Err(Error::MissingToken("primary".into()))
+        );
+    }
+}
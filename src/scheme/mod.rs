@@ -1,17 +1,35 @@
 #![allow(clippy::too_many_arguments, deprecated)]
-use crate::{color::Argb, dynamic_color::DynamicScheme, palette::CorePalette, Map};
+use crate::{
+    blend::cam16_ucs, color::Argb, dynamic_color::DynamicScheme, palette::CorePalette, Map,
+};
 #[cfg(not(feature = "std"))]
-use alloc::string::String;
-use core::{array::IntoIter, fmt};
+use alloc::{string::String, vec::Vec};
+use core::{array::IntoIter, fmt, mem};
 #[cfg(feature = "serde")]
 use serde::Serialize;
 #[cfg(feature = "std")]
-use std::string::String;
+use std::{string::String, vec::Vec};
 
+pub use extended::ExtendedScheme;
+pub use pair::Pair;
+pub use role::{CrateVersion, Iter, SchemeRole, TargetProfile};
+
+mod extended;
+mod pair;
+mod role;
 pub mod variant;
 
-#[derive(Debug, PartialEq, Eq)]
+/// Colors of every [`SchemeRole`].
+///
+/// ## Layout
+///
+/// Has the layout of `[Argb; 49]`, with the roles in the order of
+/// [`SchemeRole::ALL`], i.e. `49 * 4` bytes aligned to 1 byte. This is part of
+/// the public API and checked at compile time, so that the scheme can be
+/// copied as-is into memory shared with C code or firmware.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
+#[repr(C)]
 pub struct Scheme {
     pub primary: Argb,
     pub on_primary: Argb,
@@ -64,6 +82,11 @@ pub struct Scheme {
     pub scrim: Argb,
 }
 
+const _: () = assert!(
+    mem::size_of::<Scheme>() == SchemeRole::ALL.len() * mem::size_of::<Argb>()
+        && mem::align_of::<Scheme>() == mem::align_of::<Argb>()
+);
+
 impl fmt::Display for Scheme {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         fmt::Debug::fmt(&self, f)
@@ -176,6 +199,91 @@ impl Scheme {
     }
 }
 
+impl Scheme {
+    /// Interpolates every role between `self` and `other` in the CAM16-UCS
+    /// color space, which keeps intermediate colors perceptually even.
+    ///
+    /// `amount` ranges from 0.0 (`self`) to 1.0 (`other`).
+    #[must_use]
+    pub fn lerp(&self, other: &Self, amount: f64) -> Self {
+        self.zip_with(other, |from, to| cam16_ucs(from, to, amount))
+    }
+
+    /// Combines each role of `self` with the same role of `other` using `f`.
+    #[must_use]
+    pub fn zip_with<F>(&self, other: &Self, f: F) -> Self
+    where
+        F: Fn(Argb, Argb) -> Argb,
+    {
+        Self {
+            primary: f(self.primary, other.primary),
+            on_primary: f(self.on_primary, other.on_primary),
+            primary_container: f(self.primary_container, other.primary_container),
+            on_primary_container: f(self.on_primary_container, other.on_primary_container),
+            inverse_primary: f(self.inverse_primary, other.inverse_primary),
+            primary_fixed: f(self.primary_fixed, other.primary_fixed),
+            primary_fixed_dim: f(self.primary_fixed_dim, other.primary_fixed_dim),
+            on_primary_fixed: f(self.on_primary_fixed, other.on_primary_fixed),
+            on_primary_fixed_variant: f(
+                self.on_primary_fixed_variant,
+                other.on_primary_fixed_variant,
+            ),
+            secondary: f(self.secondary, other.secondary),
+            on_secondary: f(self.on_secondary, other.on_secondary),
+            secondary_container: f(self.secondary_container, other.secondary_container),
+            on_secondary_container: f(self.on_secondary_container, other.on_secondary_container),
+            secondary_fixed: f(self.secondary_fixed, other.secondary_fixed),
+            secondary_fixed_dim: f(self.secondary_fixed_dim, other.secondary_fixed_dim),
+            on_secondary_fixed: f(self.on_secondary_fixed, other.on_secondary_fixed),
+            on_secondary_fixed_variant: f(
+                self.on_secondary_fixed_variant,
+                other.on_secondary_fixed_variant,
+            ),
+            tertiary: f(self.tertiary, other.tertiary),
+            on_tertiary: f(self.on_tertiary, other.on_tertiary),
+            tertiary_container: f(self.tertiary_container, other.tertiary_container),
+            on_tertiary_container: f(self.on_tertiary_container, other.on_tertiary_container),
+            tertiary_fixed: f(self.tertiary_fixed, other.tertiary_fixed),
+            tertiary_fixed_dim: f(self.tertiary_fixed_dim, other.tertiary_fixed_dim),
+            on_tertiary_fixed: f(self.on_tertiary_fixed, other.on_tertiary_fixed),
+            on_tertiary_fixed_variant: f(
+                self.on_tertiary_fixed_variant,
+                other.on_tertiary_fixed_variant,
+            ),
+            error: f(self.error, other.error),
+            on_error: f(self.on_error, other.on_error),
+            error_container: f(self.error_container, other.error_container),
+            on_error_container: f(self.on_error_container, other.on_error_container),
+            surface_dim: f(self.surface_dim, other.surface_dim),
+            surface: f(self.surface, other.surface),
+            surface_tint: f(self.surface_tint, other.surface_tint),
+            surface_bright: f(self.surface_bright, other.surface_bright),
+            surface_container_lowest: f(
+                self.surface_container_lowest,
+                other.surface_container_lowest,
+            ),
+            surface_container_low: f(self.surface_container_low, other.surface_container_low),
+            surface_container: f(self.surface_container, other.surface_container),
+            surface_container_high: f(self.surface_container_high, other.surface_container_high),
+            surface_container_highest: f(
+                self.surface_container_highest,
+                other.surface_container_highest,
+            ),
+            on_surface: f(self.on_surface, other.on_surface),
+            on_surface_variant: f(self.on_surface_variant, other.on_surface_variant),
+            outline: f(self.outline, other.outline),
+            outline_variant: f(self.outline_variant, other.outline_variant),
+            inverse_surface: f(self.inverse_surface, other.inverse_surface),
+            inverse_on_surface: f(self.inverse_on_surface, other.inverse_on_surface),
+            surface_variant: f(self.surface_variant, other.surface_variant),
+            background: f(self.background, other.background),
+            on_background: f(self.on_background, other.on_background),
+            shadow: f(self.shadow, other.shadow),
+            scrim: f(self.scrim, other.scrim),
+        }
+    }
+}
+
 impl From<DynamicScheme> for Scheme {
     fn from(scheme: DynamicScheme) -> Self {
         Self::new(
@@ -308,6 +416,87 @@ impl IntoIterator for Scheme {
     }
 }
 
+/// Roles deprecated by the Material spec, paired with the role that replaces
+/// them.
+pub const DEPRECATED_ROLES: [(&str, &str); 3] = [
+    ("background", "surface"),
+    ("on_background", "on_surface"),
+    ("surface_variant", "surface_container_highest"),
+];
+
+/// Resolves a deprecated role name to the role that replaces it.
+///
+/// Names that are not deprecated are returned unchanged.
+pub fn modern_role(role: &str) -> &str {
+    DEPRECATED_ROLES
+        .iter()
+        .find(|(deprecated, _)| *deprecated == role)
+        .map_or(role, |(_, replacement)| replacement)
+}
+
+/// Set of roles targeted by a token consumer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RoleSet {
+    /// Every role, including the ones listed in [`DEPRECATED_ROLES`].
+    Legacy,
+    /// Only roles that are not deprecated.
+    Modern,
+}
+
+impl Scheme {
+    /// Returns the roles of the scheme belonging to `set`, in iteration order.
+    pub fn role_set(&self, set: RoleSet) -> Vec<(String, Argb)> {
+        self.clone()
+            .into_iter()
+            .filter(|(role, _)| set == RoleSet::Legacy || modern_role(role) == role)
+            .collect()
+    }
+}
+
+/// Width of a swatch in the ANSI previews, fits the longest role name.
+#[cfg(feature = "std")]
+const ANSI_SWATCH_WIDTH: usize = 28;
+
+/// Writes `label` padded to [`ANSI_SWATCH_WIDTH`] on a 24-bit `color`
+/// background, in black or white text, whichever is more legible.
+#[cfg(feature = "std")]
+pub(crate) fn write_ansi_swatch<W: std::io::Write>(
+    writer: &mut W,
+    color: Argb,
+    label: &str,
+) -> std::io::Result<()> {
+    let foreground = if color.as_lstar() > 60.0 { 0 } else { 255 };
+
+    write!(
+        writer,
+        "\x1b[48;2;{};{};{}m\x1b[38;2;{foreground};{foreground};{foreground}m {label:<width$}\x1b[0m",
+        color.red,
+        color.green,
+        color.blue,
+        width = ANSI_SWATCH_WIDTH - 1
+    )
+}
+
+impl Scheme {
+    /// Writes a preview of the scheme for terminals supporting 24-bit color:
+    /// one swatch per role, labelled with its name.
+    ///
+    /// Useful for showing a generated scheme from a CLI, e.g. over SSH.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error returned by `writer`.
+    #[cfg(feature = "std")]
+    pub fn to_ansi_truecolor<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        for (role, color) in self.clone() {
+            write_ansi_swatch(writer, color, &role)?;
+            writeln!(writer)?;
+        }
+
+        Ok(())
+    }
+}
+
 impl From<Scheme> for Map<String, String> {
     fn from(value: Scheme) -> Self {
         let map: Map<String, Argb> = Map::from_iter(value);
@@ -446,8 +635,63 @@ impl SchemeFromPalette {
 
 #[cfg(test)]
 mod tests {
-    use crate::{color::Argb, scheme::SchemeFromPalette};
+    use crate::{
+        color::Argb,
+        scheme::{modern_role, RoleSet, SchemeFromPalette},
+        theme::ThemeBuilder,
+    };
     use float_cmp::assert_approx_eq;
+    #[cfg(feature = "std")]
+    use std::{string::String, vec::Vec};
+
+    #[test]
+    fn test_lerp() {
+        let from = ThemeBuilder::with_source(Argb::from_u32(0xffff0000)).build();
+        let to = ThemeBuilder::with_source(Argb::from_u32(0xff0000ff)).build();
+
+        assert_eq!(
+            from.schemes.light.lerp(&to.schemes.light, 0.0),
+            from.schemes.light
+        );
+        assert_eq!(
+            from.schemes.light.lerp(&to.schemes.light, 1.0),
+            to.schemes.light
+        );
+
+        let middle = from.schemes.light.lerp(&to.schemes.light, 0.5);
+
+        assert_ne!(middle.primary, from.schemes.light.primary);
+        assert_ne!(middle.primary, to.schemes.light.primary);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_to_ansi_truecolor() {
+        let theme = ThemeBuilder::with_source(Argb::from_u32(0xffff0000)).build();
+        let mut output = Vec::new();
+
+        theme.schemes.light.to_ansi_truecolor(&mut output).unwrap();
+
+        let output = String::from_utf8(output).unwrap();
+
+        assert_eq!(output.lines().count(), 49);
+        assert!(output.starts_with("\x1b[48;2;144;75;64m\x1b[38;2;255;255;255m primary "));
+        assert!(output.lines().all(|line| line.ends_with("\x1b[0m")));
+    }
+
+    #[test]
+    fn test_role_sets() {
+        let theme = ThemeBuilder::with_source(Argb::from_u32(0xffff0000)).build();
+        let legacy = theme.schemes.light.role_set(RoleSet::Legacy);
+        let modern = theme.schemes.light.role_set(RoleSet::Modern);
+
+        assert_eq!(legacy.len(), 49);
+        assert_eq!(modern.len(), 46);
+        assert!(modern.iter().all(|(role, _)| role != "background"));
+        assert_eq!(modern_role("background"), "surface");
+        assert_eq!(modern_role("surface_variant"), "surface_container_highest");
+        assert_eq!(modern_role("primary"), "primary");
+    }
 
     #[test]
     fn test_surface_tones() {
@@ -0,0 +1,136 @@
+use super::{Scheme, SchemeRole};
+use crate::color::Argb;
+
+/// A component context with the foreground and background roles the
+/// Material 3 component specs pair in it.
+///
+/// Use [`Scheme::pair`] to get both colors at once instead of picking roles
+/// by hand, which is how text ends up on a background it was never checked
+/// against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Pair {
+    /// Filled button.
+    PrimaryButton,
+    /// Filled tonal button.
+    TonalButton,
+    /// Outlined and text buttons, whose label sits on the surface.
+    TextButton,
+    /// Floating action button.
+    Fab,
+    /// Unselected assist, filter and suggestion chips.
+    Chip,
+    /// Selected filter chip.
+    SelectedChip,
+    /// Elevated card.
+    Card,
+    /// Dialog.
+    Dialog,
+    /// Snackbar supporting text.
+    Snackbar,
+    /// Snackbar action button.
+    SnackbarAction,
+    /// Plain tooltip.
+    Tooltip,
+    /// Top app bar.
+    TopAppBar,
+    /// Navigation bar, with inactive icons and labels.
+    NavigationBar,
+    /// Active indicator of navigation bars, rails and drawers.
+    NavigationIndicator,
+    /// Badge.
+    Badge,
+    /// Error message container, e.g. a banner.
+    ErrorContainer,
+}
+
+impl Pair {
+    pub const ALL: [Self; 16] = [
+        Self::PrimaryButton,
+        Self::TonalButton,
+        Self::TextButton,
+        Self::Fab,
+        Self::Chip,
+        Self::SelectedChip,
+        Self::Card,
+        Self::Dialog,
+        Self::Snackbar,
+        Self::SnackbarAction,
+        Self::Tooltip,
+        Self::TopAppBar,
+        Self::NavigationBar,
+        Self::NavigationIndicator,
+        Self::Badge,
+        Self::ErrorContainer,
+    ];
+
+    /// Returns the (foreground, background) roles of the pair.
+    pub const fn roles(self) -> (SchemeRole, SchemeRole) {
+        match self {
+            Self::PrimaryButton => (SchemeRole::OnPrimary, SchemeRole::Primary),
+            Self::TextButton => (SchemeRole::Primary, SchemeRole::Surface),
+            Self::Fab => (SchemeRole::OnPrimaryContainer, SchemeRole::PrimaryContainer),
+            Self::Chip => (SchemeRole::OnSurfaceVariant, SchemeRole::Surface),
+            Self::TonalButton | Self::SelectedChip | Self::NavigationIndicator => (
+                SchemeRole::OnSecondaryContainer,
+                SchemeRole::SecondaryContainer,
+            ),
+            Self::Card => (SchemeRole::OnSurface, SchemeRole::SurfaceContainerLow),
+            Self::Dialog => (SchemeRole::OnSurface, SchemeRole::SurfaceContainerHigh),
+            Self::Snackbar | Self::Tooltip => {
+                (SchemeRole::InverseOnSurface, SchemeRole::InverseSurface)
+            }
+            Self::SnackbarAction => (SchemeRole::InversePrimary, SchemeRole::InverseSurface),
+            Self::TopAppBar => (SchemeRole::OnSurface, SchemeRole::Surface),
+            Self::NavigationBar => (SchemeRole::OnSurfaceVariant, SchemeRole::SurfaceContainer),
+            Self::Badge => (SchemeRole::OnError, SchemeRole::Error),
+            Self::ErrorContainer => (SchemeRole::OnErrorContainer, SchemeRole::ErrorContainer),
+        }
+    }
+}
+
+impl Scheme {
+    /// Returns the (foreground, background) colors of `pair`.
+    pub fn pair(&self, pair: Pair) -> (Argb, Argb) {
+        let (foreground, background) = pair.roles();
+
+        (self.role(foreground), self.role(background))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Pair;
+    use crate::{color::Argb, contrast::ratio_of_tones, hct::Hct, theme::ThemeBuilder};
+
+    #[test]
+    fn test_pair() {
+        let theme = ThemeBuilder::with_source(Argb::from_u32(0xff6750a4)).build();
+        let scheme = &theme.schemes.light;
+
+        assert_eq!(
+            scheme.pair(Pair::PrimaryButton),
+            (scheme.on_primary, scheme.primary)
+        );
+        assert_eq!(
+            scheme.pair(Pair::Snackbar),
+            (scheme.inverse_on_surface, scheme.inverse_surface)
+        );
+    }
+
+    #[test]
+    fn test_pairs_are_legible() {
+        let theme = ThemeBuilder::with_source(Argb::from_u32(0xff6750a4)).build();
+
+        for scheme in [&theme.schemes.light, &theme.schemes.dark] {
+            for pair in Pair::ALL {
+                let (foreground, background) = scheme.pair(pair);
+                let ratio = ratio_of_tones(
+                    Hct::new(foreground).get_tone(),
+                    Hct::new(background).get_tone(),
+                );
+
+                assert!(ratio >= 4.5, "{pair:?}: {ratio}");
+            }
+        }
+    }
+}
@@ -0,0 +1,815 @@
+use super::Scheme;
+use crate::{color::Argb, Error};
+use core::{
+    fmt,
+    iter::FusedIterator,
+    ops::{Index, IndexMut, Range},
+};
+
+/// A color role of a [`Scheme`], one variant per field.
+///
+/// Can be converted from the snake-case name of the field with
+/// [`TryFrom<&str>`] and used to index a [`Scheme`]. [`SchemeRole::since`]
+/// returns the release the role was added in, for exporters targeting
+/// consumers of older releases.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SchemeRole {
+    Primary,
+    OnPrimary,
+    PrimaryContainer,
+    OnPrimaryContainer,
+    InversePrimary,
+    PrimaryFixed,
+    PrimaryFixedDim,
+    OnPrimaryFixed,
+    OnPrimaryFixedVariant,
+    Secondary,
+    OnSecondary,
+    SecondaryContainer,
+    OnSecondaryContainer,
+    SecondaryFixed,
+    SecondaryFixedDim,
+    OnSecondaryFixed,
+    OnSecondaryFixedVariant,
+    Tertiary,
+    OnTertiary,
+    TertiaryContainer,
+    OnTertiaryContainer,
+    TertiaryFixed,
+    TertiaryFixedDim,
+    OnTertiaryFixed,
+    OnTertiaryFixedVariant,
+    Error,
+    OnError,
+    ErrorContainer,
+    OnErrorContainer,
+    SurfaceDim,
+    Surface,
+    SurfaceTint,
+    SurfaceBright,
+    SurfaceContainerLowest,
+    SurfaceContainerLow,
+    SurfaceContainer,
+    SurfaceContainerHigh,
+    SurfaceContainerHighest,
+    OnSurface,
+    OnSurfaceVariant,
+    Outline,
+    OutlineVariant,
+    InverseSurface,
+    InverseOnSurface,
+    SurfaceVariant,
+    Background,
+    OnBackground,
+    Shadow,
+    Scrim,
+}
+
+impl SchemeRole {
+    /// Every role, in the order of the fields of [`Scheme`].
+    pub const ALL: [Self; 49] = [
+        Self::Primary,
+        Self::OnPrimary,
+        Self::PrimaryContainer,
+        Self::OnPrimaryContainer,
+        Self::InversePrimary,
+        Self::PrimaryFixed,
+        Self::PrimaryFixedDim,
+        Self::OnPrimaryFixed,
+        Self::OnPrimaryFixedVariant,
+        Self::Secondary,
+        Self::OnSecondary,
+        Self::SecondaryContainer,
+        Self::OnSecondaryContainer,
+        Self::SecondaryFixed,
+        Self::SecondaryFixedDim,
+        Self::OnSecondaryFixed,
+        Self::OnSecondaryFixedVariant,
+        Self::Tertiary,
+        Self::OnTertiary,
+        Self::TertiaryContainer,
+        Self::OnTertiaryContainer,
+        Self::TertiaryFixed,
+        Self::TertiaryFixedDim,
+        Self::OnTertiaryFixed,
+        Self::OnTertiaryFixedVariant,
+        Self::Error,
+        Self::OnError,
+        Self::ErrorContainer,
+        Self::OnErrorContainer,
+        Self::SurfaceDim,
+        Self::Surface,
+        Self::SurfaceTint,
+        Self::SurfaceBright,
+        Self::SurfaceContainerLowest,
+        Self::SurfaceContainerLow,
+        Self::SurfaceContainer,
+        Self::SurfaceContainerHigh,
+        Self::SurfaceContainerHighest,
+        Self::OnSurface,
+        Self::OnSurfaceVariant,
+        Self::Outline,
+        Self::OutlineVariant,
+        Self::InverseSurface,
+        Self::InverseOnSurface,
+        Self::SurfaceVariant,
+        Self::Background,
+        Self::OnBackground,
+        Self::Shadow,
+        Self::Scrim,
+    ];
+
+    /// Returns the snake-case name of the role, as used by the fields of
+    /// [`Scheme`].
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Primary => "primary",
+            Self::OnPrimary => "on_primary",
+            Self::PrimaryContainer => "primary_container",
+            Self::OnPrimaryContainer => "on_primary_container",
+            Self::InversePrimary => "inverse_primary",
+            Self::PrimaryFixed => "primary_fixed",
+            Self::PrimaryFixedDim => "primary_fixed_dim",
+            Self::OnPrimaryFixed => "on_primary_fixed",
+            Self::OnPrimaryFixedVariant => "on_primary_fixed_variant",
+            Self::Secondary => "secondary",
+            Self::OnSecondary => "on_secondary",
+            Self::SecondaryContainer => "secondary_container",
+            Self::OnSecondaryContainer => "on_secondary_container",
+            Self::SecondaryFixed => "secondary_fixed",
+            Self::SecondaryFixedDim => "secondary_fixed_dim",
+            Self::OnSecondaryFixed => "on_secondary_fixed",
+            Self::OnSecondaryFixedVariant => "on_secondary_fixed_variant",
+            Self::Tertiary => "tertiary",
+            Self::OnTertiary => "on_tertiary",
+            Self::TertiaryContainer => "tertiary_container",
+            Self::OnTertiaryContainer => "on_tertiary_container",
+            Self::TertiaryFixed => "tertiary_fixed",
+            Self::TertiaryFixedDim => "tertiary_fixed_dim",
+            Self::OnTertiaryFixed => "on_tertiary_fixed",
+            Self::OnTertiaryFixedVariant => "on_tertiary_fixed_variant",
+            Self::Error => "error",
+            Self::OnError => "on_error",
+            Self::ErrorContainer => "error_container",
+            Self::OnErrorContainer => "on_error_container",
+            Self::SurfaceDim => "surface_dim",
+            Self::Surface => "surface",
+            Self::SurfaceTint => "surface_tint",
+            Self::SurfaceBright => "surface_bright",
+            Self::SurfaceContainerLowest => "surface_container_lowest",
+            Self::SurfaceContainerLow => "surface_container_low",
+            Self::SurfaceContainer => "surface_container",
+            Self::SurfaceContainerHigh => "surface_container_high",
+            Self::SurfaceContainerHighest => "surface_container_highest",
+            Self::OnSurface => "on_surface",
+            Self::OnSurfaceVariant => "on_surface_variant",
+            Self::Outline => "outline",
+            Self::OutlineVariant => "outline_variant",
+            Self::InverseSurface => "inverse_surface",
+            Self::InverseOnSurface => "inverse_on_surface",
+            Self::SurfaceVariant => "surface_variant",
+            Self::Background => "background",
+            Self::OnBackground => "on_background",
+            Self::Shadow => "shadow",
+            Self::Scrim => "scrim",
+        }
+    }
+
+    /// Returns the first release of this crate whose [`Scheme`] has the
+    /// role, per the changelog.
+    ///
+    /// New roles are listed here as they are added, so that exporters can
+    /// leave out roles unknown to the release they target, see
+    /// [`SchemeRole::available_in`].
+    pub const fn since(self) -> CrateVersion {
+        match self {
+            Self::Primary
+            | Self::OnPrimary
+            | Self::PrimaryContainer
+            | Self::OnPrimaryContainer
+            | Self::InversePrimary
+            | Self::PrimaryFixed
+            | Self::PrimaryFixedDim
+            | Self::OnPrimaryFixed
+            | Self::OnPrimaryFixedVariant
+            | Self::Secondary
+            | Self::OnSecondary
+            | Self::SecondaryContainer
+            | Self::OnSecondaryContainer
+            | Self::SecondaryFixed
+            | Self::SecondaryFixedDim
+            | Self::OnSecondaryFixed
+            | Self::OnSecondaryFixedVariant
+            | Self::Tertiary
+            | Self::OnTertiary
+            | Self::TertiaryContainer
+            | Self::OnTertiaryContainer
+            | Self::TertiaryFixed
+            | Self::TertiaryFixedDim
+            | Self::OnTertiaryFixed
+            | Self::OnTertiaryFixedVariant
+            | Self::Error
+            | Self::OnError
+            | Self::ErrorContainer
+            | Self::OnErrorContainer
+            | Self::SurfaceDim
+            | Self::Surface
+            | Self::SurfaceBright
+            | Self::SurfaceContainerLowest
+            | Self::SurfaceContainerLow
+            | Self::SurfaceContainer
+            | Self::SurfaceContainerHigh
+            | Self::SurfaceContainerHighest
+            | Self::OnSurface
+            | Self::OnSurfaceVariant
+            | Self::Outline
+            | Self::OutlineVariant
+            | Self::InverseSurface
+            | Self::InverseOnSurface
+            | Self::SurfaceVariant
+            | Self::Background
+            | Self::OnBackground
+            | Self::Shadow
+            | Self::Scrim => CrateVersion::new(0, 1, 0),
+            Self::SurfaceTint => CrateVersion::new(0, 4, 0),
+        }
+    }
+
+    /// Returns the roles available in `version` of this crate, in the order
+    /// of [`SchemeRole::ALL`].
+    pub fn available_in(version: CrateVersion) -> impl Iterator<Item = Self> {
+        Self::ALL
+            .into_iter()
+            .filter(move |role| role.since() <= version)
+    }
+
+    /// Returns the role standing in for this one on consumers lacking it,
+    /// the role closest in tone and purpose among the older ones, or `None`
+    /// for the primary, on-primary and shadow roles, which every consumer
+    /// has.
+    ///
+    /// Following the fallbacks forms chains, e.g. surface container, surface
+    /// variant, surface and background. See
+    /// [`SchemeRole::resolve_with_fallback`].
+    pub const fn fallback(self) -> Option<Self> {
+        Some(match self {
+            Self::InversePrimary
+            | Self::SurfaceTint
+            | Self::PrimaryContainer
+            | Self::Secondary
+            | Self::Error => Self::Primary,
+            Self::OnPrimaryContainer | Self::OnSecondary | Self::OnError => Self::OnPrimary,
+            Self::PrimaryFixed | Self::PrimaryFixedDim => Self::PrimaryContainer,
+            Self::OnPrimaryFixed | Self::OnPrimaryFixedVariant => Self::OnPrimaryContainer,
+            Self::SecondaryContainer | Self::Tertiary => Self::Secondary,
+            Self::OnSecondaryContainer | Self::OnTertiary => Self::OnSecondary,
+            Self::SecondaryFixed | Self::SecondaryFixedDim => Self::SecondaryContainer,
+            Self::OnSecondaryFixed | Self::OnSecondaryFixedVariant => Self::OnSecondaryContainer,
+            Self::TertiaryContainer => Self::Tertiary,
+            Self::OnTertiaryContainer => Self::OnTertiary,
+            Self::TertiaryFixed | Self::TertiaryFixedDim => Self::TertiaryContainer,
+            Self::OnTertiaryFixed | Self::OnTertiaryFixedVariant => Self::OnTertiaryContainer,
+            Self::ErrorContainer => Self::Error,
+            Self::OnErrorContainer => Self::OnError,
+            Self::SurfaceDim
+            | Self::SurfaceBright
+            | Self::SurfaceContainerLowest
+            | Self::SurfaceVariant
+            | Self::Background => Self::Surface,
+            Self::Surface | Self::InverseOnSurface => Self::Background,
+            Self::SurfaceContainerLow
+            | Self::SurfaceContainer
+            | Self::SurfaceContainerHigh
+            | Self::SurfaceContainerHighest => Self::SurfaceVariant,
+            Self::OnSurface => Self::OnBackground,
+            Self::OnSurfaceVariant | Self::InverseSurface | Self::OnBackground => Self::OnSurface,
+            Self::Outline => Self::OnSurfaceVariant,
+            Self::OutlineVariant => Self::Outline,
+            Self::Scrim => Self::Shadow,
+            Self::Primary | Self::OnPrimary | Self::Shadow => return None,
+        })
+    }
+
+    /// Returns the role replacing this one if it is deprecated, see
+    /// [`DEPRECATED_ROLES`].
+    ///
+    /// [`DEPRECATED_ROLES`]: super::DEPRECATED_ROLES
+    pub const fn replacement(self) -> Option<Self> {
+        match self {
+            Self::Background => Some(Self::Surface),
+            Self::OnBackground => Some(Self::OnSurface),
+            Self::SurfaceVariant => Some(Self::SurfaceContainerHighest),
+            _ => None,
+        }
+    }
+
+    /// Returns this role if `target` has it, or else the first role of its
+    /// fallback chain that `target` has, see [`SchemeRole::fallback`].
+    /// Deprecated roles resolve to their replacement first, if `target` has
+    /// it. Returns `None` if there is no such role.
+    ///
+    /// Exporters and adapters for older consumers use it to map every role
+    /// onto one the consumer understands, consistently.
+    pub fn resolve_with_fallback(self, target: TargetProfile) -> Option<Self> {
+        let mut role = self;
+
+        // Chains are shorter than the count of roles, unless they cycle
+        // through roles missing on `target`, e.g. surface and background.
+        for _ in 0..Self::ALL.len() {
+            if target.has(role) {
+                return Some(role);
+            }
+
+            if let Some(replacement) = role.replacement() {
+                if target.has(replacement) {
+                    return Some(replacement);
+                }
+            }
+
+            role = role.fallback()?;
+        }
+
+        None
+    }
+}
+
+/// Set of roles known to a token consumer, see
+/// [`SchemeRole::resolve_with_fallback`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TargetProfile {
+    /// Material Design 2 themes: primary, secondary, error, surface and
+    /// background, with their on-colors.
+    Material2,
+    /// The initial Material Design 3 tokens of 2021, without outline
+    /// variant, scrim, surface tint, surface containers and fixed roles.
+    Material3Initial,
+    /// Every role of [`Scheme`].
+    Material3,
+    /// Every role of [`Scheme`] except the deprecated ones, see
+    /// [`DEPRECATED_ROLES`].
+    ///
+    /// [`DEPRECATED_ROLES`]: super::DEPRECATED_ROLES
+    Material3Modern,
+}
+
+impl TargetProfile {
+    /// Whether consumers of this profile have `role`.
+    pub const fn has(self, role: SchemeRole) -> bool {
+        match self {
+            Self::Material2 => matches!(
+                role,
+                SchemeRole::Primary
+                    | SchemeRole::OnPrimary
+                    | SchemeRole::Secondary
+                    | SchemeRole::OnSecondary
+                    | SchemeRole::Error
+                    | SchemeRole::OnError
+                    | SchemeRole::Surface
+                    | SchemeRole::OnSurface
+                    | SchemeRole::Background
+                    | SchemeRole::OnBackground
+            ),
+            Self::Material3Initial => !matches!(
+                role,
+                SchemeRole::PrimaryFixed
+                    | SchemeRole::PrimaryFixedDim
+                    | SchemeRole::OnPrimaryFixed
+                    | SchemeRole::OnPrimaryFixedVariant
+                    | SchemeRole::SecondaryFixed
+                    | SchemeRole::SecondaryFixedDim
+                    | SchemeRole::OnSecondaryFixed
+                    | SchemeRole::OnSecondaryFixedVariant
+                    | SchemeRole::TertiaryFixed
+                    | SchemeRole::TertiaryFixedDim
+                    | SchemeRole::OnTertiaryFixed
+                    | SchemeRole::OnTertiaryFixedVariant
+                    | SchemeRole::SurfaceDim
+                    | SchemeRole::SurfaceTint
+                    | SchemeRole::SurfaceBright
+                    | SchemeRole::SurfaceContainerLowest
+                    | SchemeRole::SurfaceContainerLow
+                    | SchemeRole::SurfaceContainer
+                    | SchemeRole::SurfaceContainerHigh
+                    | SchemeRole::SurfaceContainerHighest
+                    | SchemeRole::OutlineVariant
+                    | SchemeRole::Scrim
+            ),
+            Self::Material3 => true,
+            Self::Material3Modern => !matches!(
+                role,
+                SchemeRole::Background | SchemeRole::OnBackground | SchemeRole::SurfaceVariant
+            ),
+        }
+    }
+}
+
+/// Release of this crate, see [`SchemeRole::since`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CrateVersion {
+    pub major: u16,
+    pub minor: u16,
+    pub patch: u16,
+}
+
+impl CrateVersion {
+    /// Version of this build of the crate.
+    pub const CURRENT: Self = Self::new(
+        parse_u16(env!("CARGO_PKG_VERSION_MAJOR")),
+        parse_u16(env!("CARGO_PKG_VERSION_MINOR")),
+        parse_u16(env!("CARGO_PKG_VERSION_PATCH")),
+    );
+
+    pub const fn new(major: u16, minor: u16, patch: u16) -> Self {
+        Self {
+            major,
+            minor,
+            patch,
+        }
+    }
+}
+
+impl fmt::Display for CrateVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+/// Parses a version component known at compile time.
+const fn parse_u16(digits: &str) -> u16 {
+    let digits = digits.as_bytes();
+    let mut value = 0;
+    let mut i = 0;
+
+    while i < digits.len() {
+        value = value * 10 + (digits[i] - b'0') as u16;
+        i += 1;
+    }
+
+    value
+}
+
+impl fmt::Display for SchemeRole {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.name().fmt(f)
+    }
+}
+
+impl TryFrom<&str> for SchemeRole {
+    type Error = Error;
+
+    fn try_from(name: &str) -> Result<Self, Error> {
+        Self::ALL
+            .into_iter()
+            .find(|role| role.name() == name)
+            .ok_or(Error::UnknownRole)
+    }
+}
+
+impl Scheme {
+    /// Returns the color of `role`.
+    pub fn role(&self, role: SchemeRole) -> Argb {
+        self[role]
+    }
+}
+
+impl Scheme {
+    /// Returns an iterator over the roles of the scheme and their colors,
+    /// in the order of [`SchemeRole::ALL`], without cloning the scheme.
+    pub const fn iter(&self) -> Iter<'_> {
+        Iter {
+            scheme: self,
+            indices: 0..SchemeRole::ALL.len(),
+        }
+    }
+}
+
+/// Iterator over the roles of a [`Scheme`], created by [`Scheme::iter`].
+#[derive(Debug, Clone)]
+pub struct Iter<'a> {
+    scheme: &'a Scheme,
+    indices: Range<usize>,
+}
+
+impl Iterator for Iter<'_> {
+    type Item = (SchemeRole, Argb);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let role = SchemeRole::ALL[self.indices.next()?];
+
+        Some((role, self.scheme.role(role)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.indices.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for Iter<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let role = SchemeRole::ALL[self.indices.next_back()?];
+
+        Some((role, self.scheme.role(role)))
+    }
+}
+
+impl ExactSizeIterator for Iter<'_> {}
+
+impl FusedIterator for Iter<'_> {}
+
+impl<'a> IntoIterator for &'a Scheme {
+    type Item = (SchemeRole, Argb);
+    type IntoIter = Iter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl Index<SchemeRole> for Scheme {
+    type Output = Argb;
+
+    fn index(&self, role: SchemeRole) -> &Self::Output {
+        match role {
+            SchemeRole::Primary => &self.primary,
+            SchemeRole::OnPrimary => &self.on_primary,
+            SchemeRole::PrimaryContainer => &self.primary_container,
+            SchemeRole::OnPrimaryContainer => &self.on_primary_container,
+            SchemeRole::InversePrimary => &self.inverse_primary,
+            SchemeRole::PrimaryFixed => &self.primary_fixed,
+            SchemeRole::PrimaryFixedDim => &self.primary_fixed_dim,
+            SchemeRole::OnPrimaryFixed => &self.on_primary_fixed,
+            SchemeRole::OnPrimaryFixedVariant => &self.on_primary_fixed_variant,
+            SchemeRole::Secondary => &self.secondary,
+            SchemeRole::OnSecondary => &self.on_secondary,
+            SchemeRole::SecondaryContainer => &self.secondary_container,
+            SchemeRole::OnSecondaryContainer => &self.on_secondary_container,
+            SchemeRole::SecondaryFixed => &self.secondary_fixed,
+            SchemeRole::SecondaryFixedDim => &self.secondary_fixed_dim,
+            SchemeRole::OnSecondaryFixed => &self.on_secondary_fixed,
+            SchemeRole::OnSecondaryFixedVariant => &self.on_secondary_fixed_variant,
+            SchemeRole::Tertiary => &self.tertiary,
+            SchemeRole::OnTertiary => &self.on_tertiary,
+            SchemeRole::TertiaryContainer => &self.tertiary_container,
+            SchemeRole::OnTertiaryContainer => &self.on_tertiary_container,
+            SchemeRole::TertiaryFixed => &self.tertiary_fixed,
+            SchemeRole::TertiaryFixedDim => &self.tertiary_fixed_dim,
+            SchemeRole::OnTertiaryFixed => &self.on_tertiary_fixed,
+            SchemeRole::OnTertiaryFixedVariant => &self.on_tertiary_fixed_variant,
+            SchemeRole::Error => &self.error,
+            SchemeRole::OnError => &self.on_error,
+            SchemeRole::ErrorContainer => &self.error_container,
+            SchemeRole::OnErrorContainer => &self.on_error_container,
+            SchemeRole::SurfaceDim => &self.surface_dim,
+            SchemeRole::Surface => &self.surface,
+            SchemeRole::SurfaceTint => &self.surface_tint,
+            SchemeRole::SurfaceBright => &self.surface_bright,
+            SchemeRole::SurfaceContainerLowest => &self.surface_container_lowest,
+            SchemeRole::SurfaceContainerLow => &self.surface_container_low,
+            SchemeRole::SurfaceContainer => &self.surface_container,
+            SchemeRole::SurfaceContainerHigh => &self.surface_container_high,
+            SchemeRole::SurfaceContainerHighest => &self.surface_container_highest,
+            SchemeRole::OnSurface => &self.on_surface,
+            SchemeRole::OnSurfaceVariant => &self.on_surface_variant,
+            SchemeRole::Outline => &self.outline,
+            SchemeRole::OutlineVariant => &self.outline_variant,
+            SchemeRole::InverseSurface => &self.inverse_surface,
+            SchemeRole::InverseOnSurface => &self.inverse_on_surface,
+            SchemeRole::SurfaceVariant => &self.surface_variant,
+            SchemeRole::Background => &self.background,
+            SchemeRole::OnBackground => &self.on_background,
+            SchemeRole::Shadow => &self.shadow,
+            SchemeRole::Scrim => &self.scrim,
+        }
+    }
+}
+
+impl IndexMut<SchemeRole> for Scheme {
+    fn index_mut(&mut self, role: SchemeRole) -> &mut Self::Output {
+        match role {
+            SchemeRole::Primary => &mut self.primary,
+            SchemeRole::OnPrimary => &mut self.on_primary,
+            SchemeRole::PrimaryContainer => &mut self.primary_container,
+            SchemeRole::OnPrimaryContainer => &mut self.on_primary_container,
+            SchemeRole::InversePrimary => &mut self.inverse_primary,
+            SchemeRole::PrimaryFixed => &mut self.primary_fixed,
+            SchemeRole::PrimaryFixedDim => &mut self.primary_fixed_dim,
+            SchemeRole::OnPrimaryFixed => &mut self.on_primary_fixed,
+            SchemeRole::OnPrimaryFixedVariant => &mut self.on_primary_fixed_variant,
+            SchemeRole::Secondary => &mut self.secondary,
+            SchemeRole::OnSecondary => &mut self.on_secondary,
+            SchemeRole::SecondaryContainer => &mut self.secondary_container,
+            SchemeRole::OnSecondaryContainer => &mut self.on_secondary_container,
+            SchemeRole::SecondaryFixed => &mut self.secondary_fixed,
+            SchemeRole::SecondaryFixedDim => &mut self.secondary_fixed_dim,
+            SchemeRole::OnSecondaryFixed => &mut self.on_secondary_fixed,
+            SchemeRole::OnSecondaryFixedVariant => &mut self.on_secondary_fixed_variant,
+            SchemeRole::Tertiary => &mut self.tertiary,
+            SchemeRole::OnTertiary => &mut self.on_tertiary,
+            SchemeRole::TertiaryContainer => &mut self.tertiary_container,
+            SchemeRole::OnTertiaryContainer => &mut self.on_tertiary_container,
+            SchemeRole::TertiaryFixed => &mut self.tertiary_fixed,
+            SchemeRole::TertiaryFixedDim => &mut self.tertiary_fixed_dim,
+            SchemeRole::OnTertiaryFixed => &mut self.on_tertiary_fixed,
+            SchemeRole::OnTertiaryFixedVariant => &mut self.on_tertiary_fixed_variant,
+            SchemeRole::Error => &mut self.error,
+            SchemeRole::OnError => &mut self.on_error,
+            SchemeRole::ErrorContainer => &mut self.error_container,
+            SchemeRole::OnErrorContainer => &mut self.on_error_container,
+            SchemeRole::SurfaceDim => &mut self.surface_dim,
+            SchemeRole::Surface => &mut self.surface,
+            SchemeRole::SurfaceTint => &mut self.surface_tint,
+            SchemeRole::SurfaceBright => &mut self.surface_bright,
+            SchemeRole::SurfaceContainerLowest => &mut self.surface_container_lowest,
+            SchemeRole::SurfaceContainerLow => &mut self.surface_container_low,
+            SchemeRole::SurfaceContainer => &mut self.surface_container,
+            SchemeRole::SurfaceContainerHigh => &mut self.surface_container_high,
+            SchemeRole::SurfaceContainerHighest => &mut self.surface_container_highest,
+            SchemeRole::OnSurface => &mut self.on_surface,
+            SchemeRole::OnSurfaceVariant => &mut self.on_surface_variant,
+            SchemeRole::Outline => &mut self.outline,
+            SchemeRole::OutlineVariant => &mut self.outline_variant,
+            SchemeRole::InverseSurface => &mut self.inverse_surface,
+            SchemeRole::InverseOnSurface => &mut self.inverse_on_surface,
+            SchemeRole::SurfaceVariant => &mut self.surface_variant,
+            SchemeRole::Background => &mut self.background,
+            SchemeRole::OnBackground => &mut self.on_background,
+            SchemeRole::Shadow => &mut self.shadow,
+            SchemeRole::Scrim => &mut self.scrim,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CrateVersion, SchemeRole, TargetProfile};
+    use crate::{
+        color::Argb,
+        theme::{ThemeBuilder, ThemeMetadata},
+        Error,
+    };
+    #[cfg(not(feature = "std"))]
+    use alloc::{string::ToString, vec::Vec};
+    #[cfg(feature = "std")]
+    use std::{string::ToString, vec::Vec};
+
+    #[test]
+    fn test_roles_match_scheme() {
+        let theme = ThemeBuilder::with_source(Argb::from_u32(0xff0000ff)).build();
+        let mut scheme = theme.schemes.light;
+
+        for (role, (name, color)) in SchemeRole::ALL.into_iter().zip(scheme.clone()) {
+            assert_eq!(role.name(), name);
+            assert_eq!(SchemeRole::try_from(name.as_str()), Ok(role));
+            assert_eq!(scheme.role(role), color);
+            assert_eq!(scheme[role], color);
+        }
+
+        scheme[SchemeRole::OnPrimary] = Argb::from_u32(0xff123456);
+
+        assert_eq!(scheme.on_primary, Argb::from_u32(0xff123456));
+        assert_eq!(SchemeRole::try_from("unknown"), Err(Error::UnknownRole));
+        assert_eq!(SchemeRole::try_from("Primary"), Err(Error::UnknownRole));
+    }
+
+    #[test]
+    fn test_iter() {
+        let theme = ThemeBuilder::with_source(Argb::from_u32(0xff0000ff)).build();
+        let scheme = &theme.schemes.dark;
+        let mut iter = scheme.iter();
+
+        assert_eq!(iter.len(), 49);
+        assert_eq!(iter.next(), Some((SchemeRole::Primary, scheme.primary)));
+        assert_eq!(iter.next_back(), Some((SchemeRole::Scrim, scheme.scrim)));
+        assert_eq!(iter.len(), 47);
+
+        let reversed = scheme
+            .iter()
+            .rev()
+            .map(|(role, _)| role)
+            .collect::<Vec<_>>();
+
+        assert_eq!(reversed.len(), 49);
+        assert_eq!(reversed[0], SchemeRole::Scrim);
+        assert!(scheme
+            .iter()
+            .zip(scheme.clone())
+            .all(|((role, color), (name, other))| role.name() == name && color == other));
+        assert_eq!(scheme.into_iter().count(), 49);
+    }
+
+    #[test]
+    fn test_since() {
+        // Fails whenever a role is added: list it in `SchemeRole::since`
+        // with the release it is added in and extend this test.
+        let added = SchemeRole::ALL
+            .into_iter()
+            .filter(|role| role.since() > CrateVersion::new(0, 1, 0))
+            .collect::<Vec<_>>();
+
+        assert_eq!(added, [SchemeRole::SurfaceTint]);
+        assert_eq!(SchemeRole::ALL.len(), 49);
+        assert!(SchemeRole::ALL
+            .into_iter()
+            .all(|role| role.since() <= CrateVersion::CURRENT));
+    }
+
+    #[test]
+    fn test_available_in() {
+        assert_eq!(
+            SchemeRole::available_in(CrateVersion::new(0, 3, 3)).count(),
+            48
+        );
+        assert!(!SchemeRole::available_in(CrateVersion::new(0, 3, 3))
+            .any(|role| role == SchemeRole::SurfaceTint));
+        assert!(SchemeRole::available_in(CrateVersion::CURRENT).eq(SchemeRole::ALL));
+        assert_eq!(
+            SchemeRole::available_in(CrateVersion::new(0, 0, 9)).count(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_current_version() {
+        assert_eq!(
+            CrateVersion::CURRENT.to_string(),
+            ThemeMetadata::CRATE_VERSION
+        );
+        assert!(CrateVersion::new(0, 4, 0) < CrateVersion::new(0, 10, 0));
+    }
+
+    #[test]
+    fn test_resolve_with_fallback() {
+        use SchemeRole::*;
+
+        let cases = [
+            (
+                SurfaceContainer,
+                TargetProfile::Material3Initial,
+                SurfaceVariant,
+            ),
+            (SurfaceContainer, TargetProfile::Material2, Surface),
+            (
+                SurfaceContainer,
+                TargetProfile::Material3Modern,
+                SurfaceContainer,
+            ),
+            (
+                SurfaceVariant,
+                TargetProfile::Material3Modern,
+                SurfaceContainerHighest,
+            ),
+            (Background, TargetProfile::Material3Modern, Surface),
+            (
+                OnPrimaryFixedVariant,
+                TargetProfile::Material3Initial,
+                OnPrimaryContainer,
+            ),
+            (OnPrimaryFixedVariant, TargetProfile::Material2, OnPrimary),
+            (TertiaryContainer, TargetProfile::Material2, Secondary),
+            (
+                InverseOnSurface,
+                TargetProfile::Material3Modern,
+                InverseOnSurface,
+            ),
+            (OutlineVariant, TargetProfile::Material3Initial, Outline),
+            (Scrim, TargetProfile::Material3Initial, Shadow),
+        ];
+
+        for (role, target, expected) in cases {
+            assert_eq!(
+                role.resolve_with_fallback(target),
+                Some(expected),
+                "{role} on {target:?}"
+            );
+        }
+
+        assert_eq!(Shadow.resolve_with_fallback(TargetProfile::Material2), None);
+
+        for (deprecated, replacement) in crate::scheme::DEPRECATED_ROLES {
+            assert_eq!(
+                SchemeRole::try_from(deprecated).unwrap().replacement(),
+                SchemeRole::try_from(replacement).ok()
+            );
+        }
+    }
+
+    #[test]
+    fn test_fallback_chains_terminate() {
+        for target in [
+            TargetProfile::Material2,
+            TargetProfile::Material3Initial,
+            TargetProfile::Material3,
+            TargetProfile::Material3Modern,
+        ] {
+            for role in SchemeRole::ALL {
+                if let Some(resolved) = role.resolve_with_fallback(target) {
+                    assert!(target.has(resolved));
+                }
+            }
+        }
+
+        assert!(SchemeRole::ALL
+            .into_iter()
+            .all(|role| role.resolve_with_fallback(TargetProfile::Material3) == Some(role)));
+    }
+}
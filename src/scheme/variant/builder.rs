@@ -0,0 +1,207 @@
+use crate::{
+    dynamic_color::{DynamicScheme, ToneTable, Variant},
+    hct::Hct,
+    palette::{Palette, TonalPalette},
+};
+
+/// Builds a [`DynamicScheme`] of a variant, with some of its palettes
+/// replaced.
+///
+/// Created with the `builder` function of a variant, e.g.
+/// [`SchemeVibrant::builder`]. Palettes that are not overridden are derived
+/// from the source color like [`SchemeVibrant::new`] does, including the hue
+/// rotations of the variant.
+///
+/// ```
+/// use material_colors::{
+///     color::Argb,
+///     dynamic_color::Variant,
+///     palette::{Palette, TonalPalette},
+///     scheme::variant::SchemeVibrant,
+/// };
+///
+/// let source = Argb::from_u32(0xff4285f4).into();
+/// let scheme = SchemeVibrant::builder(source)
+///     .dark(true)
+///     .neutral_palette(TonalPalette::by_variant(
+///         &source,
+///         &Variant::TonalSpot,
+///         &Palette::Neutral,
+///     ))
+///     .build();
+///
+/// assert_eq!(scheme.primary(), SchemeVibrant::new(source, true, None).scheme.primary());
+/// ```
+///
+/// [`SchemeVibrant::builder`]: super::SchemeVibrant::builder
+/// [`SchemeVibrant::new`]: super::SchemeVibrant::new
+#[derive(Debug, Clone, PartialEq)]
+pub struct SchemeBuilder {
+    source_color_hct: Hct,
+    variant: Variant,
+    is_dark: bool,
+    contrast_level: Option<f64>,
+    primary_palette: Option<TonalPalette>,
+    secondary_palette: Option<TonalPalette>,
+    tertiary_palette: Option<TonalPalette>,
+    neutral_palette: Option<TonalPalette>,
+    neutral_variant_palette: Option<TonalPalette>,
+    error_palette: Option<TonalPalette>,
+    tone_table: ToneTable,
+}
+
+impl SchemeBuilder {
+    /// Creates a builder of a light scheme of `variant` at the standard
+    /// contrast level.
+    pub const fn new(source_color_hct: Hct, variant: Variant) -> Self {
+        Self {
+            source_color_hct,
+            variant,
+            is_dark: false,
+            contrast_level: None,
+            primary_palette: None,
+            secondary_palette: None,
+            tertiary_palette: None,
+            neutral_palette: None,
+            neutral_variant_palette: None,
+            error_palette: None,
+            tone_table: ToneTable::new(),
+        }
+    }
+
+    /// Sets whether the scheme is dark.
+    #[must_use]
+    pub const fn dark(mut self, is_dark: bool) -> Self {
+        self.is_dark = is_dark;
+
+        self
+    }
+
+    /// Sets the contrast level, from -1.0 to 1.0.
+    #[must_use]
+    pub const fn contrast_level(mut self, contrast_level: f64) -> Self {
+        self.contrast_level = Some(contrast_level);
+
+        self
+    }
+
+    /// Overrides the primary palette.
+    #[must_use]
+    pub const fn primary_palette(mut self, palette: TonalPalette) -> Self {
+        self.primary_palette = Some(palette);
+
+        self
+    }
+
+    /// Overrides the secondary palette.
+    #[must_use]
+    pub const fn secondary_palette(mut self, palette: TonalPalette) -> Self {
+        self.secondary_palette = Some(palette);
+
+        self
+    }
+
+    /// Overrides the tertiary palette.
+    #[must_use]
+    pub const fn tertiary_palette(mut self, palette: TonalPalette) -> Self {
+        self.tertiary_palette = Some(palette);
+
+        self
+    }
+
+    /// Overrides the neutral palette, used for backgrounds and surfaces.
+    #[must_use]
+    pub const fn neutral_palette(mut self, palette: TonalPalette) -> Self {
+        self.neutral_palette = Some(palette);
+
+        self
+    }
+
+    /// Overrides the neutral variant palette, used for medium emphasis and
+    /// variants.
+    #[must_use]
+    pub const fn neutral_variant_palette(mut self, palette: TonalPalette) -> Self {
+        self.neutral_variant_palette = Some(palette);
+
+        self
+    }
+
+    /// Overrides the error palette.
+    #[must_use]
+    pub const fn error_palette(mut self, palette: TonalPalette) -> Self {
+        self.error_palette = Some(palette);
+
+        self
+    }
+
+    /// Sets the tones roles start from, see [`ToneTable`].
+    #[must_use]
+    pub fn tone_table(mut self, tone_table: ToneTable) -> Self {
+        self.tone_table = tone_table;
+
+        self
+    }
+
+    pub fn build(self) -> DynamicScheme {
+        let palette = |palette: Option<TonalPalette>, kind| {
+            palette.unwrap_or_else(|| {
+                TonalPalette::by_variant(&self.source_color_hct, &self.variant, &kind)
+            })
+        };
+
+        DynamicScheme::new(
+            self.source_color_hct,
+            self.variant.clone(),
+            self.is_dark,
+            self.contrast_level,
+            palette(self.primary_palette, Palette::Primary),
+            palette(self.secondary_palette, Palette::Secondary),
+            palette(self.tertiary_palette, Palette::Tertiary),
+            palette(self.neutral_palette, Palette::Neutral),
+            palette(self.neutral_variant_palette, Palette::NeutralVariant),
+            Some(palette(self.error_palette, Palette::Error)),
+        )
+        .with_tone_table(self.tone_table)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SchemeBuilder;
+    use crate::{
+        color::Argb,
+        dynamic_color::{DynamicScheme, Variant},
+        hct::Hct,
+        palette::TonalPalette,
+    };
+
+    #[test]
+    fn test_defaults_match_variants() {
+        let source = Hct::new(Argb::from_u32(0xffb3261e));
+
+        for variant in Variant::ALL {
+            assert!(
+                SchemeBuilder::new(source, variant.clone())
+                    .dark(true)
+                    .contrast_level(0.5)
+                    .build()
+                    == DynamicScheme::by_variant(source, &variant, true, Some(0.5))
+            );
+        }
+    }
+
+    #[test]
+    fn test_overrides() {
+        let source = Hct::new(Argb::from_u32(0xff4285f4));
+        let neutral = TonalPalette::of(40.0, 2.0);
+        let scheme = SchemeBuilder::new(source, Variant::Expressive)
+            .neutral_palette(neutral)
+            .build();
+        let stock = DynamicScheme::by_variant(source, &Variant::Expressive, false, None);
+
+        assert_eq!(scheme.neutral_palette, neutral);
+        assert_eq!(scheme.tertiary_palette, stock.tertiary_palette);
+        assert_eq!(scheme.primary(), stock.primary());
+        assert_ne!(scheme.surface(), stock.surface());
+    }
+}
@@ -1,3 +1,4 @@
+use super::{HueRotation, SchemeBuilder};
 use crate::{
     dynamic_color::{DynamicScheme, Variant},
     hct::Hct,
@@ -13,15 +14,20 @@ pub struct SchemeExpressive {
 impl SchemeExpressive {
     /// Hues used at breakpoints such that designers can specify a hue rotation
     /// that occurs at a given break point.
-    const HUES: [f64; 9] = [0.0, 21.0, 51.0, 121.0, 151.0, 191.0, 271.0, 321.0, 360.0];
+    ///
+    /// A source hue strictly between `HUES[i]` and `HUES[i + 1]` is rotated by
+    /// the `i`-th rotation; one falling on a breakpoint is not rotated.
+    pub const HUES: [f64; 9] = [0.0, 21.0, 51.0, 121.0, 151.0, 191.0, 271.0, 321.0, 360.0];
 
     /// Hue rotations of the Secondary [`TonalPalette`], corresponding to the
-    /// breakpoints in `hues`.
-    const SECONDARY_ROTATIONS: [f64; 9] = [45.0, 95.0, 45.0, 20.0, 45.0, 90.0, 45.0, 45.0, 45.0];
+    /// breakpoints in [`Self::HUES`].
+    pub const SECONDARY_ROTATIONS: [f64; 9] =
+        [45.0, 95.0, 45.0, 20.0, 45.0, 90.0, 45.0, 45.0, 45.0];
 
     /// Hue rotations of the Tertiary [`TonalPalette`], corresponding to the
-    /// breakpoints in `hues`.
-    const TERTIARY_ROTATIONS: [f64; 9] = [120.0, 120.0, 20.0, 45.0, 20.0, 15.0, 20.0, 120.0, 120.0];
+    /// breakpoints in [`Self::HUES`].
+    pub const TERTIARY_ROTATIONS: [f64; 9] =
+        [120.0, 120.0, 20.0, 45.0, 20.0, 15.0, 20.0, 120.0, 120.0];
 
     pub fn new(source_color_hct: Hct, is_dark: bool, contrast_level: Option<f64>) -> Self {
         Self {
@@ -40,6 +46,22 @@ impl SchemeExpressive {
         }
     }
 
+    /// Returns a builder of this variant, to override some of its palettes.
+    pub const fn builder(source_color_hct: Hct) -> SchemeBuilder {
+        SchemeBuilder::new(source_color_hct, Variant::Expressive)
+    }
+
+    /// Returns the rotations applied to `source_hue` and the resulting hues
+    /// of the secondary and tertiary palettes.
+    pub fn rotation_for(source_hue: f64) -> HueRotation {
+        HueRotation::of(
+            source_hue,
+            &Self::HUES,
+            &Self::SECONDARY_ROTATIONS,
+            &Self::TERTIARY_ROTATIONS,
+        )
+    }
+
     pub fn palette(source_color_hct: &Hct, variant: &Palette) -> TonalPalette {
         match variant {
             Palette::Primary => TonalPalette::of(
@@ -72,7 +94,45 @@ impl SchemeExpressive {
 #[cfg(test)]
 mod tests {
     use super::SchemeExpressive;
-    use crate::color::Argb;
+    use crate::{color::Argb, hct::Hct, palette::Palette};
+    use float_cmp::assert_approx_eq;
+
+    #[test]
+    fn test_rotation_for() {
+        let rotation = SchemeExpressive::rotation_for(30.0);
+
+        assert_approx_eq!(f64, rotation.secondary_rotation, 95.0);
+        assert_approx_eq!(f64, rotation.tertiary_rotation, 120.0);
+        assert_approx_eq!(f64, rotation.secondary_hue, 125.0);
+        assert_approx_eq!(f64, rotation.tertiary_hue, 150.0);
+
+        let rotation = SchemeExpressive::rotation_for(330.0);
+
+        assert_approx_eq!(f64, rotation.tertiary_hue, 90.0);
+
+        // Breakpoints are not rotated.
+        let rotation = SchemeExpressive::rotation_for(21.0);
+
+        assert_approx_eq!(f64, rotation.secondary_rotation, 0.0);
+        assert_approx_eq!(f64, rotation.tertiary_hue, 21.0);
+    }
+
+    #[test]
+    fn test_rotation_for_matches_palettes() {
+        let source = Hct::from(212.0, 48.0, 50.0);
+        let rotation = SchemeExpressive::rotation_for(source.get_hue());
+
+        assert_approx_eq!(
+            f64,
+            SchemeExpressive::palette(&source, &Palette::Secondary).hue(),
+            rotation.secondary_hue
+        );
+        assert_approx_eq!(
+            f64,
+            SchemeExpressive::palette(&source, &Palette::Tertiary).hue(),
+            rotation.tertiary_hue
+        );
+    }
 
     #[test]
     fn test_key_colors() {
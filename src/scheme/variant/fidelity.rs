@@ -1,3 +1,4 @@
+use super::SchemeBuilder;
 use crate::{
     dislike::fix_if_disliked,
     dynamic_color::{DynamicScheme, Variant},
@@ -28,6 +29,11 @@ impl SchemeFidelity {
         }
     }
 
+    /// Returns a builder of this variant, to override some of its palettes.
+    pub const fn builder(source_color_hct: Hct) -> SchemeBuilder {
+        SchemeBuilder::new(source_color_hct, Variant::Fidelity)
+    }
+
     pub fn palette(source_color_hct: &Hct, variant: &Palette) -> TonalPalette {
         match variant {
             Palette::Primary => {
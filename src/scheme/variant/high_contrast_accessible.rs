@@ -0,0 +1,92 @@
+use super::SchemeTonalSpot;
+use crate::{
+    dynamic_color::{AccessibilityOptions, DynamicScheme},
+    hct::Hct,
+    scheme::Scheme,
+};
+
+/// A high contrast scheme for accessibility themes, whose text roles are
+/// pure black or white.
+///
+/// Surfaces and accents come from [`SchemeTonalSpot`] at the maximum contrast
+/// level, so they keep the tint of the source color, while the scheme sets
+/// [`AccessibilityOptions::high_contrast_text`] to resolve every role of
+/// [`AccessibilityOptions::TEXT_ROLES`] into black or white. Raising the
+/// contrast level of other variants alone still yields tinted text.
+///
+/// ```
+/// use material_colors::{color::Argb, scheme::{variant::SchemeHighContrastAccessible, Scheme}};
+///
+/// let scheme: Scheme =
+///     SchemeHighContrastAccessible::new(Argb::from_u32(0xff4285f4).into(), false).into();
+///
+/// assert_eq!(scheme.on_surface, Argb::from_u32(0xff000000));
+/// assert_eq!(scheme.on_primary, Argb::from_u32(0xffffffff));
+/// ```
+pub struct SchemeHighContrastAccessible {
+    pub scheme: DynamicScheme,
+}
+
+impl SchemeHighContrastAccessible {
+    pub fn new(source_color_hct: Hct, is_dark: bool) -> Self {
+        Self {
+            scheme: SchemeTonalSpot::new(source_color_hct, is_dark, Some(1.0))
+                .scheme
+                .with_accessibility(AccessibilityOptions {
+                    high_contrast_text: true,
+                    ..AccessibilityOptions::DEFAULT
+                }),
+        }
+    }
+}
+
+impl From<SchemeHighContrastAccessible> for Scheme {
+    fn from(value: SchemeHighContrastAccessible) -> Self {
+        Self::from(value.scheme)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SchemeHighContrastAccessible;
+    use crate::{
+        color::Argb,
+        contrast::ratio_of_colors,
+        dynamic_color::{AccessibilityOptions, MaterialDynamicColors},
+        hct::Hct,
+        scheme::{variant::SchemeTonalSpot, Scheme},
+    };
+
+    #[test]
+    fn test_text_roles() {
+        let source = Hct::new(Argb::from_u32(0xff6750a4));
+
+        for is_dark in [false, true] {
+            let scheme: Scheme = SchemeHighContrastAccessible::new(source, is_dark).into();
+            let tonal_spot: Scheme = SchemeTonalSpot::new(source, is_dark, Some(1.0))
+                .scheme
+                .into();
+
+            let dynamic_scheme = SchemeHighContrastAccessible::new(source, is_dark).scheme;
+
+            for (text, backgrounds) in AccessibilityOptions::TEXT_ROLES {
+                let color = scheme[text];
+
+                assert!(color == Argb::from_u32(0xff000000) || color == Argb::from_u32(0xffffffff));
+                assert_eq!(
+                    MaterialDynamicColors::by_role(text).get_argb(&dynamic_scheme),
+                    color
+                );
+
+                for &background in backgrounds {
+                    assert!(ratio_of_colors(color, scheme[background]) >= 7.0);
+                }
+            }
+
+            assert_eq!(scheme.surface, tonal_spot.surface);
+            assert_eq!(scheme.primary, tonal_spot.primary);
+            assert_ne!(scheme.surface, Argb::from_u32(0xff000000));
+            assert_ne!(scheme.surface, Argb::from_u32(0xffffffff));
+        }
+    }
+}
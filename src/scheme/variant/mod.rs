@@ -1,19 +1,25 @@
+pub use builder::SchemeBuilder;
 pub use content::SchemeContent;
 pub use expressive::SchemeExpressive;
 pub use fidelity::SchemeFidelity;
 pub use fruit_salad::SchemeFruitSalad;
+pub use high_contrast_accessible::SchemeHighContrastAccessible;
 pub use monochrome::SchemeMonochrome;
 pub use neutral::SchemeNeutral;
 pub use rainbow::SchemeRainbow;
+pub use rotation::HueRotation;
 pub use tonal_spot::SchemeTonalSpot;
 pub use vibrant::SchemeVibrant;
 
+mod builder;
 mod content;
 mod expressive;
 mod fidelity;
 mod fruit_salad;
+mod high_contrast_accessible;
 mod monochrome;
 mod neutral;
 mod rainbow;
+mod rotation;
 mod tonal_spot;
 mod vibrant;
@@ -0,0 +1,42 @@
+use crate::{dynamic_color::DynamicScheme, utils::math::sanitize_degrees_double};
+
+/// Hue rotations of the secondary and tertiary palettes of a variant.
+///
+/// Returned by
+/// [`SchemeExpressive::rotation_for`](super::SchemeExpressive::rotation_for)
+/// and [`SchemeVibrant::rotation_for`](super::SchemeVibrant::rotation_for).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HueRotation {
+    /// Rotation of the secondary palette, in degrees.
+    pub secondary_rotation: f64,
+    /// Rotation of the tertiary palette, in degrees.
+    pub tertiary_rotation: f64,
+    /// Hue of the secondary palette.
+    pub secondary_hue: f64,
+    /// Hue of the tertiary palette.
+    pub tertiary_hue: f64,
+}
+
+impl HueRotation {
+    /// Looks up the rotations of `source_hue` in tables of breakpoints and
+    /// rotations, as [`DynamicScheme::get_rotated_hue`] does. A hue falling
+    /// on a breakpoint is not rotated.
+    pub(crate) fn of(
+        source_hue: f64,
+        hues: &[f64],
+        secondary_rotations: &[f64],
+        tertiary_rotations: &[f64],
+    ) -> Self {
+        let rotation =
+            |rotations| DynamicScheme::get_hue_rotation(source_hue, hues, rotations).unwrap_or(0.0);
+        let secondary_rotation = rotation(secondary_rotations);
+        let tertiary_rotation = rotation(tertiary_rotations);
+
+        Self {
+            secondary_rotation,
+            tertiary_rotation,
+            secondary_hue: sanitize_degrees_double(source_hue + secondary_rotation),
+            tertiary_hue: sanitize_degrees_double(source_hue + tertiary_rotation),
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use super::SchemeBuilder;
 use crate::{
     dynamic_color::{DynamicScheme, Variant},
     hct::Hct,
@@ -27,6 +28,11 @@ impl SchemeTonalSpot {
         }
     }
 
+    /// Returns a builder of this variant, to override some of its palettes.
+    pub const fn builder(source_color_hct: Hct) -> SchemeBuilder {
+        SchemeBuilder::new(source_color_hct, Variant::TonalSpot)
+    }
+
     pub fn palette(source_color_hct: &Hct, variant: &Palette) -> TonalPalette {
         match variant {
             Palette::Primary => TonalPalette::of(source_color_hct.get_hue(), 36.0),
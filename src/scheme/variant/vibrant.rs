@@ -1,3 +1,4 @@
+use super::{HueRotation, SchemeBuilder};
 use crate::{
     dynamic_color::{DynamicScheme, Variant},
     hct::Hct,
@@ -12,15 +13,19 @@ pub struct SchemeVibrant {
 impl SchemeVibrant {
     /// Hues used at breakpoints such that designers can specify a hue rotation
     /// that occurs at a given break point.
-    const HUES: [f64; 9] = [0.0, 41.0, 61.0, 101.0, 131.0, 181.0, 251.0, 301.0, 360.0];
+    ///
+    /// A source hue strictly between `HUES[i]` and `HUES[i + 1]` is rotated by
+    /// the `i`-th rotation; one falling on a breakpoint is not rotated.
+    pub const HUES: [f64; 9] = [0.0, 41.0, 61.0, 101.0, 131.0, 181.0, 251.0, 301.0, 360.0];
 
     /// Hue rotations of the Secondary [`TonalPalette`], corresponding to the
-    /// breakpoints in `hues`.
-    const SECONDARY_ROTATIONS: [f64; 9] = [18.0, 15.0, 10.0, 12.0, 15.0, 18.0, 15.0, 12.0, 12.0];
+    /// breakpoints in [`Self::HUES`].
+    pub const SECONDARY_ROTATIONS: [f64; 9] =
+        [18.0, 15.0, 10.0, 12.0, 15.0, 18.0, 15.0, 12.0, 12.0];
 
     /// Hue rotations of the Tertiary [`TonalPalette`], corresponding to the
-    /// breakpoints in `hues`.
-    const TERTIARY_ROTATIONS: [f64; 9] = [35.0, 30.0, 20.0, 25.0, 30.0, 35.0, 30.0, 25.0, 25.0];
+    /// breakpoints in [`Self::HUES`].
+    pub const TERTIARY_ROTATIONS: [f64; 9] = [35.0, 30.0, 20.0, 25.0, 30.0, 35.0, 30.0, 25.0, 25.0];
 
     pub fn new(source_color_hct: Hct, is_dark: bool, contrast_level: Option<f64>) -> Self {
         Self {
@@ -39,6 +44,22 @@ impl SchemeVibrant {
         }
     }
 
+    /// Returns a builder of this variant, to override some of its palettes.
+    pub const fn builder(source_color_hct: Hct) -> SchemeBuilder {
+        SchemeBuilder::new(source_color_hct, Variant::Vibrant)
+    }
+
+    /// Returns the rotations applied to `source_hue` and the resulting hues
+    /// of the secondary and tertiary palettes.
+    pub fn rotation_for(source_hue: f64) -> HueRotation {
+        HueRotation::of(
+            source_hue,
+            &Self::HUES,
+            &Self::SECONDARY_ROTATIONS,
+            &Self::TERTIARY_ROTATIONS,
+        )
+    }
+
     pub fn palette(source_color_hct: &Hct, variant: &Palette) -> TonalPalette {
         match variant {
             Palette::Primary => TonalPalette::of(source_color_hct.get_hue(), 200.0),
@@ -69,7 +90,27 @@ impl SchemeVibrant {
 #[cfg(test)]
 mod tests {
     use super::SchemeVibrant;
-    use crate::color::Argb;
+    use crate::{color::Argb, hct::Hct, palette::Palette};
+    use float_cmp::assert_approx_eq;
+
+    #[test]
+    fn test_rotation_for() {
+        let source = Hct::from(140.0, 48.0, 50.0);
+        let rotation = SchemeVibrant::rotation_for(source.get_hue());
+
+        assert_approx_eq!(f64, rotation.secondary_rotation, 15.0);
+        assert_approx_eq!(f64, rotation.tertiary_rotation, 30.0);
+        assert_approx_eq!(
+            f64,
+            SchemeVibrant::palette(&source, &Palette::Secondary).hue(),
+            rotation.secondary_hue
+        );
+        assert_approx_eq!(
+            f64,
+            SchemeVibrant::palette(&source, &Palette::Tertiary).hue(),
+            rotation.tertiary_hue
+        );
+    }
 
     #[test]
     fn test_key_colors() {
@@ -1,20 +1,27 @@
 #[cfg(all(not(feature = "std"), feature = "libm"))]
 #[allow(unused_imports)]
 use crate::utils::no_std::FloatExt;
+#[cfg(feature = "serde")]
+use crate::utils::precision;
 use crate::{
     color::Argb,
-    hct::Hct,
+    hct::{Cam16, Hct},
+    temperature::TemperatureCache,
     utils::math::{difference_degrees, sanitize_degrees_int},
     IndexMap,
 };
 #[cfg(not(feature = "std"))]
 use alloc::{vec, vec::Vec};
+use core::cmp::Reverse;
+#[cfg(feature = "serde")]
+use serde::Serialize;
 #[cfg(feature = "std")]
 use std::{vec, vec::Vec};
 
 #[derive(Debug)]
 struct ScoredHCT {
     hct: Hct,
+    proportion: f64,
     score: f64,
 }
 
@@ -26,13 +33,159 @@ struct ScoredHCT {
 ///  smaller number of appropriate choices.
 pub struct Score;
 
+/// Weights and cutoffs used by [`Score::score_with_options`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScoreOptions {
+    /// Chroma that neither raises nor lowers the score of a color.
+    pub target_chroma: f64,
+    /// Weight of the proportion of the image with a similar hue.
+    pub weight_proportion: f64,
+    /// Weight of the chroma difference for colors above `target_chroma`.
+    pub weight_chroma_above: f64,
+    /// Weight of the chroma difference for colors below `target_chroma`.
+    pub weight_chroma_below: f64,
+    /// Colors with less chroma are filtered out.
+    pub cutoff_chroma: f64,
+    /// Colors whose hue covers no more than this proportion of the image
+    /// are filtered out.
+    pub cutoff_excited_proportion: f64,
+    /// Start of the offsets, in degrees, over which the population of a hue
+    /// is spread to its neighbors, inclusive.
+    pub excitation_start: i32,
+    /// End of the offsets over which the population of a hue is spread to its
+    /// neighbors, exclusive.
+    pub excitation_end: i32,
+    /// Weight of the temperature of a color, see
+    /// [`TemperatureCache::raw_temperature`].
+    ///
+    /// Positive values prefer warm colors and negative values cool ones. The
+    /// raw temperature of saturated colors is around ±5, so a bias of 1
+    /// shifts scores by about as much as 7% of the image does with the
+    /// default weights.
+    pub temperature_bias: f64,
+}
+
+impl ScoreOptions {
+    /// Options used by [`Score::score`].
+    pub const DEFAULT: Self = Self {
+        target_chroma: 48.0, // A1 Chroma
+        weight_proportion: 0.7,
+        weight_chroma_above: 0.3,
+        weight_chroma_below: 0.1,
+        cutoff_chroma: 5.0,
+        cutoff_excited_proportion: 0.01,
+        excitation_start: -14,
+        excitation_end: 16,
+        temperature_bias: 0.0,
+    };
+}
+
+impl Default for ScoreOptions {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Confidence in the color recommended by [`Score::score_with_confidence`].
+///
+/// Every component ranges from 0 to 1, higher meaning more confident.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct SeedConfidence {
+    /// Proportion of the image with a hue close to the recommended color,
+    /// i.e. within [`ScoreOptions::excitation_start`] and
+    /// [`ScoreOptions::excitation_end`] of it.
+    #[cfg_attr(feature = "serde", serde(serialize_with = "precision::serialize"))]
+    pub dominance: f64,
+    /// Chroma of the recommended color relative to
+    /// [`ScoreOptions::target_chroma`], capped at 1.
+    #[cfg_attr(feature = "serde", serde(serialize_with = "precision::serialize"))]
+    pub chroma: f64,
+    /// Margin of the score of the recommended color over the best color of a
+    /// different hue, relative to both scores. 1 if there is no such color.
+    #[cfg_attr(feature = "serde", serde(serialize_with = "precision::serialize"))]
+    pub margin: f64,
+}
+
+impl SeedConfidence {
+    /// Confidence of a fallback color, which was not found in the image.
+    pub const NONE: Self = Self {
+        dominance: 0.0,
+        chroma: 0.0,
+        margin: 0.0,
+    };
+
+    /// Overall confidence from 0 to 1, the mean of the components.
+    pub fn value(&self) -> f64 {
+        (self.dominance + self.chroma + self.margin) / 3.0
+    }
+}
+
 impl Score {
-    const TARGET_CHROMA: f64 = 48.0; // A1 Chroma
-    const WEIGHT_PROPORTION: f64 = 0.7;
-    const WEIGHT_CHROMA_ABOVE: f64 = 0.3;
-    const WEIGHT_CHROMA_BELOW: f64 = 0.1;
-    const CUTOFF_CHROMA: f64 = 5.0;
-    const CUTOFF_EXCITED_PROPORTION: f64 = 0.01;
+    /// Merges clusters whose colors are within `max_distance` of each other in
+    /// CAM16-UCS, so that near-identical clusters don't split population
+    /// before scoring.
+    ///
+    /// Clusters are visited from the most to the least populous; each one is
+    /// merged into the first kept cluster within `max_distance`, which keeps
+    /// its color and gains its population. A `max_distance` of 0 or less
+    /// returns the input unchanged.
+    ///
+    /// - Parameters:
+    ///   `colorsToPopulation`: is a map with keys of colors and values of often
+    ///   the color appears, usually from a quantizer.
+    ///   `maxDistance`: Max CAM16-UCS distance (ΔE) between merged colors.
+    pub fn merge_similar(
+        colors_to_population: &IndexMap<Argb, u32>,
+        max_distance: f64,
+    ) -> IndexMap<Argb, u32> {
+        if max_distance <= 0.0 {
+            return colors_to_population.clone();
+        }
+
+        let mut clusters = colors_to_population
+            .iter()
+            .map(|(argb, population)| (*argb, *population))
+            .collect::<Vec<_>>();
+
+        clusters.sort_by_key(|(_, population)| Reverse(*population));
+
+        let mut kept: Vec<(Argb, Cam16, u32)> = vec![];
+
+        for (argb, population) in clusters {
+            let cam = Cam16::from(argb);
+
+            match kept
+                .iter_mut()
+                .find(|(_, other, _)| cam.distance(other) <= max_distance)
+            {
+                Some((_, _, total)) => *total += population,
+                None => kept.push((argb, cam, population)),
+            }
+        }
+
+        kept.into_iter()
+            .map(|(argb, _, population)| (argb, population))
+            .collect()
+    }
+
+    /// Like [`Score::score`], but first merges clusters within `max_distance`
+    /// of each other with [`Score::merge_similar`].
+    pub fn score_merged(
+        colors_to_population: &IndexMap<Argb, u32>,
+        max_distance: f64,
+        desired: Option<i32>,
+        fallback_color_argb: Option<Argb>,
+        filter: Option<bool>,
+    ) -> Vec<Argb> {
+        Self::score(
+            &Self::merge_similar(colors_to_population, max_distance),
+            desired,
+            fallback_color_argb,
+            filter,
+        )
+    }
+
     /// Given a map with keys of colors and values of how often the color appears,
     /// rank the colors based on suitability for being used for a UI theme.
     ///
@@ -55,10 +208,117 @@ impl Score {
         desired: Option<i32>,
         fallback_color_argb: Option<Argb>,
         filter: Option<bool>,
+    ) -> Vec<Argb> {
+        Self::score_with_options(
+            colors_to_population,
+            desired,
+            fallback_color_argb,
+            filter,
+            &ScoreOptions::DEFAULT,
+        )
+    }
+
+    /// Like [`Score::score`], but with custom weights and cutoffs.
+    pub fn score_with_options(
+        colors_to_population: &IndexMap<Argb, u32>,
+        desired: Option<i32>,
+        fallback_color_argb: Option<Argb>,
+        filter: Option<bool>,
+        options: &ScoreOptions,
     ) -> Vec<Argb> {
         let desired = desired.unwrap_or(4);
         let fallback_color_argb = fallback_color_argb.unwrap_or(Argb::new(255, 66, 133, 244));
-        let filter = filter.unwrap_or(true);
+        let scored_hcts = Self::scored(colors_to_population, filter.unwrap_or(true), options);
+
+        // Iterates through potential hue differences in degrees in order to select
+        // the colors with the largest distribution of hues possible. Starting at
+        // 90 degrees(maximum difference for 4 colors) then decreasing down to a
+        // 15 degree minimum.
+        let mut chosen_colors: Vec<Hct> = vec![];
+
+        for difference_degree in (15..=90).rev() {
+            chosen_colors.clear();
+
+            for entry in &scored_hcts {
+                let hct = entry.hct;
+
+                if !chosen_colors.iter().any(|color| {
+                    difference_degrees(entry.hct.get_hue(), color.get_hue())
+                        < f64::from(difference_degree)
+                }) {
+                    chosen_colors.push(hct);
+                }
+
+                if chosen_colors.len() >= desired as usize {
+                    break;
+                }
+            }
+
+            if chosen_colors.len() >= desired as usize {
+                break;
+            }
+        }
+
+        let mut colors = vec![];
+
+        if chosen_colors.is_empty() {
+            colors.push(fallback_color_argb);
+        }
+
+        for chosen_hct in chosen_colors {
+            colors.push(Argb::from(chosen_hct));
+        }
+
+        colors
+    }
+
+    /// Like [`Score::score_with_options`], but returns only the recommended
+    /// color, along with how confident the recommendation is.
+    ///
+    /// Applications can compare [`SeedConfidence::value`] with a threshold to
+    /// fall back to a default theme when an image is too noisy or neutral.
+    /// If no color is suitable, `fallback_color_argb` is returned with
+    /// [`SeedConfidence::NONE`].
+    pub fn score_with_confidence(
+        colors_to_population: &IndexMap<Argb, u32>,
+        fallback_color_argb: Option<Argb>,
+        filter: Option<bool>,
+        options: &ScoreOptions,
+    ) -> (Argb, SeedConfidence) {
+        let fallback_color_argb = fallback_color_argb.unwrap_or(Argb::new(255, 66, 133, 244));
+        let scored_hcts = Self::scored(colors_to_population, filter.unwrap_or(true), options);
+
+        let best = match scored_hcts.first() {
+            Some(best) => best,
+            None => return (fallback_color_argb, SeedConfidence::NONE),
+        };
+
+        // Colors of a similar hue would lead to a similar theme, so only the
+        // best color of a different hue competes with the recommendation.
+        let margin = scored_hcts
+            .iter()
+            .find(|entry| difference_degrees(entry.hct.get_hue(), best.hct.get_hue()) >= 15.0)
+            .map_or(1.0, |runner_up| {
+                (best.score - runner_up.score)
+                    / (best.score.abs() + runner_up.score.abs()).max(f64::EPSILON)
+            });
+
+        let confidence = SeedConfidence {
+            dominance: best.proportion.min(1.0),
+            chroma: (best.hct.get_chroma() / options.target_chroma).min(1.0),
+            margin,
+        };
+
+        (Argb::from(best.hct), confidence)
+    }
+
+    /// Scores each color of `colors_to_population`, with the highest score
+    /// first.
+    fn scored(
+        colors_to_population: &IndexMap<Argb, u32>,
+        filter: bool,
+        options: &ScoreOptions,
+    ) -> Vec<ScoredHCT> {
         // Get the HCT color for each Argb value, while finding the per hue count and
         // total count.
         let mut colors_hct = vec![];
@@ -82,7 +342,8 @@ impl Score {
         for (hue, population) in hue_population.into_iter().enumerate().take(360) {
             let proportion = f64::from(population) / population_sum;
 
-            for i in ((hue as i32) - 14)..((hue as i32) + 16) {
+            for i in (hue as i32 + options.excitation_start)..(hue as i32 + options.excitation_end)
+            {
                 let neighbor_hue = sanitize_degrees_int(i);
 
                 hue_excited_proportions[neighbor_hue as usize] += proportion;
@@ -100,75 +361,151 @@ impl Score {
             let proportion = hue_excited_proportions[hue as usize];
 
             if filter
-                && (hct.get_chroma() < Self::CUTOFF_CHROMA
-                    || proportion <= Self::CUTOFF_EXCITED_PROPORTION)
+                && (hct.get_chroma() < options.cutoff_chroma
+                    || proportion <= options.cutoff_excited_proportion)
             {
                 continue;
             }
 
-            let proportion_score = proportion * 100.0 * Self::WEIGHT_PROPORTION;
-            let chroma_weight = if hct.get_chroma() < Self::TARGET_CHROMA {
-                Self::WEIGHT_CHROMA_BELOW
+            let proportion_score = proportion * 100.0 * options.weight_proportion;
+            let chroma_weight = if hct.get_chroma() < options.target_chroma {
+                options.weight_chroma_below
             } else {
-                Self::WEIGHT_CHROMA_ABOVE
+                options.weight_chroma_above
             };
-            let chroma_score = (hct.get_chroma() - Self::TARGET_CHROMA) * chroma_weight;
-            let score = proportion_score + chroma_score;
-
-            scored_hcts.push(ScoredHCT { hct, score });
+            let chroma_score = (hct.get_chroma() - options.target_chroma) * chroma_weight;
+            let temperature_score =
+                TemperatureCache::raw_temperature(&hct) * options.temperature_bias;
+            let score = proportion_score + chroma_score + temperature_score;
+
+            scored_hcts.push(ScoredHCT {
+                hct,
+                proportion,
+                score,
+            });
         }
 
         // Sorted so that colors with higher scores come first.
         // SAFETY: The score will never be NAN, so using `unwrap_unchecked` is completely safe
         scored_hcts.sort_by(|a, b| unsafe { b.score.partial_cmp(&a.score).unwrap_unchecked() });
 
-        // Iterates through potential hue differences in degrees in order to select
-        // the colors with the largest distribution of hues possible. Starting at
-        // 90 degrees(maximum difference for 4 colors) then decreasing down to a
-        // 15 degree minimum.
-        let mut chosen_colors: Vec<Hct> = vec![];
+        scored_hcts
+    }
+}
 
-        for difference_degree in (15..=90).rev() {
-            chosen_colors.clear();
+#[cfg(test)]
+mod tests {
+    use super::{Score, ScoreOptions, SeedConfidence};
+    use crate::{color::Argb, IndexMap};
 
-            for entry in &scored_hcts {
-                let hct = entry.hct;
+    #[test]
+    fn test_score_with_options() {
+        let argb_to_population: IndexMap<Argb, u32> = IndexMap::from_iter([
+            (Argb::from_u32(0xff4c6a8a), 1),
+            (Argb::from_u32(0xffff0000), 1),
+        ]);
+        let options = ScoreOptions {
+            cutoff_chroma: 100.0,
+            ..ScoreOptions::DEFAULT
+        };
+
+        assert_eq!(
+            Score::score_with_options(
+                &argb_to_population,
+                None,
+                None,
+                None,
+                &ScoreOptions::DEFAULT
+            ),
+            Score::score(&argb_to_population, None, None, None)
+        );
+        assert_eq!(
+            Score::score_with_options(&argb_to_population, None, None, None, &options),
+            [Argb::from_u32(0xffff0000)]
+        );
+    }
 
-                if !chosen_colors.iter().any(|color| {
-                    difference_degrees(entry.hct.get_hue(), color.get_hue())
-                        < f64::from(difference_degree)
-                }) {
-                    chosen_colors.push(hct);
-                }
+    #[test]
+    fn test_temperature_bias() {
+        let argb_to_population: IndexMap<Argb, u32> = IndexMap::from_iter([
+            (Argb::from_u32(0xffd04030), 2),
+            (Argb::from_u32(0xff3050d0), 3),
+        ]);
+        let score = |temperature_bias| {
+            let options = ScoreOptions {
+                temperature_bias,
+                ..ScoreOptions::DEFAULT
+            };
 
-                if chosen_colors.len() >= desired as usize {
-                    break;
-                }
-            }
+            Score::score_with_options(&argb_to_population, Some(1), None, None, &options)
+        };
 
-            if chosen_colors.len() >= desired as usize {
-                break;
+        assert_eq!(score(0.0), [Argb::from_u32(0xff3050d0)]);
+        assert_eq!(score(-5.0), [Argb::from_u32(0xff3050d0)]);
+        assert_eq!(score(5.0), [Argb::from_u32(0xffd04030)]);
+    }
+
+    #[test]
+    fn test_score_with_confidence() {
+        let score = |colors: &[(u32, u32)]| {
+            let argb_to_population: IndexMap<Argb, u32> = colors
+                .iter()
+                .map(|&(argb, population)| (Argb::from_u32(argb), population))
+                .collect();
+
+            Score::score_with_confidence(&argb_to_population, None, None, &ScoreOptions::DEFAULT)
+        };
+
+        let (seed, confidence) = score(&[(0xffff0000, 10)]);
+
+        assert_eq!(seed, Argb::from_u32(0xffff0000));
+        assert_eq!(
+            confidence,
+            SeedConfidence {
+                dominance: 1.0,
+                chroma: 1.0,
+                margin: 1.0
             }
-        }
+        );
 
-        let mut colors = vec![];
+        let (_, split) = score(&[(0xffff0000, 10), (0xff0000ff, 10)]);
+        let (_, dominated) = score(&[(0xffff0000, 10), (0xff0000ff, 1)]);
+        let (_, muted) = score(&[(0xff806a68, 10), (0xff686a80, 1)]);
 
-        if chosen_colors.is_empty() {
-            colors.push(fallback_color_argb);
-        }
+        assert!(split.margin < dominated.margin);
+        assert!(split.value() < dominated.value());
+        assert!(muted.chroma < 0.5);
+        assert!(muted.value() < dominated.value());
 
-        for chosen_hct in chosen_colors {
-            colors.push(Argb::from(chosen_hct));
-        }
+        let (seed, confidence) = score(&[(0xff808080, 10)]);
 
-        colors
+        assert_eq!(seed, Argb::from_u32(0xff4285f4));
+        assert_eq!(confidence, SeedConfidence::NONE);
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::Score;
-    use crate::{color::Argb, IndexMap};
+    #[test]
+    fn test_merge_similar() {
+        let argb_to_population: IndexMap<Argb, u32> = IndexMap::from_iter([
+            (Argb::from_u32(0xffff0000), 3),
+            (Argb::from_u32(0xfffe0101), 5),
+            (Argb::from_u32(0xff0000ff), 4),
+        ]);
+
+        let merged = Score::merge_similar(&argb_to_population, 2.0);
+
+        assert_eq!(merged.len(), 2);
+        assert_eq!(merged.get(&Argb::from_u32(0xfffe0101)), Some(&8));
+        assert_eq!(merged.get(&Argb::from_u32(0xff0000ff)), Some(&4));
+        assert_eq!(
+            Score::merge_similar(&argb_to_population, 0.0),
+            argb_to_population
+        );
+
+        let ranked = Score::score_merged(&argb_to_population, 2.0, None, None, None);
+
+        assert_eq!(ranked.len(), 2);
+        assert_eq!(ranked[0], Argb::from_u32(0xfffe0101));
+    }
 
     #[test]
     fn test_prioritizes_chroma() {
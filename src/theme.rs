@@ -1,27 +1,54 @@
+#[cfg(feature = "std")]
+use crate::scheme::write_ansi_swatch;
+#[cfg(feature = "serde")]
+use crate::utils::precision;
 #[allow(deprecated)]
 use crate::{
     blend::harmonize,
     color::Argb,
-    dynamic_color::{DynamicScheme, Variant},
+    dynamic_color::{AccessibilityOptions, DynamicScheme, ToneTable, Variant},
+    hct::Hct,
     palette::{CorePalette, Palette, TonalPalette},
     scheme::Scheme,
+    trace::{GenerationTrace, Stage, Stopwatch},
 };
 #[cfg(not(feature = "std"))]
 use alloc::{string::String, vec::Vec};
+#[cfg(feature = "rayon")]
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
 #[cfg(feature = "serde")]
 use serde::Serialize;
 #[cfg(feature = "std")]
 use std::{string::String, vec::Vec};
 
 /// Custom color used to pair with a theme
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct CustomColor {
-    pub value: Argb,
+    /// Serialized as the [`Argb`] it maps to.
+    #[cfg_attr(feature = "serde", serde(serialize_with = "serialize_as_argb"))]
+    pub value: Hct,
     pub name: String,
     pub blend: bool,
 }
 
+impl CustomColor {
+    /// Creates a custom color from any color convertible into [`Hct`], like
+    /// [`ThemeBuilder::with_source`].
+    pub fn new<T: Into<Hct>, N: Into<String>>(value: T, name: N, blend: bool) -> Self {
+        Self {
+            value: value.into(),
+            name: name.into(),
+            blend,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+fn serialize_as_argb<S: serde::Serializer>(value: &Hct, serializer: S) -> Result<S::Ok, S::Error> {
+    Argb::from(*value).serialize(serializer)
+}
+
 /// Color group
 #[derive(Debug)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
@@ -46,21 +73,21 @@ impl CustomColorGroup {
     /// Generate custom color group from source and target color
     ///
     /// @link <https://m3.material.io/styles/color/the-color-system/color-roles>
-    fn new(source: Argb, color: CustomColor) -> Self {
+    fn new(source: Hct, color: CustomColor) -> Self {
         let mut value = color.value;
 
         if color.blend {
-            value = harmonize(value, source);
+            value = Hct::new(harmonize(value.into(), source.into()));
         }
 
         #[allow(deprecated)]
-        let palette = CorePalette::of(value);
+        let palette = CorePalette::from_hct(value);
         #[allow(deprecated)]
         let tones = palette.primary;
 
         Self {
             color,
-            value,
+            value: value.into(),
             light: ColorGroup {
                 color: tones.tone(40),
                 on_color: tones.tone(100),
@@ -84,6 +111,65 @@ pub struct Schemes {
     pub dark: Scheme,
 }
 
+/// Light and dark schemes at the standard, medium and high contrast levels,
+/// the six schemes exported by theme packaging pipelines.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct ContrastSchemes {
+    pub light: Scheme,
+    pub light_medium_contrast: Scheme,
+    pub light_high_contrast: Scheme,
+    pub dark: Scheme,
+    pub dark_medium_contrast: Scheme,
+    pub dark_high_contrast: Scheme,
+}
+
+impl ContrastSchemes {
+    /// Standard, medium and high contrast levels, in field order.
+    pub const CONTRAST_LEVELS: [f64; 3] = [0.0, 0.5, 1.0];
+
+    /// Generates the schemes of `variant` from `source`.
+    pub fn new<T: Into<Hct>>(source: T, variant: &Variant) -> Self {
+        Self::from_dynamic(&DynamicScheme::by_variant(source, variant, false, None))
+    }
+
+    /// Resolves the six schemes from the palettes of `scheme`, ignoring its
+    /// mode and contrast level.
+    ///
+    /// Palettes and their key colors are computed once and shared, unlike
+    /// calling [`DynamicScheme::by_variant`] for every scheme. With the
+    /// `rayon` feature, the schemes are resolved in parallel.
+    pub fn from_dynamic(scheme: &DynamicScheme) -> Self {
+        let resolve = |index: usize| {
+            let mut scheme = scheme.clone();
+
+            scheme.is_dark = index >= Self::CONTRAST_LEVELS.len();
+            scheme.contrast_level = Self::CONTRAST_LEVELS[index % Self::CONTRAST_LEVELS.len()];
+
+            Scheme::from(scheme)
+        };
+
+        #[cfg(feature = "rayon")]
+        let schemes = (0..6).into_par_iter().map(resolve).collect::<Vec<_>>();
+        #[cfg(not(feature = "rayon"))]
+        let schemes = (0..6).map(resolve).collect::<Vec<_>>();
+
+        match <[Scheme; 6]>::try_from(schemes) {
+            Ok(
+                [light, light_medium_contrast, light_high_contrast, dark, dark_medium_contrast, dark_high_contrast],
+            ) => Self {
+                light,
+                light_medium_contrast,
+                light_high_contrast,
+                dark,
+                dark_medium_contrast,
+                dark_high_contrast,
+            },
+            Err(_) => unreachable!("six schemes are resolved"),
+        }
+    }
+}
+
 #[derive(Debug)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct Palettes {
@@ -96,24 +182,42 @@ pub struct Palettes {
 }
 
 pub struct ThemeBuilder {
-    source: Argb,
+    source: Hct,
     variant: Variant,
     color_match: bool,
-    primary: Option<Argb>,
-    secondary: Option<Argb>,
-    tertiary: Option<Argb>,
-    error: Option<Argb>,
-    neutral: Option<Argb>,
-    neutral_variant: Option<Argb>,
+    primary: Option<Hct>,
+    secondary: Option<Hct>,
+    tertiary: Option<Hct>,
+    error: Option<TonalPalette>,
+    neutral: Option<Hct>,
+    neutral_variant: Option<Hct>,
     custom_colors: Vec<CustomColor>,
+    schedule: Vec<ScheduleEntry>,
+    pure_black: bool,
+    accessibility: AccessibilityOptions,
+    tone_table: ToneTable,
+    timestamp: Option<u64>,
+    context: Option<String>,
+}
+
+struct ScheduleEntry {
+    start: f64,
+    source: Hct,
+    contrast_level: f64,
 }
 
 impl ThemeBuilder {
     /// Creates a theme builder with a custom source color.
+    ///
+    /// Accepts any color convertible into [`Hct`], such as [`Argb`], [`Hct`]
+    /// or [`Lab`], so colors from pickers or sensors are used as precisely as
+    /// possible.
+    ///
+    /// [`Lab`]: crate::color::Lab
     #[must_use]
-    pub const fn with_source(source: Argb) -> Self {
+    pub fn with_source<T: Into<Hct>>(source: T) -> Self {
         Self {
-            source,
+            source: source.into(),
             variant: Variant::TonalSpot,
             color_match: false,
             primary: None,
@@ -123,6 +227,12 @@ impl ThemeBuilder {
             neutral: None,
             neutral_variant: None,
             custom_colors: Vec::new(),
+            schedule: Vec::new(),
+            pure_black: false,
+            accessibility: AccessibilityOptions::DEFAULT,
+            tone_table: ToneTable::new(),
+            timestamp: None,
+            context: None,
         }
     }
 
@@ -136,48 +246,62 @@ impl ThemeBuilder {
 
     /// Sets the primary color of the theme.
     #[must_use]
-    pub const fn primary(mut self, color: Argb) -> Self {
-        self.primary = Some(color);
+    pub fn primary<T: Into<Hct>>(mut self, color: T) -> Self {
+        self.primary = Some(color.into());
 
         self
     }
 
     /// Sets the secondary color of the theme.
     #[must_use]
-    pub const fn secondary(mut self, color: Argb) -> Self {
-        self.secondary = Some(color);
+    pub fn secondary<T: Into<Hct>>(mut self, color: T) -> Self {
+        self.secondary = Some(color.into());
 
         self
     }
 
     /// Sets the tertiary color of the theme.
     #[must_use]
-    pub const fn tertiary(mut self, color: Argb) -> Self {
-        self.tertiary = Some(color);
+    pub fn tertiary<T: Into<Hct>>(mut self, color: T) -> Self {
+        self.tertiary = Some(color.into());
+
+        self
+    }
+
+    /// Sets the error color of the theme, replacing the default red error
+    /// palette with the palette of the hue and chroma of `color`.
+    ///
+    /// Error roles still meet their contrast requirements, since their tones
+    /// are picked by [`MaterialDynamicColors`] as for the default palette.
+    ///
+    /// [`MaterialDynamicColors`]: crate::dynamic_color::MaterialDynamicColors
+    #[must_use]
+    pub fn error<T: Into<Hct>>(mut self, color: T) -> Self {
+        self.error = Some(TonalPalette::from_hct(color.into()));
 
         self
     }
 
-    /// Sets the error color of the theme.
+    /// Sets the error palette of the theme, see [`ThemeBuilder::error`].
     #[must_use]
-    pub const fn error(mut self, color: Argb) -> Self {
-        self.error = Some(color);
+    pub const fn error_palette(mut self, palette: TonalPalette) -> Self {
+        self.error = Some(palette);
 
         self
     }
 
     /// Sets the neutral color, used for background and surfaces.
     #[must_use]
-    pub const fn neutral(mut self, color: Argb) -> Self {
-        self.neutral = Some(color);
+    pub fn neutral<T: Into<Hct>>(mut self, color: T) -> Self {
+        self.neutral = Some(color.into());
 
         self
     }
 
     /// Sets the neutral variant color, used for for medium emphasis and variants.
     #[must_use]
-    pub const fn neutral_variant(mut self, color: Argb) -> Self {
-        self.neutral_variant = Some(color);
+    pub fn neutral_variant<T: Into<Hct>>(mut self, color: T) -> Self {
+        self.neutral_variant = Some(color.into());
 
         self
     }
@@ -199,69 +323,193 @@ impl ThemeBuilder {
         self
     }
 
+    /// Lowers the surfaces of dark schemes to pure black, for AMOLED displays,
+    /// see [`DynamicScheme::pure_black`].
     #[must_use]
-    pub fn build(mut self) -> Theme {
-        #[allow(deprecated)]
-        let palette = CorePalette::of(self.source);
+    pub const fn pure_black(mut self, enabled: bool) -> Self {
+        self.pure_black = enabled;
 
-        if self.color_match {
-            self.variant = Variant::Fidelity;
-        }
+        self
+    }
 
-        let mut light = DynamicScheme::by_variant(self.source, &self.variant, false, None);
-        let mut dark = DynamicScheme::by_variant(self.source, &self.variant, true, None);
+    /// Sets the accessibility settings of the platform honored by the
+    /// schemes, see [`AccessibilityOptions`].
+    #[must_use]
+    pub const fn accessibility(mut self, accessibility: AccessibilityOptions) -> Self {
+        self.accessibility = accessibility;
+
+        self
+    }
+
+    /// Sets the tones roles start from in every scheme of the theme, e.g. to
+    /// adjust containers design-system-wide, see [`ToneTable`].
+    #[must_use]
+    pub fn tone_table(mut self, tone_table: ToneTable) -> Self {
+        self.tone_table = tone_table;
+
+        self
+    }
+
+    /// Sets the time the theme is generated at, stored in its
+    /// [`ThemeMetadata`]. The crate has no clock, so it is up to the caller,
+    /// e.g. seconds since the Unix epoch.
+    #[must_use]
+    pub const fn timestamp(mut self, timestamp: u64) -> Self {
+        self.timestamp = Some(timestamp);
+
+        self
+    }
+
+    /// Sets a free-form description of how the theme is generated, stored in
+    /// its [`ThemeMetadata`], e.g. the name and version of the application.
+    #[must_use]
+    pub fn context<T: Into<String>>(mut self, context: T) -> Self {
+        self.context = Some(context.into());
+
+        self
+    }
+
+    /// Adds an entry to the schedule of the theme, active from `start` until
+    /// the start of the next entry, see [`Theme::schemes_at`].
+    ///
+    /// Its schemes are generated from `source` at `contrast_level`, with the
+    /// variant and palette colors of this builder.
+    #[must_use]
+    pub fn schedule<T: Into<Hct>>(mut self, start: f64, source: T, contrast_level: f64) -> Self {
+        self.schedule.push(ScheduleEntry {
+            start,
+            source: source.into(),
+            contrast_level,
+        });
+
+        self
+    }
+
+    fn schemes(&self, source: Hct, contrast_level: Option<f64>) -> Schemes {
+        let mut light = DynamicScheme::by_variant(source, &self.variant, false, contrast_level)
+            .with_accessibility(self.accessibility)
+            .with_tone_table(self.tone_table.clone());
+        let mut dark = DynamicScheme::by_variant(source, &self.variant, true, contrast_level)
+            .with_pure_black(self.pure_black)
+            .with_accessibility(self.accessibility)
+            .with_tone_table(self.tone_table.clone());
 
         if let Some(color) = self.primary {
-            let palette = TonalPalette::by_variant(&color.into(), &self.variant, &Palette::Primary);
+            let palette = TonalPalette::by_variant(&color, &self.variant, &Palette::Primary);
 
             light.primary_palette = palette;
             dark.primary_palette = palette;
         }
 
         if let Some(color) = self.secondary {
-            let palette =
-                TonalPalette::by_variant(&color.into(), &self.variant, &Palette::Secondary);
+            let palette = TonalPalette::by_variant(&color, &self.variant, &Palette::Secondary);
 
             light.secondary_palette = palette;
             dark.secondary_palette = palette;
         }
 
         if let Some(color) = self.tertiary {
-            let palette =
-                TonalPalette::by_variant(&color.into(), &self.variant, &Palette::Tertiary);
+            let palette = TonalPalette::by_variant(&color, &self.variant, &Palette::Tertiary);
 
             light.tertiary_palette = palette;
             dark.tertiary_palette = palette;
         }
 
-        if let Some(color) = self.error {
-            let palette = TonalPalette::by_variant(&color.into(), &self.variant, &Palette::Error);
-
+        if let Some(palette) = self.error {
             light.error_palette = palette;
             dark.error_palette = palette;
         }
 
         if let Some(color) = self.neutral {
-            let palette = TonalPalette::by_variant(&color.into(), &self.variant, &Palette::Neutral);
+            let palette = TonalPalette::by_variant(&color, &self.variant, &Palette::Neutral);
 
             light.neutral_palette = palette;
             dark.neutral_palette = palette;
         }
 
         if let Some(color) = self.neutral_variant {
-            let palette =
-                TonalPalette::by_variant(&color.into(), &self.variant, &Palette::NeutralVariant);
+            let palette = TonalPalette::by_variant(&color, &self.variant, &Palette::NeutralVariant);
 
             light.neutral_variant_palette = palette;
             dark.neutral_variant_palette = palette;
         }
 
+        Schemes {
+            light: light.into(),
+            dark: dark.into(),
+        }
+    }
+
+    #[must_use]
+    pub fn build(self) -> Theme {
+        self.build_with(&mut Stopwatch::new(false))
+    }
+
+    /// Like [`ThemeBuilder::build`], but also returns a trace of the inputs
+    /// and the time spent in each stage, see [`GenerationTrace`].
+    #[must_use]
+    pub fn build_traced(self) -> (Theme, GenerationTrace) {
+        let mut stopwatch = Stopwatch::new(true);
+        let variant = if self.color_match {
+            Variant::Fidelity
+        } else {
+            self.variant.clone()
+        };
+        let color_match = self.color_match;
+        let pure_black = self.pure_black;
+        let theme = self.build_with(&mut stopwatch);
+        let trace = GenerationTrace {
+            source: theme.source,
+            variant,
+            contrast_level: 0.0,
+            color_match,
+            pure_black,
+            seed: None,
+            stages: stopwatch.into_stages(),
+        };
+
+        (theme, trace)
+    }
+
+    fn build_with(mut self, stopwatch: &mut Stopwatch) -> Theme {
+        #[allow(deprecated)]
+        let palette = CorePalette::from_hct(self.source);
+
+        stopwatch.finish(Stage::Palettes);
+
+        if self.color_match {
+            self.variant = Variant::Fidelity;
+        }
+
+        let source = Argb::from(self.source);
+        let schemes = self.schemes(self.source, None);
+        let mut schedule = self
+            .schedule
+            .iter()
+            .map(|entry| ScheduledSchemes {
+                start: entry.start,
+                source: Argb::from(entry.source),
+                contrast_level: entry.contrast_level,
+                schemes: self.schemes(entry.source, Some(entry.contrast_level)),
+            })
+            .collect::<Vec<_>>();
+
+        schedule.sort_by(|a, b| a.start.total_cmp(&b.start));
+
+        stopwatch.finish(Stage::Schemes);
+
+        let custom_colors = self
+            .custom_colors
+            .into_iter()
+            .map(|color| CustomColorGroup::new(self.source, color))
+            .collect();
+
+        stopwatch.finish(Stage::CustomColors);
+
         Theme {
-            source: self.source,
-            schemes: Schemes {
-                light: light.into(),
-                dark: dark.into(),
-            },
+            source,
+            schemes,
+            schedule,
             #[allow(deprecated)]
             palettes: Palettes {
                 primary: palette.primary,
@@ -269,13 +517,14 @@ impl ThemeBuilder {
                 tertiary: palette.tertiary,
                 neutral: palette.neutral,
                 neutral_variant: palette.neutral_variant,
-                error: palette.error,
+                error: self.error.unwrap_or(palette.error),
+            },
+            custom_colors,
+            metadata: ThemeMetadata {
+                timestamp: self.timestamp,
+                context: self.context,
+                ..ThemeMetadata::default()
             },
-            custom_colors: self
-                .custom_colors
-                .into_iter()
-                .map(|color| CustomColorGroup::new(self.source, color))
-                .collect(),
         }
     }
 }
@@ -285,6 +534,434 @@ impl ThemeBuilder {
 pub struct Theme {
     pub source: Argb,
     pub schemes: Schemes,
+    /// Schemes replacing [`Theme::schemes`] during parts of a period, e.g. a
+    /// day, sorted by start. See [`Theme::schemes_at`].
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Vec::is_empty"))]
+    pub schedule: Vec<ScheduledSchemes>,
     pub palettes: Palettes,
     pub custom_colors: Vec<CustomColorGroup>,
+    pub metadata: ThemeMetadata,
+}
+
+/// How a [`Theme`] was generated, to decide whether a stored theme should be
+/// regenerated after upgrading the crate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct ThemeMetadata {
+    /// Version of this crate, [`ThemeMetadata::CRATE_VERSION`].
+    pub crate_version: &'static str,
+    /// Version of the Material color specification implemented by the
+    /// crate, [`ThemeMetadata::SPEC_VERSION`].
+    pub spec_version: &'static str,
+    /// Caller-supplied time of generation, see [`ThemeBuilder::timestamp`].
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub timestamp: Option<u64>,
+    /// Caller-supplied context, see [`ThemeBuilder::context`].
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub context: Option<String>,
+}
+
+impl ThemeMetadata {
+    /// Version of this crate.
+    pub const CRATE_VERSION: &'static str = env!("CARGO_PKG_VERSION");
+
+    /// Version of the Material color specification, the 2021 release of
+    /// Material Design 3. Changes whenever generated colors change, even
+    /// without a new specification.
+    pub const SPEC_VERSION: &'static str = "2021";
+}
+
+impl Default for ThemeMetadata {
+    fn default() -> Self {
+        Self {
+            crate_version: Self::CRATE_VERSION,
+            spec_version: Self::SPEC_VERSION,
+            timestamp: None,
+            context: None,
+        }
+    }
+}
+
+/// Schemes of a [`Theme`] active from `start` until the start of the next
+/// entry of its schedule.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct ScheduledSchemes {
+    /// Start of the entry, in the unit of the time passed to
+    /// [`Theme::schemes_at`], e.g. hours since midnight.
+    #[cfg_attr(feature = "serde", serde(serialize_with = "precision::serialize"))]
+    pub start: f64,
+    pub source: Argb,
+    #[cfg_attr(feature = "serde", serde(serialize_with = "precision::serialize"))]
+    pub contrast_level: f64,
+    pub schemes: Schemes,
+}
+
+impl Theme {
+    /// Returns the schemes active at `time`.
+    ///
+    /// That is the scheduled entry with the latest start not after `time`.
+    /// The schedule is treated as cyclic, so before the first start the last
+    /// entry is still active, like night before dawn. Without a schedule,
+    /// [`Theme::schemes`] are always active.
+    pub fn schemes_at(&self, time: f64) -> &Schemes {
+        self.schedule
+            .iter()
+            .rev()
+            .find(|entry| entry.start <= time)
+            .or_else(|| self.schedule.last())
+            .map_or(&self.schemes, |entry| &entry.schemes)
+    }
+
+    /// Writes a preview of the light and dark schemes side by side for
+    /// terminals supporting 24-bit color, one row per role.
+    ///
+    /// See [`Scheme::to_ansi_truecolor`].
+    ///
+    /// # Errors
+    ///
+    /// Returns any error returned by `writer`.
+    #[cfg(feature = "std")]
+    pub fn to_ansi_truecolor<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        write_ansi_swatch(writer, self.source, "source")?;
+        writeln!(writer)?;
+
+        for ((role, light), (_, dark)) in self
+            .schemes
+            .light
+            .clone()
+            .into_iter()
+            .zip(self.schemes.dark.clone())
+        {
+            write_ansi_swatch(writer, light, &role)?;
+            write_ansi_swatch(writer, dark, &role)?;
+            writeln!(writer)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Cross-fades the schemes of two themes.
+///
+/// The transition does not depend on a clock: the caller passes the elapsed
+/// time to [`ThemeTransition::sample`], in the same unit as the duration.
+pub struct ThemeTransition<'a> {
+    from: &'a Theme,
+    to: &'a Theme,
+    duration: f64,
+    easing: fn(f64) -> f64,
+}
+
+impl<'a> ThemeTransition<'a> {
+    /// Creates a transition from `from` to `to` lasting `duration`, with
+    /// progress shaped by `easing` (see [`easing`]).
+    ///
+    /// [`easing`]: crate::utils::easing
+    pub const fn new(
+        from: &'a Theme,
+        to: &'a Theme,
+        duration: f64,
+        easing: fn(f64) -> f64,
+    ) -> Self {
+        Self {
+            from,
+            to,
+            duration,
+            easing,
+        }
+    }
+
+    /// Returns the transition with `accessibility` honored, i.e. completing
+    /// at once if [`AccessibilityOptions::reduce_motion`] is set.
+    #[must_use]
+    pub const fn with_accessibility(mut self, accessibility: AccessibilityOptions) -> Self {
+        if accessibility.reduce_motion {
+            self.duration = 0.0;
+        }
+
+        self
+    }
+
+    /// Returns the eased progress of the transition at `time`, from 0.0 to 1.0.
+    pub fn progress(&self, time: f64) -> f64 {
+        if self.duration <= 0.0 {
+            return 1.0;
+        }
+
+        (self.easing)((time / self.duration).clamp(0.0, 1.0))
+    }
+
+    /// Returns the interpolated light and dark schemes at `time`.
+    pub fn sample(&self, time: f64) -> Schemes {
+        let amount = self.progress(time);
+
+        Schemes {
+            light: self.from.schemes.light.lerp(&self.to.schemes.light, amount),
+            dark: self.from.schemes.dark.lerp(&self.to.schemes.dark, amount),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ContrastSchemes, CustomColor, ThemeBuilder, ThemeMetadata, ThemeTransition};
+    use crate::{
+        color::{Argb, Lab},
+        contrast::ratio_of_colors,
+        dynamic_color::{AccessibilityOptions, DynamicScheme, ToneTable, Variant},
+        hct::Hct,
+        palette::TonalPalette,
+        scheme::{Scheme, SchemeRole},
+        trace::Stage,
+        utils::easing,
+    };
+    #[cfg(not(feature = "std"))]
+    use alloc::{vec, vec::Vec};
+    use float_cmp::assert_approx_eq;
+    #[cfg(feature = "std")]
+    use std::{vec, vec::Vec};
+
+    #[test]
+    fn test_error_color() {
+        let brand = Hct::new(Argb::from_u32(0xffd81b60));
+        let theme = ThemeBuilder::with_source(Argb::from_u32(0xff4285f4))
+            .error(brand)
+            .build();
+        let stock = ThemeBuilder::with_source(Argb::from_u32(0xff4285f4)).build();
+        let error = Hct::new(theme.schemes.light.error);
+
+        assert_ne!(theme.schemes.light.error, stock.schemes.light.error);
+        assert_approx_eq!(f64, error.get_hue(), brand.get_hue(), epsilon = 2.0);
+        assert_approx_eq!(f64, theme.palettes.error.hue(), brand.get_hue());
+        assert!(ratio_of_colors(theme.schemes.light.error, theme.schemes.light.on_error) >= 4.5);
+        assert_eq!(theme.schemes.light.primary, stock.schemes.light.primary);
+    }
+
+    #[test]
+    fn test_error_palette() {
+        let palette = TonalPalette::of(300.0, 60.0);
+        let theme = ThemeBuilder::with_source(Argb::from_u32(0xff4285f4))
+            .error_palette(palette)
+            .build();
+
+        assert_eq!(theme.schemes.dark.error_container, palette.tone(30));
+        assert_eq!(theme.palettes.error, palette);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_to_ansi_truecolor() {
+        let theme = ThemeBuilder::with_source(Argb::from_u32(0xffff0000)).build();
+        let mut output = std::vec::Vec::new();
+
+        theme.to_ansi_truecolor(&mut output).unwrap();
+
+        let output = std::string::String::from_utf8(output).unwrap();
+
+        assert_eq!(output.lines().count(), 1 + 49);
+        assert!(output
+            .lines()
+            .skip(1)
+            .all(|line| line.matches("\x1b[0m").count() == 2));
+    }
+
+    #[test]
+    fn test_accepts_hct_and_lab_sources() {
+        let argb = Argb::from_u32(0xff4285f4);
+        let from_argb = ThemeBuilder::with_source(argb).build();
+        let from_hct = ThemeBuilder::with_source(Hct::new(argb)).build();
+        let from_lab = ThemeBuilder::with_source(Lab::from(argb))
+            .primary(Hct::from(120.0, 40.0, 50.0))
+            .build();
+
+        assert_eq!(from_hct.source, argb);
+        assert_eq!(from_hct.schemes.light, from_argb.schemes.light);
+        assert_eq!(from_lab.source, argb);
+        assert_ne!(
+            from_lab.schemes.light.primary,
+            from_argb.schemes.light.primary
+        );
+
+        let hct = Hct::from(250.0, 60.0, 50.0);
+        let theme = ThemeBuilder::with_source(hct)
+            .custom_colors(vec![
+                CustomColor::new(hct, "hct", false),
+                CustomColor::new(Argb::from(hct), "argb", false),
+            ])
+            .build();
+
+        assert_approx_eq!(f64, theme.palettes.primary.hue(), hct.get_hue());
+        assert_eq!(theme.custom_colors[0].value, Argb::from(hct));
+        assert_eq!(
+            theme.custom_colors[0].light.color,
+            theme.custom_colors[1].light.color
+        );
+    }
+
+    #[test]
+    fn test_pure_black() {
+        let source = Argb::from_u32(0xff4285f4);
+        let theme = ThemeBuilder::with_source(source).build();
+        let black = ThemeBuilder::with_source(source).pure_black(true).build();
+
+        assert_eq!(black.schemes.light, theme.schemes.light);
+        assert_eq!(black.schemes.dark.surface, Argb::from_u32(0xff000000));
+        assert_eq!(black.schemes.dark.primary, theme.schemes.dark.primary);
+    }
+
+    #[test]
+    fn test_metadata() {
+        let source = Argb::from_u32(0xff4285f4);
+        let theme = ThemeBuilder::with_source(source).build();
+
+        assert_eq!(theme.metadata, ThemeMetadata::default());
+        assert_eq!(theme.metadata.crate_version, env!("CARGO_PKG_VERSION"));
+
+        let theme = ThemeBuilder::with_source(source)
+            .timestamp(1_700_000_000)
+            .context("wallpaper-daemon")
+            .build();
+
+        assert_eq!(theme.metadata.spec_version, ThemeMetadata::SPEC_VERSION);
+        assert_eq!(theme.metadata.timestamp, Some(1_700_000_000));
+        assert_eq!(theme.metadata.context.as_deref(), Some("wallpaper-daemon"));
+    }
+
+    #[test]
+    fn test_accessibility() {
+        let source = Argb::from_u32(0xff4285f4);
+        let accessibility = AccessibilityOptions {
+            reduce_transparency: true,
+            reduce_motion: true,
+            high_contrast_text: false,
+        };
+        let theme = ThemeBuilder::with_source(source).build();
+        let accessible = ThemeBuilder::with_source(source)
+            .accessibility(accessibility)
+            .build();
+
+        assert_eq!(theme.schemes.light.scrim, Argb::from_u32(0xff000000));
+        assert_ne!(accessible.schemes.light.scrim, theme.schemes.light.scrim);
+        assert_eq!(
+            accessible.schemes.light.primary,
+            theme.schemes.light.primary
+        );
+
+        let transition = ThemeTransition::new(&theme, &accessible, 300.0, easing::linear)
+            .with_accessibility(accessibility);
+
+        assert_approx_eq!(f64, transition.progress(0.0), 1.0);
+    }
+
+    #[test]
+    fn test_schemes_at() {
+        let theme = ThemeBuilder::with_source(Argb::from_u32(0xff4285f4))
+            .schedule(20.0, Argb::from_u32(0xff3050a0), 0.5)
+            .schedule(6.0, Argb::from_u32(0xffff8040), 0.0)
+            .schedule(12.0, Argb::from_u32(0xff4285f4), 0.0)
+            .build();
+        let start_at = |time| {
+            let schemes = theme.schemes_at(time);
+
+            theme
+                .schedule
+                .iter()
+                .find(|entry| core::ptr::eq(&entry.schemes, schemes))
+                .map(|entry| entry.start)
+        };
+
+        assert!(theme
+            .schedule
+            .windows(2)
+            .all(|entries| entries[0].start < entries[1].start));
+        assert_eq!(start_at(3.0), Some(20.0));
+        assert_eq!(start_at(6.0), Some(6.0));
+        assert_eq!(start_at(15.0), Some(12.0));
+        assert_eq!(start_at(23.0), Some(20.0));
+        assert_eq!(theme.schedule[1].schemes.light, theme.schemes.light);
+        assert_ne!(
+            theme.schedule[2].schemes.dark,
+            theme.schedule[1].schemes.dark
+        );
+
+        let theme = ThemeBuilder::with_source(Argb::from_u32(0xff4285f4)).build();
+
+        assert!(core::ptr::eq(theme.schemes_at(12.0), &theme.schemes));
+    }
+
+    #[test]
+    fn test_contrast_schemes() {
+        let source = Argb::from_u32(0xff4285f4);
+        let schemes = ContrastSchemes::new(source, &Variant::Vibrant);
+        let scheme = |is_dark, contrast_level| {
+            Scheme::from(DynamicScheme::by_variant(
+                source,
+                &Variant::Vibrant,
+                is_dark,
+                Some(contrast_level),
+            ))
+        };
+
+        assert_eq!(schemes.light, scheme(false, 0.0));
+        assert_eq!(schemes.light_medium_contrast, scheme(false, 0.5));
+        assert_eq!(schemes.light_high_contrast, scheme(false, 1.0));
+        assert_eq!(schemes.dark, scheme(true, 0.0));
+        assert_eq!(schemes.dark_medium_contrast, scheme(true, 0.5));
+        assert_eq!(schemes.dark_high_contrast, scheme(true, 1.0));
+    }
+
+    #[test]
+    fn test_theme_transition() {
+        let from = ThemeBuilder::with_source(Argb::from_u32(0xffff0000)).build();
+        let to = ThemeBuilder::with_source(Argb::from_u32(0xff0000ff)).build();
+        let transition = ThemeTransition::new(&from, &to, 300.0, easing::ease_in_out);
+
+        assert_eq!(transition.sample(0.0).light, from.schemes.light);
+        assert_eq!(transition.sample(300.0).dark, to.schemes.dark);
+        assert_eq!(transition.sample(600.0).dark, to.schemes.dark);
+        assert_approx_eq!(f64, transition.progress(150.0), 0.5);
+        assert_approx_eq!(
+            f64,
+            ThemeTransition::new(&from, &to, 0.0, easing::linear).progress(0.0),
+            1.0
+        );
+    }
+
+    #[test]
+    fn test_build_traced() {
+        let builder = || {
+            ThemeBuilder::with_source(Argb::from_u32(0xff4285f4))
+                .color_match(true)
+                .pure_black(true)
+        };
+        let (theme, trace) = builder().build_traced();
+
+        assert_eq!(theme.schemes.dark, builder().build().schemes.dark);
+        assert_eq!(trace.source, theme.source);
+        assert_eq!(trace.variant, Variant::Fidelity);
+        assert!(trace.color_match && trace.pure_black);
+        assert_eq!(trace.seed, None);
+        assert_eq!(
+            trace
+                .stages
+                .iter()
+                .map(|timing| timing.stage)
+                .collect::<Vec<_>>(),
+            [Stage::Palettes, Stage::Schemes, Stage::CustomColors]
+        );
+    }
+
+    #[test]
+    fn test_tone_table() {
+        let table = ToneTable::new().with(SchemeRole::PrimaryContainer, 85.0, 25.0);
+        let theme = ThemeBuilder::with_source(Argb::from_u32(0xff6750a4))
+            .tone_table(table)
+            .build();
+        let palette = DynamicScheme::by_variant(theme.source, &Variant::TonalSpot, false, None)
+            .primary_palette;
+
+        assert_eq!(theme.schemes.light.primary_container, palette.tone(85));
+        assert_eq!(theme.schemes.dark.primary_container, palette.tone(25));
+    }
 }
@@ -0,0 +1,192 @@
+//! Traces of generation runs, explaining how a theme came to be.
+//!
+//! [`ThemeBuilder::build_traced`] returns a [`GenerationTrace`] next to the
+//! theme, and [`ImageReader::extract_color_traced`] a [`SeedTrace`] of the
+//! choice of its source color. With the `serde` feature enabled, traces
+//! serialize to JSON, for "why this theme" panels or for bug reports that
+//! can be reproduced exactly.
+//!
+//! Timings are measured with the clock of `std`; without it, they are
+//! `None`.
+//!
+//! [`ThemeBuilder::build_traced`]: crate::theme::ThemeBuilder::build_traced
+//! [`ImageReader::extract_color_traced`]: crate::image::ImageReader::extract_color_traced
+
+#[cfg(feature = "serde")]
+use crate::utils::precision;
+use crate::{color::Argb, dynamic_color::Variant, score::SeedConfidence};
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+use core::time::Duration;
+#[cfg(feature = "serde")]
+use serde::{Serialize, Serializer};
+#[cfg(feature = "std")]
+use std::{time::Instant, vec::Vec};
+
+/// Stage of a generation run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum Stage {
+    /// Quantizing the pixels of an image into clusters.
+    Quantize,
+    /// Ranking the clusters to choose the source color.
+    Score,
+    /// Generating the tonal palettes of the theme.
+    Palettes,
+    /// Generating the schemes of the theme, including its schedule.
+    Schemes,
+    /// Generating the groups of the custom colors of the theme.
+    CustomColors,
+}
+
+/// Time spent in a [`Stage`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct StageTiming {
+    pub stage: Stage,
+    /// Duration of the stage, `None` without `std`. Serialized in seconds.
+    #[cfg_attr(feature = "serde", serde(serialize_with = "serialize_seconds"))]
+    pub duration: Option<Duration>,
+}
+
+/// How the source color was chosen from an image.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct SeedTrace {
+    /// Count of pixels extracted from.
+    pub pixels: usize,
+    /// Count of clusters produced by the quantizer.
+    pub clusters: usize,
+    /// Suitable source colors, best first. The first one was chosen, which
+    /// is the fallback color if none was suitable.
+    pub candidates: Vec<Argb>,
+    pub confidence: SeedConfidence,
+    pub stages: Vec<StageTiming>,
+}
+
+/// How a theme was generated, see [`ThemeBuilder::build_traced`].
+///
+/// [`ThemeBuilder::build_traced`]: crate::theme::ThemeBuilder::build_traced
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct GenerationTrace {
+    pub source: Argb,
+    /// Variant the schemes were generated with, after color matching.
+    pub variant: Variant,
+    /// Contrast level of [`Theme::schemes`].
+    ///
+    /// [`Theme::schemes`]: crate::theme::Theme::schemes
+    #[cfg_attr(feature = "serde", serde(serialize_with = "precision::serialize"))]
+    pub contrast_level: f64,
+    pub color_match: bool,
+    pub pure_black: bool,
+    /// How the source color was chosen, if it was extracted from an image.
+    /// Not filled in by the builder, which only sees the chosen color.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub seed: Option<SeedTrace>,
+    pub stages: Vec<StageTiming>,
+}
+
+impl GenerationTrace {
+    /// Attaches the trace of the choice of the source color.
+    #[must_use]
+    pub fn with_seed(mut self, seed: SeedTrace) -> Self {
+        self.seed = Some(seed);
+
+        self
+    }
+}
+
+/// Records the timings of consecutive stages, or nothing if disabled.
+pub(crate) struct Stopwatch {
+    enabled: bool,
+    #[cfg(feature = "std")]
+    lap: Instant,
+    stages: Vec<StageTiming>,
+}
+
+impl Stopwatch {
+    pub(crate) fn new(enabled: bool) -> Self {
+        Self {
+            enabled,
+            #[cfg(feature = "std")]
+            lap: Instant::now(),
+            stages: Vec::new(),
+        }
+    }
+
+    /// Records the time since the previous stage, or since the stopwatch was
+    /// created, as spent in `stage`.
+    pub(crate) fn finish(&mut self, stage: Stage) {
+        if !self.enabled {
+            return;
+        }
+
+        #[cfg(feature = "std")]
+        let duration = {
+            let now = Instant::now();
+            let duration = now - self.lap;
+
+            self.lap = now;
+
+            Some(duration)
+        };
+        #[cfg(not(feature = "std"))]
+        let duration = None;
+
+        self.stages.push(StageTiming { stage, duration });
+    }
+
+    pub(crate) fn into_stages(self) -> Vec<StageTiming> {
+        self.stages
+    }
+}
+
+#[cfg(feature = "serde")]
+#[allow(clippy::ref_option)]
+fn serialize_seconds<S: Serializer>(
+    duration: &Option<Duration>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match duration {
+        Some(duration) => serializer.serialize_some(&precision::round(duration.as_secs_f64())),
+        None => serializer.serialize_none(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Stage, Stopwatch};
+    #[cfg(not(feature = "std"))]
+    use alloc::vec::Vec;
+    #[cfg(feature = "std")]
+    use std::vec::Vec;
+
+    #[test]
+    fn test_stopwatch_records_stages_in_order() {
+        let mut stopwatch = Stopwatch::new(true);
+
+        stopwatch.finish(Stage::Palettes);
+        stopwatch.finish(Stage::Schemes);
+
+        let stages = stopwatch.into_stages();
+
+        assert_eq!(
+            stages.iter().map(|timing| timing.stage).collect::<Vec<_>>(),
+            [Stage::Palettes, Stage::Schemes]
+        );
+        assert_eq!(
+            stages.iter().all(|timing| timing.duration.is_some()),
+            cfg!(feature = "std")
+        );
+    }
+
+    #[test]
+    fn test_disabled_stopwatch_records_nothing() {
+        let mut stopwatch = Stopwatch::new(false);
+
+        stopwatch.finish(Stage::Palettes);
+
+        assert!(stopwatch.into_stages().is_empty());
+    }
+}
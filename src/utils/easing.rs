@@ -0,0 +1,60 @@
+//! Easing functions mapping linear progress (0.0 to 1.0) to eased progress.
+//!
+//! Each function is suitable for [`ThemeTransition`].
+//!
+//! [`ThemeTransition`]: crate::theme::ThemeTransition
+
+#[cfg(all(not(feature = "std"), feature = "libm"))]
+#[allow(unused_imports)]
+use crate::utils::no_std::FloatExt;
+
+pub const fn linear(t: f64) -> f64 {
+    t
+}
+
+const_fn! {
+    #[cfg(material_colors_const_float_arithmetic)]
+    /// Cubic ease-in: starts slowly and accelerates.
+    pub fn ease_in(t: f64) -> f64 {
+        t * t * t
+    }
+}
+
+const_fn! {
+    #[cfg(material_colors_const_float_arithmetic)]
+    /// Cubic ease-out: starts quickly and decelerates.
+    pub fn ease_out(t: f64) -> f64 {
+        let inverse = 1.0 - t;
+
+        1.0 - inverse * inverse * inverse
+    }
+}
+
+/// Cubic ease-in-out: accelerates until the midpoint, then decelerates.
+pub fn ease_in_out(t: f64) -> f64 {
+    if t < 0.5 {
+        4.0 * t * t * t
+    } else {
+        let inverse = (-2.0f64).mul_add(t, 2.0);
+
+        1.0 - inverse * inverse * inverse / 2.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ease_in, ease_in_out, ease_out, linear};
+    use float_cmp::assert_approx_eq;
+
+    #[test]
+    fn test_endpoints() {
+        for easing in [linear, ease_in, ease_out, ease_in_out] {
+            assert_approx_eq!(f64, easing(0.0), 0.0);
+            assert_approx_eq!(f64, easing(1.0), 1.0);
+        }
+
+        assert_approx_eq!(f64, ease_in_out(0.5), 0.5);
+        assert!(ease_in(0.5) < 0.5);
+        assert!(ease_out(0.5) > 0.5);
+    }
+}
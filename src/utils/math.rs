@@ -2,13 +2,16 @@
 #[allow(unused_imports)]
 use crate::utils::no_std::FloatExt;
 
-pub fn signum(value: f64) -> f64 {
-    if value < 0.0 {
-        -1.0
-    } else if value == 0.0 {
-        0.0
-    } else {
-        1.0
+const_fn! {
+    #[cfg(material_colors_const_float_arithmetic)]
+    pub fn signum(value: f64) -> f64 {
+        if value < 0.0 {
+            -1.0
+        } else if value == 0.0 {
+            0.0
+        } else {
+            1.0
+        }
     }
 }
 
@@ -23,25 +26,37 @@ pub const fn sanitize_degrees_int(degrees: i32) -> u32 {
     }
 }
 
-pub fn sanitize_degrees_double(degrees: f64) -> f64 {
-    match degrees {
-        value if value < 0.0 => value + 360.0,
-        value => value % 360.0,
+const_fn! {
+    #[cfg(material_colors_const_float_arithmetic)]
+    pub fn sanitize_degrees_double(degrees: f64) -> f64 {
+        match degrees {
+            value if value < 0.0 => value + 360.0,
+            value => value % 360.0,
+        }
     }
 }
 
-pub fn rotate_direction(from: f64, to: f64) -> f64 {
-    let increasing_difference = sanitize_degrees_double(to - from);
+const_fn! {
+    #[cfg(material_colors_const_float_arithmetic)]
+    pub fn rotate_direction(from: f64, to: f64) -> f64 {
+        let increasing_difference = sanitize_degrees_double(to - from);
 
-    if increasing_difference <= 180.0 {
-        1.0
-    } else {
-        -1.0
+        if increasing_difference <= 180.0 {
+            1.0
+        } else {
+            -1.0
+        }
     }
 }
 
-pub fn difference_degrees(a: f64, b: f64) -> f64 {
-    180.0 - ((a - b).abs() - 180.0).abs()
+const_fn! {
+    #[cfg(material_colors_const_float_methods)]
+    // `f64::abs` is only `const` with the compilers the build script enables
+    // `material_colors_const_float_methods` for.
+    #[allow(clippy::incompatible_msrv)]
+    pub fn difference_degrees(a: f64, b: f64) -> f64 {
+        180.0 - ((a - b).abs() - 180.0).abs()
+    }
 }
 
 pub fn matrix_multiply(row: [f64; 3], matrix: [[f64; 3]; 3]) -> [f64; 3] {
@@ -71,6 +86,16 @@ mod tests {
         difference_degrees, rotate_direction, sanitize_degrees_double, sanitize_degrees_int,
     };
 
+    #[test]
+    #[cfg(material_colors_const_float_methods)]
+    fn test_const() {
+        const DIFFERENCE: f64 = difference_degrees(350.0, 10.0);
+        const DIRECTION: f64 = rotate_direction(350.0, 10.0);
+
+        assert_approx_eq!(f64, DIFFERENCE, 20.0);
+        assert_approx_eq!(f64, DIRECTION, 1.0);
+    }
+
     #[test]
     fn test_signum() {
         let result1 = signum(-2.0);
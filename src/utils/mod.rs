@@ -1,7 +1,35 @@
+/// Declares a function that is `const` only with compilers supporting the
+/// language features its body needs, given as a `cfg` set by the build
+/// script, and a plain function otherwise.
+macro_rules! const_fn {
+    (#[cfg($cfg:meta)] $(#[$attr:meta])* $vis:vis fn $($rest:tt)*) => {
+        #[cfg($cfg)]
+        $(#[$attr])*
+        $vis const fn $($rest)*
+
+        #[cfg(not($cfg))]
+        $(#[$attr])*
+        $vis fn $($rest)*
+    };
+}
+
+pub mod easing;
 pub mod math;
 #[cfg(all(not(feature = "std"), feature = "libm"))]
 pub mod no_std;
+pub mod precision;
 pub mod random;
+pub mod vec_map;
+
+// Public APIs take and return `f64` whichever type the solver uses.
+
+/// Float type of the HCT solver, `f32` for hardware without a double
+/// precision FPU.
+#[cfg(feature = "f32")]
+pub type Float = f32;
+/// Float type of the HCT solver, `f64` unless the `f32` feature is enabled.
+#[cfg(not(feature = "f32"))]
+pub type Float = f64;
 
 pub trait FromRef<T> {
     fn from_ref(value: &T) -> Self;
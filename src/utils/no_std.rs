@@ -3,6 +3,9 @@ pub trait FloatExt {
     #[must_use]
     fn abs(self) -> Self;
 
+    #[must_use]
+    fn copysign(self, sign: Self) -> Self;
+
     #[must_use]
     fn mul_add(self, x: Self, y: Self) -> Self;
 
@@ -57,6 +60,10 @@ impl FloatExt for f64 {
         libm::fabs(self)
     }
 
+    fn copysign(self, sign: Self) -> Self {
+        libm::copysign(self, sign)
+    }
+
     fn mul_add(self, x: Self, y: Self) -> Self {
         libm::fma(self, x, y)
     }
@@ -121,3 +128,77 @@ impl FloatExt for f64 {
         libm::atan2(self, n)
     }
 }
+
+impl FloatExt for f32 {
+    fn abs(self) -> Self {
+        libm::fabsf(self)
+    }
+
+    fn copysign(self, sign: Self) -> Self {
+        libm::copysignf(self, sign)
+    }
+
+    fn mul_add(self, x: Self, y: Self) -> Self {
+        libm::fmaf(self, x, y)
+    }
+
+    fn powf(self, n: Self) -> Self {
+        libm::powf(self, n)
+    }
+
+    fn powi(self, n: i64) -> Self {
+        libm::powf(self, n as Self)
+    }
+
+    fn cos(self) -> Self {
+        libm::cosf(self)
+    }
+
+    fn sin(self) -> Self {
+        libm::sinf(self)
+    }
+
+    fn cbrt(self) -> Self {
+        libm::cbrtf(self)
+    }
+
+    fn ln(self) -> Self {
+        libm::logf(self)
+    }
+
+    fn ln_1p(self) -> Self {
+        libm::log1pf(self)
+    }
+
+    fn exp(self) -> Self {
+        libm::expf(self)
+    }
+
+    fn exp_m1(self) -> Self {
+        libm::expm1f(self)
+    }
+
+    fn round(self) -> Self {
+        libm::roundf(self)
+    }
+
+    fn ceil(self) -> Self {
+        libm::ceilf(self)
+    }
+
+    fn floor(self) -> Self {
+        libm::floorf(self)
+    }
+
+    fn sqrt(self) -> Self {
+        libm::sqrtf(self)
+    }
+
+    fn hypot(self, n: Self) -> Self {
+        libm::hypotf(self, n)
+    }
+
+    fn atan2(self, n: Self) -> Self {
+        libm::atan2f(self, n)
+    }
+}
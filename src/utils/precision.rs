@@ -0,0 +1,52 @@
+//! Fixed precision of floats in serialized output.
+//!
+//! Hues, chromas, tones and other floats are computed with transcendental
+//! functions whose last digits differ across platforms and math libraries.
+//! Serialized types round them with [`round`] first, so that artifacts such
+//! as JSON themes are reproducible and diff cleanly.
+
+#[cfg(all(not(feature = "std"), feature = "libm"))]
+#[allow(unused_imports)]
+use crate::utils::no_std::FloatExt;
+#[cfg(feature = "serde")]
+use serde::Serializer;
+
+/// Count of decimal places floats are serialized with.
+pub const DECIMALS: i32 = 6;
+
+const SCALE: f64 = 1e6;
+
+/// Rounds `value` to [`DECIMALS`] decimal places, with ties away from zero.
+/// Non-finite values are returned as-is, and negative zero becomes zero.
+pub fn round(value: f64) -> f64 {
+    if !value.is_finite() {
+        return value;
+    }
+
+    (value * SCALE).round() / SCALE + 0.0
+}
+
+/// Serializes `value` rounded with [`round`], for
+/// `#[serde(serialize_with = "...")]`.
+#[cfg(feature = "serde")]
+#[allow(clippy::trivially_copy_pass_by_ref)]
+pub(crate) fn serialize<S: Serializer>(value: &f64, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_f64(round(*value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{round, DECIMALS};
+    use float_cmp::assert_approx_eq;
+
+    #[test]
+    fn test_round() {
+        assert_eq!(DECIMALS, 6);
+        assert_approx_eq!(f64, round(282.788_370_155_618_6), 282.788_37, ulps = 0);
+        assert_approx_eq!(f64, round(0.000_000_5), 0.000_001, ulps = 0);
+        assert_approx_eq!(f64, round(36.000_000_000_000_01), 36.0, ulps = 0);
+        assert!(round(-0.000_000_1).is_sign_positive());
+        assert!(round(f64::NAN).is_nan());
+        assert!(round(f64::INFINITY).is_infinite());
+    }
+}
@@ -1,7 +1,25 @@
+/// Source of pseudo-random numbers used by the quantizers to pick starting
+/// clusters.
+///
+/// Implement it to plug in another generator; [`Random`] is the default. The
+/// quantizers never read system entropy, so their output depends only on the
+/// input and the source.
+pub trait RandomSource {
+    /// Returns a number in `0..range`. `range` is always positive.
+    fn next_range(&mut self, range: i32) -> i32;
+}
+
 /// Partial LCG Algorithm implementation.
+///
+/// Deterministic and free of platform dependencies, so it works on every
+/// target, including `wasm32-unknown-unknown` without `std`.
+#[derive(Debug, Clone)]
 pub struct Random(i64);
 
 impl Random {
+    /// Seed used by the quantizers unless another source is provided.
+    pub const DEFAULT_SEED: i64 = 0x42688;
+
     pub const fn new(seed: i64) -> Self {
         Self((seed ^ 0x5DEECE66Di64) & ((1i64 << 48) - 1))
     }
@@ -12,7 +30,12 @@ impl Random {
         ((self.0 as u64) >> (48 - bits)) as i32
     }
 
+    /// # Panics
+    ///
+    /// Will panic if `range` is not positive.
     pub fn next_range(&mut self, range: i32) -> i32 {
+        assert!(range > 0, "range must be positive");
+
         if (range & -range) == range {
             return (i64::from(range).wrapping_mul(i64::from(self._next(31))) >> 31) as i32;
         }
@@ -32,3 +55,15 @@ impl Random {
         val
     }
 }
+
+impl Default for Random {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_SEED)
+    }
+}
+
+impl RandomSource for Random {
+    fn next_range(&mut self, range: i32) -> i32 {
+        Self::next_range(self, range)
+    }
+}
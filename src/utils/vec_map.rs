@@ -0,0 +1,313 @@
+//! An insertion-ordered map backed by vectors, used in place of
+//! `indexmap::IndexMap` when the `fast-maps` feature is disabled.
+//!
+//! Entries are stored in insertion order, like `IndexMap`, next to a list of
+//! their indices sorted by key, so lookups are a binary search. Inserting a
+//! new key shifts the sorted list, which makes building large maps slower
+//! than with a hash map.
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::{self, Vec};
+use core::{cmp::Ordering, fmt, iter::FusedIterator, ops::Index, slice};
+#[cfg(feature = "std")]
+use std::vec::{self, Vec};
+
+#[derive(Clone)]
+pub struct VecMap<K, V> {
+    entries: Vec<(K, V)>,
+    sorted: Vec<usize>,
+}
+
+impl<K, V> VecMap<K, V> {
+    pub const fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+            sorted: Vec::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns an iterator over the entries, in insertion order.
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter(self.entries.iter())
+    }
+
+    pub fn keys(&self) -> impl DoubleEndedIterator<Item = &K> + ExactSizeIterator {
+        self.entries.iter().map(|(key, _)| key)
+    }
+
+    pub fn values(&self) -> impl DoubleEndedIterator<Item = &V> + ExactSizeIterator {
+        self.entries.iter().map(|(_, value)| value)
+    }
+
+    pub fn values_mut(&mut self) -> impl DoubleEndedIterator<Item = &mut V> + ExactSizeIterator {
+        self.entries.iter_mut().map(|(_, value)| value)
+    }
+
+    pub fn into_keys(self) -> impl DoubleEndedIterator<Item = K> + ExactSizeIterator {
+        self.entries.into_iter().map(|(key, _)| key)
+    }
+
+    pub fn into_values(self) -> impl DoubleEndedIterator<Item = V> + ExactSizeIterator {
+        self.entries.into_iter().map(|(_, value)| value)
+    }
+
+    /// Returns the entry at `index`, in insertion order.
+    pub fn get_index(&self, index: usize) -> Option<(&K, &V)> {
+        self.entries.get(index).map(|(key, value)| (key, value))
+    }
+}
+
+impl<K: Ord, V> VecMap<K, V> {
+    /// Returns the position of `key` in `sorted`, or where it would be
+    /// inserted.
+    fn search(&self, key: &K) -> Result<usize, usize> {
+        self.sorted
+            .binary_search_by(|&index| self.entries[index].0.cmp(key))
+    }
+
+    pub fn get_index_of(&self, key: &K) -> Option<usize> {
+        self.search(key).ok().map(|position| self.sorted[position])
+    }
+
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.search(key).is_ok()
+    }
+
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.get_index_of(key).map(|index| &self.entries[index].1)
+    }
+
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        self.get_index_of(key)
+            .map(move |index| &mut self.entries[index].1)
+    }
+
+    /// Inserts `value` at `key`, returning the previous value. New keys are
+    /// appended to the end of the map.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        match self.search(&key) {
+            Ok(position) => Some(core::mem::replace(
+                &mut self.entries[self.sorted[position]].1,
+                value,
+            )),
+            Err(position) => {
+                self.sorted.insert(position, self.entries.len());
+                self.entries.push((key, value));
+
+                None
+            }
+        }
+    }
+
+    pub fn entry(&mut self, key: K) -> Entry<'_, K, V> {
+        let position = self.search(&key);
+
+        Entry {
+            map: self,
+            key,
+            position,
+        }
+    }
+
+    /// Sorts the entries with `compare`, which receives the key and value
+    /// of both entries.
+    pub fn sort_by<F>(&mut self, mut compare: F)
+    where
+        F: FnMut(&K, &V, &K, &V) -> Ordering,
+    {
+        self.entries
+            .sort_by(|(a, a_value), (b, b_value)| compare(a, a_value, b, b_value));
+        self.reindex();
+    }
+
+    fn reindex(&mut self) {
+        let entries = &self.entries;
+
+        self.sorted = (0..entries.len()).collect();
+        self.sorted
+            .sort_by(|&a, &b| entries[a].0.cmp(&entries[b].0));
+    }
+}
+
+impl<K, V> Default for VecMap<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Ord, V> Index<&K> for VecMap<K, V> {
+    type Output = V;
+
+    /// # Panics
+    ///
+    /// Will panic if `key` is not in the map.
+    fn index(&self, key: &K) -> &V {
+        self.get(key).expect("key not found in VecMap")
+    }
+}
+
+impl<K: fmt::Debug, V: fmt::Debug> fmt::Debug for VecMap<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+/// Maps are equal if they contain the same entries, regardless of order,
+/// like `IndexMap`.
+impl<K: Ord, V: PartialEq> PartialEq for VecMap<K, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .all(|(key, value)| other.get(key) == Some(value))
+    }
+}
+
+impl<K: Ord, V: Eq> Eq for VecMap<K, V> {}
+
+impl<K: Ord, V> FromIterator<(K, V)> for VecMap<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = Self::new();
+
+        map.extend(iter);
+
+        map
+    }
+}
+
+impl<K: Ord, V> Extend<(K, V)> for VecMap<K, V> {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+impl<K, V> IntoIterator for VecMap<K, V> {
+    type Item = (K, V);
+    type IntoIter = vec::IntoIter<(K, V)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.into_iter()
+    }
+}
+
+impl<'a, K, V> IntoIterator for &'a VecMap<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator over the entries of a [`VecMap`], in insertion order.
+#[derive(Debug, Clone)]
+pub struct Iter<'a, K, V>(slice::Iter<'a, (K, V)>);
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|(key, value)| (key, value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<K, V> DoubleEndedIterator for Iter<'_, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back().map(|(key, value)| (key, value))
+    }
+}
+
+impl<K, V> ExactSizeIterator for Iter<'_, K, V> {}
+
+impl<K, V> FusedIterator for Iter<'_, K, V> {}
+
+/// A key of a [`VecMap`] that may or may not be present, see
+/// [`VecMap::entry`].
+pub struct Entry<'a, K, V> {
+    map: &'a mut VecMap<K, V>,
+    key: K,
+    position: Result<usize, usize>,
+}
+
+impl<'a, K: Ord, V> Entry<'a, K, V> {
+    /// Modifies the value if the key is present.
+    #[must_use]
+    pub fn and_modify<F: FnOnce(&mut V)>(self, modify: F) -> Self {
+        if let Ok(position) = self.position {
+            modify(&mut self.map.entries[self.map.sorted[position]].1);
+        }
+
+        self
+    }
+
+    /// Returns the value of the key, inserting `default` if it is absent.
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        let index = match self.position {
+            Ok(position) => self.map.sorted[position],
+            Err(position) => {
+                let index = self.map.entries.len();
+
+                self.map.sorted.insert(position, index);
+                self.map.entries.push((self.key, default));
+
+                index
+            }
+        };
+
+        &mut self.map.entries[index].1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::VecMap;
+
+    #[test]
+    fn test_insertion_order() {
+        let mut map = VecMap::new();
+
+        map.insert(3, "c");
+        map.insert(1, "a");
+        map.insert(2, "b");
+
+        assert_eq!(map.insert(1, "A"), Some("a"));
+        assert_eq!(map.len(), 3);
+        assert_eq!(map.get(&1), Some(&"A"));
+        assert_eq!(map.get(&4), None);
+        assert_eq!(map.get_index(0), Some((&3, &"c")));
+        assert_eq!(map.get_index_of(&2), Some(2));
+        assert!(map.keys().copied().eq([3, 1, 2]));
+    }
+
+    #[test]
+    fn test_entry_and_sort() {
+        let mut map = VecMap::new();
+
+        for key in [5, 1, 5, 3, 5, 1] {
+            map.entry(key).and_modify(|count| *count += 1).or_insert(1);
+        }
+
+        map.sort_by(|a, a_count, b, b_count| b_count.cmp(a_count).then_with(|| a.cmp(b)));
+
+        assert!(map
+            .iter()
+            .map(|(&k, &v)| (k, v))
+            .eq([(5, 3), (1, 2), (3, 1)]));
+        assert_eq!(map.get(&3), Some(&1));
+        assert_eq!(map, VecMap::from_iter([(3, 1), (1, 2), (5, 3)]));
+    }
+}
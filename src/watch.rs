@@ -0,0 +1,226 @@
+//! Detection of meaningful changes between successive color extractions, so
+//! that a theme is only regenerated when the image changed enough to matter.
+
+use crate::{color::Argb, hct::Cam16, quantize::QuantizerResult};
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::vec::Vec;
+
+/// Perceptual thresholds used by [`DominantColorWatch`].
+///
+/// Distances are CAM16-UCS distances, see [`Cam16::distance`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ChangeThresholds {
+    /// Distance the seed color must move by to count as a change.
+    pub seed_distance: f64,
+    /// Number of most populous clusters compared between extractions.
+    pub top_clusters: usize,
+    /// Distance from every previous top cluster beyond which a top cluster
+    /// counts as new.
+    pub cluster_distance: f64,
+    /// Proportion of the image that new top clusters must cover to count as
+    /// a change.
+    pub cluster_proportion: f64,
+}
+
+impl Default for ChangeThresholds {
+    fn default() -> Self {
+        Self {
+            seed_distance: 5.0,
+            top_clusters: 5,
+            cluster_distance: 10.0,
+            cluster_proportion: 0.2,
+        }
+    }
+}
+
+/// The parts of an extraction compared by [`DominantColorWatch`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExtractionSnapshot {
+    pub seed: Argb,
+    /// Most populous clusters with the proportion of the image they cover,
+    /// most populous first.
+    pub clusters: Vec<(Argb, f64)>,
+}
+
+impl ExtractionSnapshot {
+    /// Takes the `top_clusters` most populous clusters of `result` and the
+    /// `seed` chosen from it.
+    pub fn new(result: &QuantizerResult, seed: Argb, top_clusters: usize) -> Self {
+        let total = result
+            .color_to_count
+            .values()
+            .map(|&count| u64::from(count))
+            .sum::<u64>()
+            .max(1) as f64;
+
+        let mut clusters = result
+            .iter()
+            .map(|(&color, &count)| (color, f64::from(count) / total))
+            .collect::<Vec<_>>();
+
+        clusters.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+        clusters.truncate(top_clusters);
+
+        Self { seed, clusters }
+    }
+
+    /// Whether this extraction differs meaningfully from `previous`.
+    ///
+    /// It does if the seed moved by more than
+    /// [`ChangeThresholds::seed_distance`], or if top clusters far from all
+    /// previous top clusters cover more than
+    /// [`ChangeThresholds::cluster_proportion`] of the image.
+    pub fn differs_from(&self, previous: &Self, thresholds: &ChangeThresholds) -> bool {
+        if Cam16::from(self.seed).distance(&Cam16::from(previous.seed)) > thresholds.seed_distance {
+            return true;
+        }
+
+        let previous_clusters = previous
+            .clusters
+            .iter()
+            .map(|(color, _)| Cam16::from(*color))
+            .collect::<Vec<_>>();
+
+        let new_proportion = self
+            .clusters
+            .iter()
+            .filter(|(color, _)| {
+                let cam = Cam16::from(*color);
+
+                previous_clusters
+                    .iter()
+                    .all(|other| cam.distance(other) > thresholds.cluster_distance)
+            })
+            .map(|(_, proportion)| proportion)
+            .sum::<f64>();
+
+        new_proportion > thresholds.cluster_proportion
+    }
+}
+
+/// Detects meaningful changes between successive extractions.
+///
+/// Daemons can skip regenerating and exporting themes when a wallpaper only
+/// changed trivially, e.g. a clock or a notification.
+///
+/// Comparing with the last reported extraction, rather than the previous
+/// one, keeps slow drifts from going unnoticed.
+#[derive(Debug, Clone, Default)]
+pub struct DominantColorWatch {
+    thresholds: ChangeThresholds,
+    last: Option<ExtractionSnapshot>,
+}
+
+impl DominantColorWatch {
+    pub const fn new(thresholds: ChangeThresholds) -> Self {
+        Self {
+            thresholds,
+            last: None,
+        }
+    }
+
+    /// The last extraction reported as a change.
+    pub const fn last(&self) -> Option<&ExtractionSnapshot> {
+        self.last.as_ref()
+    }
+
+    /// Records the extraction of `result` with `seed`, returning whether the
+    /// theme should be regenerated. The first extraction is always a change.
+    pub fn update(&mut self, result: &QuantizerResult, seed: Argb) -> bool {
+        let snapshot = ExtractionSnapshot::new(result, seed, self.thresholds.top_clusters);
+        let changed = self
+            .last
+            .as_ref()
+            .map_or(true, |last| snapshot.differs_from(last, &self.thresholds));
+
+        if changed {
+            self.last = Some(snapshot);
+        }
+
+        changed
+    }
+
+    /// Forgets the last extraction, so that the next one is reported as a
+    /// change.
+    pub fn reset(&mut self) {
+        self.last = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DominantColorWatch;
+    use crate::{
+        color::Argb,
+        quantize::{Quantizer, QuantizerCelebi},
+        score::Score,
+    };
+    #[cfg(not(feature = "std"))]
+    use alloc::vec::Vec;
+    #[cfg(feature = "std")]
+    use std::vec::Vec;
+
+    fn update(watch: &mut DominantColorWatch, pixels: &[Argb]) -> bool {
+        let result = QuantizerCelebi::quantize(pixels, 128);
+        let seed = Score::score(&result.color_to_count, None, None, None)[0];
+
+        watch.update(&result, seed)
+    }
+
+    fn wallpaper(accent: Argb, accent_count: usize, detail: Argb) -> Vec<Argb> {
+        let mut pixels = [Argb::from_u32(0xff202830); 600].to_vec();
+
+        pixels.extend([Argb::from_u32(0xff3060c0); 300]);
+        pixels.extend(core::iter::repeat(accent).take(accent_count));
+        pixels.extend([detail; 4]);
+
+        pixels
+    }
+
+    #[test]
+    fn test_trivial_changes() {
+        let mut watch = DominantColorWatch::default();
+
+        assert!(update(
+            &mut watch,
+            &wallpaper(Argb::from_u32(0xffc03030), 100, Argb::from_u32(0xffffffff))
+        ));
+        assert!(!update(
+            &mut watch,
+            &wallpaper(Argb::from_u32(0xffc03030), 100, Argb::from_u32(0xff20ff20))
+        ));
+        assert!(!update(
+            &mut watch,
+            &wallpaper(Argb::from_u32(0xffc23131), 96, Argb::from_u32(0xffffffff))
+        ));
+    }
+
+    #[test]
+    fn test_meaningful_changes() {
+        let mut watch = DominantColorWatch::default();
+
+        update(
+            &mut watch,
+            &wallpaper(Argb::from_u32(0xffc03030), 100, Argb::from_u32(0xffffffff)),
+        );
+
+        assert!(update(
+            &mut watch,
+            &wallpaper(Argb::from_u32(0xff30c030), 800, Argb::from_u32(0xffffffff))
+        ));
+        assert_eq!(
+            watch.last().unwrap().clusters[0].0,
+            Argb::from_u32(0xff30c030)
+        );
+
+        watch.reset();
+
+        assert!(watch.last().is_none());
+        assert!(update(
+            &mut watch,
+            &wallpaper(Argb::from_u32(0xff30c030), 800, Argb::from_u32(0xffffffff))
+        ));
+    }
+}
@@ -0,0 +1,81 @@
+//! Allocation budget of the seed to scheme pipeline, the contract of no-std
+//! deployments with small heaps.
+//!
+//! Run it against the no-std build of the crate with
+//! `cargo test --test alloc_budget --no-default-features -F libm`.
+
+use core::cell::Cell;
+use material_colors::{
+    color::Argb,
+    dynamic_color::{DynamicScheme, Variant},
+    scheme::Scheme,
+};
+use std::alloc::{GlobalAlloc, Layout, System};
+
+struct CountingAllocator;
+
+thread_local! {
+    // Counted per thread, since tests run in parallel.
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Returns the result of `f` and the count of allocations it made.
+fn count_allocations<T>(f: impl FnOnce() -> T) -> (T, usize) {
+    let before = ALLOCATIONS.with(Cell::get);
+    let result = f();
+
+    (result, ALLOCATIONS.with(Cell::get) - before)
+}
+
+/// Max count of allocations made by [`DynamicScheme::by_variant`], mostly
+/// by the temperature cache of the Fidelity and Content variants.
+const DYNAMIC_SCHEME_BUDGET: usize = 64;
+
+/// Max count of allocations made resolving every role of a [`DynamicScheme`]
+/// into a [`Scheme`], mostly by the names and closures of its dynamic
+/// colors.
+const SCHEME_BUDGET: usize = 1024;
+
+#[test]
+fn test_budget() {
+    for variant in &Variant::ALL {
+        for is_dark in [false, true] {
+            let (scheme, count) = count_allocations(|| {
+                DynamicScheme::by_variant(Argb::from_u32(0xff4285f4), variant, is_dark, None)
+            });
+
+            assert!(
+                count <= DYNAMIC_SCHEME_BUDGET,
+                "{variant:?} scheme made {count} allocations, over {DYNAMIC_SCHEME_BUDGET}"
+            );
+
+            let (_, count) = count_allocations(|| Scheme::from(scheme));
+
+            assert!(
+                count <= SCHEME_BUDGET,
+                "{variant:?} roles made {count} allocations, over {SCHEME_BUDGET}"
+            );
+        }
+    }
+}
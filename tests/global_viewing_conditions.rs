@@ -0,0 +1,33 @@
+#[cfg(feature = "global-viewing-conditions")]
+#[test]
+fn main() {
+    use material_colors::{
+        color::Argb,
+        hct::{Cam16, Hct, ViewingConditions},
+        Error,
+    };
+
+    let color = Argb::from_u32(0xff6750a4);
+    let standard = Cam16::from(color);
+
+    assert_eq!(
+        ViewingConditions::current().adapting_luminance,
+        ViewingConditions::STANDARD.adapting_luminance
+    );
+
+    let dim = ViewingConditions::make(None, Some(5.0), Some(20.0), Some(1.0), None);
+
+    assert!(ViewingConditions::set_global(dim).is_ok());
+    assert_eq!(
+        ViewingConditions::set_global(dim),
+        Err(Error::ViewingConditionsAlreadySet)
+    );
+
+    let adapted = Cam16::from(color);
+    let expected = Cam16::fromi32_in_viewing_conditions(color, &dim);
+
+    assert!((adapted.chroma - expected.chroma).abs() < 1e-9);
+    assert!((adapted.chroma - standard.chroma).abs() > 1.0);
+    assert!((Hct::new(color).get_chroma() - expected.chroma).abs() < 1e-9);
+    assert_eq!(Argb::from(adapted), color);
+}